@@ -0,0 +1,54 @@
+use crate::{
+    core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
+    log_error, log_info,
+    models::commands::{
+        renew_acme_certificate_request::RenewAcmeCertificateRequest,
+        renew_acme_certificate_response::RenewAcmeCertificateResponse,
+    },
+};
+use clap::Args;
+use hyper::StatusCode;
+
+#[derive(Debug, Args)]
+pub struct AcmeRenewArgs {
+    /// Source (host) of an HTTPS or Secure IWS route added with '--acme'
+    pub source: String,
+}
+
+pub async fn acme_renew(args: AcmeRenewArgs) {
+    let renew_acme_certificate_request = RenewAcmeCertificateRequest {
+        source: args.source.clone(),
+    };
+
+    match execute(renew_acme_certificate_request).await {
+        Ok(_) => log_info!(
+            "Certificate renewal of '{}' is scheduled. Follow it with 'acme-status'",
+            args.source
+        ),
+        Err(error) => log_error!("{}", error.reason),
+    }
+}
+
+async fn execute(
+    input: RenewAcmeCertificateRequest,
+) -> Result<RenewAcmeCertificateResponse, RPCBaseError> {
+    let serialized_input = serde_json::to_value(input)
+        .map_err(|_| RPCBaseError::build("Object can not serialized"))?;
+
+    let rpc_client = RPC_CLIENT.read().await;
+    let rpc_call_response = rpc_client
+        .call("renew_acme_certificate", serialized_input)
+        .await?;
+
+    let response: RenewAcmeCertificateResponse = serde_json::from_value(rpc_call_response.result)
+        .map_err(|e| RPCBaseError::build(&format!("Response parse error: {}", e)))?;
+
+    if response.code != StatusCode::OK.as_u16() {
+        return Err(RPCBaseError::build(&format!(
+            "Error while requesting certificate renewal. Details: {}",
+            response.message
+        )));
+    }
+
+    Ok(response)
+}
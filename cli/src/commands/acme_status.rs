@@ -0,0 +1,120 @@
+use crate::{
+    commands::inspect_ssl_file::format_expiry,
+    core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
+    log_error, log_info,
+    models::{
+        commands::get_acme_certificates_response::GetAcmeCertificatesResponse,
+        entity::acme_certificate::AcmeCertificate,
+    },
+    utils::{json_utility::create_empty_json_object, time_utility::format_timestamp},
+};
+use clap::Args;
+use colored::Colorize;
+use hyper::StatusCode;
+use prettytable::{
+    format::{self, TableFormat},
+    Cell, Row, Table,
+};
+
+#[derive(Debug, Args)]
+pub struct AcmeStatusArgs {}
+
+pub async fn acme_status(_args: AcmeStatusArgs) {
+    let response = match execute().await {
+        Ok(response) => response,
+        Err(error) => {
+            log_error!("{}", error.reason);
+            return;
+        }
+    };
+
+    if response.acme_certificates.is_empty() {
+        log_info!("No route uses ACME certificates. Add one with 'add-https-route <source> <target> --acme'");
+        return;
+    }
+
+    print_acme_certificates(&response.acme_certificates);
+}
+
+async fn execute() -> Result<GetAcmeCertificatesResponse, RPCBaseError> {
+    let rpc_client = RPC_CLIENT.read().await;
+    let rpc_call_response = rpc_client
+        .call("get_acme_certificates", create_empty_json_object())
+        .await?;
+
+    let response: GetAcmeCertificatesResponse = serde_json::from_value(rpc_call_response.result)
+        .map_err(|e| RPCBaseError::build(&format!("Response parse error: {}", e)))?;
+
+    if response.code != StatusCode::OK.as_u16() {
+        return Err(RPCBaseError::build(&format!(
+            "Error while fetching ACME certificates. Details: {}",
+            response.message
+        )));
+    }
+
+    Ok(response)
+}
+
+fn print_acme_certificates(acme_certificates: &[AcmeCertificate]) {
+    let styled_header = format!("[ACME Certificates ({})]", acme_certificates.len())
+        .cyan()
+        .bold();
+    println!("{}", styled_header);
+
+    let mut table = Table::new();
+    table.set_format(table_format());
+    table.set_titles(Row::new(vec![
+        Cell::new("Source"),
+        Cell::new("Status"),
+        Cell::new("Valid Until"),
+        Cell::new("Next Attempt"),
+        Cell::new("Last Error"),
+    ]));
+
+    for acme_certificate in acme_certificates {
+        table.add_row(Row::new(vec![
+            Cell::new(&acme_certificate.source),
+            Cell::new(&format_acme_status(&acme_certificate.status)),
+            Cell::new(&acme_certificate.not_after.map(format_expiry).unwrap_or("-".to_string())),
+            Cell::new(
+                &acme_certificate
+                    .next_attempt_at
+                    .map(format_timestamp)
+                    .unwrap_or("-".to_string()),
+            ),
+            Cell::new(acme_certificate.last_error.as_deref().unwrap_or("-")),
+        ]));
+    }
+
+    table.printstd();
+}
+
+fn format_acme_status(status: &str) -> String {
+    match status {
+        "valid" => "Valid".green().to_string(),
+        "issuing" => "Issuing".yellow().to_string(),
+        "failed" => "Failed".red().to_string(),
+        _ => "Pending".to_string(),
+    }
+}
+
+fn table_format() -> TableFormat {
+    format::FormatBuilder::new()
+        .column_separator(' ')
+        .left_border('\0')
+        .right_border('\0')
+        .padding(2, 1)
+        .separators(
+            &[format::LinePosition::Top],
+            format::LineSeparator::new('─', '┬', '┌', '┐'),
+        )
+        .separators(
+            &[format::LinePosition::Bottom],
+            format::LineSeparator::new('─', '┴', '└', '┘'),
+        )
+        .separators(
+            &[format::LinePosition::Title],
+            format::LineSeparator::new('─', '┼', '├', '┤'),
+        )
+        .build()
+}
@@ -1,9 +1,10 @@
 use crate::{
     core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
     log_error, log_info,
-    models::
-        commands::{add_http_route_request::AddHttpRouteRequest, add_http_route_response::AddHttpRouteResponse}
-    ,
+    models::{
+        commands::{add_http_route_request::AddHttpRouteRequest, add_http_route_response::AddHttpRouteResponse},
+        entity::{header_rule::HeaderRule, traffic_policy::TrafficPolicy, upstream_pool::UpstreamPool},
+    },
 };
 use clap::Args;
 use hyper::StatusCode;
@@ -11,14 +12,120 @@ use hyper::StatusCode;
 #[derive(Debug, Args)]
 pub struct AddHttpRouteArgs {
     pub source: String,
-    pub target: String
-    // todo: implement traffic policy here ! 
+    pub target: String,
+    /// Additional upstream in 'host:port' or 'host:port@weight' format. Can be repeated
+    #[arg(long = "upstream")]
+    pub upstreams: Vec<String>,
+    /// Load balancing strategy: 'round-robin', 'weighted-round-robin', 'least-connections', 'random-two-choices', 'consistent-hash'
+    #[arg(long)]
+    pub strategy: Option<String>,
+    /// Hash key of consistent hash strategy: 'ip', 'header:<name>', 'cookie:<name>'
+    #[arg(long)]
+    pub hash_key: Option<String>,
+    /// Path which is probed on every upstream by active health checks, e.g. '/health'
+    #[arg(long)]
+    pub health_check: Option<String>,
+    /// Interval of active health checks in seconds
+    #[arg(long)]
+    pub health_check_interval: Option<u64>,
+    /// Consecutive failed requests which eject an upstream for a while (passive health check)
+    #[arg(long)]
+    pub max_failures: Option<u32>,
+    /// PEM bundle of CA certificates trusted for 'https://' upstreams
+    #[arg(long)]
+    pub upstream_ca: Option<String>,
+    /// Server name sent to 'https://' upstreams by SNI instead of target host
+    #[arg(long)]
+    pub upstream_sni: Option<String>,
+    /// Client certificate presented to 'https://' upstreams (mTLS)
+    #[arg(long)]
+    pub upstream_client_cert: Option<String>,
+    /// Private key of upstream client certificate
+    #[arg(long)]
+    pub upstream_client_key: Option<String>,
+    /// Skips certificate verification of 'https://' upstreams. Only for lab setups
+    #[arg(long)]
+    pub upstream_insecure: bool,
+    /// Forwarding header mode: 'append' (default) adds this hop to incoming chains, 'overwrite' discards them
+    #[arg(long)]
+    pub forwarding_mode: Option<String>,
+    /// Forwarding header sent to upstream: 'x-forwarded-for', 'x-forwarded-proto', 'x-forwarded-host', 'x-forwarded-port', 'forwarded', 'via'. Can be repeated
+    #[arg(long = "forward-header")]
+    pub forward_headers: Vec<String>,
+    // todo: implement traffic policy here !
+    /// Request header rule: 'add:<name>=<value>', 'set:<name>=<value>', 'remove:<name>' or 'rename:<name>=<new name>'. Values may use $client_ip, $host, $request_id & $route_source. Can be repeated
+    #[arg(long = "request-header")]
+    pub request_headers: Vec<String>,
+    /// Response header rule, same format as '--request-header'. Can be repeated
+    #[arg(long = "response-header")]
+    pub response_headers: Vec<String>,
 }
 
 pub async fn add_http_route(args: AddHttpRouteArgs) {
+    let upstream_pool = match UpstreamPool::from_args(
+        &args.target,
+        &args.upstreams,
+        &args.strategy,
+        &args.hash_key,
+    ) {
+        Ok(upstream_pool) => upstream_pool,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
+    let has_health_check_args = args.health_check.is_some()
+        || args.health_check_interval.is_some()
+        || args.max_failures.is_some();
+
+    if has_health_check_args && upstream_pool.is_none() {
+        log_error!("Health checks require an upstream pool. Please define '--upstream' or '--strategy'");
+        return;
+    }
+
+    let upstream_pool = upstream_pool.map(|upstream_pool| {
+        upstream_pool.with_health_checks(
+            &args.health_check,
+            &args.health_check_interval,
+            &args.max_failures,
+        )
+    });
+
+    let traffic_policy = TrafficPolicy::from_upstream_tls_args(
+        &args.upstream_ca,
+        &args.upstream_sni,
+        &args.upstream_client_cert,
+        &args.upstream_client_key,
+        args.upstream_insecure,
+    );
+
+    let traffic_policy = match TrafficPolicy::with_forwarded_header_args(
+        traffic_policy,
+        &args.forwarding_mode,
+        &args.forward_headers,
+    ) {
+        Ok(traffic_policy) => traffic_policy,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
+    let header_rules = match HeaderRule::from_args(&args.request_headers, &args.response_headers) {
+        Ok(header_rules) => header_rules,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
     let add_http_route_request = AddHttpRouteRequest {
         source: args.source,
         target: args.target,
+        upstream_pool,
+        traffic_policy,
+        header_rules,
     };
 
     let result = execute(add_http_route_request).await;
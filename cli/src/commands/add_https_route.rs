@@ -1,9 +1,15 @@
 use crate::{
     core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
     log_error, log_info,
-    models::commands::{
-        add_https_route_request::AddHttpsRouteRequest,
-        add_https_route_response::AddHttpsRouteResponse,
+    models::{
+        commands::{
+            add_https_route_request::AddHttpsRouteRequest,
+            add_https_route_response::AddHttpsRouteResponse,
+        },
+        entity::{
+            client_auth::ClientAuth, header_rule::HeaderRule, tls_settings::TlsSettings,
+            traffic_policy::TrafficPolicy, upstream_pool::UpstreamPool,
+        },
     },
 };
 use clap::Args;
@@ -13,16 +19,193 @@ use hyper::StatusCode;
 pub struct AddHttpsRouteArgs {
     pub source: String,
     pub target: String,
-    pub ssl_cert_path: String,
-    pub ssl_private_key_path: String,
+    /// Certificate (chain) path or '@vanguard/<name>' reference. Not needed with '--acme'
+    pub ssl_cert_path: Option<String>,
+    /// Private key path or '@vanguard/<name>' reference. Not needed with '--acme'
+    pub ssl_private_key_path: Option<String>,
+    /// Issues and renews certificate of source host automatically by ACME (Let's Encrypt)
+    #[arg(long)]
+    pub acme: bool,
+    /// PEM bundle of CA certificates which issue client certificates. Enables client certificate authentication (mTLS)
+    #[arg(long)]
+    pub client_ca: Option<String>,
+    /// Client certificate mode: 'required' (default) or 'optional'
+    #[arg(long)]
+    pub client_auth: Option<String>,
+    /// Client identity (subject, common name or SAN) allowed to reach the route. Can be repeated
+    #[arg(long = "allow-client")]
+    pub allowed_clients: Vec<String>,
+    /// Does not forward verified client identity to upstream by 'X-Client-Cert-*' headers
+    #[arg(long)]
+    pub hide_client_identity: bool,
+    /// Lowest TLS version accepted by the route: '1.2' or '1.3'
+    #[arg(long)]
+    pub tls_min_version: Option<String>,
+    /// Highest TLS version accepted by the route: '1.2' or '1.3'
+    #[arg(long)]
+    pub tls_max_version: Option<String>,
+    /// Cipher suite allowed by the route in preference order, e.g. 'TLS13_AES_128_GCM_SHA256'. Can be repeated
+    #[arg(long = "cipher-suite")]
+    pub cipher_suites: Vec<String>,
+    /// Key exchange group allowed by the route: 'X25519', 'secp256r1', 'secp384r1'. Can be repeated
+    #[arg(long = "kx-group")]
+    pub kx_groups: Vec<String>,
+    /// Protocol offered by ALPN, e.g. 'http/1.1' to disable HTTP/2. Can be repeated
+    #[arg(long = "alpn")]
+    pub alpn_protocols: Vec<String>,
+    /// Issues TLS session tickets for stateless session resumption
+    #[arg(long)]
+    pub session_tickets: bool,
+    /// Additional upstream in 'host:port' or 'host:port@weight' format. Can be repeated
+    #[arg(long = "upstream")]
+    pub upstreams: Vec<String>,
+    /// Load balancing strategy: 'round-robin', 'weighted-round-robin', 'least-connections', 'random-two-choices', 'consistent-hash'
+    #[arg(long)]
+    pub strategy: Option<String>,
+    /// Hash key of consistent hash strategy: 'ip', 'header:<name>', 'cookie:<name>'
+    #[arg(long)]
+    pub hash_key: Option<String>,
+    /// Path which is probed on every upstream by active health checks, e.g. '/health'
+    #[arg(long)]
+    pub health_check: Option<String>,
+    /// Interval of active health checks in seconds
+    #[arg(long)]
+    pub health_check_interval: Option<u64>,
+    /// Consecutive failed requests which eject an upstream for a while (passive health check)
+    #[arg(long)]
+    pub max_failures: Option<u32>,
+    /// PEM bundle of CA certificates trusted for 'https://' upstreams
+    #[arg(long)]
+    pub upstream_ca: Option<String>,
+    /// Server name sent to 'https://' upstreams by SNI instead of target host
+    #[arg(long)]
+    pub upstream_sni: Option<String>,
+    /// Client certificate presented to 'https://' upstreams (mTLS)
+    #[arg(long)]
+    pub upstream_client_cert: Option<String>,
+    /// Private key of upstream client certificate
+    #[arg(long)]
+    pub upstream_client_key: Option<String>,
+    /// Skips certificate verification of 'https://' upstreams. Only for lab setups
+    #[arg(long)]
+    pub upstream_insecure: bool,
+    /// Forwarding header mode: 'append' (default) adds this hop to incoming chains, 'overwrite' discards them
+    #[arg(long)]
+    pub forwarding_mode: Option<String>,
+    /// Forwarding header sent to upstream: 'x-forwarded-for', 'x-forwarded-proto', 'x-forwarded-host', 'x-forwarded-port', 'forwarded', 'via'. Can be repeated
+    #[arg(long = "forward-header")]
+    pub forward_headers: Vec<String>,
+    /// Request header rule: 'add:<name>=<value>', 'set:<name>=<value>', 'remove:<name>' or 'rename:<name>=<new name>'. Values may use $client_ip, $host, $request_id & $route_source. Can be repeated
+    #[arg(long = "request-header")]
+    pub request_headers: Vec<String>,
+    /// Response header rule, same format as '--request-header'. Can be repeated
+    #[arg(long = "response-header")]
+    pub response_headers: Vec<String>,
 }
 
 pub async fn add_https_route(args: AddHttpsRouteArgs) {
+    if !args.acme && (args.ssl_cert_path.is_none() || args.ssl_private_key_path.is_none()) {
+        log_error!("Please provide SSL certificate & private key paths or use '--acme'");
+        return;
+    }
+
+    let tls_settings = match TlsSettings::from_args(
+        &args.tls_min_version,
+        &args.tls_max_version,
+        &args.cipher_suites,
+        &args.kx_groups,
+        &args.alpn_protocols,
+        args.session_tickets,
+    ) {
+        Ok(tls_settings) => tls_settings,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
+    let client_auth = match ClientAuth::from_args(
+        &args.client_ca,
+        &args.client_auth,
+        &args.allowed_clients,
+        args.hide_client_identity,
+    ) {
+        Ok(client_auth) => client_auth,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
+    let upstream_pool = match UpstreamPool::from_args(
+        &args.target,
+        &args.upstreams,
+        &args.strategy,
+        &args.hash_key,
+    ) {
+        Ok(upstream_pool) => upstream_pool,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
+    let has_health_check_args = args.health_check.is_some()
+        || args.health_check_interval.is_some()
+        || args.max_failures.is_some();
+
+    if has_health_check_args && upstream_pool.is_none() {
+        log_error!("Health checks require an upstream pool. Please define '--upstream' or '--strategy'");
+        return;
+    }
+
+    let upstream_pool = upstream_pool.map(|upstream_pool| {
+        upstream_pool.with_health_checks(
+            &args.health_check,
+            &args.health_check_interval,
+            &args.max_failures,
+        )
+    });
+
+    let traffic_policy = TrafficPolicy::from_upstream_tls_args(
+        &args.upstream_ca,
+        &args.upstream_sni,
+        &args.upstream_client_cert,
+        &args.upstream_client_key,
+        args.upstream_insecure,
+    );
+
+    let traffic_policy = match TrafficPolicy::with_forwarded_header_args(
+        traffic_policy,
+        &args.forwarding_mode,
+        &args.forward_headers,
+    ) {
+        Ok(traffic_policy) => traffic_policy,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
+    let header_rules = match HeaderRule::from_args(&args.request_headers, &args.response_headers) {
+        Ok(header_rules) => header_rules,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
     let add_https_route_request = AddHttpsRouteRequest {
         source: args.source,
         target: args.target,
         ssl_cert_path: args.ssl_cert_path,
         ssl_private_key_path: args.ssl_private_key_path,
+        acme: args.acme,
+        client_auth,
+        tls: tls_settings,
+        upstream_pool,
+        traffic_policy,
+        header_rules,
     };
 
     let result = execute(add_https_route_request).await;
@@ -1,8 +1,11 @@
 use crate::{
     core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
     log_error, log_info,
-    models::commands::{
-        add_iws_route_request::AddIwsRouteRequest, add_iws_route_response::AddIwsRouteResponse,
+    models::{
+        commands::{
+            add_iws_route_request::AddIwsRouteRequest, add_iws_route_response::AddIwsRouteResponse,
+        },
+        entity::header_rule::HeaderRule,
     },
 };
 use clap::Args;
@@ -12,12 +15,27 @@ use hyper::StatusCode;
 pub struct AddIwsRouteArgs {
     pub source: String,
     pub serving_path: String,
+    /// Request header rule: 'add:<name>=<value>', 'set:<name>=<value>', 'remove:<name>' or 'rename:<name>=<new name>'. Values may use $client_ip, $host, $request_id & $route_source. Can be repeated
+    #[arg(long = "request-header")]
+    pub request_headers: Vec<String>,
+    /// Response header rule, same format as '--request-header'. Can be repeated
+    #[arg(long = "response-header")]
+    pub response_headers: Vec<String>,
 }
 
 pub async fn add_iws_route(args: AddIwsRouteArgs) {
+    let header_rules = match HeaderRule::from_args(&args.request_headers, &args.response_headers) {
+        Ok(header_rules) => header_rules,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
     let add_iws_route_request = AddIwsRouteRequest {
         source: args.source,
         serving_path: args.serving_path,
+        header_rules,
     };
 
     let result = execute(add_iws_route_request).await;
@@ -0,0 +1,89 @@
+use std::str::FromStr;
+
+use crate::{
+    common::enums::route_type::RouteType,
+    core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
+    log_error, log_info,
+    models::commands::{
+        add_path_rule_request::AddPathRuleRequest, add_path_rule_response::AddPathRuleResponse,
+    },
+};
+use clap::Args;
+use hyper::StatusCode;
+
+#[derive(Debug, Args)]
+pub struct AddPathRuleArgs {
+    /// Route type of host entry: 'http', 'https', 'iws', 'secure-iws'
+    pub route_type: String,
+    /// Source (host) of existing route
+    pub source: String,
+    /// Request path, prefix or regex to be matched
+    pub path: String,
+    /// Target URL for HTTP/HTTPS routes, serving path for IWS/Secure IWS routes
+    pub destination: String,
+    /// Path match type: 'prefix', 'exact', 'regex'
+    #[arg(long, default_value = "prefix")]
+    pub match_type: String,
+    /// Removes matched part of the path before forwarding request
+    #[arg(long, default_value_t = false)]
+    pub strip_prefix: bool,
+}
+
+pub async fn add_path_rule(args: AddPathRuleArgs) {
+    let parsed_route_type = RouteType::from_str(&args.route_type);
+    if parsed_route_type.is_err() || parsed_route_type == Ok(RouteType::All) {
+        log_error!("Not accepted route type. Please type one of following: 'http', 'https', 'iws', 'secure-iws'");
+        return;
+    }
+
+    let route_type = parsed_route_type.unwrap();
+    let is_proxy_route = route_type == RouteType::Http || route_type == RouteType::Https;
+
+    let add_path_rule_request = AddPathRuleRequest {
+        route_type,
+        source: args.source,
+        path: args.path,
+        match_type: args.match_type,
+        target: is_proxy_route.then(|| args.destination.clone()),
+        serving_path: (!is_proxy_route).then_some(args.destination),
+        strip_prefix: args.strip_prefix,
+    };
+
+    let response = match execute(add_path_rule_request).await {
+        Ok(response) => response,
+        Err(error_message) => {
+            log_error!("{}", error_message.reason);
+            return;
+        }
+    };
+
+    if response.code == StatusCode::OK.as_u16() {
+        log_info!("Path rule added successfully");
+    } else {
+        log_error!(
+            "An error occured while adding path rule: {}",
+            response.message
+        )
+    }
+}
+
+async fn execute(input: AddPathRuleRequest) -> Result<AddPathRuleResponse, RPCBaseError> {
+    let serialized_input = serde_json::to_value(input)
+        .map_err(|_| RPCBaseError::build("Object can not serialized"))?;
+
+    let lock = {
+        let rpc_client = RPC_CLIENT.read().await;
+        let rpc_call_response = rpc_client.call("add_path_rule", serialized_input).await?;
+        let result = rpc_call_response.result;
+
+        let code = &result["code"].as_i64().unwrap_or_default();
+        let message = &result["message"].as_str().unwrap_or_default().to_string();
+
+        Ok(AddPathRuleResponse {
+            code: code.to_owned() as u16,
+            message: message.to_owned(),
+        })
+    }?;
+
+    Ok(lock)
+}
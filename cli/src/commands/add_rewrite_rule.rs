@@ -0,0 +1,118 @@
+use std::str::FromStr;
+
+use crate::{
+    common::enums::route_type::RouteType,
+    core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
+    log_error, log_info,
+    models::{
+        commands::{
+            add_rewrite_rule_request::AddRewriteRuleRequest,
+            add_rewrite_rule_response::AddRewriteRuleResponse,
+        },
+        entity::rewrite_rule::RewriteCondition,
+    },
+};
+use clap::Args;
+use hyper::StatusCode;
+
+#[derive(Debug, Args)]
+pub struct AddRewriteRuleArgs {
+    /// Route type of host entry: 'http', 'https', 'iws', 'secure-iws'
+    pub route_type: String,
+    /// Source (host) of existing route
+    pub source: String,
+    /// Regex matched against request path. Captures are used as $1 or ${name}
+    pub pattern: String,
+    /// Rule action: 'rewrite', 'redirect', 'return'
+    pub action: String,
+    /// New path of rewrite rules, location of redirect rules
+    pub replacement: Option<String>,
+    /// Status of redirect (301, 302, 307, 308) or return rules
+    #[arg(long)]
+    pub status: Option<u16>,
+    /// Response body of return rules
+    #[arg(long)]
+    pub body: Option<String>,
+    /// Comma separated request methods, e.g. 'GET,HEAD'. A leading '!' negates the condition
+    #[arg(long = "if-method")]
+    pub if_method: Vec<String>,
+    /// Header condition as '<name>' or '<name>=<regex>'. A leading '!' negates the condition
+    #[arg(long = "if-header")]
+    pub if_header: Vec<String>,
+    /// Query parameter condition as '<name>' or '<name>=<regex>'. A leading '!' negates the condition
+    #[arg(long = "if-query")]
+    pub if_query: Vec<String>,
+    /// Comma separated ip addresses or CIDR blocks of client. A leading '!' negates the condition
+    #[arg(long = "if-client-ip")]
+    pub if_client_ip: Vec<String>,
+}
+
+pub async fn add_rewrite_rule(args: AddRewriteRuleArgs) {
+    let parsed_route_type = RouteType::from_str(&args.route_type);
+    if parsed_route_type.is_err() || parsed_route_type == Ok(RouteType::All) {
+        log_error!("Not accepted route type. Please type one of following: 'http', 'https', 'iws', 'secure-iws'");
+        return;
+    }
+
+    let conditions = match RewriteCondition::from_args(
+        &args.if_method,
+        &args.if_header,
+        &args.if_query,
+        &args.if_client_ip,
+    ) {
+        Ok(conditions) => conditions,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
+    let add_rewrite_rule_request = AddRewriteRuleRequest {
+        route_type: parsed_route_type.unwrap(),
+        source: args.source,
+        pattern: args.pattern,
+        action: args.action.to_lowercase(),
+        replacement: args.replacement,
+        status_code: args.status,
+        body: args.body,
+        conditions,
+    };
+
+    let response = match execute(add_rewrite_rule_request).await {
+        Ok(response) => response,
+        Err(error_message) => {
+            log_error!("{}", error_message.reason);
+            return;
+        }
+    };
+
+    if response.code == StatusCode::OK.as_u16() {
+        log_info!("Rewrite rule added successfully");
+    } else {
+        log_error!(
+            "An error occured while adding rewrite rule: {}",
+            response.message
+        )
+    }
+}
+
+async fn execute(input: AddRewriteRuleRequest) -> Result<AddRewriteRuleResponse, RPCBaseError> {
+    let serialized_input = serde_json::to_value(input)
+        .map_err(|_| RPCBaseError::build("Object can not serialized"))?;
+
+    let lock = {
+        let rpc_client = RPC_CLIENT.read().await;
+        let rpc_call_response = rpc_client.call("add_rewrite_rule", serialized_input).await?;
+        let result = rpc_call_response.result;
+
+        let code = &result["code"].as_i64().unwrap_or_default();
+        let message = &result["message"].as_str().unwrap_or_default().to_string();
+
+        Ok(AddRewriteRuleResponse {
+            code: code.to_owned() as u16,
+            message: message.to_owned(),
+        })
+    }?;
+
+    Ok(lock)
+}
@@ -1,9 +1,12 @@
 use crate::{
     core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
     log_error, log_info,
-    models::commands::{
-        add_secure_iws_route_request::AddSecureIwsRouteRequest,
-        add_secure_iws_route_response::AddSecureIwsRouteResponse,
+    models::{
+        commands::{
+            add_secure_iws_route_request::AddSecureIwsRouteRequest,
+            add_secure_iws_route_response::AddSecureIwsRouteResponse,
+        },
+        entity::{client_auth::ClientAuth, header_rule::HeaderRule, tls_settings::TlsSettings},
     },
 };
 use clap::Args;
@@ -13,16 +16,102 @@ use hyper::StatusCode;
 pub struct AddSecureIwsRouteArgs {
     pub source: String,
     pub serving_path: String,
-    pub ssl_cert_path: String,
-    pub ssl_private_key_path: String,
+    /// Certificate (chain) path or '@vanguard/<name>' reference. Not needed with '--acme'
+    pub ssl_cert_path: Option<String>,
+    /// Private key path or '@vanguard/<name>' reference. Not needed with '--acme'
+    pub ssl_private_key_path: Option<String>,
+    /// Issues and renews certificate of source host automatically by ACME (Let's Encrypt)
+    #[arg(long)]
+    pub acme: bool,
+    /// PEM bundle of CA certificates which issue client certificates. Enables client certificate authentication (mTLS)
+    #[arg(long)]
+    pub client_ca: Option<String>,
+    /// Client certificate mode: 'required' (default) or 'optional'
+    #[arg(long)]
+    pub client_auth: Option<String>,
+    /// Client identity (subject, common name or SAN) allowed to reach the route. Can be repeated
+    #[arg(long = "allow-client")]
+    pub allowed_clients: Vec<String>,
+    /// Does not forward verified client identity to upstream by 'X-Client-Cert-*' headers
+    #[arg(long)]
+    pub hide_client_identity: bool,
+    /// Lowest TLS version accepted by the route: '1.2' or '1.3'
+    #[arg(long)]
+    pub tls_min_version: Option<String>,
+    /// Highest TLS version accepted by the route: '1.2' or '1.3'
+    #[arg(long)]
+    pub tls_max_version: Option<String>,
+    /// Cipher suite allowed by the route in preference order, e.g. 'TLS13_AES_128_GCM_SHA256'. Can be repeated
+    #[arg(long = "cipher-suite")]
+    pub cipher_suites: Vec<String>,
+    /// Key exchange group allowed by the route: 'X25519', 'secp256r1', 'secp384r1'. Can be repeated
+    #[arg(long = "kx-group")]
+    pub kx_groups: Vec<String>,
+    /// Protocol offered by ALPN, e.g. 'http/1.1' to disable HTTP/2. Can be repeated
+    #[arg(long = "alpn")]
+    pub alpn_protocols: Vec<String>,
+    /// Issues TLS session tickets for stateless session resumption
+    #[arg(long)]
+    pub session_tickets: bool,
+    /// Request header rule: 'add:<name>=<value>', 'set:<name>=<value>', 'remove:<name>' or 'rename:<name>=<new name>'. Values may use $client_ip, $host, $request_id & $route_source. Can be repeated
+    #[arg(long = "request-header")]
+    pub request_headers: Vec<String>,
+    /// Response header rule, same format as '--request-header'. Can be repeated
+    #[arg(long = "response-header")]
+    pub response_headers: Vec<String>,
 }
 
 pub async fn add_secure_iws_route(args: AddSecureIwsRouteArgs) {
+    if !args.acme && (args.ssl_cert_path.is_none() || args.ssl_private_key_path.is_none()) {
+        log_error!("Please provide SSL certificate & private key paths or use '--acme'");
+        return;
+    }
+
+    let tls_settings = match TlsSettings::from_args(
+        &args.tls_min_version,
+        &args.tls_max_version,
+        &args.cipher_suites,
+        &args.kx_groups,
+        &args.alpn_protocols,
+        args.session_tickets,
+    ) {
+        Ok(tls_settings) => tls_settings,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
+    let client_auth = match ClientAuth::from_args(
+        &args.client_ca,
+        &args.client_auth,
+        &args.allowed_clients,
+        args.hide_client_identity,
+    ) {
+        Ok(client_auth) => client_auth,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
+    let header_rules = match HeaderRule::from_args(&args.request_headers, &args.response_headers) {
+        Ok(header_rules) => header_rules,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
     let add_secure_iws_route_request = AddSecureIwsRouteRequest {
         source: args.source,
         serving_path: args.serving_path,
         ssl_cert_path: args.ssl_cert_path,
         ssl_private_key_path: args.ssl_private_key_path,
+        acme: args.acme,
+        client_auth,
+        tls: tls_settings,
+        header_rules,
     };
 
     let result = execute(add_secure_iws_route_request).await;
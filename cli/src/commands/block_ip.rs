@@ -0,0 +1,130 @@
+use std::str::FromStr;
+
+use crate::{
+    common::enums::route_type::RouteType,
+    core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
+    log_error, log_info,
+    models::commands::{
+        block_ip_response::BlockIpResponse, ip_access_entry_request::IpAccessEntryRequest,
+    },
+};
+use clap::Args;
+use hyper::StatusCode;
+
+#[derive(Debug, Args)]
+pub struct BlockIpArgs {
+    /// IPv4/IPv6 address or CIDR block (e.g. 203.0.113.7, 10.0.0.0/8, 2001:db8::/32)
+    pub ip: String,
+    /// Level of the list: 'global', 'http', 'https' or 'route'
+    #[arg(long, default_value = "global")]
+    pub scope: String,
+    /// List which entry is added into: 'deny' or 'allow'
+    #[arg(long, default_value = "deny")]
+    pub list: String,
+    /// Route type of host entry for 'route' scope: 'http', 'https', 'iws', 'secure-iws'
+    #[arg(long)]
+    pub route_type: Option<String>,
+    /// Source (host) of existing route for 'route' scope
+    #[arg(long)]
+    pub source: Option<String>,
+}
+
+pub async fn block_ip(args: BlockIpArgs) {
+    let block_ip_request = match build_ip_access_entry_request(
+        &args.ip,
+        &args.scope,
+        &args.list,
+        &args.route_type,
+        &args.source,
+    ) {
+        Ok(request) => request,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
+    let response = match execute(block_ip_request).await {
+        Ok(response) => response,
+        Err(error_message) => {
+            log_error!("{}", error_message.reason);
+            return;
+        }
+    };
+
+    if response.code == StatusCode::OK.as_u16() {
+        log_info!("'{}' is added into {} list of {} scope", args.ip, args.list, args.scope);
+    } else {
+        log_error!(
+            "Error while adding '{}' into {} list. Details: {}",
+            args.ip,
+            args.list,
+            response.message
+        )
+    }
+}
+
+/// Validates scope related arguments of `block-ip` & `unblock-ip` commands
+pub fn build_ip_access_entry_request(
+    ip: &str,
+    scope: &str,
+    list: &str,
+    route_type: &Option<String>,
+    source: &Option<String>,
+) -> Result<IpAccessEntryRequest, String> {
+    let scope = scope.to_lowercase();
+    let list = list.to_lowercase();
+
+    if !matches!(scope.as_str(), "global" | "http" | "https" | "route") {
+        return Err(
+            "Not accepted scope. Please type one of following: 'global', 'http', 'https', 'route'"
+                .to_string(),
+        );
+    }
+
+    if !matches!(list.as_str(), "allow" | "deny") {
+        return Err("Not accepted list. Please type one of following: 'allow', 'deny'".to_string());
+    }
+
+    let mut request = IpAccessEntryRequest {
+        ip: ip.to_string(),
+        scope,
+        list,
+        route_type: None,
+        source: None,
+    };
+
+    if request.scope != "route" {
+        return Ok(request);
+    }
+
+    let parsed_route_type = route_type
+        .as_deref()
+        .map(RouteType::from_str)
+        .unwrap_or(Ok(RouteType::All));
+
+    match parsed_route_type {
+        Ok(RouteType::All) | Err(_) => {
+            return Err("Please provide --route-type for 'route' scope: 'http', 'https', 'iws', 'secure-iws'".to_string());
+        }
+        Ok(route_type) => request.route_type = Some(route_type),
+    }
+
+    if source.is_none() {
+        return Err("Please provide --source for 'route' scope".to_string());
+    }
+
+    request.source = source.clone();
+    Ok(request)
+}
+
+async fn execute(input: IpAccessEntryRequest) -> Result<BlockIpResponse, RPCBaseError> {
+    let serialized_input = serde_json::to_value(input)
+        .map_err(|_| RPCBaseError::build("Object can not serialized"))?;
+
+    let rpc_client = RPC_CLIENT.read().await;
+    let rpc_call_response = rpc_client.call("block_ip", serialized_input).await?;
+
+    serde_json::from_value(rpc_call_response.result)
+        .map_err(|e| RPCBaseError::build(&format!("Response parse error: {}", e)))
+}
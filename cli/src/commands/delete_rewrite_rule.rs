@@ -0,0 +1,79 @@
+use std::str::FromStr;
+
+use crate::{
+    common::enums::route_type::RouteType,
+    core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
+    log_error, log_info,
+    models::commands::{
+        delete_rewrite_rule_request::DeleteRewriteRuleRequest,
+        delete_rewrite_rule_response::DeleteRewriteRuleResponse,
+    },
+};
+use clap::Args;
+use hyper::StatusCode;
+
+#[derive(Debug, Args)]
+pub struct DeleteRewriteRuleArgs {
+    /// Route type of host entry: 'http', 'https', 'iws', 'secure-iws'
+    pub route_type: String,
+    /// Source (host) of existing route
+    pub source: String,
+    /// Pattern of the rewrite rule to be removed
+    pub pattern: String,
+}
+
+pub async fn delete_rewrite_rule(args: DeleteRewriteRuleArgs) {
+    let parsed_route_type = RouteType::from_str(&args.route_type);
+    if parsed_route_type.is_err() || parsed_route_type == Ok(RouteType::All) {
+        log_error!("Not accepted route type. Please type one of following: 'http', 'https', 'iws', 'secure-iws'");
+        return;
+    }
+
+    let delete_rewrite_rule_request = DeleteRewriteRuleRequest {
+        route_type: parsed_route_type.unwrap(),
+        source: args.source.clone(),
+        pattern: args.pattern.clone(),
+    };
+
+    let response = match execute(delete_rewrite_rule_request).await {
+        Ok(response) => response,
+        Err(error_message) => {
+            log_error!("{}", error_message.reason);
+            return;
+        }
+    };
+
+    if response.code == StatusCode::OK.as_u16() {
+        log_info!("Rewrite rule deleted successfully");
+    } else {
+        log_error!(
+            "Error while deleting rewrite rule '{}' of route: {}. Details: {}",
+            args.pattern,
+            args.source,
+            response.message
+        )
+    }
+}
+
+async fn execute(input: DeleteRewriteRuleRequest) -> Result<DeleteRewriteRuleResponse, RPCBaseError> {
+    let serialized_input = serde_json::to_value(input)
+        .map_err(|_| RPCBaseError::build("Object can not serialized"))?;
+
+    let lock = {
+        let rpc_client = RPC_CLIENT.read().await;
+        let rpc_call_response = rpc_client
+            .call("delete_rewrite_rule", serialized_input)
+            .await?;
+        let result = rpc_call_response.result;
+
+        let code = &result["code"].as_i64().unwrap_or_default();
+        let message = &result["message"].as_str().unwrap_or_default().to_string();
+
+        Ok(DeleteRewriteRuleResponse {
+            code: code.to_owned() as u16,
+            message: message.to_owned(),
+        })
+    }?;
+
+    Ok(lock)
+}
@@ -0,0 +1,46 @@
+use crate::{
+    core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
+    log_error, log_info,
+    models::commands::{
+        delete_ssl_file_response::DeleteSslFileResponse, ssl_file_request::SslFileRequest,
+    },
+};
+use clap::Args;
+use hyper::StatusCode;
+
+#[derive(Debug, Args)]
+pub struct DeleteSslFileArgs {
+    /// Name (or '@vanguard/<name>' reference) of an uploaded file
+    pub name: String,
+}
+
+pub async fn delete_ssl_file(args: DeleteSslFileArgs) {
+    let delete_ssl_file_request = SslFileRequest {
+        name: args.name.clone(),
+    };
+
+    match execute(delete_ssl_file_request).await {
+        Ok(_) => log_info!("SSL file '{}' is deleted", args.name),
+        Err(error) => log_error!("{}", error.reason),
+    }
+}
+
+async fn execute(input: SslFileRequest) -> Result<DeleteSslFileResponse, RPCBaseError> {
+    let serialized_input = serde_json::to_value(input)
+        .map_err(|_| RPCBaseError::build("Object can not serialized"))?;
+
+    let rpc_client = RPC_CLIENT.read().await;
+    let rpc_call_response = rpc_client.call("delete_ssl_file", serialized_input).await?;
+
+    let response: DeleteSslFileResponse = serde_json::from_value(rpc_call_response.result)
+        .map_err(|e| RPCBaseError::build(&format!("Response parse error: {}", e)))?;
+
+    if response.code != StatusCode::OK.as_u16() {
+        return Err(RPCBaseError::build(&format!(
+            "Error while deleting SSL file. Details: {}",
+            response.message
+        )));
+    }
+
+    Ok(response)
+}
@@ -10,8 +10,10 @@ use crate::{
             get_route_list_response::GetRouteListResponse,
         },
         entity::{
-            http_route::HttpRoute, https_route::HttpsRoute, iws_route::IwsRoute,
-            secure_iws_route::SecureIwsRoute,
+            client_auth::ClientAuth, degraded_route::DegradedRoute, header_rule::HeaderRule, http_route::HttpRoute,
+            https_route::HttpsRoute, ip_access::IpAccess, iws_route::IwsRoute,
+            path_rule::PathRule, rewrite_rule::RewriteRule, secure_iws_route::SecureIwsRoute, tls_settings::TlsSettings,
+            traffic_policy::TrafficPolicy, upstream_pool::UpstreamPool,
         },
     },
     utils::console::{print_colored, separator},
@@ -57,18 +59,20 @@ pub async fn get_route_list(args: GetRouteListArgs) {
         return;
     }
 
+    let degraded_routes = get_route_list_response.degraded_routes.unwrap_or_default();
+
     separator(36);
     if route_type == RouteType::Http || route_type == RouteType::All {
         print_http_routes(get_route_list_response.http_routes);
     }
     if route_type == RouteType::Https || route_type == RouteType::All {
-        print_https_routes(get_route_list_response.https_routes);
+        print_https_routes(get_route_list_response.https_routes, &degraded_routes);
     }
     if route_type == RouteType::Iws || route_type == RouteType::All {
         print_iws_routes(get_route_list_response.iws_routes);
     }
     if route_type == RouteType::SecureIws || route_type == RouteType::All {
-        print_secure_iws_routes(get_route_list_response.secure_iws_routes);
+        print_secure_iws_routes(get_route_list_response.secure_iws_routes, &degraded_routes);
     }
     separator(36);
 }
@@ -117,10 +121,17 @@ fn print_http_routes(http_routes: Option<HashMap<String, HttpRoute>>) {
         print_colored(index.as_str(), Color::Yellow);
         println!("  Domain: {}", domain);
         println!("  Target: {}", route.target);
+        print_upstream_pool(&route.upstream_pool);
+        print_upstream_tls(&route.traffic_policy);
+        print_forwarded_headers(&route.traffic_policy);
+        print_ip_access(&route.ip_access);
+        print_header_rules(&route.header_rules);
+        print_rewrite_rules(&route.rewrite_rules);
+        print_path_rules(&route.path_rules);
     }
 }
 
-fn print_https_routes(https_routes: Option<HashMap<String, HttpsRoute>>) {
+fn print_https_routes(https_routes: Option<HashMap<String, HttpsRoute>>, degraded_routes: &[DegradedRoute]) {
     if https_routes.is_none() {
         return;
     }
@@ -143,6 +154,19 @@ fn print_https_routes(https_routes: Option<HashMap<String, HttpsRoute>>) {
         println!("  Target: {}", route.target);
         println!("  SSL Certificate path: {}", ssl_cert_path);
         println!("  SSL Private Key path: {}", ssl_private_key_path);
+        if route.ssl_context.acme.unwrap_or(false) {
+            println!("  SSL Certificate: Managed by ACME ('acme-status' for details)");
+        }
+        print_degraded_state(domain, degraded_routes);
+        print_client_auth(&route.ssl_context.client_auth);
+        print_tls_settings(&route.ssl_context.tls);
+        print_upstream_pool(&route.upstream_pool);
+        print_upstream_tls(&route.traffic_policy);
+        print_forwarded_headers(&route.traffic_policy);
+        print_ip_access(&route.ip_access);
+        print_header_rules(&route.header_rules);
+        print_rewrite_rules(&route.rewrite_rules);
+        print_path_rules(&route.path_rules);
     }
 }
 
@@ -166,10 +190,17 @@ fn print_iws_routes(iws_routes: Option<HashMap<String, IwsRoute>>) {
         print_colored(index.as_str(), Color::Yellow);
         println!("  Domain: {}", domain);
         println!("  Serving Path: {}", route.serving_path);
+        print_ip_access(&route.ip_access);
+        print_header_rules(&route.header_rules);
+        print_rewrite_rules(&route.rewrite_rules);
+        print_path_rules(&route.path_rules);
     }
 }
 
-fn print_secure_iws_routes(secure_iws_routes: Option<HashMap<String, SecureIwsRoute>>) {
+fn print_secure_iws_routes(
+    secure_iws_routes: Option<HashMap<String, SecureIwsRoute>>,
+    degraded_routes: &[DegradedRoute],
+) {
     if secure_iws_routes.is_none() {
         return;
     }
@@ -194,5 +225,236 @@ fn print_secure_iws_routes(secure_iws_routes: Option<HashMap<String, SecureIwsRo
         println!("  Target: {}", route.serving_path);
         println!("  SSL Certificate path: {}", ssl_cert_path);
         println!("  SSL Private Key path: {}", ssl_private_key_path);
+        if route.ssl_context.acme.unwrap_or(false) {
+            println!("  SSL Certificate: Managed by ACME ('acme-status' for details)");
+        }
+        print_degraded_state(domain, degraded_routes);
+        print_client_auth(&route.ssl_context.client_auth);
+        print_tls_settings(&route.ssl_context.tls);
+        print_ip_access(&route.ip_access);
+        print_header_rules(&route.header_rules);
+        print_rewrite_rules(&route.rewrite_rules);
+        print_path_rules(&route.path_rules);
+    }
+}
+
+fn print_degraded_state(domain: &str, degraded_routes: &[DegradedRoute]) {
+    let Some(degraded_route) = degraded_routes.iter().find(|degraded_route| degraded_route.source == domain) else {
+        return;
+    };
+
+    let served_with = match degraded_route.is_fallback_certificate_served {
+        true => "served with fallback certificate",
+        false => "not served over TLS",
+    };
+
+    print_colored(&format!("  Degraded ({}): {}", served_with, degraded_route.reason), Color::Red);
+}
+
+fn print_client_auth(client_auth: &Option<ClientAuth>) {
+    let Some(client_auth) = client_auth else {
+        return;
+    };
+
+    println!(
+        "  Client Certificates: {} (CA: {})",
+        client_auth.mode.as_deref().unwrap_or("required"),
+        client_auth.ca_bundle_path
+    );
+
+    if let Some(ref allowed_identities) = client_auth.allowed_identities {
+        println!("  Allowed Clients: {}", allowed_identities.join(", "));
+    }
+}
+
+fn print_tls_settings(tls_settings: &Option<TlsSettings>) {
+    let Some(tls_settings) = tls_settings else {
+        return;
+    };
+
+    if tls_settings.min_version.is_some() || tls_settings.max_version.is_some() {
+        println!(
+            "  TLS Versions: {} - {}",
+            tls_settings.min_version.as_deref().unwrap_or("default"),
+            tls_settings.max_version.as_deref().unwrap_or("default")
+        );
+    }
+
+    if let Some(ref cipher_suites) = tls_settings.cipher_suites {
+        println!("  Cipher Suites: {}", cipher_suites.join(", "));
+    }
+
+    if let Some(ref kx_groups) = tls_settings.kx_groups {
+        println!("  Key Exchange Groups: {}", kx_groups.join(", "));
+    }
+
+    if let Some(ref alpn_protocols) = tls_settings.alpn_protocols {
+        println!("  ALPN Protocols: {}", alpn_protocols.join(", "));
+    }
+}
+
+fn print_upstream_pool(upstream_pool: &Option<UpstreamPool>) {
+    let Some(upstream_pool) = upstream_pool else {
+        return;
+    };
+
+    match &upstream_pool.hash_key {
+        Some(hash_key) => println!("  Load Balancing: {} ({})", upstream_pool.strategy, hash_key),
+        None => println!("  Load Balancing: {}", upstream_pool.strategy),
+    }
+
+    for upstream in &upstream_pool.upstreams {
+        println!("    {} (weight: {})", upstream.target, upstream.weight);
+    }
+
+    if let Some(health_check) = &upstream_pool.health_check {
+        let path = health_check.path.as_deref().unwrap_or("/");
+        match health_check.interval {
+            Some(interval) => println!("  Health Check: GET {} every {}s", path, interval),
+            None => println!("  Health Check: GET {}", path),
+        }
+    }
+
+    if let Some(outlier_detection) = &upstream_pool.outlier_detection {
+        match outlier_detection.consecutive_failures {
+            Some(consecutive_failures) => {
+                println!("  Outlier Detection: eject after {} failures", consecutive_failures)
+            }
+            None => println!("  Outlier Detection: enabled"),
+        }
+    }
+}
+
+fn print_upstream_tls(traffic_policy: &Option<TrafficPolicy>) {
+    let Some(upstream_tls) = traffic_policy
+        .as_ref()
+        .and_then(|traffic_policy| traffic_policy.upstream_settings.tls.as_ref())
+    else {
+        return;
+    };
+
+    println!("  Upstream TLS:");
+    if let Some(ca_bundle_path) = &upstream_tls.ca_bundle_path {
+        println!("    CA Bundle: {}", ca_bundle_path);
+    }
+    if let Some(sni_override) = &upstream_tls.sni_override {
+        println!("    SNI: {}", sni_override);
+    }
+    if let Some(client_certificate_path) = &upstream_tls.client_certificate_path {
+        println!("    Client Certificate: {}", client_certificate_path);
+    }
+    if upstream_tls.insecure_skip_verify == Some(true) {
+        println!("    Certificate Verification: Disabled");
+    }
+}
+
+fn print_forwarded_headers(traffic_policy: &Option<TrafficPolicy>) {
+    let Some(forwarded_headers) = traffic_policy
+        .as_ref()
+        .and_then(|traffic_policy| traffic_policy.forwarded_headers.as_ref())
+    else {
+        return;
+    };
+
+    println!("  Forwarding Headers:");
+    if let Some(mode) = &forwarded_headers.mode {
+        println!("    Mode: {}", mode);
+    }
+    if let Some(headers) = &forwarded_headers.headers {
+        println!("    Headers: {}", headers.join(", "));
+    }
+}
+
+fn print_header_rules(header_rules: &[HeaderRule]) {
+    if header_rules.is_empty() {
+        return;
+    }
+
+    println!("  Header Rules:");
+    for header_rule in header_rules {
+        let rule = match (&header_rule.value, &header_rule.new_name) {
+            (Some(value), _) => format!("{}: {}", header_rule.name, value),
+            (None, Some(new_name)) => format!("{} -> {}", header_rule.name, new_name),
+            (None, None) => header_rule.name.clone(),
+        };
+
+        println!("    [{} {}] {}", header_rule.phase, header_rule.action, rule);
+    }
+}
+
+fn print_ip_access(ip_access: &Option<IpAccess>) {
+    let Some(ip_access) = ip_access else {
+        return;
+    };
+
+    if !ip_access.deny.is_empty() {
+        println!("  IP Deny List: {}", ip_access.deny.join(", "));
+    }
+    if !ip_access.allow.is_empty() {
+        println!("  IP Allow List: {}", ip_access.allow.join(", "));
+    }
+    if let Some(forbidden_page_path) = &ip_access.forbidden_page_path {
+        println!("  Forbidden Page: {}", forbidden_page_path);
+    }
+}
+
+fn print_path_rules(path_rules: &[PathRule]) {
+    if path_rules.is_empty() {
+        return;
+    }
+
+    println!("  Path Rules:");
+    for path_rule in path_rules {
+        let destination = path_rule
+            .target
+            .as_ref()
+            .or(path_rule.serving_path.as_ref())
+            .cloned()
+            .unwrap_or_default();
+
+        let strip_prefix = if path_rule.strip_prefix { " (strip prefix)" } else { "" };
+
+        println!(
+            "    [{}] {} -> {}{}",
+            path_rule.match_type, path_rule.path, destination, strip_prefix
+        );
+    }
+}
+
+fn print_rewrite_rules(rewrite_rules: &[RewriteRule]) {
+    if rewrite_rules.is_empty() {
+        return;
+    }
+
+    println!("  Rewrite Rules:");
+    for rewrite_rule in rewrite_rules {
+        let status = rewrite_rule
+            .status_code
+            .map(|status_code| format!(" {}", status_code))
+            .unwrap_or_default();
+
+        let result = match (&rewrite_rule.replacement, &rewrite_rule.body) {
+            (Some(replacement), _) => format!(" -> {}", replacement),
+            (None, Some(body)) => format!(" \"{}\"", body),
+            (None, None) => String::new(),
+        };
+
+        println!(
+            "    [{}{}] {}{}",
+            rewrite_rule.action, status, rewrite_rule.pattern, result
+        );
+
+        for condition in &rewrite_rule.conditions {
+            let negation = if condition.negate { "not " } else { "" };
+            let subject = match condition.name {
+                Some(ref name) => format!("{} {}", condition.condition_type, name),
+                None => condition.condition_type.clone(),
+            };
+
+            match condition.value {
+                Some(ref value) => println!("      if {} {}matches {}", subject, negation, value),
+                None => println!("      if {} {}exists", subject, negation),
+            }
+        }
     }
 }
@@ -1,84 +1,111 @@
 use crate::{
+    common::enums::ssl_file_type::SSlFileType,
     core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
-    log_error,
+    log_error, log_info,
     models::{
         commands::get_uploaded_ssl_file_list_response::GetUploadedSslFileListResponse,
         entity::ssl_file::SSlFile,
     },
-    utils::{console::{print_colored, separator}, json_utility::create_empty_json_object},
+    utils::json_utility::create_empty_json_object,
 };
 use clap::Args;
-use crossterm::style::Color;
+use colored::Colorize;
 use hyper::StatusCode;
+use prettytable::{
+    format::{self, TableFormat},
+    Cell, Row, Table,
+};
 
 #[derive(Debug, Args)]
 pub struct GetSslFilesArgs {}
 
 pub async fn get_ssl_files(_args: GetSslFilesArgs) {
-    let result = execute().await;
+    let response = match execute().await {
+        Ok(response) => response,
+        Err(error) => {
+            log_error!("{}", error.reason);
+            return;
+        }
+    };
 
-    if result.is_err() {
-        let error_message = result.unwrap_err();
-        log_error!("{}", error_message.reason);
+    if response.ssl_file_list.is_empty() {
+        log_info!("No SSL file is uploaded yet. Use 'upload-ssl-file <name> <path>' to upload one");
         return;
     }
 
-    let response = result.unwrap();
+    print_ssl_files(&response.ssl_file_list);
+}
+
+async fn execute() -> Result<GetUploadedSslFileListResponse, RPCBaseError> {
+    let rpc_client = RPC_CLIENT.read().await;
+    let rpc_call_response = rpc_client
+        .call("get_uploaded_ssl_file_list", create_empty_json_object())
+        .await?;
+
+    let response: GetUploadedSslFileListResponse =
+        serde_json::from_value(rpc_call_response.result)
+            .map_err(|e| RPCBaseError::build(&format!("Response parse error: {}", e)))?;
 
     if response.code != StatusCode::OK.as_u16() {
-        log_error!(
+        return Err(RPCBaseError::build(&format!(
             "Error while fetching SSL file list. Details: {}",
             response.message
-        );
-        return;
+        )));
     }
 
-    print_ssl_files(response.ssl_file_list);
+    Ok(response)
 }
 
-async fn execute() -> Result<GetUploadedSslFileListResponse, RPCBaseError> {
-    let serialized_input = create_empty_json_object();
+fn print_ssl_files(ssl_files: &[SSlFile]) {
+    let styled_header = format!("[Uploaded SSL Files ({})]", ssl_files.len()).cyan().bold();
+    println!("{}", styled_header);
 
-    let lock = {
-        let rpc_client = RPC_CLIENT.read().await;
-        // Assuming the RPC method name is "get_uploaded_ssl_file_list"
-        let rpc_call_response = rpc_client
-            .call("get_uploaded_ssl_file_list", serialized_input)
-            .await?;
-        let result = rpc_call_response.result;
+    let mut table = Table::new();
+    table.set_format(table_format());
+    table.set_titles(Row::new(vec![
+        Cell::new("Name"),
+        Cell::new("Type"),
+        Cell::new("Size"),
+        Cell::new("Reference"),
+    ]));
 
-        // Handling potential different response structures (string vs object)
-        // Similar to get_route_list, checking if it returns a string first
-        if let Some(json_string) = result.as_str() {
-             let response: GetUploadedSslFileListResponse = serde_json::from_str(json_string)
-            .map_err(|e| RPCBaseError::build(&format!("Yanıt ayrıştırma hatası: {}", e)))?;
-             Ok(response)
-        } else {
-            // If it's already an object, try to deserialize directly
-             let response: GetUploadedSslFileListResponse = serde_json::from_value(result)
-            .map_err(|e| RPCBaseError::build(&format!("Yanıt ayrıştırma hatası: {}", e)))?;
-             Ok(response)
-        }
-    }?;
+    for ssl_file in ssl_files {
+        table.add_row(Row::new(vec![
+            Cell::new(&ssl_file.name),
+            Cell::new(&format_ssl_file_type(&ssl_file.file_type)),
+            Cell::new(&format!("{} bytes", ssl_file.size)),
+            Cell::new(&ssl_file.reference),
+        ]));
+    }
 
-    Ok(lock)
+    table.printstd();
 }
 
-fn print_ssl_files(ssl_files: Vec<SSlFile>) {
-    separator(36);
-    println!("\n--- Uploaded SSL Files ({}) ---", ssl_files.len());
-
-    if ssl_files.is_empty() {
-        println!("  (No SSL files found)");
-        separator(36);
-        return;
+pub fn format_ssl_file_type(file_type: &SSlFileType) -> String {
+    match file_type {
+        SSlFileType::PemCertificate => "Certificate".to_string(),
+        SSlFileType::PemPrivateKey => "Private Key".to_string(),
+        SSlFileType::Invalid => "Invalid".red().to_string(),
     }
+}
 
-    for (i, ssl_file) in ssl_files.iter().enumerate() {
-        let index = format!("#{}", i + 1);
-        print_colored(index.as_str(), Color::Yellow);
-        println!("  Domain: {}", ssl_file.domain);
-        println!("  Type: {:?}", ssl_file.file_type);
-    }
-    separator(36);
+fn table_format() -> TableFormat {
+    format::FormatBuilder::new()
+        .column_separator(' ')
+        .left_border('\0')
+        .right_border('\0')
+        .padding(2, 1)
+        .separators(
+            &[format::LinePosition::Top],
+            format::LineSeparator::new('─', '┬', '┌', '┐'),
+        )
+        .separators(
+            &[format::LinePosition::Bottom],
+            format::LineSeparator::new('─', '┴', '└', '┘'),
+        )
+        .separators(
+            &[format::LinePosition::Title],
+            format::LineSeparator::new('─', '┼', '├', '┤'),
+        )
+        .build()
 }
@@ -3,17 +3,21 @@ use crate::{
     core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
     log_error,
     models::{
-        commands::get_status_response::GetStatusResponse,
+        commands::{
+            get_status_response::GetStatusResponse,
+            get_upstream_health_response::GetUpstreamHealthResponse,
+        },
         entity::{
-            engine_http_server_config::EngineHttpServerConfig,
+            degraded_route::DegradedRoute, engine_http_server_config::EngineHttpServerConfig,
             engine_https_server_config::EngineHttpsServerConfig,
-            engine_logger_config::EngineLoggerConfig,
+            engine_logger_config::EngineLoggerConfig, upstream_health::UpstreamHealth,
         },
     },
     utils::{
         console::separator, json_utility::create_empty_json_object, text_utility::status_flag,
     },
 };
+use chrono::{Local, TimeZone};
 use colored::Colorize;
 use hyper::StatusCode;
 use prettytable::{
@@ -41,6 +45,29 @@ pub async fn get_status() {
     print_table_header();
     print_status_table(&get_status_response);
     separator(36);
+
+    if !get_status_response.degraded_routes.is_empty() {
+        print_degraded_routes_table(&get_status_response.degraded_routes);
+        separator(36);
+    }
+
+    let get_upstream_health_response = match execute_upstream_health().await {
+        Ok(response) => response,
+        Err(error) => {
+            log_error!("{}", error.reason);
+            return;
+        }
+    };
+
+    if get_upstream_health_response.code != StatusCode::OK.as_u16() {
+        log_error!("An error occured while getting upstream health of Vanguard Engine.");
+        return;
+    }
+
+    if !get_upstream_health_response.upstreams.is_empty() {
+        print_upstream_health_table(&get_upstream_health_response.upstreams);
+        separator(36);
+    }
 }
 
 async fn execute() -> Result<GetStatusResponse, RPCBaseError> {
@@ -60,6 +87,16 @@ async fn execute() -> Result<GetStatusResponse, RPCBaseError> {
     Ok(lock)
 }
 
+async fn execute_upstream_health() -> Result<GetUpstreamHealthResponse, RPCBaseError> {
+    let request = create_empty_json_object();
+
+    let rpc_client = RPC_CLIENT.read().await;
+    let rpc_call_response = rpc_client.call("get_upstream_health", request).await?;
+
+    serde_json::from_value(rpc_call_response.result)
+        .map_err(|e| RPCBaseError::build(&format!("Response parse error: {}", e)))
+}
+
 fn print_table_header() {
     let styled_header = format!("[{}]", "Vanguard Engine Status").cyan().bold();
     println!("{}", styled_header);
@@ -288,3 +325,109 @@ fn print_config_file(table: &mut Table, is_config_loaded_successfully: bool, con
         Cell::new(formatted_config_file_path.as_str()),
     ]));
 }
+
+fn print_degraded_routes_table(degraded_routes: &[DegradedRoute]) {
+    let styled_header = format!("[{}]", "Degraded Routes").cyan().bold();
+    println!("{}", styled_header);
+
+    let mut table = Table::new();
+    table.set_format(table_format());
+
+    table.set_titles(Row::new(vec![
+        Cell::new("Route"),
+        Cell::new("Serving"),
+        Cell::new("Reason"),
+    ]));
+
+    for degraded_route in degraded_routes {
+        let route = format!("{} ({})", degraded_route.source, degraded_route.route_type.to_lowercase());
+
+        let serving = match degraded_route.is_fallback_certificate_served {
+            true => format!("{} {}", "●".yellow(), "Fallback certificate".white().bold()),
+            false => format!("{} {}", "●".red(), "Not served".white().bold()),
+        };
+
+        table.add_row(Row::new(vec![
+            Cell::new(route.as_str()),
+            Cell::new(serving.as_str()),
+            Cell::new(degraded_route.reason.as_str()),
+        ]));
+    }
+
+    table.printstd();
+}
+
+fn print_upstream_health_table(upstreams: &[UpstreamHealth]) {
+    let styled_header = format!("[{}]", "Upstream Health").cyan().bold();
+    println!("{}", styled_header);
+
+    let mut table = Table::new();
+    table.set_format(table_format());
+
+    table.set_titles(Row::new(vec![
+        Cell::new("Route"),
+        Cell::new("Upstream"),
+        Cell::new("Status"),
+        Cell::new("Last Check"),
+        Cell::new("Details"),
+    ]));
+
+    for upstream in upstreams {
+        let route = format!("{} ({})", upstream.source, upstream.route_type.to_lowercase());
+
+        let last_check = match upstream.last_checked_at {
+            Some(timestamp) => Local
+                .timestamp_opt(timestamp, 0)
+                .single()
+                .map(|checked_at| checked_at.format("%H:%M:%S").to_string())
+                .unwrap_or_default(),
+            None if upstream.is_active_check_enabled => "Pending".to_string(),
+            None => "Passive only".to_string(),
+        };
+
+        table.add_row(Row::new(vec![
+            Cell::new(route.as_str()),
+            Cell::new(upstream.target.as_str()),
+            Cell::new(render_upstream_health_status(upstream).as_str()),
+            Cell::new(last_check.as_str()),
+            Cell::new(render_upstream_health_details(upstream).as_str()),
+        ]));
+    }
+
+    table.printstd();
+}
+
+fn render_upstream_health_status(upstream: &UpstreamHealth) -> String {
+    match upstream.status.as_str() {
+        "healthy" => format!("{} {}", "●".green(), "Healthy".white().bold()),
+        "unhealthy" => format!("{} {}", "●".red(), "Unhealthy".white().bold()),
+        "ejected" => format!("{} {}", "●".yellow(), "Ejected".white().bold()),
+        _ => format!("{} {}", "●".dimmed(), "Unknown".white().bold()),
+    }
+}
+
+fn render_upstream_health_details(upstream: &UpstreamHealth) -> String {
+    let mut details = Vec::new();
+
+    if let Some(remaining_seconds) = upstream.ejection_remaining_seconds {
+        details.push(format!("back in {}s", remaining_seconds));
+    }
+
+    if upstream.consecutive_failures > 0 {
+        details.push(format!("{} failed checks", upstream.consecutive_failures));
+    }
+
+    if upstream.passive_failures > 0 {
+        details.push(format!("{} failed requests", upstream.passive_failures));
+    }
+
+    if let Some(last_error) = &upstream.last_error {
+        details.push(last_error.clone());
+    }
+
+    if details.is_empty() {
+        return "-".dimmed().to_string();
+    }
+
+    details.join(" | ")
+}
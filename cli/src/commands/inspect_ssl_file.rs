@@ -0,0 +1,164 @@
+use crate::{
+    commands::get_ssl_files::format_ssl_file_type,
+    core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
+    log_error,
+    models::{
+        commands::{
+            inspect_ssl_file_response::InspectSslFileResponse, ssl_file_request::SslFileRequest,
+        },
+        entity::certificate_info::CertificateInfo,
+    },
+    utils::time_utility::{format_timestamp, get_current_timestamp},
+};
+use clap::Args;
+use colored::Colorize;
+use hyper::StatusCode;
+use prettytable::{
+    format::{self, TableFormat},
+    Cell, Row, Table,
+};
+
+#[derive(Debug, Args)]
+pub struct InspectSslFileArgs {
+    /// Name (or '@vanguard/<name>' reference) of an uploaded file
+    pub name: String,
+}
+
+pub async fn inspect_ssl_file(args: InspectSslFileArgs) {
+    let inspect_ssl_file_request = SslFileRequest { name: args.name };
+
+    let response = match execute(inspect_ssl_file_request).await {
+        Ok(response) => response,
+        Err(error) => {
+            log_error!("{}", error.reason);
+            return;
+        }
+    };
+
+    print_ssl_file_details(&response);
+}
+
+async fn execute(input: SslFileRequest) -> Result<InspectSslFileResponse, RPCBaseError> {
+    let serialized_input = serde_json::to_value(input)
+        .map_err(|_| RPCBaseError::build("Object can not serialized"))?;
+
+    let rpc_client = RPC_CLIENT.read().await;
+    let rpc_call_response = rpc_client.call("inspect_ssl_file", serialized_input).await?;
+
+    let response: InspectSslFileResponse = serde_json::from_value(rpc_call_response.result)
+        .map_err(|e| RPCBaseError::build(&format!("Response parse error: {}", e)))?;
+
+    if response.code != StatusCode::OK.as_u16() {
+        return Err(RPCBaseError::build(&format!(
+            "Error while inspecting SSL file. Details: {}",
+            response.message
+        )));
+    }
+
+    Ok(response)
+}
+
+fn print_ssl_file_details(response: &InspectSslFileResponse) {
+    if let Some(ssl_file) = &response.ssl_file {
+        let styled_header = format!("[{}]", ssl_file.reference).cyan().bold();
+        println!("{}", styled_header);
+
+        let mut table = Table::new();
+        table.set_format(table_format());
+        table.add_row(Row::new(vec![
+            Cell::new("Type"),
+            Cell::new(&format_ssl_file_type(&ssl_file.file_type)),
+        ]));
+        table.add_row(Row::new(vec![
+            Cell::new("Size"),
+            Cell::new(&format!("{} bytes", ssl_file.size)),
+        ]));
+        if let Some(private_key_format) = &response.private_key_format {
+            table.add_row(Row::new(vec![
+                Cell::new("Private Key Format"),
+                Cell::new(private_key_format),
+            ]));
+        }
+
+        let used_by = match response.used_by.is_empty() {
+            true => "None".dimmed().to_string(),
+            false => response.used_by.join("\n"),
+        };
+        table.add_row(Row::new(vec![Cell::new("Used By"), Cell::new(&used_by)]));
+        table.printstd();
+    }
+
+    for (index, certificate) in response.certificates.iter().enumerate() {
+        let title = match index {
+            0 => "Certificate".to_string(),
+            _ => format!("Chain Certificate #{}", index),
+        };
+        println!();
+        println!("{}", format!("[{}]", title).cyan().bold());
+        print_certificate(certificate);
+    }
+}
+
+fn print_certificate(certificate: &CertificateInfo) {
+    let subject_alt_names = match certificate.subject_alt_names.is_empty() {
+        true => "None".dimmed().to_string(),
+        false => certificate.subject_alt_names.join("\n"),
+    };
+
+    let mut table = Table::new();
+    table.set_format(table_format());
+    table.add_row(Row::new(vec![Cell::new("Subject"), Cell::new(&certificate.subject)]));
+    table.add_row(Row::new(vec![
+        Cell::new("Subject Alt Names"),
+        Cell::new(&subject_alt_names),
+    ]));
+    table.add_row(Row::new(vec![Cell::new("Issuer"), Cell::new(&certificate.issuer)]));
+    table.add_row(Row::new(vec![
+        Cell::new("Serial Number"),
+        Cell::new(&certificate.serial_number),
+    ]));
+    table.add_row(Row::new(vec![
+        Cell::new("Valid From"),
+        Cell::new(&format_timestamp(certificate.not_before)),
+    ]));
+    table.add_row(Row::new(vec![
+        Cell::new("Valid Until"),
+        Cell::new(&format_expiry(certificate.not_after)),
+    ]));
+    table.printstd();
+}
+
+/// Expiry date with remaining days, colored when certificate is expired or expires within 30 days
+pub fn format_expiry(not_after: i64) -> String {
+    let days_left = (not_after - get_current_timestamp()).div_euclid(86_400);
+    let expiry = format_timestamp(not_after);
+
+    match days_left {
+        days_left if days_left < 0 => format!("{} (expired)", expiry).red().to_string(),
+        days_left if days_left < 30 => format!("{} ({} days left)", expiry, days_left)
+            .yellow()
+            .to_string(),
+        days_left => format!("{} ({} days left)", expiry, days_left),
+    }
+}
+
+fn table_format() -> TableFormat {
+    format::FormatBuilder::new()
+        .column_separator(' ')
+        .left_border('\0')
+        .right_border('\0')
+        .padding(2, 1)
+        .separators(
+            &[format::LinePosition::Top],
+            format::LineSeparator::new('─', '┬', '┌', '┐'),
+        )
+        .separators(
+            &[format::LinePosition::Bottom],
+            format::LineSeparator::new('─', '┴', '└', '┘'),
+        )
+        .separators(
+            &[format::LinePosition::Intern],
+            format::LineSeparator::new('─', '┼', '├', '┤'),
+        )
+        .build()
+}
@@ -0,0 +1,121 @@
+use crate::{
+    core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
+    log_error, log_info,
+    models::{
+        commands::list_blocked_response::ListBlockedResponse, entity::ip_access::IpAccess,
+    },
+    utils::json_utility::create_empty_json_object,
+};
+use colored::Colorize;
+use hyper::StatusCode;
+use prettytable::{
+    format::{self, TableFormat},
+    Cell, Row, Table,
+};
+
+pub async fn list_blocked() {
+    let response = match execute().await {
+        Ok(response) => response,
+        Err(error) => {
+            log_error!("{}", error.reason);
+            return;
+        }
+    };
+
+    if response.code != StatusCode::OK.as_u16() {
+        log_error!("An error occured while getting ip access lists of Vanguard Engine.");
+        return;
+    }
+
+    let mut scopes = vec![
+        ("Global".to_string(), &response.global),
+        ("HTTP Server".to_string(), &response.http_server),
+        ("HTTPS Server".to_string(), &response.https_server),
+    ];
+
+    for route in &response.routes {
+        scopes.push((
+            format!("{} ({})", route.source, route.route_type.to_lowercase()),
+            &route.ip_access,
+        ));
+    }
+
+    scopes.retain(|(_, ip_access)| !is_empty(ip_access));
+
+    if scopes.is_empty() {
+        log_info!("No ip address is allowed or denied explicitly");
+        return;
+    }
+
+    let styled_header = format!("[{}]", "IP Access Lists").cyan().bold();
+    println!("{}", styled_header);
+
+    let mut table = Table::new();
+    table.set_format(table_format());
+
+    table.set_titles(Row::new(vec![
+        Cell::new("Scope"),
+        Cell::new("Deny"),
+        Cell::new("Allow"),
+        Cell::new("Forbidden Page"),
+    ]));
+
+    for (scope, ip_access) in scopes {
+        table.add_row(Row::new(vec![
+            Cell::new(scope.as_str()),
+            Cell::new(render_entries(&ip_access.deny).as_str()),
+            Cell::new(render_entries(&ip_access.allow).as_str()),
+            Cell::new(
+                ip_access
+                    .forbidden_page_path
+                    .as_deref()
+                    .unwrap_or("Default"),
+            ),
+        ]));
+    }
+
+    table.printstd();
+}
+
+fn is_empty(ip_access: &IpAccess) -> bool {
+    ip_access.allow.is_empty() && ip_access.deny.is_empty() && ip_access.forbidden_page_path.is_none()
+}
+
+fn render_entries(entries: &[String]) -> String {
+    if entries.is_empty() {
+        return "-".to_string();
+    }
+
+    entries.join("\n")
+}
+
+fn table_format() -> TableFormat {
+    format::FormatBuilder::new()
+        .column_separator(' ')
+        .left_border('\0')
+        .right_border('\0')
+        .padding(2, 1)
+        .separators(
+            &[format::LinePosition::Top],
+            format::LineSeparator::new('─', '┬', '┌', '┐'),
+        )
+        .separators(
+            &[format::LinePosition::Bottom],
+            format::LineSeparator::new('─', '┴', '└', '┘'),
+        )
+        .separators(
+            &[format::LinePosition::Intern],
+            format::LineSeparator::new('─', '┼', '├', '┤'),
+        )
+        .build()
+}
+
+async fn execute() -> Result<ListBlockedResponse, RPCBaseError> {
+    let request = create_empty_json_object();
+
+    let rpc_client = RPC_CLIENT.read().await;
+    let rpc_call_response = rpc_client.call("list_blocked", request).await?;
+
+    serde_json::from_value(rpc_call_response.result)
+        .map_err(|e| RPCBaseError::build(&format!("Response parse error: {}", e)))
+}
@@ -1,25 +1,423 @@
-use clap::Args;
+use std::{
+    io::{stdout, Write},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::{
+    common::enums::log_level::LogLevel,
+    constants::Constants,
+    core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
+    log_error, log_info,
+    models::{
+        commands::{
+            get_logger_config_response::GetLoggerConfigResponse, get_logs_request::GetLogsRequest,
+            get_logs_response::GetLogsResponse, set_logger_config_request::SetLoggerConfigRequest,
+            subscribe_logs_request::SubscribeLogsRequest,
+        },
+        entity::{engine_logger_config::EngineLoggerConfig, log_record::LogRecord},
+    },
+    utils::{
+        console::wait_for_exit_key,
+        time_utility::{format_timestamp, parse_time_reference},
+    },
+};
+use clap::{Args, Subcommand};
+use colored::Colorize;
+use crossterm::terminal;
+use hyper::StatusCode;
+use prettytable::{
+    format::{self, TableFormat},
+    Cell, Row, Table,
+};
+use serde_json::{json, Value};
 
 #[derive(Debug, Args)]
 pub struct LogsArgs {
-    pub command: Option<String>,
+    #[command(subcommand)]
+    pub command: Option<LogsCommand>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum LogsCommand {
+    /// Prints the most recent log records kept in engine memory
+    Show(LogsShowArgs),
+    /// Prints new log records as they are written, until 'q' or 'Ctrl+C' is pressed
+    Trail(LogsTrailArgs),
+    /// Changes log levels & log file rotation of the running engine. Changes are saved into engine settings
+    Set(LogsSetArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct LogFilterArgs {
+    /// Comma separated log levels to print: error, warning, info, debug
+    #[arg(long, value_delimiter = ',')]
+    pub level: Vec<String>,
+    /// Prints only records which contain given text (case-insensitive)
+    #[arg(long)]
+    pub grep: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct LogsShowArgs {
+    #[command(flatten)]
+    pub filter: LogFilterArgs,
+    /// Start of time range: relative (30s, 15m, 2h, 1d) or local date time (YYYY-MM-DD HH:MM:SS)
+    #[arg(long)]
+    pub since: Option<String>,
+    /// End of time range: relative (30s, 15m, 2h, 1d) or local date time (YYYY-MM-DD HH:MM:SS)
+    #[arg(long)]
+    pub until: Option<String>,
+    /// Maximum number of records printed
+    #[arg(long, default_value_t = Constants::DEFAULT_LOG_SHOW_LIMIT)]
+    pub limit: usize,
+}
+
+#[derive(Debug, Args)]
+pub struct LogsTrailArgs {
+    #[command(flatten)]
+    pub filter: LogFilterArgs,
+    /// Number of recent records printed before following new ones
+    #[arg(long, default_value_t = Constants::DEFAULT_LOG_TRAIL_LINES)]
+    pub lines: usize,
+}
+
+#[derive(Debug, Args)]
+pub struct LogsSetArgs {
+    /// Comma separated log levels to enable: error, warning, info, debug
+    #[arg(long, value_delimiter = ',')]
+    pub level: Option<Vec<String>>,
+    /// Maximum size of a log file in bytes, before it is rotated
+    #[arg(long)]
+    pub file_size: Option<u64>,
+    /// Number of rotated log files kept
+    #[arg(long)]
+    pub keep_last: Option<usize>,
 }
 
 pub async fn logs(args: LogsArgs) {
-    if args.command.is_none() {
-        show_logs_config().await;
+    match args.command {
+        Some(LogsCommand::Show(show_args)) => show_logs(show_args).await,
+        Some(LogsCommand::Trail(trail_args)) => trail_logs(trail_args).await,
+        Some(LogsCommand::Set(set_args)) => set_logs_config(set_args).await,
+        None => show_logs_config().await,
+    }
+}
+
+pub async fn show_logs_config() {
+    match execute_logger_config("get_logger_config", json!({})).await {
+        Ok(response) => print_logger_config(&response.logger),
+        Err(error) => log_error!("{}", error.reason),
+    }
+}
+
+async fn set_logs_config(args: LogsSetArgs) {
+    if args.level.is_none() && args.file_size.is_none() && args.keep_last.is_none() {
+        log_error!("At least one of '--level', '--file-size' or '--keep-last' is required");
         return;
     }
 
-    let command = args.command.unwrap().trim().to_lowercase();
+    if args.file_size == Some(0) || args.keep_last == Some(0) {
+        log_error!("'--file-size' & '--keep-last' should be positive numbers");
+        return;
+    }
+
+    let log_levels = match args.level.as_deref().map(normalize_levels).transpose() {
+        Ok(log_levels) => log_levels,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
 
-    if command == "trail" {
-        println!("trail");
-    } else if command == "show" {
-        println!("show");
+    let request = SetLoggerConfigRequest {
+        log_levels,
+        log_file_size: args.file_size,
+        keep_last_logs: args.keep_last,
+    };
+
+    let request = match serde_json::to_value(request) {
+        Ok(request) => request,
+        Err(error) => {
+            log_error!("Request build error: {}", error);
+            return;
+        }
+    };
+
+    match execute_logger_config("set_logger_config", request).await {
+        Ok(response) => {
+            log_info!("Logger configuration is updated");
+            print_logger_config(&response.logger);
+        }
+        Err(error) => log_error!("{}", error.reason),
     }
 }
 
-pub async fn show_logs_config() {
-    println!("show_logs_config");
+fn print_logger_config(logger_config: &EngineLoggerConfig) {
+    let styled_header = format!("[{}]", "Logger Configuration").cyan().bold();
+    println!("{}", styled_header);
+
+    let log_levels: Vec<String> = logger_config
+        .log_levels
+        .iter()
+        .map(|log_level| match LogLevel::from_str(log_level) {
+            Ok(log_level) => log_level.to_string(),
+            Err(_) => format!("[{}]", log_level),
+        })
+        .collect();
+
+    let log_levels = match log_levels.is_empty() {
+        true => "None".dimmed().to_string(),
+        false => log_levels.join(" "),
+    };
+
+    let log_file_size = format!(
+        "{} ({} mb)",
+        logger_config.log_file_size,
+        logger_config.log_file_size / 1_000_000
+    );
+
+    let mut table = Table::new();
+    table.set_format(table_format());
+    table.add_row(Row::new(vec![Cell::new("Log Levels"), Cell::new(&log_levels)]));
+    table.add_row(Row::new(vec![
+        Cell::new("Maximum Log File Size"),
+        Cell::new(&log_file_size),
+    ]));
+    table.add_row(Row::new(vec![
+        Cell::new("Kept Log Files"),
+        Cell::new(&logger_config.keep_last_logs.to_string()),
+    ]));
+    table.add_row(Row::new(vec![
+        Cell::new("Log Directory"),
+        Cell::new(&logger_config.log_dir_name),
+    ]));
+    table.printstd();
+}
+
+fn table_format() -> TableFormat {
+    format::FormatBuilder::new()
+        .column_separator(' ')
+        .left_border('\0')
+        .right_border('\0')
+        .padding(2, 1)
+        .separators(
+            &[format::LinePosition::Top],
+            format::LineSeparator::new('─', '┬', '┌', '┐'),
+        )
+        .separators(
+            &[format::LinePosition::Bottom],
+            format::LineSeparator::new('─', '┴', '└', '┘'),
+        )
+        .separators(
+            &[format::LinePosition::Intern],
+            format::LineSeparator::new('─', '┼', '├', '┤'),
+        )
+        .build()
+}
+
+async fn show_logs(args: LogsShowArgs) {
+    let request = match build_get_logs_request(&args.filter, &args.since, &args.until, args.limit) {
+        Ok(request) => request,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
+    let response = match execute("get_logs", request).await {
+        Ok(response) => response,
+        Err(error) => {
+            log_error!("{}", error.reason);
+            return;
+        }
+    };
+
+    if response.records.is_empty() {
+        log_info!("No log record matches given filters");
+        return;
+    }
+
+    for record in &response.records {
+        println!("{}", format_log_record(record));
+    }
+}
+
+async fn trail_logs(args: LogsTrailArgs) {
+    let levels = match normalize_levels(&args.filter.level) {
+        Ok(levels) => levels,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
+    // Recent records are printed first, then subscription continues from the cursor of that query
+    let mut cursor = None;
+    if args.lines > 0 {
+        let request = match build_get_logs_request(&args.filter, &None, &None, args.lines) {
+            Ok(request) => request,
+            Err(error_message) => {
+                log_error!("{}", error_message);
+                return;
+            }
+        };
+
+        match execute("get_logs", request).await {
+            Ok(response) => {
+                for record in &response.records {
+                    println!("{}", format_log_record(record));
+                }
+                cursor = Some(response.cursor);
+            }
+            Err(error) => {
+                log_error!("{}", error.reason);
+                return;
+            }
+        };
+    }
+
+    println!("{}", "Following engine logs. Press 'q' to stop.".dimmed());
+
+    if let Err(error) = terminal::enable_raw_mode() {
+        log_error!("Logs can not be followed: {}", error);
+        return;
+    }
+
+    let result = follow_logs(cursor, levels, args.filter.grep).await;
+    let _ = terminal::disable_raw_mode();
+
+    if let Err(error_message) = result {
+        log_error!("{}", error_message);
+    }
+}
+
+async fn follow_logs(
+    mut cursor: Option<u64>,
+    levels: Vec<String>,
+    contains: Option<String>,
+) -> Result<(), String> {
+    let mut stdout = stdout();
+
+    loop {
+        let request = serde_json::to_value(SubscribeLogsRequest {
+            cursor,
+            levels: levels.clone(),
+            contains: contains.clone(),
+        })
+        .map_err(|error| format!("Request build error: {}", error))?;
+
+        let response = execute("subscribe_logs", request)
+            .await
+            .map_err(|error| error.reason)?;
+
+        // Raw mode does not translate line feeds into carriage returns
+        if response.dropped_count > 0 {
+            let warning = format!(
+                "{} {} log records were overwritten before they could be read",
+                LogLevel::Warning,
+                response.dropped_count
+            );
+            let _ = write!(stdout, "{}\r\n", warning);
+        }
+
+        for record in &response.records {
+            let _ = write!(stdout, "{}\r\n", format_log_record(record));
+        }
+        let _ = stdout.flush();
+
+        cursor = Some(response.cursor);
+
+        let is_stopped = wait_for_exit_key(Duration::from_millis(Constants::LOG_TRAIL_INTERVAL_MS))
+            .map_err(|error| error.to_string())?;
+
+        if is_stopped {
+            return Ok(());
+        }
+    }
+}
+
+fn build_get_logs_request(
+    filter: &LogFilterArgs,
+    since: &Option<String>,
+    until: &Option<String>,
+    limit: usize,
+) -> Result<Value, String> {
+    if limit == 0 {
+        return Err("'--limit' should be a positive number".to_string());
+    }
+
+    let since = since.as_deref().map(parse_time_reference).transpose()?;
+    let until = until.as_deref().map(parse_time_reference).transpose()?;
+
+    let request = GetLogsRequest {
+        levels: normalize_levels(&filter.level)?,
+        since,
+        until,
+        contains: filter.grep.clone(),
+        limit,
+    };
+
+    serde_json::to_value(request).map_err(|error| format!("Request build error: {}", error))
+}
+
+/// Validates given levels against `LogLevel` and converts them into engine format (e.g. `ERROR`)
+fn normalize_levels(levels: &[String]) -> Result<Vec<String>, String> {
+    levels
+        .iter()
+        .map(|level| level.trim().to_uppercase())
+        .filter(|level| !level.is_empty())
+        .map(|level| LogLevel::from_str(&level).map(|_| level))
+        .collect()
+}
+
+fn format_log_record(record: &LogRecord) -> String {
+    let level = match LogLevel::from_str(&record.level) {
+        Ok(log_level) => log_level.to_string(),
+        Err(_) => format!("[{}]", record.level),
+    };
+
+    format!(
+        "{} {} {}",
+        format_timestamp(record.timestamp).dimmed(),
+        level,
+        record.message
+    )
+}
+
+async fn execute(method_name: &str, request: Value) -> Result<GetLogsResponse, RPCBaseError> {
+    let rpc_client = RPC_CLIENT.read().await;
+    let rpc_call_response = rpc_client.call(method_name, request).await?;
+
+    let response: GetLogsResponse = serde_json::from_value(rpc_call_response.result)
+        .map_err(|e| RPCBaseError::build(&format!("Response parse error: {}", e)))?;
+
+    if response.code != StatusCode::OK.as_u16() {
+        return Err(RPCBaseError::build(&format!(
+            "An error occured while getting logs of Vanguard Engine: {}",
+            response.message
+        )));
+    }
+
+    Ok(response)
+}
+
+async fn execute_logger_config(
+    method_name: &str,
+    request: Value,
+) -> Result<GetLoggerConfigResponse, RPCBaseError> {
+    let rpc_client = RPC_CLIENT.read().await;
+    let rpc_call_response = rpc_client.call(method_name, request).await?;
+
+    let response: GetLoggerConfigResponse = serde_json::from_value(rpc_call_response.result)
+        .map_err(|e| RPCBaseError::build(&format!("Response parse error: {}", e)))?;
+
+    if response.code != StatusCode::OK.as_u16() {
+        return Err(RPCBaseError::build(&format!(
+            "An error occured while configuring logger of Vanguard Engine: {}",
+            response.message
+        )));
+    }
+
+    Ok(response)
 }
@@ -2,11 +2,16 @@ use clap::Subcommand;
 
 use crate::commands::{
     add_http_route::AddHttpRouteArgs, add_https_route::AddHttpsRouteArgs,
-    add_iws_route::AddIwsRouteArgs, add_secure_iws_route::AddSecureIwsRouteArgs,
+    add_iws_route::AddIwsRouteArgs, add_path_rule::AddPathRuleArgs,
+    add_secure_iws_route::AddSecureIwsRouteArgs, delete_path_rule::DeletePathRuleArgs,
+    add_rewrite_rule::AddRewriteRuleArgs, delete_rewrite_rule::DeleteRewriteRuleArgs,
     delete_http_route::DeleteHttpRouteArgs, delete_https_route::DeleteHttpsRouteArgs,
     delete_iws_route::DeleteIwsRouteArgs, delete_secure_iws_route::DeleteSecureIwsRouteArgs,
     echo::EchoArgs, get_route_list::GetRouteListArgs, get_ssl_files::GetSslFilesArgs,
-    logs::LogsArgs,
+    logs::LogsArgs, block_ip::BlockIpArgs, unblock_ip::UnblockIpArgs, monitor::MonitorArgs,
+    upload_ssl_file::UploadSslFileArgs, inspect_ssl_file::InspectSslFileArgs,
+    delete_ssl_file::DeleteSslFileArgs, acme_status::AcmeStatusArgs, acme_renew::AcmeRenewArgs,
+    ssl_expiry::SslExpiryArgs,
 };
 
 #[derive(Subcommand)]
@@ -26,7 +31,7 @@ pub enum Commands {
     /// Adds a new HTTP route mapping from source to target
     AddHttpRoute(AddHttpRouteArgs),
     /// Adds a new HTTPS route with SSL certificate configuration
-    AddHttpsRoute(AddHttpsRouteArgs),
+    AddHttpsRoute(Box<AddHttpsRouteArgs>),
     /// Removes an existing HTTP route by source path
     DeleteHttpRoute(DeleteHttpRouteArgs),
     /// Removes an existing HTTPS route by source path
@@ -35,6 +40,14 @@ pub enum Commands {
     DeleteIwsRoute(DeleteIwsRouteArgs),
     /// Removes an existing Secure IWS route by source path
     DeleteSecureIwsRoute(DeleteSecureIwsRouteArgs),
+    /// Adds (or replaces) a path based rule into an existing route's host entry
+    AddPathRule(AddPathRuleArgs),
+    /// Removes a path based rule from an existing route's host entry
+    DeletePathRule(DeletePathRuleArgs),
+    /// Adds (or replaces) an URL rewrite, redirect or return rule into an existing route's host entry
+    AddRewriteRule(AddRewriteRuleArgs),
+    /// Removes an URL rewrite rule from an existing route's host entry
+    DeleteRewriteRule(DeleteRewriteRuleArgs),
     /// Retrieves and displays the list of configured routes
     GetRouteList(GetRouteListArgs),
     /// Shows current engine status and configuration details
@@ -43,20 +56,44 @@ pub enum Commands {
     AddIwsRoute(AddIwsRouteArgs),
     /// Adds a new Secure Internal Web Service route with SSL certificate configuration
     AddSecureIwsRoute(AddSecureIwsRouteArgs),
-    /// Retrieves and displays the list of uploaded SSL files
+    /// Adds an IPv4/IPv6 address or CIDR block into deny (or allow) list of global, server or route scope
+    BlockIp(BlockIpArgs),
+    /// Removes an IPv4/IPv6 address or CIDR block from deny (or allow) list of global, server or route scope
+    UnblockIp(UnblockIpArgs),
+    /// Retrieves and displays global, server and route level ip allow & deny lists
+    ListBlocked,
+    /// Opens a live dashboard of engine metrics (RPS, latency percentiles, top routes & client ips). Press 'q' to quit
+    Monitor(MonitorArgs),
+    /// Prints logger configuration, recent engine logs (`logs show`) or follows new ones (`logs trail`). `logs set` changes logger configuration
     Logs(LogsArgs),
-    
+    /// Retrieves and displays the list of uploaded SSL files
     GetSslFiles(GetSslFilesArgs),
+    /// Uploads a PEM certificate (chain) or private key into engine's SSL directory. Routes refer to it as '@vanguard/<name>'
+    UploadSslFile(UploadSslFileArgs),
+    /// Shows type, routes using it and certificate details (subject, SANs, issuer, expiry) of an uploaded SSL file
+    InspectSslFile(InspectSslFileArgs),
+    /// Removes an uploaded SSL file which is not used by any route
+    DeleteSslFile(DeleteSslFileArgs),
+    /// Shows issue state, expiry and last error of certificates issued by ACME (Let's Encrypt)
+    AcmeStatus(AcmeStatusArgs),
+    /// Orders a new ACME certificate for a route, even if current one is not close to expiry
+    AcmeRenew(AcmeRenewArgs),
+    /// Shows expiry of certificates served by HTTPS & Secure IWS routes, soonest first
+    SslExpiry(SslExpiryArgs),
 }
 
 pub mod add_http_route;
 pub mod add_https_route;
 pub mod add_iws_route;
+pub mod add_path_rule;
 pub mod add_secure_iws_route;
 pub mod clear_terminal;
 pub mod delete_http_route;
 pub mod delete_https_route;
 pub mod delete_iws_route;
+pub mod delete_path_rule;
+pub mod add_rewrite_rule;
+pub mod delete_rewrite_rule;
 pub mod delete_secure_iws_route;
 pub mod echo;
 pub mod exit;
@@ -64,4 +101,14 @@ pub mod get_route_list;
 pub mod get_ssl_files;
 pub mod get_status;
 pub mod version;
-pub mod logs;
\ No newline at end of file
+pub mod logs;
+pub mod block_ip;
+pub mod unblock_ip;
+pub mod list_blocked;
+pub mod monitor;
+pub mod upload_ssl_file;
+pub mod inspect_ssl_file;
+pub mod delete_ssl_file;
+pub mod acme_status;
+pub mod acme_renew;
+pub mod ssl_expiry;
@@ -0,0 +1,474 @@
+use std::{
+    collections::HashMap,
+    io::{stdout, Write},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    constants::Constants,
+    core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
+    log_error,
+    models::{
+        commands::{
+            get_metrics_request::GetMetricsRequest, get_metrics_response::GetMetricsResponse,
+        },
+        entity::metrics::{EngineMetrics, LatencyBucket},
+    },
+    utils::console::wait_for_exit_key,
+};
+use clap::Args;
+use colored::Colorize;
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    execute, queue,
+    style::Print,
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use hyper::StatusCode;
+use prettytable::{
+    format::{self, TableFormat},
+    Cell, Row, Table,
+};
+
+#[derive(Debug, Args)]
+pub struct MonitorArgs {
+    /// Refresh interval of the dashboard in seconds
+    #[arg(long, default_value_t = Constants::DEFAULT_MONITOR_INTERVAL)]
+    pub interval: u64,
+    /// Number of routes and client ips listed
+    #[arg(long, default_value_t = Constants::DEFAULT_MONITOR_TOP_LIMIT)]
+    pub top: usize,
+}
+
+/// Previous snapshot and time passed since it was fetched. Rates are calculated over this window
+struct MetricsWindow<'a> {
+    previous: &'a EngineMetrics,
+    elapsed: Duration,
+}
+
+pub async fn monitor(args: MonitorArgs) {
+    if args.interval == 0 || args.top == 0 {
+        log_error!("'--interval' and '--top' should be positive numbers");
+        return;
+    }
+
+    // First snapshot is fetched before switching screens, so connection errors stay visible in the shell
+    let metrics = match fetch_metrics(args.top).await {
+        Ok(metrics) => metrics,
+        Err(error) => {
+            log_error!("{}", error.reason);
+            return;
+        }
+    };
+
+    if let Err(error) = enter_dashboard_screen() {
+        leave_dashboard_screen();
+        log_error!("Monitor can not be started: {}", error);
+        return;
+    }
+
+    let result = run_dashboard(&args, metrics).await;
+    leave_dashboard_screen();
+
+    if let Err(error) = result {
+        log_error!("{}", error);
+    }
+}
+
+async fn run_dashboard(args: &MonitorArgs, metrics: EngineMetrics) -> Result<(), String> {
+    let mut current = metrics;
+    let mut fetched_at = Instant::now();
+    let mut previous: Option<(EngineMetrics, Instant)> = None;
+
+    loop {
+        let window = previous.as_ref().map(|(previous_metrics, previous_fetched_at)| MetricsWindow {
+            previous: previous_metrics,
+            elapsed: fetched_at.duration_since(*previous_fetched_at),
+        });
+
+        draw(&render_dashboard(args, &current, window.as_ref())).map_err(|error| error.to_string())?;
+
+        if wait_for_exit_key(Duration::from_secs(args.interval)).map_err(|error| error.to_string())? {
+            return Ok(());
+        }
+
+        let next = fetch_metrics(args.top).await.map_err(|error| error.reason)?;
+
+        previous = Some((current, fetched_at));
+        current = next;
+        fetched_at = Instant::now();
+    }
+}
+
+async fn fetch_metrics(top_limit: usize) -> Result<EngineMetrics, RPCBaseError> {
+    let request = serde_json::to_value(GetMetricsRequest { top_limit })
+        .map_err(|e| RPCBaseError::build(&format!("Request build error: {}", e)))?;
+
+    let rpc_client = RPC_CLIENT.read().await;
+    let rpc_call_response = rpc_client.call("get_metrics", request).await?;
+
+    let response: GetMetricsResponse = serde_json::from_value(rpc_call_response.result)
+        .map_err(|e| RPCBaseError::build(&format!("Response parse error: {}", e)))?;
+
+    if response.code != StatusCode::OK.as_u16() {
+        return Err(RPCBaseError::build(&format!(
+            "An error occured while getting metrics of Vanguard Engine: {}",
+            response.message
+        )));
+    }
+
+    Ok(response.metrics)
+}
+
+fn enter_dashboard_screen() -> std::io::Result<()> {
+    terminal::enable_raw_mode()?;
+    execute!(stdout(), EnterAlternateScreen, Hide)
+}
+
+fn leave_dashboard_screen() {
+    let _ = execute!(stdout(), Show, LeaveAlternateScreen);
+    let _ = terminal::disable_raw_mode();
+}
+
+fn draw(content: &str) -> std::io::Result<()> {
+    let mut stdout = stdout();
+
+    // Raw mode does not translate line feeds into carriage returns
+    queue!(
+        stdout,
+        MoveTo(0, 0),
+        Clear(ClearType::All),
+        Print(content.replace('\n', "\r\n"))
+    )?;
+    stdout.flush()
+}
+
+fn render_dashboard(args: &MonitorArgs, metrics: &EngineMetrics, window: Option<&MetricsWindow>) -> String {
+    let mut output = String::new();
+
+    output.push_str(&format!(
+        "{}  uptime {} | refresh {}s | press {} to quit\n\n",
+        format!("[{}]", "Vanguard Engine Monitor").cyan().bold(),
+        format_uptime(metrics.uptime_seconds),
+        args.interval,
+        "q".bold()
+    ));
+
+    output.push_str(&render_summary(metrics, window));
+    output.push('\n');
+
+    output.push_str(&format!("{}\n", format!("[{}]", "Top Routes").cyan().bold()));
+    output.push_str(&render_routes_table(metrics, window));
+    output.push('\n');
+
+    output.push_str(&format!("{}\n", format!("[{}]", "Top Client IPs").cyan().bold()));
+    output.push_str(&render_clients_table(metrics, window));
+
+    output
+}
+
+fn render_summary(metrics: &EngineMetrics, window: Option<&MetricsWindow>) -> String {
+    let status_class_count = |metrics: &EngineMetrics, status_classes: &[&str]| -> u64 {
+        status_classes
+            .iter()
+            .filter_map(|status_class| metrics.status_classes.get(*status_class))
+            .sum()
+    };
+
+    // Without a previous snapshot values are averaged since engine start
+    let (requests_per_second, request_count, client_errors, server_errors, latency) = match window {
+        Some(window) => {
+            let request_count = metrics.total_requests.saturating_sub(window.previous.total_requests);
+            let latency_buckets = subtract_buckets(&metrics.latency.buckets, &window.previous.latency.buckets);
+
+            (
+                calculate_rate(request_count, window.elapsed.as_secs_f64()),
+                request_count,
+                status_class_count(metrics, &["4xx"])
+                    .saturating_sub(status_class_count(window.previous, &["4xx"])),
+                status_class_count(metrics, &["5xx", "error"])
+                    .saturating_sub(status_class_count(window.previous, &["5xx", "error"])),
+                [0.50, 0.95, 0.99].map(|quantile| {
+                    estimate_quantile(&latency_buckets, request_count, quantile)
+                }),
+            )
+        }
+        None => (
+            calculate_rate(metrics.total_requests, metrics.uptime_seconds as f64),
+            metrics.total_requests,
+            status_class_count(metrics, &["4xx"]),
+            status_class_count(metrics, &["5xx", "error"]),
+            [
+                metrics.latency.p50_ms,
+                metrics.latency.p95_ms,
+                metrics.latency.p99_ms,
+            ],
+        ),
+    };
+
+    let render_latency = |latency_ms: f64| {
+        if request_count == 0 {
+            "-".dimmed().to_string()
+        } else {
+            format!("{:.1} ms", latency_ms).white().bold().to_string()
+        }
+    };
+
+    let mut table = Table::new();
+    table.set_format(table_format());
+
+    table.add_row(Row::new(vec![
+        Cell::new("Requests/s"),
+        Cell::new(&format!("{:.1}", requests_per_second).white().bold().to_string()),
+        Cell::new("Active Connections"),
+        Cell::new(&metrics.active_connections.to_string().white().bold().to_string()),
+        Cell::new("Total Requests"),
+        Cell::new(&metrics.total_requests.to_string()),
+    ]));
+
+    table.add_row(Row::new(vec![
+        Cell::new("Latency p50"),
+        Cell::new(&render_latency(latency[0])),
+        Cell::new("Latency p95"),
+        Cell::new(&render_latency(latency[1])),
+        Cell::new("Latency p99"),
+        Cell::new(&render_latency(latency[2])),
+    ]));
+
+    table.add_row(Row::new(vec![
+        Cell::new("4xx Rate"),
+        Cell::new(&render_error_rate(client_errors, request_count)),
+        Cell::new("5xx Rate"),
+        Cell::new(&render_error_rate(server_errors, request_count)),
+        Cell::new("Upstream Errors"),
+        Cell::new(&metrics.upstream_errors.to_string()),
+    ]));
+
+    table.add_row(Row::new(vec![
+        Cell::new("Rate Limited"),
+        Cell::new(&metrics.rate_limit_rejections.to_string()),
+        Cell::new("TLS Handshake Failures"),
+        Cell::new(&metrics.tls_handshake_failures.to_string()),
+        Cell::new("Upgraded Connections"),
+        Cell::new(&format!(
+            "{} ({} total)",
+            metrics.active_upgraded_connections, metrics.total_upgraded_connections
+        )),
+    ]));
+
+    table.to_string()
+}
+
+fn render_routes_table(metrics: &EngineMetrics, window: Option<&MetricsWindow>) -> String {
+    if metrics.routes.is_empty() {
+        return format!("{}\n", "No requests handled yet".dimmed());
+    }
+
+    let previous_routes: HashMap<(&str, &str), (u64, u64, u64)> = window
+        .map(|window| {
+            window
+                .previous
+                .routes
+                .iter()
+                .map(|route| {
+                    (
+                        (route.server.as_str(), route.route.as_str()),
+                        (route.requests, route.client_errors, route.server_errors),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut table = Table::new();
+    table.set_format(table_format());
+
+    table.set_titles(Row::new(vec![
+        Cell::new("Server"),
+        Cell::new("Route"),
+        Cell::new("Requests"),
+        Cell::new("Req/s"),
+        Cell::new("4xx Rate"),
+        Cell::new("5xx Rate"),
+    ]));
+
+    for route in &metrics.routes {
+        let previous_route = previous_routes.get(&(route.server.as_str(), route.route.as_str()));
+
+        // Routes which just entered the list have no previous value, their rates are shown since engine start
+        let (requests_per_second, request_count, client_errors, server_errors) = match (window, previous_route) {
+            (Some(window), Some((requests, client_errors, server_errors))) => {
+                let request_count = route.requests.saturating_sub(*requests);
+                (
+                    format!("{:.1}", calculate_rate(request_count, window.elapsed.as_secs_f64())),
+                    request_count,
+                    route.client_errors.saturating_sub(*client_errors),
+                    route.server_errors.saturating_sub(*server_errors),
+                )
+            }
+            _ => (
+                "-".dimmed().to_string(),
+                route.requests,
+                route.client_errors,
+                route.server_errors,
+            ),
+        };
+
+        table.add_row(Row::new(vec![
+            Cell::new(&route.server.to_uppercase()),
+            Cell::new(&route.route),
+            Cell::new(&route.requests.to_string()),
+            Cell::new(&requests_per_second),
+            Cell::new(&render_error_rate(client_errors, request_count)),
+            Cell::new(&render_error_rate(server_errors, request_count)),
+        ]));
+    }
+
+    table.to_string()
+}
+
+fn render_clients_table(metrics: &EngineMetrics, window: Option<&MetricsWindow>) -> String {
+    if metrics.top_clients.is_empty() {
+        return format!("{}\n", "No requests handled yet".dimmed());
+    }
+
+    let previous_clients: HashMap<&str, u64> = window
+        .map(|window| {
+            window
+                .previous
+                .top_clients
+                .iter()
+                .map(|client| (client.ip.as_str(), client.requests))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut table = Table::new();
+    table.set_format(table_format());
+
+    table.set_titles(Row::new(vec![
+        Cell::new("Client IP"),
+        Cell::new("Requests"),
+        Cell::new("Req/s"),
+    ]));
+
+    for client in &metrics.top_clients {
+        let requests_per_second = match (window, previous_clients.get(client.ip.as_str())) {
+            (Some(window), Some(requests)) => format!(
+                "{:.1}",
+                calculate_rate(
+                    client.requests.saturating_sub(*requests),
+                    window.elapsed.as_secs_f64()
+                )
+            ),
+            _ => "-".dimmed().to_string(),
+        };
+
+        table.add_row(Row::new(vec![
+            Cell::new(&client.ip),
+            Cell::new(&client.requests.to_string()),
+            Cell::new(&requests_per_second),
+        ]));
+    }
+
+    table.to_string()
+}
+
+fn render_error_rate(error_count: u64, request_count: u64) -> String {
+    if request_count == 0 {
+        return "-".dimmed().to_string();
+    }
+
+    let error_rate = error_count as f64 * 100.0 / request_count as f64;
+    let formatted_error_rate = format!("{:.1}%", error_rate);
+
+    if error_rate >= 5.0 {
+        formatted_error_rate.red().bold().to_string()
+    } else if error_rate > 0.0 {
+        formatted_error_rate.yellow().to_string()
+    } else {
+        formatted_error_rate.green().to_string()
+    }
+}
+
+fn calculate_rate(count: u64, seconds: f64) -> f64 {
+    if seconds > 0.0 {
+        count as f64 / seconds
+    } else {
+        0.0
+    }
+}
+
+/// Latency buckets of requests completed between two snapshots
+fn subtract_buckets(current: &[LatencyBucket], previous: &[LatencyBucket]) -> Vec<(f64, u64)> {
+    current
+        .iter()
+        .enumerate()
+        .map(|(index, bucket)| {
+            let previous_count = previous.get(index).map(|bucket| bucket.count).unwrap_or(0);
+            (bucket.upper_bound_ms, bucket.count.saturating_sub(previous_count))
+        })
+        .collect()
+}
+
+/// Estimates a quantile from cumulative buckets by linear interpolation inside the matching bucket
+fn estimate_quantile(cumulative_buckets: &[(f64, u64)], total_count: u64, quantile: f64) -> f64 {
+    if total_count == 0 {
+        return 0.0;
+    }
+
+    let rank = quantile * total_count as f64;
+    let mut lower_bound = 0.0;
+    let mut lower_count = 0;
+
+    for (upper_bound, cumulative_count) in cumulative_buckets {
+        if *cumulative_count as f64 >= rank {
+            let bucket_count = cumulative_count - lower_count;
+            if bucket_count == 0 {
+                return *upper_bound;
+            }
+
+            let position = (rank - lower_count as f64) / bucket_count as f64;
+            return lower_bound + (upper_bound - lower_bound) * position;
+        }
+
+        lower_bound = *upper_bound;
+        lower_count = *cumulative_count;
+    }
+
+    lower_bound
+}
+
+fn format_uptime(uptime_seconds: u64) -> String {
+    let days = uptime_seconds / 86_400;
+    let hours = (uptime_seconds % 86_400) / 3_600;
+    let minutes = (uptime_seconds % 3_600) / 60;
+    let seconds = uptime_seconds % 60;
+
+    if days > 0 {
+        format!("{}d {:02}h {:02}m {:02}s", days, hours, minutes, seconds)
+    } else {
+        format!("{:02}h {:02}m {:02}s", hours, minutes, seconds)
+    }
+}
+
+fn table_format() -> TableFormat {
+    format::FormatBuilder::new()
+        .column_separator(' ')
+        .left_border('\0')
+        .right_border('\0')
+        .padding(2, 1)
+        .separators(
+            &[format::LinePosition::Top],
+            format::LineSeparator::new('─', '┬', '┌', '┐'),
+        )
+        .separators(
+            &[format::LinePosition::Bottom],
+            format::LineSeparator::new('─', '┴', '└', '┘'),
+        )
+        .separators(
+            &[format::LinePosition::Title],
+            format::LineSeparator::new('─', '┼', '├', '┤'),
+        )
+        .build()
+}
@@ -0,0 +1,122 @@
+use crate::{
+    commands::inspect_ssl_file::format_expiry,
+    core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
+    log_error, log_info,
+    models::{
+        commands::get_certificate_expiry_response::GetCertificateExpiryResponse,
+        entity::certificate_expiry::CertificateExpiry,
+    },
+    utils::json_utility::create_empty_json_object,
+};
+use clap::Args;
+use colored::Colorize;
+use hyper::StatusCode;
+use prettytable::{
+    format::{self, TableFormat},
+    Cell, Row, Table,
+};
+
+#[derive(Debug, Args)]
+pub struct SslExpiryArgs {}
+
+pub async fn ssl_expiry(_args: SslExpiryArgs) {
+    let response = match execute().await {
+        Ok(response) => response,
+        Err(error) => {
+            log_error!("{}", error.reason);
+            return;
+        }
+    };
+
+    if response.certificates.is_empty() {
+        log_info!("No HTTPS or Secure IWS route is defined");
+        return;
+    }
+
+    print_certificates(&response.certificates);
+}
+
+async fn execute() -> Result<GetCertificateExpiryResponse, RPCBaseError> {
+    let rpc_client = RPC_CLIENT.read().await;
+    let rpc_call_response = rpc_client
+        .call("get_certificate_expiry", create_empty_json_object())
+        .await?;
+
+    let response: GetCertificateExpiryResponse = serde_json::from_value(rpc_call_response.result)
+        .map_err(|e| RPCBaseError::build(&format!("Response parse error: {}", e)))?;
+
+    if response.code != StatusCode::OK.as_u16() {
+        return Err(RPCBaseError::build(&format!(
+            "Error while fetching certificate expiry. Details: {}",
+            response.message
+        )));
+    }
+
+    Ok(response)
+}
+
+fn print_certificates(certificates: &[CertificateExpiry]) {
+    let styled_header = format!("[Certificate Expiry ({})]", certificates.len())
+        .cyan()
+        .bold();
+    println!("{}", styled_header);
+
+    let mut table = Table::new();
+    table.set_format(table_format());
+    table.set_titles(Row::new(vec![
+        Cell::new("Source"),
+        Cell::new("Type"),
+        Cell::new("Status"),
+        Cell::new("Valid Until"),
+        Cell::new("Certificate"),
+    ]));
+
+    for certificate in certificates {
+        // Unreadable certificates show the read error instead of an expiry
+        let valid_until = match (certificate.not_after, &certificate.error) {
+            (Some(not_after), _) => format_expiry(not_after),
+            (None, Some(error)) => error.red().to_string(),
+            (None, None) => "-".to_string(),
+        };
+
+        table.add_row(Row::new(vec![
+            Cell::new(&certificate.source),
+            Cell::new(&certificate.route_type),
+            Cell::new(&format_expiry_status(&certificate.status)),
+            Cell::new(&valid_until),
+            Cell::new(&certificate.certificate_file_path),
+        ]));
+    }
+
+    table.printstd();
+}
+
+fn format_expiry_status(status: &str) -> String {
+    match status {
+        "valid" => "Valid".green().to_string(),
+        "expiring" => "Expiring".yellow().to_string(),
+        "expired" => "Expired".red().to_string(),
+        _ => "Unreadable".red().to_string(),
+    }
+}
+
+fn table_format() -> TableFormat {
+    format::FormatBuilder::new()
+        .column_separator(' ')
+        .left_border('\0')
+        .right_border('\0')
+        .padding(2, 1)
+        .separators(
+            &[format::LinePosition::Top],
+            format::LineSeparator::new('─', '┬', '┌', '┐'),
+        )
+        .separators(
+            &[format::LinePosition::Bottom],
+            format::LineSeparator::new('─', '┴', '└', '┘'),
+        )
+        .separators(
+            &[format::LinePosition::Title],
+            format::LineSeparator::new('─', '┼', '├', '┤'),
+        )
+        .build()
+}
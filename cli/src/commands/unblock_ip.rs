@@ -0,0 +1,74 @@
+use crate::{
+    commands::block_ip::build_ip_access_entry_request,
+    core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
+    log_error, log_info,
+    models::commands::{
+        ip_access_entry_request::IpAccessEntryRequest, unblock_ip_response::UnblockIpResponse,
+    },
+};
+use clap::Args;
+use hyper::StatusCode;
+
+#[derive(Debug, Args)]
+pub struct UnblockIpArgs {
+    /// IPv4/IPv6 address or CIDR block which was added before
+    pub ip: String,
+    /// Level of the list: 'global', 'http', 'https' or 'route'
+    #[arg(long, default_value = "global")]
+    pub scope: String,
+    /// List which entry is removed from: 'deny' or 'allow'
+    #[arg(long, default_value = "deny")]
+    pub list: String,
+    /// Route type of host entry for 'route' scope: 'http', 'https', 'iws', 'secure-iws'
+    #[arg(long)]
+    pub route_type: Option<String>,
+    /// Source (host) of existing route for 'route' scope
+    #[arg(long)]
+    pub source: Option<String>,
+}
+
+pub async fn unblock_ip(args: UnblockIpArgs) {
+    let unblock_ip_request = match build_ip_access_entry_request(
+        &args.ip,
+        &args.scope,
+        &args.list,
+        &args.route_type,
+        &args.source,
+    ) {
+        Ok(request) => request,
+        Err(error_message) => {
+            log_error!("{}", error_message);
+            return;
+        }
+    };
+
+    let response = match execute(unblock_ip_request).await {
+        Ok(response) => response,
+        Err(error_message) => {
+            log_error!("{}", error_message.reason);
+            return;
+        }
+    };
+
+    if response.code == StatusCode::OK.as_u16() {
+        log_info!("'{}' is removed from {} list of {} scope", args.ip, args.list, args.scope);
+    } else {
+        log_error!(
+            "Error while removing '{}' from {} list. Details: {}",
+            args.ip,
+            args.list,
+            response.message
+        )
+    }
+}
+
+async fn execute(input: IpAccessEntryRequest) -> Result<UnblockIpResponse, RPCBaseError> {
+    let serialized_input = serde_json::to_value(input)
+        .map_err(|_| RPCBaseError::build("Object can not serialized"))?;
+
+    let rpc_client = RPC_CLIENT.read().await;
+    let rpc_call_response = rpc_client.call("unblock_ip", serialized_input).await?;
+
+    serde_json::from_value(rpc_call_response.result)
+        .map_err(|e| RPCBaseError::build(&format!("Response parse error: {}", e)))
+}
@@ -0,0 +1,73 @@
+use std::fs;
+
+use crate::{
+    commands::get_ssl_files::format_ssl_file_type,
+    core::{errors::rpc_base_error::RPCBaseError, shared_memory::RPC_CLIENT},
+    log_error, log_info,
+    models::commands::{
+        upload_ssl_file_request::UploadSslFileRequest,
+        upload_ssl_file_response::UploadSslFileResponse,
+    },
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use clap::Args;
+use hyper::StatusCode;
+
+#[derive(Debug, Args)]
+pub struct UploadSslFileArgs {
+    /// Name of the file in engine's SSL directory. Routes refer to it as '@vanguard/<name>'
+    pub name: String,
+    /// Local path of PEM encoded certificate (chain) or private key
+    pub file_path: String,
+    /// Replaces an already uploaded file with the same name
+    #[arg(long)]
+    pub overwrite: bool,
+}
+
+pub async fn upload_ssl_file(args: UploadSslFileArgs) {
+    let content = match fs::read(&args.file_path) {
+        Ok(content) => content,
+        Err(error) => {
+            log_error!("'{}' can not be read: {}", args.file_path, error);
+            return;
+        }
+    };
+
+    let upload_ssl_file_request = UploadSslFileRequest {
+        name: args.name.clone(),
+        content: STANDARD.encode(content),
+        overwrite: args.overwrite,
+    };
+
+    match execute(upload_ssl_file_request).await {
+        Ok(response) => match response.ssl_file {
+            Some(ssl_file) => log_info!(
+                "{} is uploaded as '{}'",
+                format_ssl_file_type(&ssl_file.file_type),
+                ssl_file.reference
+            ),
+            None => log_info!("SSL file is uploaded"),
+        },
+        Err(error) => log_error!("{}", error.reason),
+    }
+}
+
+async fn execute(input: UploadSslFileRequest) -> Result<UploadSslFileResponse, RPCBaseError> {
+    let serialized_input = serde_json::to_value(input)
+        .map_err(|_| RPCBaseError::build("Object can not serialized"))?;
+
+    let rpc_client = RPC_CLIENT.read().await;
+    let rpc_call_response = rpc_client.call("upload_ssl_file", serialized_input).await?;
+
+    let response: UploadSslFileResponse = serde_json::from_value(rpc_call_response.result)
+        .map_err(|e| RPCBaseError::build(&format!("Response parse error: {}", e)))?;
+
+    if response.code != StatusCode::OK.as_u16() {
+        return Err(RPCBaseError::build(&format!(
+            "Error while uploading SSL file. Details: {}",
+            response.message
+        )));
+    }
+
+    Ok(response)
+}
@@ -37,4 +37,11 @@ impl Constants {
     pub const DEFAULT_RPC_IP: &'static str = "127.0.0.1";
     pub const DEFAULT_RPC_PORT: u16 = 4242;
     pub const DEFAULT_PRIVATE_SECRET_KEY: &'static str = "0xVanguard";
+
+    pub const DEFAULT_MONITOR_INTERVAL: u64 = 1;
+    pub const DEFAULT_MONITOR_TOP_LIMIT: usize = 5;
+
+    pub const DEFAULT_LOG_SHOW_LIMIT: usize = 100;
+    pub const DEFAULT_LOG_TRAIL_LINES: usize = 10;
+    pub const LOG_TRAIL_INTERVAL_MS: u64 = 500;
 }
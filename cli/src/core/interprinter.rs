@@ -3,21 +3,35 @@ use shlex::Shlex;
 
 use crate::commands::{
     Commands,
+    acme_renew::acme_renew,
+    acme_status::acme_status,
     add_http_route::add_http_route,
     add_https_route::add_https_route,
     add_iws_route::add_iws_route,
+    add_path_rule::add_path_rule,
+    add_rewrite_rule::add_rewrite_rule,
     add_secure_iws_route::add_secure_iws_route,
+    block_ip::block_ip,
     clear_terminal::clear_terminal,
     delete_http_route::delete_http_route,
     delete_https_route::delete_https_route,
     delete_iws_route::delete_iws_route,
+    delete_path_rule::delete_path_rule,
+    delete_rewrite_rule::delete_rewrite_rule,
     delete_secure_iws_route::delete_secure_iws_route,
+    delete_ssl_file::delete_ssl_file,
     echo::echo,
     exit::exit,
     get_route_list::get_route_list,
     get_ssl_files::get_ssl_files,
     get_status::get_status,
+    inspect_ssl_file::inspect_ssl_file,
+    list_blocked::list_blocked,
     logs::logs,
+    monitor::monitor,
+    ssl_expiry::ssl_expiry,
+    unblock_ip::unblock_ip,
+    upload_ssl_file::upload_ssl_file,
     version::version,
 };
 
@@ -43,17 +57,31 @@ impl Interprinter {
             Commands::Clear => clear_terminal().await,
             Commands::Echo(args) => echo(args).await,
             Commands::AddHttpRoute(args) => add_http_route(args).await,
-            Commands::AddHttpsRoute(args) => add_https_route(args).await,
+            Commands::AddHttpsRoute(args) => add_https_route(*args).await,
             Commands::DeleteHttpRoute(args) => delete_http_route(args).await,
             Commands::DeleteHttpsRoute(args) => delete_https_route(args).await,
             Commands::DeleteIwsRoute(args) => delete_iws_route(args).await,
             Commands::DeleteSecureIwsRoute(args) => delete_secure_iws_route(args).await,
+            Commands::AddPathRule(args) => add_path_rule(args).await,
+            Commands::DeletePathRule(args) => delete_path_rule(args).await,
+            Commands::AddRewriteRule(args) => add_rewrite_rule(args).await,
+            Commands::DeleteRewriteRule(args) => delete_rewrite_rule(args).await,
             Commands::GetRouteList(args) => get_route_list(args).await,
             Commands::Status => get_status().await,
             Commands::AddIwsRoute(args) => add_iws_route(args).await,
             Commands::AddSecureIwsRoute(args) => add_secure_iws_route(args).await,
             Commands::GetSslFiles(args) => get_ssl_files(args).await,
             Commands::Logs(args) => logs(args).await,
+            Commands::BlockIp(args) => block_ip(args).await,
+            Commands::UnblockIp(args) => unblock_ip(args).await,
+            Commands::ListBlocked => list_blocked().await,
+            Commands::Monitor(args) => monitor(args).await,
+            Commands::UploadSslFile(args) => upload_ssl_file(args).await,
+            Commands::InspectSslFile(args) => inspect_ssl_file(args).await,
+            Commands::DeleteSslFile(args) => delete_ssl_file(args).await,
+            Commands::AcmeStatus(args) => acme_status(args).await,
+            Commands::AcmeRenew(args) => acme_renew(args).await,
+            Commands::SslExpiry(args) => ssl_expiry(args).await,
         }
     }
     pub async fn run(&self, input: String) {
@@ -1,8 +1,19 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::models::entity::{header_rule::HeaderRule, traffic_policy::TrafficPolicy, upstream_pool::UpstreamPool};
+
 #[derive(Serialize, Deserialize)]
 pub struct AddHttpRouteRequest {
     pub source: String,
     pub target: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream_pool: Option<UpstreamPool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub traffic_policy: Option<TrafficPolicy>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub header_rules: Vec<HeaderRule>,
 }
@@ -1,10 +1,37 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::models::entity::{
+    client_auth::ClientAuth, header_rule::HeaderRule, tls_settings::TlsSettings, traffic_policy::TrafficPolicy,
+    upstream_pool::UpstreamPool,
+};
+
 #[derive(Serialize, Deserialize)]
 pub struct AddHttpsRouteRequest {
     pub source: String,
     pub target: String,
-    pub ssl_cert_path: String,
-    pub ssl_private_key_path: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssl_cert_path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssl_private_key_path: Option<String>,
+
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub acme: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_auth: Option<ClientAuth>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsSettings>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream_pool: Option<UpstreamPool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub traffic_policy: Option<TrafficPolicy>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub header_rules: Vec<HeaderRule>,
 }
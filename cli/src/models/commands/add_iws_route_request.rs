@@ -1,8 +1,13 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::models::entity::header_rule::HeaderRule;
+
 #[derive(Serialize, Deserialize)]
 pub struct AddIwsRouteRequest {
     pub source: String,
     pub serving_path: String,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub header_rules: Vec<HeaderRule>,
 }
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::common::enums::route_type::RouteType;
+
+#[derive(Serialize, Deserialize)]
+pub struct AddPathRuleRequest {
+    pub route_type: RouteType,
+    pub source: String,
+    pub path: String,
+    pub match_type: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serving_path: Option<String>,
+
+    pub strip_prefix: bool,
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::common::enums::route_type::RouteType;
+use crate::models::entity::rewrite_rule::RewriteCondition;
+
+#[derive(Serialize, Deserialize)]
+pub struct AddRewriteRuleRequest {
+    pub route_type: RouteType,
+    pub source: String,
+    pub pattern: String,
+    pub action: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<RewriteCondition>,
+}
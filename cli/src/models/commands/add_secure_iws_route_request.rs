@@ -1,10 +1,28 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::models::entity::{client_auth::ClientAuth, header_rule::HeaderRule, tls_settings::TlsSettings};
+
 #[derive(Serialize, Deserialize)]
 pub struct AddSecureIwsRouteRequest {
     pub source: String,
     pub serving_path: String,
-    pub ssl_cert_path: String,
-    pub ssl_private_key_path: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssl_cert_path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssl_private_key_path: Option<String>,
+
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub acme: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_auth: Option<ClientAuth>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsSettings>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub header_rules: Vec<HeaderRule>,
 }
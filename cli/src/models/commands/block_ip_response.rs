@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BlockIpResponse {
+    pub code: u16,
+    pub message: String,
+}
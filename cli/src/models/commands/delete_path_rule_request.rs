@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::common::enums::route_type::RouteType;
+
+#[derive(Serialize, Deserialize)]
+pub struct DeletePathRuleRequest {
+    pub route_type: RouteType,
+    pub source: String,
+    pub path: String,
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::common::enums::route_type::RouteType;
+
+#[derive(Serialize, Deserialize)]
+pub struct DeleteRewriteRuleRequest {
+    pub route_type: RouteType,
+    pub source: String,
+    pub pattern: String,
+}
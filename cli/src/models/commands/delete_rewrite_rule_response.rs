@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeleteRewriteRuleResponse {
+    pub code: u16,
+    pub message: String,
+}
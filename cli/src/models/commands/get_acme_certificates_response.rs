@@ -0,0 +1,10 @@
+use crate::models::entity::acme_certificate::AcmeCertificate;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetAcmeCertificatesResponse {
+    pub code: u16,
+    pub message: String,
+    pub acme_certificates: Vec<AcmeCertificate>,
+}
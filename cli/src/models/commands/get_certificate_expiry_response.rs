@@ -0,0 +1,10 @@
+use crate::models::entity::certificate_expiry::CertificateExpiry;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetCertificateExpiryResponse {
+    pub code: u16,
+    pub message: String,
+    pub certificates: Vec<CertificateExpiry>,
+}
@@ -0,0 +1,11 @@
+use crate::models::entity::engine_logger_config::EngineLoggerConfig;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Response of `get_logger_config` & `set_logger_config`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetLoggerConfigResponse {
+    pub code: u16,
+    pub message: String,
+    pub logger: EngineLoggerConfig,
+}
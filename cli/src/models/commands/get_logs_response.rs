@@ -0,0 +1,13 @@
+use crate::models::entity::log_record::LogRecord;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Response of `get_logs` & `subscribe_logs`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetLogsResponse {
+    pub code: u16,
+    pub message: String,
+    pub records: Vec<LogRecord>,
+    pub cursor: u64,
+    pub dropped_count: u64,
+}
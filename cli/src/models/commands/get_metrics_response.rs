@@ -0,0 +1,11 @@
+use crate::models::entity::metrics::EngineMetrics;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetMetricsResponse {
+    pub code: u16,
+    pub message: String,
+    #[serde(flatten)]
+    pub metrics: EngineMetrics,
+}
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::models::entity::degraded_route::DegradedRoute;
 use crate::models::entity::http_route::HttpRoute;
 use crate::models::entity::https_route::HttpsRoute;
 use crate::models::entity::iws_route::IwsRoute;
@@ -16,4 +17,6 @@ pub struct GetRouteListResponse {
     pub https_routes: Option<HashMap<String, HttpsRoute>>,
     pub iws_routes: Option<HashMap<String, IwsRoute>>,
     pub secure_iws_routes: Option<HashMap<String, SecureIwsRoute>>,
+    #[serde(default)]
+    pub degraded_routes: Option<Vec<DegradedRoute>>,
 }
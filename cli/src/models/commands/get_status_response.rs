@@ -1,4 +1,4 @@
-use crate::models::entity::engine_config::EngineConfig;
+use crate::models::entity::{degraded_route::DegradedRoute, engine_config::EngineConfig};
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -18,5 +18,8 @@ pub struct GetStatusResponse {
     pub http_route_count: usize,
     pub https_route_count: usize,
     pub iws_route_count: usize,
-    pub secure_iws_route_count: usize
+    pub secure_iws_route_count: usize,
+
+    #[serde(default)]
+    pub degraded_routes: Vec<DegradedRoute>,
 } 
\ No newline at end of file
@@ -0,0 +1,10 @@
+use crate::models::entity::upstream_health::UpstreamHealth;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetUpstreamHealthResponse {
+    pub code: u16,
+    pub message: String,
+    pub upstreams: Vec<UpstreamHealth>,
+}
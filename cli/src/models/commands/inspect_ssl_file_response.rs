@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::entity::{certificate_info::CertificateInfo, ssl_file::SSlFile};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InspectSslFileResponse {
+    pub code: u16,
+    pub message: String,
+    pub ssl_file: Option<SSlFile>,
+    #[serde(default)]
+    pub certificates: Vec<CertificateInfo>,
+    pub private_key_format: Option<String>,
+    #[serde(default)]
+    pub used_by: Vec<String>,
+}
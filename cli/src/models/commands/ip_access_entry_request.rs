@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::common::enums::route_type::RouteType;
+
+#[derive(Serialize, Deserialize)]
+pub struct IpAccessEntryRequest {
+    pub ip: String,
+    pub scope: String,
+    pub list: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub route_type: Option<RouteType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
@@ -0,0 +1,21 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::models::entity::ip_access::IpAccess;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RouteIpAccess {
+    pub route_type: String,
+    pub source: String,
+    pub ip_access: IpAccess,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListBlockedResponse {
+    pub code: u16,
+    pub message: String,
+    pub global: IpAccess,
+    pub http_server: IpAccess,
+    pub https_server: IpAccess,
+    pub routes: Vec<RouteIpAccess>,
+}
@@ -0,0 +1,7 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Serialize, Deserialize)]
+pub struct RenewAcmeCertificateRequest {
+    pub source: String,
+}
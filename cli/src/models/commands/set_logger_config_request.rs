@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Serialize, Deserialize)]
+pub struct SetLoggerConfigRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_levels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_file_size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_last_logs: Option<usize>,
+}
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// `content` is base64 encoded PEM text
+#[derive(Serialize, Deserialize)]
+pub struct UploadSslFileRequest {
+    pub name: String,
+    pub content: String,
+    pub overwrite: bool,
+}
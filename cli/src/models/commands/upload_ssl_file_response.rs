@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::entity::ssl_file::SSlFile;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UploadSslFileResponse {
+    pub code: u16,
+    pub message: String,
+    pub ssl_file: Option<SSlFile>,
+}
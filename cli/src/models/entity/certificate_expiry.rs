@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CertificateExpiry {
+    pub source: String,
+    pub route_type: String,
+    pub certificate_file_path: String,
+    pub status: String,
+    pub subject: Option<String>,
+    pub not_after: Option<i64>,
+    pub days_left: Option<i64>,
+    pub error: Option<String>,
+}
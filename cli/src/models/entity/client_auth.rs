@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// Client certificate authentication (mTLS) of an HTTPS or Secure IWS route
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct ClientAuth {
+    pub ca_bundle_path: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_identities: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_identity_headers: Option<bool>,
+}
+
+impl ClientAuth {
+    /// Builds client auth settings from CLI arguments.
+    /// Returns `None` when no client CA is given, or an error when other mTLS arguments are given without it
+    pub fn from_args(
+        ca_bundle_path: &Option<String>,
+        mode: &Option<String>,
+        allowed_identities: &[String],
+        hide_identity_headers: bool,
+    ) -> Result<Option<ClientAuth>, String> {
+        let Some(ca_bundle_path) = ca_bundle_path else {
+            if mode.is_some() || !allowed_identities.is_empty() || hide_identity_headers {
+                return Err("Client certificate authentication requires '--client-ca'".to_string());
+            }
+
+            return Ok(None);
+        };
+
+        if let Some(mode) = mode {
+            if !["required", "optional"].contains(&mode.to_lowercase().as_str()) {
+                return Err("Not accepted client auth mode. Please type one of following: 'required', 'optional'".to_string());
+            }
+        }
+
+        Ok(Some(ClientAuth {
+            ca_bundle_path: ca_bundle_path.clone(),
+            mode: mode.as_ref().map(|mode| mode.to_lowercase()),
+            allowed_identities: (!allowed_identities.is_empty()).then(|| allowed_identities.to_vec()),
+            forward_identity_headers: hide_identity_headers.then_some(false),
+        }))
+    }
+}
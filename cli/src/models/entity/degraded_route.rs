@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// HTTPS or Secure IWS route whose certificate or TLS settings can not be loaded by engine
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DegradedRoute {
+    pub source: String,
+    pub route_type: String,
+    pub reason: String,
+    pub is_fallback_certificate_served: bool,
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct HeaderRule {
+    pub action: String,
+
+    #[serde(default)]
+    pub phase: String,
+
+    pub name: String,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_name: Option<String>,
+}
+
+impl HeaderRule {
+    /// Builds header rules from `--request-header` & `--response-header` arguments, keeping their order.
+    /// Arguments are `add:<name>=<value>`, `set:<name>=<value>`, `remove:<name>` or `rename:<name>=<new name>`
+    pub fn from_args(request_headers: &[String], response_headers: &[String]) -> Result<Vec<HeaderRule>, String> {
+        let request_rules = request_headers.iter().map(|argument| Self::parse(argument, "request"));
+        let response_rules = response_headers.iter().map(|argument| Self::parse(argument, "response"));
+
+        request_rules.chain(response_rules).collect()
+    }
+
+    fn parse(argument: &str, phase: &str) -> Result<HeaderRule, String> {
+        let Some((action, rule)) = argument.split_once(':') else {
+            return Err(format!(
+                "Invalid header rule '{}'. Please use 'add:<name>=<value>', 'set:<name>=<value>', 'remove:<name>' or 'rename:<name>=<new name>'",
+                argument
+            ));
+        };
+
+        let action = action.trim().to_lowercase();
+        let (name, value) = match rule.split_once('=') {
+            Some((name, value)) => (name.trim().to_string(), Some(value.trim().to_string())),
+            None => (rule.trim().to_string(), None),
+        };
+
+        let mut header_rule = HeaderRule {
+            action: action.clone(),
+            phase: phase.to_string(),
+            name,
+            value: None,
+            new_name: None,
+        };
+
+        match (action.as_str(), value) {
+            ("add" | "set", Some(value)) => header_rule.value = Some(value),
+            ("rename", Some(new_name)) => header_rule.new_name = Some(new_name),
+            ("remove", None) => {}
+            ("add" | "set" | "rename" | "remove", _) => {
+                return Err(format!("Invalid '{}' header rule: '{}'", action, argument));
+            }
+            _ => {
+                return Err(
+                    "Not accepted header rule action. Please type one of following: 'add', 'set', 'remove', 'rename'"
+                        .to_string(),
+                );
+            }
+        }
+
+        Ok(header_rule)
+    }
+}
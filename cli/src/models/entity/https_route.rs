@@ -1,9 +1,30 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::entity::ssl_context::SslContext;
+use crate::models::entity::{
+    header_rule::HeaderRule, ip_access::IpAccess, path_rule::PathRule, rewrite_rule::RewriteRule, ssl_context::SslContext, traffic_policy::TrafficPolicy,
+    upstream_pool::UpstreamPool,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
 pub struct HttpsRoute {
     pub target: String,
     pub ssl_context: SslContext,
-}
\ No newline at end of file
+
+    #[serde(default)]
+    pub path_rules: Vec<PathRule>,
+
+    #[serde(default)]
+    pub rewrite_rules: Vec<RewriteRule>,
+
+    #[serde(default)]
+    pub upstream_pool: Option<UpstreamPool>,
+
+    #[serde(default)]
+    pub traffic_policy: Option<TrafficPolicy>,
+
+    #[serde(default)]
+    pub ip_access: Option<IpAccess>,
+
+    #[serde(default)]
+    pub header_rules: Vec<HeaderRule>,
+}
@@ -1,6 +1,20 @@
 use serde::{Deserialize, Serialize};
 
+use crate::models::entity::{header_rule::HeaderRule, ip_access::IpAccess, path_rule::PathRule, rewrite_rule::RewriteRule};
+
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
 pub struct IwsRoute {
     pub serving_path: String,
-}
\ No newline at end of file
+
+    #[serde(default)]
+    pub path_rules: Vec<PathRule>,
+
+    #[serde(default)]
+    pub rewrite_rules: Vec<RewriteRule>,
+
+    #[serde(default)]
+    pub ip_access: Option<IpAccess>,
+
+    #[serde(default)]
+    pub header_rules: Vec<HeaderRule>,
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::entity::ssl_context::SslContext;
+use crate::models::entity::{
+    ip_access::IpAccess, path_rule::PathRule, ssl_context::SslContext, upstream_pool::UpstreamPool,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
 pub struct JsonRoute {
@@ -12,4 +14,13 @@ pub struct JsonRoute {
     pub ssl: Option<SslContext>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub serving_path: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub path_rules: Vec<PathRule>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream_pool: Option<UpstreamPool>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_access: Option<IpAccess>,
 }
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LogRecord {
+    pub id: u64,
+    pub timestamp: i64,
+    pub level: String,
+    pub message: String,
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Snapshot of engine metrics. Counters are cumulative since engine start
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EngineMetrics {
+    pub uptime_seconds: u64,
+    pub active_connections: usize,
+    pub active_upgraded_connections: usize,
+    pub total_upgraded_connections: u64,
+    pub total_requests: u64,
+    pub status_classes: BTreeMap<String, u64>,
+    pub latency: LatencySummary,
+    pub routes: Vec<RouteMetrics>,
+    pub top_clients: Vec<ClientMetrics>,
+    pub upstream_errors: u64,
+    pub rate_limit_rejections: u64,
+    pub tls_handshake_failures: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LatencySummary {
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub buckets: Vec<LatencyBucket>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LatencyBucket {
+    pub upper_bound_ms: f64,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RouteMetrics {
+    pub server: String,
+    pub route: String,
+    pub requests: u64,
+    pub client_errors: u64,
+    pub server_errors: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ClientMetrics {
+    pub ip: String,
+    pub requests: u64,
+}
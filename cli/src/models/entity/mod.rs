@@ -4,10 +4,25 @@ pub mod http_route;
 pub mod https_route;
 pub mod iws_route;
 pub mod secure_iws_route;
+pub mod path_rule;
 
 pub mod engine_config;
 pub mod engine_http_server_config;
 pub mod engine_https_server_config;
 pub mod engine_rpc_server_config;
 pub mod engine_logger_config;
-pub mod ssl_file;
\ No newline at end of file
+pub mod ssl_file;
+pub mod upstream_pool;
+pub mod upstream_health;
+pub mod traffic_policy;
+pub mod ip_access;
+pub mod metrics;
+pub mod log_record;
+pub mod certificate_info;
+pub mod acme_certificate;
+pub mod degraded_route;
+pub mod client_auth;
+pub mod tls_settings;
+pub mod certificate_expiry;
+pub mod header_rule;
+pub mod rewrite_rule;
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct PathRule {
+    pub path: String,
+
+    #[serde(default)]
+    pub match_type: String,
+
+    #[serde(default)]
+    pub target: Option<String>,
+
+    #[serde(default)]
+    pub serving_path: Option<String>,
+
+    #[serde(default)]
+    pub strip_prefix: bool,
+}
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct RewriteRule {
+    pub pattern: String,
+
+    pub action: String,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<RewriteCondition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct RewriteCondition {
+    pub condition_type: String,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    #[serde(default)]
+    pub negate: bool,
+}
+
+impl RewriteCondition {
+    /// Builds conditions from `--if-method`, `--if-header`, `--if-query` & `--if-client-ip` arguments.
+    /// Header & query arguments are `<name>` or `<name>=<regex>`, a leading `!` negates any condition
+    pub fn from_args(
+        methods: &[String],
+        headers: &[String],
+        queries: &[String],
+        client_ips: &[String],
+    ) -> Result<Vec<RewriteCondition>, String> {
+        let method_conditions = methods.iter().map(|argument| Self::parse_list(argument, "method"));
+        let header_conditions = headers.iter().map(|argument| Self::parse_named(argument, "header"));
+        let query_conditions = queries.iter().map(|argument| Self::parse_named(argument, "query"));
+        let client_ip_conditions = client_ips.iter().map(|argument| Self::parse_list(argument, "client_ip"));
+
+        method_conditions
+            .chain(header_conditions)
+            .chain(query_conditions)
+            .chain(client_ip_conditions)
+            .collect()
+    }
+
+    fn parse_list(argument: &str, condition_type: &str) -> Result<RewriteCondition, String> {
+        let (negate, value) = Self::split_negation(argument);
+        if value.is_empty() {
+            return Err(format!("Rewrite condition '{}' needs a value", condition_type));
+        }
+
+        Ok(RewriteCondition {
+            condition_type: condition_type.to_string(),
+            name: None,
+            value: Some(value.to_string()),
+            negate,
+        })
+    }
+
+    fn parse_named(argument: &str, condition_type: &str) -> Result<RewriteCondition, String> {
+        let (negate, rule) = Self::split_negation(argument);
+        let (name, value) = match rule.split_once('=') {
+            Some((name, value)) => (name.trim(), Some(value.to_string())),
+            None => (rule, None),
+        };
+
+        if name.is_empty() {
+            return Err(format!(
+                "Invalid {} condition '{}'. Please use '<name>' or '<name>=<regex>'",
+                condition_type, argument
+            ));
+        }
+
+        Ok(RewriteCondition {
+            condition_type: condition_type.to_string(),
+            name: Some(name.to_string()),
+            value,
+            negate,
+        })
+    }
+
+    fn split_negation(argument: &str) -> (bool, &str) {
+        let argument = argument.trim();
+        match argument.strip_prefix('!') {
+            Some(argument) => (true, argument.trim()),
+            None => (false, argument),
+        }
+    }
+}
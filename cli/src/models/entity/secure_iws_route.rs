@@ -1,9 +1,21 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::entity::ssl_context::SslContext;
+use crate::models::entity::{header_rule::HeaderRule, ip_access::IpAccess, path_rule::PathRule, rewrite_rule::RewriteRule, ssl_context::SslContext};
 
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
 pub struct SecureIwsRoute {
     pub serving_path: String,
     pub ssl_context: SslContext,
+
+    #[serde(default)]
+    pub path_rules: Vec<PathRule>,
+
+    #[serde(default)]
+    pub rewrite_rules: Vec<RewriteRule>,
+
+    #[serde(default)]
+    pub ip_access: Option<IpAccess>,
+
+    #[serde(default)]
+    pub header_rules: Vec<HeaderRule>,
 }
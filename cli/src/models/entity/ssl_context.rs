@@ -1,7 +1,15 @@
 use serde::{Deserialize, Serialize};
 
+use crate::models::entity::{client_auth::ClientAuth, tls_settings::TlsSettings};
+
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
 pub struct SslContext {
     pub certificate_file_path: String,
-    pub private_key_file_path: String
+    pub private_key_file_path: String,
+    #[serde(default)]
+    pub acme: Option<bool>,
+    #[serde(default)]
+    pub client_auth: Option<ClientAuth>,
+    #[serde(default)]
+    pub tls: Option<TlsSettings>,
 }
@@ -3,9 +3,11 @@ use serde::Serialize;
 
 use crate::common::enums::ssl_file_type::SSlFileType;
 
-/// Domain - SSL File Type pair for crud operations on @Vanguard path's ssl file item list
+/// File in engine's SSL upload directory. Routes refer to it by `reference` (`@vanguard/<name>`)
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SSlFile {
-    pub domain: String,
+    pub name: String,
+    pub reference: String,
     pub file_type: SSlFileType,
+    pub size: u64,
 }
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+/// TLS handshake policy of an HTTPS or Secure IWS route. Undefined fields fall back to `https_server.tls` settings
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct TlsSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_version: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_version: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cipher_suites: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kx_groups: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpn_protocols: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_tickets: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_resumption: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_cache_size: Option<usize>,
+}
+
+impl TlsSettings {
+    /// Builds route TLS settings from CLI arguments.
+    /// Returns `None` when no TLS argument is given, or an error when a TLS version is not supported
+    pub fn from_args(
+        min_version: &Option<String>,
+        max_version: &Option<String>,
+        cipher_suites: &[String],
+        kx_groups: &[String],
+        alpn_protocols: &[String],
+        session_tickets: bool,
+    ) -> Result<Option<TlsSettings>, String> {
+        for version in [min_version, max_version].into_iter().flatten() {
+            if !["1.2", "1.3"].contains(&version.as_str()) {
+                return Err("Not accepted TLS version. Please type one of following: '1.2', '1.3'".to_string());
+            }
+        }
+
+        let to_option = |values: &[String]| (!values.is_empty()).then(|| values.to_vec());
+
+        let tls_settings = TlsSettings {
+            min_version: min_version.clone(),
+            max_version: max_version.clone(),
+            cipher_suites: to_option(cipher_suites),
+            kx_groups: to_option(kx_groups),
+            alpn_protocols: to_option(alpn_protocols),
+            session_tickets: session_tickets.then_some(true),
+            ..Default::default()
+        };
+
+        Ok((tls_settings != TlsSettings::default()).then_some(tls_settings))
+    }
+}
@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+
+/// Route level traffic policy. Only the fields managed by CLI are modelled, the rest is kept by engine defaults
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct TrafficPolicy {
+    #[serde(default)]
+    pub upstream_settings: UpstreamSettings,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forwarded_headers: Option<ForwardedHeaders>,
+}
+
+/// Forwarding headers (`X-Forwarded-*`, `Forwarded`, `Via`) sent to route upstreams
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct ForwardedHeaders {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct UpstreamSettings {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls: Option<UpstreamTls>,
+}
+
+/// TLS settings used by engine when route target or upstreams have `https://` scheme
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct UpstreamTls {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ca_bundle_path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sni_override: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_certificate_path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_private_key_path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insecure_skip_verify: Option<bool>,
+}
+
+impl TrafficPolicy {
+    /// Builds a traffic policy carrying upstream TLS settings.
+    /// Returns `None` when none of the upstream TLS arguments is given
+    pub fn from_upstream_tls_args(
+        ca_bundle_path: &Option<String>,
+        sni_override: &Option<String>,
+        client_certificate_path: &Option<String>,
+        client_private_key_path: &Option<String>,
+        insecure_skip_verify: bool,
+    ) -> Option<TrafficPolicy> {
+        let upstream_tls = UpstreamTls {
+            ca_bundle_path: ca_bundle_path.clone(),
+            sni_override: sni_override.clone(),
+            client_certificate_path: client_certificate_path.clone(),
+            client_private_key_path: client_private_key_path.clone(),
+            insecure_skip_verify: insecure_skip_verify.then_some(true),
+        };
+
+        if upstream_tls == UpstreamTls::default() {
+            return None;
+        }
+
+        Some(TrafficPolicy {
+            upstream_settings: UpstreamSettings {
+                tls: Some(upstream_tls),
+            },
+            forwarded_headers: None,
+        })
+    }
+
+    /// Adds forwarding header policy of the route into given traffic policy.
+    /// Returns an error when forwarding mode or a header name is not supported
+    pub fn with_forwarded_header_args(
+        traffic_policy: Option<TrafficPolicy>,
+        mode: &Option<String>,
+        headers: &[String],
+    ) -> Result<Option<TrafficPolicy>, String> {
+        if let Some(mode) = mode {
+            if !["append", "overwrite"].contains(&mode.as_str()) {
+                return Err("Not accepted forwarding mode. Please type one of following: 'append', 'overwrite'".to_string());
+            }
+        }
+
+        let supported_headers = [
+            "x-forwarded-for",
+            "x-forwarded-proto",
+            "x-forwarded-host",
+            "x-forwarded-port",
+            "forwarded",
+            "via",
+        ];
+
+        let headers: Vec<String> = headers.iter().map(|header| header.to_lowercase()).collect();
+        if let Some(header) = headers.iter().find(|header| !supported_headers.contains(&header.as_str())) {
+            return Err(format!(
+                "Not accepted forwarding header '{}'. Please type one of following: '{}'",
+                header,
+                supported_headers.join("', '")
+            ));
+        }
+
+        let forwarded_headers = ForwardedHeaders {
+            mode: mode.clone(),
+            headers: (!headers.is_empty()).then_some(headers),
+        };
+
+        if forwarded_headers == ForwardedHeaders::default() {
+            return Ok(traffic_policy);
+        }
+
+        let mut traffic_policy = traffic_policy.unwrap_or_default();
+        traffic_policy.forwarded_headers = Some(forwarded_headers);
+
+        Ok(Some(traffic_policy))
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UpstreamHealth {
+    pub route_type: String,
+    pub source: String,
+    pub target: String,
+    pub status: String,
+    pub is_active_check_enabled: bool,
+    pub consecutive_successes: u32,
+    pub consecutive_failures: u32,
+    pub passive_failures: u32,
+    pub ejection_remaining_seconds: Option<u64>,
+    pub last_checked_at: Option<i64>,
+    pub last_error: Option<String>,
+}
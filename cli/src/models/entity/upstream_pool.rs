@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct Upstream {
+    pub target: String,
+    pub weight: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct UpstreamPool {
+    pub strategy: String,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash_key: Option<String>,
+
+    pub upstreams: Vec<Upstream>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check: Option<HealthCheck>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outlier_detection: Option<OutlierDetection>,
+}
+
+/// Active health check settings. Engine defaults are used for fields which are not set
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct HealthCheck {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub healthy_threshold: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unhealthy_threshold: Option<u32>,
+}
+
+/// Passive health check settings. Engine defaults are used for fields which are not set
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct OutlierDetection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consecutive_failures: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ejection_time: Option<u64>,
+}
+
+impl UpstreamPool {
+    /// Builds an upstream pool from route target and additional `--upstream` arguments.
+    /// Returns `None` when neither additional upstreams nor a strategy is given (single target route)
+    pub fn from_args(
+        target: &str,
+        additional_upstreams: &[String],
+        strategy: &Option<String>,
+        hash_key: &Option<String>,
+    ) -> Result<Option<UpstreamPool>, String> {
+        if additional_upstreams.is_empty() && strategy.is_none() {
+            return Ok(None);
+        }
+
+        let mut upstreams = vec![Self::parse_upstream(target)?];
+        for each_upstream in additional_upstreams {
+            upstreams.push(Self::parse_upstream(each_upstream)?);
+        }
+
+        let strategy = strategy
+            .clone()
+            .unwrap_or("round-robin".to_string())
+            .to_lowercase()
+            .replace('-', "_");
+
+        Ok(Some(UpstreamPool {
+            strategy,
+            hash_key: hash_key.clone(),
+            upstreams,
+            health_check: None,
+            outlier_detection: None,
+        }))
+    }
+
+    /// Enables active health checks on given path and passive outlier detection after given consecutive failures
+    pub fn with_health_checks(
+        mut self,
+        health_check_path: &Option<String>,
+        health_check_interval: &Option<u64>,
+        max_failures: &Option<u32>,
+    ) -> UpstreamPool {
+        if health_check_path.is_some() || health_check_interval.is_some() {
+            self.health_check = Some(HealthCheck {
+                path: health_check_path.clone(),
+                interval: *health_check_interval,
+                ..Default::default()
+            });
+        }
+
+        if let Some(consecutive_failures) = max_failures {
+            self.outlier_detection = Some(OutlierDetection {
+                consecutive_failures: Some(*consecutive_failures),
+                ejection_time: None,
+            });
+        }
+
+        self
+    }
+
+    /// Parses upstream definition in `host:port` or `host:port@weight` format
+    fn parse_upstream(definition: &str) -> Result<Upstream, String> {
+        match definition.rsplit_once('@') {
+            Some((target, weight)) => {
+                let weight = weight
+                    .parse::<u32>()
+                    .map_err(|_| format!("Invalid weight of upstream '{}'", definition))?;
+
+                Ok(Upstream {
+                    target: target.to_string(),
+                    weight,
+                })
+            }
+            None => Ok(Upstream {
+                target: definition.to_string(),
+                weight: 1,
+            }),
+        }
+    }
+}
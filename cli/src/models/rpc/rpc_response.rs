@@ -15,6 +15,11 @@ impl RPCResponse {
         let parsed_input: Value = serde_json::from_str(&input)
             .map_err(|_| RPCBaseError::build("RPC response can not parsed"))?;
 
+        // Controllers report failures as JSON-RPC error objects instead of a result
+        if let Some(error_message) = parsed_input["error"]["message"].as_str() {
+            return Err(RPCBaseError::build(error_message));
+        }
+
         let jsonrpc_version = &parsed_input["jsonrpc"];
         let jsonrpc = jsonrpc_version.as_str().unwrap_or_default().to_string();
 
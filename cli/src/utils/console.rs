@@ -1,6 +1,8 @@
 use std::io::{self, stdout, Write};
+use std::time::{Duration, Instant};
 
 use crossterm::{
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
     style::{Color, Print, ResetColor, SetForegroundColor},
     terminal::{self, ClearType},
     ExecutableCommand,
@@ -63,3 +65,31 @@ pub fn convert_input_to_boolean(input: &str) -> Option<bool> {
 
     None
 }
+
+/// Waits for given duration. Returns true if user pressed 'q', 'Esc' or 'Ctrl+C' meanwhile
+pub fn wait_for_exit_key(duration: Duration) -> std::io::Result<bool> {
+    let deadline = Instant::now() + duration;
+
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() || !event::poll(remaining)? {
+            return Ok(false);
+        }
+
+        if let Event::Key(key_event) = event::read()? {
+            if key_event.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            let is_exit_key = match key_event.code {
+                KeyCode::Char('q') | KeyCode::Esc => true,
+                KeyCode::Char('c') => key_event.modifiers.contains(KeyModifiers::CONTROL),
+                _ => false,
+            };
+
+            if is_exit_key {
+                return Ok(true);
+            }
+        }
+    }
+}
@@ -1,5 +1,53 @@
-use chrono::Utc;
+use chrono::{Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
 
 pub fn get_current_timestamp() -> i64 {
     Utc::now().timestamp()
 }
+
+/// Converts a relative time (e.g. `30s`, `15m`, `2h`, `1d` ago) or a local date time
+/// (`YYYY-MM-DD HH:MM:SS` or `YYYY-MM-DD`) into unix timestamp
+pub fn parse_time_reference(input: &str) -> Result<i64, String> {
+    let input = input.trim();
+    let invalid_error = || {
+        format!(
+            "'{}' is not a valid time. Use a relative time like 30s, 15m, 2h, 1d or a date like 2024-01-31 13:45:00",
+            input
+        )
+    };
+
+    let unit_seconds = match input.chars().last() {
+        Some('s') => Some(1),
+        Some('m') => Some(60),
+        Some('h') => Some(3_600),
+        Some('d') => Some(86_400),
+        _ => None,
+    };
+
+    if let Some(unit_seconds) = unit_seconds {
+        if let Ok(amount) = input[..input.len() - 1].parse::<i64>() {
+            return Ok(get_current_timestamp() - amount * unit_seconds);
+        }
+    }
+
+    let date_time = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M:%S")
+        .or_else(|_| {
+            NaiveDate::parse_from_str(input, "%Y-%m-%d")
+                .map(|date| date.and_hms_opt(0, 0, 0).unwrap_or_default())
+        })
+        .map_err(|_| invalid_error())?;
+
+    Local
+        .from_local_datetime(&date_time)
+        .earliest()
+        .map(|date_time| date_time.timestamp())
+        .ok_or_else(invalid_error)
+}
+
+/// Formats unix timestamp as local date time
+pub fn format_timestamp(timestamp: i64) -> String {
+    Local
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .map(|date_time| date_time.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default()
+}
@@ -1,10 +1,17 @@
 extern crate prettytable;
 use std::path::PathBuf;
+use std::sync::Arc;
 
+use crate::common::enums::route_type::RouteType;
+use crate::core::access_log::AccessLogger;
 use crate::core::http_server::HttpServer;
 use crate::core::https_server::HttpsServer;
 use crate::core::rpc_session::RpcSession;
-use crate::core::shared_memory::{HTTP_SERVER, HTTPS_SERVER, LOGGER, ROUTER, RPC_SERVER, RUNTIME_BOOT_INFO};
+use crate::core::ip_access_control::IpAccessControl;
+use crate::core::shared_memory::{
+    ACCESS_LOGGER, HTTP_SERVER, HTTPS_SERVER, IP_ACCESS_CONTROL, LOGGER, ROUTER, RPC_SERVER,
+    RUNTIME_BOOT_INFO,
+};
 use crate::models::boot_result::BootResult;
 use crate::rpc_service::rpc_server::RPCServer;
 use crate::utils::file_utility::save_json;
@@ -52,7 +59,14 @@ impl Boot {
         );
 
         let mut logger = LOGGER.write().unwrap();
-        *logger = LogService::init(&runtime_path, config.logger.clone());
+        *logger = LogService::init(&runtime_path, config.logger.clone(), &config.access_log);
+        drop(logger);
+
+        // Access log template is compiled after logger, so an invalid template is reported
+        ACCESS_LOGGER.store(Arc::new(AccessLogger::build(&config.access_log)));
+
+        // Ip access lists are compiled after logger, so skipped entries are reported
+        IP_ACCESS_CONTROL.store(Arc::new(IpAccessControl::build(&config)));
 
         let rpc_session = RpcSession::init(
             config.rpc_server.ip_address.clone(),
@@ -77,6 +91,11 @@ impl Boot {
 
         let mut runtime_boot_info = RUNTIME_BOOT_INFO.write().unwrap();
         *runtime_boot_info = boot_info.clone();
+        drop(runtime_boot_info);
+
+        // Publishing initial route snapshots which are served by HTTP & HTTPS servers.
+        // Boot info is stored first, since HTTPS snapshot reads TLS settings of HTTPS server
+        ROUTER.read().unwrap().publish(&RouteType::All);
 
         boot_info
     }
@@ -100,12 +119,14 @@ impl Boot {
         }
 
         let config = read_config_operation.unwrap();
-        let validation = config.validate();
 
-        if validation.is_ok() {
-            (config, true)
-        } else {
-            (Config::default(), false)
+        match config.validate() {
+            Ok(()) => (config, true),
+            Err(validation_error) => {
+                // Logger is not initialized yet, so the reason is printed for the operator
+                println!("Config file is invalid: {}", validation_error);
+                (Config::default(), false)
+            }
         }
     }
 
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// State of a certificate which is issued & renewed by ACME
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AcmeCertificateStatus {
+    /// Certificate is not issued yet
+    #[default]
+    Pending,
+    /// An order is in progress
+    Issuing,
+    Valid,
+    /// Last issuance failed, it is retried after retry interval
+    Failed,
+}
@@ -0,0 +1,36 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// ACME challenge answered while proving control of a domain.
+/// `http-01` is served by HTTP server on port 80, `tls-alpn-01` by HTTPS server on port 443
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+pub enum AcmeChallengeType {
+    #[default]
+    #[serde(rename = "http-01")]
+    Http01,
+    #[serde(rename = "tls-alpn-01")]
+    TlsAlpn01,
+}
+
+impl AcmeChallengeType {
+    /// Returns challenge type identifier used by ACME servers
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AcmeChallengeType::Http01 => "http-01",
+            AcmeChallengeType::TlsAlpn01 => "tls-alpn-01",
+        }
+    }
+}
+
+impl FromStr for AcmeChallengeType {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "http-01" => Ok(AcmeChallengeType::Http01),
+            "tls-alpn-01" => Ok(AcmeChallengeType::TlsAlpn01),
+            _ => Err("Not accepted ACME challenge type. Please type one of following: 'http-01', 'tls-alpn-01'"),
+        }
+    }
+}
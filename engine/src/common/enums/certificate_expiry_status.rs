@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Expiry state of the leaf certificate of a route
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CertificateExpiryStatus {
+    Valid,
+    /// Certificate expires within one of warning thresholds
+    Expiring,
+    Expired,
+    /// Certificate file can not be read or parsed
+    Unreadable,
+}
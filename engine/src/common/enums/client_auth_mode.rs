@@ -0,0 +1,24 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Whether a client certificate is mandatory (`required`) or only verified when presented (`optional`)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientAuthMode {
+    #[default]
+    Required,
+    Optional,
+}
+
+impl FromStr for ClientAuthMode {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "required" => Ok(ClientAuthMode::Required),
+            "optional" => Ok(ClientAuthMode::Optional),
+            _ => Err("Not accepted client auth mode. Please type one of following: 'required', 'optional'"),
+        }
+    }
+}
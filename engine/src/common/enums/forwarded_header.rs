@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Forwarding headers which can be emitted to upstreams
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ForwardedHeader {
+    XForwardedFor,
+    XForwardedProto,
+    XForwardedHost,
+    XForwardedPort,
+    /// RFC 7239 `Forwarded` header
+    Forwarded,
+    Via,
+}
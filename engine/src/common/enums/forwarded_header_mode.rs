@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// How forwarding headers of incoming requests are handled
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ForwardedHeaderMode {
+    /// Current hop is appended to incoming values
+    #[default]
+    Append,
+    /// Incoming values are discarded, upstream only sees the values of current hop
+    Overwrite,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Change made by a header rule
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum HeaderRuleAction {
+    /// Adds a value, existing values of the header are kept
+    Add,
+    /// Replaces all values of the header
+    Set,
+    Remove,
+    /// Moves values of the header under `new_name`
+    Rename,
+}
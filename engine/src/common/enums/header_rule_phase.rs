@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Message which a header rule is applied to
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum HeaderRulePhase {
+    /// Request, before it is forwarded to upstream or served
+    #[default]
+    Request,
+    /// Response, before it is returned to client
+    Response,
+}
@@ -0,0 +1,33 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum IpAccessListType {
+    Allow,
+    #[default]
+    Deny,
+}
+
+impl FromStr for IpAccessListType {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "allow" => Ok(IpAccessListType::Allow),
+            "deny" => Ok(IpAccessListType::Deny),
+            _ => Err("Not accepted ip list. Please type one of following: 'allow', 'deny'"),
+        }
+    }
+}
+
+impl fmt::Display for IpAccessListType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IpAccessListType::Allow => write!(f, "allow"),
+            IpAccessListType::Deny => write!(f, "deny"),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Level which an ip access list belongs to. Lists of every level are evaluated, deny of any level rejects the request
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum IpAccessScope {
+    #[default]
+    Global,
+    HttpServer,
+    HttpsServer,
+    Route,
+}
+
+impl FromStr for IpAccessScope {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.to_lowercase().replace(['-', '_'], "");
+
+        match normalized.as_str() {
+            "global" => Ok(IpAccessScope::Global),
+            "http" | "httpserver" => Ok(IpAccessScope::HttpServer),
+            "https" | "httpsserver" => Ok(IpAccessScope::HttpsServer),
+            "route" => Ok(IpAccessScope::Route),
+            _ => Err("Not accepted scope. Please type one of following: 'global', 'http', 'https', 'route'"),
+        }
+    }
+}
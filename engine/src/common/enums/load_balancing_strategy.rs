@@ -0,0 +1,31 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadBalancingStrategy {
+    #[default]
+    RoundRobin,
+    WeightedRoundRobin,
+    LeastConnections,
+    RandomTwoChoices,
+    ConsistentHash,
+}
+
+impl FromStr for LoadBalancingStrategy {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.to_lowercase().replace(['-', '_'], "");
+
+        match normalized.as_str() {
+            "roundrobin" => Ok(LoadBalancingStrategy::RoundRobin),
+            "weightedroundrobin" => Ok(LoadBalancingStrategy::WeightedRoundRobin),
+            "leastconnections" => Ok(LoadBalancingStrategy::LeastConnections),
+            "randomtwochoices" => Ok(LoadBalancingStrategy::RandomTwoChoices),
+            "consistenthash" => Ok(LoadBalancingStrategy::ConsistentHash),
+            _ => Err("Not accepted load balancing strategy. Please type one of following: 'round-robin', 'weighted-round-robin', 'least-connections', 'random-two-choices', 'consistent-hash'"),
+        }
+    }
+}
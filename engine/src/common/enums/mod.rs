@@ -1 +1,19 @@
 pub mod route_type;
+pub mod path_match_type;
+pub mod load_balancing_strategy;
+pub mod upstream_health_status;
+pub mod ip_access_list_type;
+pub mod ip_access_scope;
+pub mod rate_limit_algorithm;
+pub mod access_log_format;
+pub mod ssl_file_type;
+pub mod acme_challenge_type;
+pub mod acme_certificate_status;
+pub mod client_auth_mode;
+pub mod certificate_expiry_status;
+pub mod forwarded_header_mode;
+pub mod forwarded_header;
+pub mod header_rule_action;
+pub mod header_rule_phase;
+pub mod rewrite_action;
+pub mod rewrite_condition_type;
@@ -0,0 +1,27 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PathMatchType {
+    #[default]
+    Prefix,
+    Exact,
+    Regex,
+}
+
+impl FromStr for PathMatchType {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.to_lowercase();
+
+        match normalized.as_str() {
+            "prefix" => Ok(PathMatchType::Prefix),
+            "exact" => Ok(PathMatchType::Exact),
+            "regex" => Ok(PathMatchType::Regex),
+            _ => Err("Not accepted path match type. Please type one of following: 'prefix', 'exact', 'regex'"),
+        }
+    }
+}
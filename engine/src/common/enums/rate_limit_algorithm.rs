@@ -0,0 +1,27 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitAlgorithm {
+    #[default]
+    TokenBucket,
+    Gcra,
+    SlidingLog,
+}
+
+impl FromStr for RateLimitAlgorithm {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.to_lowercase().replace(['-', '_'], "");
+
+        match normalized.as_str() {
+            "tokenbucket" => Ok(RateLimitAlgorithm::TokenBucket),
+            "gcra" => Ok(RateLimitAlgorithm::Gcra),
+            "slidinglog" => Ok(RateLimitAlgorithm::SlidingLog),
+            _ => Err("Not accepted rate limit algorithm. Please type one of following: 'token-bucket', 'gcra', 'sliding-log'"),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// What a matching rewrite rule does with a request
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RewriteAction {
+    /// Changes path (and query) of the request, following rules see the new path
+    Rewrite,
+    /// Answers with a `301`, `302`, `307` or `308` redirect to `replacement`
+    Redirect,
+    /// Answers with `status_code` and `body`
+    Return,
+}
+
+impl FromStr for RewriteAction {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.to_lowercase();
+
+        match normalized.as_str() {
+            "rewrite" => Ok(RewriteAction::Rewrite),
+            "redirect" => Ok(RewriteAction::Redirect),
+            "return" => Ok(RewriteAction::Return),
+            _ => Err("Not accepted rewrite action. Please type one of following: 'rewrite', 'redirect', 'return'"),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Part of a request checked by a rewrite rule condition
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RewriteConditionType {
+    Method,
+    Header,
+    Query,
+    ClientIp,
+}
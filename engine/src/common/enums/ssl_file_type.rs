@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// Content type of a file in SSL upload directory
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Copy)]
+pub enum SslFileType {
+    Invalid,
+    PemCertificate,
+    PemPrivateKey,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UpstreamHealthStatus {
+    /// Upstream has active health checks but has not been probed yet
+    Unknown,
+    Healthy,
+    /// Upstream failed active health checks
+    Unhealthy,
+    /// Upstream is temporarily removed by passive outlier detection
+    Ejected,
+}
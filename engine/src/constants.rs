@@ -10,6 +10,9 @@ impl Constants {
     pub const LINUX_RUNTIME_PATH: &'static str = "/etc/vanguard";
     pub const OSX_RUNTIME_PATH: &'static str = "Library/Application Support/Vanguard";
 
+    pub const SSL_FILE_REFERENCE_PREFIX: &'static str = "@vanguard/";
+    pub const MAX_SSL_FILE_SIZE: usize = 1_000_000; // 1 MB
+
     pub const SETTINGS_FILENAME: &'static str = "settings.json";
     pub const SESSION_FILENAME: &'static str = ".session.json";
     pub const ROUTER_FILENAME: &'static str = "routing.json";
@@ -24,6 +27,21 @@ impl Constants {
     pub const DEFAULT_LOG_LEVELS: &'static [&'static str] = &["ERROR", "INFO", "WARNING", "DEBUG"];
     pub const DEFAULT_LOG_FILE_SIZE: u64 = 10_000_000; // 10 MB
     pub const DEFAULT_KEEP_LAST_LOGS: usize = 7;
+    pub const LOG_BUFFER_CAPACITY: usize = 5000;
+
+    pub const ACCESS_LOG_WRITER_NAME: &'static str = "AccessLog";
+    pub const ACCESS_LOG_TARGET: &'static str = "{AccessLog}";
+    pub const DEFAULT_ACCESS_LOG_FILE_NAME: &'static str = "access";
+    pub const DEFAULT_ACCESS_LOG_SAMPLE_RATE: f64 = 1.0;
+    pub const ACCESS_LOG_TIME_FORMAT: &'static str = "%d/%b/%Y:%H:%M:%S %z";
+    pub const ACCESS_LOG_COMMON_TEMPLATE: &'static str =
+        "$remote_addr - - [$time_local] \"$request\" $status $bytes_sent";
+    pub const ACCESS_LOG_COMBINED_TEMPLATE: &'static str =
+        "$remote_addr - - [$time_local] \"$request\" $status $bytes_sent \"$http_referer\" \"$http_user_agent\"";
+    pub const REQUEST_ID_HEADER: &'static str = "x-request-id";
+    pub const CLIENT_CERT_SUBJECT_HEADER: &'static str = "x-client-cert-subject";
+    pub const CLIENT_CERT_SAN_HEADER: &'static str = "x-client-cert-san";
+    pub const DEFAULT_LOG_QUERY_LIMIT: usize = 100;
 
     pub const DEFUALT_HTTPS_IS_ACTIVE: bool = true;
     pub const DEFAULT_HTTP_IP: &'static str = "0.0.0.0";
@@ -32,12 +50,26 @@ impl Constants {
     pub const DEFUALT_HTTP_IS_ACTIVE: bool = true;
     pub const DEFAULT_HTTPS_IP: &'static str = "0.0.0.0";
     pub const DEFAULT_HTTPS_PORT: u16 = 443;
+    pub const DEFAULT_CERTIFICATE_COMMON_NAME: &'static str = "Vanguard Engine Default Certificate";
+    pub const DEFAULT_TLS_MIN_VERSION: &'static str = "1.2";
+    pub const DEFAULT_TLS_MAX_VERSION: &'static str = "1.3";
+    pub const DEFAULT_ALPN_PROTOCOLS: [&'static str; 2] = ["h2", "http/1.1"];
+    pub const DEFAULT_TLS_SESSION_CACHE_SIZE: usize = 256;
 
     pub const DEFUALT_RPC_IS_ACTIVE: bool = true;
     pub const DEFAULT_RPC_IP: &'static str = "127.0.0.1";
     pub const DEFAULT_RPC_PORT: u16 = 4242;
     pub const DEFAULT_PRIVATE_SECRET_KEY: &'static str = "0xVanguard";
 
+    pub const DEFAULT_METRICS_IS_ACTIVE: bool = true;
+    pub const DEFAULT_METRICS_IP: &'static str = "127.0.0.1";
+    pub const DEFAULT_METRICS_PORT: u16 = 9464;
+    pub const METRICS_PATH: &'static str = "/metrics";
+    pub const METRICS_LATENCY_BUCKETS: &'static [f64] =
+        &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+    pub const METRICS_MAX_TRACKED_CLIENTS: usize = 1024;
+    pub const DEFAULT_METRICS_TOP_LIMIT: usize = 10;
+
     // Only Global Scalability & Performance Settings
     pub const DEFAULT_HTTP1_HEADER_READ_TIMEOUT: u64 = 10; // 10 seconds
     pub const DEFAULT_MAXIMUM_TOTAL_CONNECTIONS: u64 = 10000; // 10k connections in same time
@@ -56,4 +88,51 @@ impl Constants {
     pub const DEFAULT_SERVER_WRITE_TIMEOUT: u64 = 30; // 30 seconds
     pub const DEFAULT_MAX_REQUEST_BODY_SIZE: u64 = 10 * 1024 * 1024; // 10 MB
     pub const DEFAULT_MAX_REQUESTS_PER_MINUTE: u32 = 120; // 2 RPS on average
+    pub const DEFAULT_UPGRADED_CONNECTION_IDLE_TIMEOUT: u64 = 300; // 5 minutes
+    pub const UPGRADED_CONNECTION_BUFFER_SIZE: usize = 16 * 1024; // 16 KB
+
+    // Load Balancing Settings
+    pub const DEFAULT_CONSISTENT_HASH_VIRTUAL_NODES: u32 = 160; // Ring points per unit of upstream weight
+    pub const MAX_UPSTREAM_WEIGHT: u32 = 1000; // Keeps consistent hash ring of an upstream at most 160K points
+
+    // Upstream Health Check Settings
+    pub const DEFAULT_HEALTH_CHECK_PATH: &'static str = "/";
+    pub const DEFAULT_HEALTH_CHECK_INTERVAL: u64 = 10; // 10 seconds
+    pub const DEFAULT_HEALTH_CHECK_TIMEOUT: u64 = 2; // 2 seconds
+    pub const DEFAULT_HEALTHY_THRESHOLD: u32 = 2;
+    pub const DEFAULT_UNHEALTHY_THRESHOLD: u32 = 3;
+    pub const DEFAULT_OUTLIER_CONSECUTIVE_FAILURES: u32 = 5;
+    pub const DEFAULT_OUTLIER_EJECTION_TIME: u64 = 30; // 30 seconds
+    pub const HEALTH_CHECK_TICK_INTERVAL: u64 = 1; // 1 second
+
+    // Rate Limiting Settings
+    pub const DEFAULT_RATE_LIMIT_KEY: &'static str = "ip";
+    pub const DEFAULT_RATE_LIMIT_API_KEY_HEADER: &'static str = "x-api-key";
+    pub const RATE_LIMIT_PERIOD: u64 = 60; // Limits are defined per minute
+    pub const RATE_LIMIT_SHARD_COUNT: usize = 64;
+    pub const RATE_LIMIT_SWEEP_INTERVAL: u64 = 30; // 30 seconds
+
+    // Forwarding Header Settings
+    pub const VIA_PSEUDONYM: &'static str = "vanguard";
+
+    // ACME (automatic certificate) Settings
+    pub const DEFAULT_ACME_DIRECTORY_URL: &'static str =
+        "https://acme-v02.api.letsencrypt.org/directory";
+    pub const DEFAULT_ACME_RENEW_BEFORE_DAYS: u64 = 30;
+    pub const DEFAULT_ACME_RETRY_INTERVAL: u64 = 3600; // 1 hour
+    pub const ACME_TICK_INTERVAL: u64 = 10; // 10 seconds
+    pub const ACME_POLL_INTERVAL: u64 = 2; // 2 seconds
+    pub const ACME_MAX_POLL_ATTEMPTS: u32 = 30;
+    pub const ACME_REQUEST_TIMEOUT: u64 = 30; // 30 seconds, per request & per response body
+    pub const ACME_ISSUE_TIMEOUT: u64 = 600; // 10 minutes, whole order of a certificate
+    pub const ACME_MAX_RESPONSE_SIZE: usize = 1_000_000; // 1 MB
+    pub const ACME_DIR_NAME: &'static str = "ACME";
+    pub const ACME_ACCOUNT_KEY_FILENAME: &'static str = "account.key";
+    pub const ACME_HTTP_CHALLENGE_PATH: &'static str = "/.well-known/acme-challenge/";
+    pub const ACME_TLS_ALPN_PROTOCOL: &'static [u8] = b"acme-tls/1";
+
+    // Certificate Expiry Monitoring Settings
+    pub const DEFAULT_CERTIFICATE_WARNING_DAYS: [u64; 3] = [30, 7, 1];
+    pub const DEFAULT_CERTIFICATE_CHECK_INTERVAL: u64 = 3600; // 1 hour
+    pub const CERTIFICATE_WEBHOOK_TIMEOUT: u64 = 10; // 10 seconds
 }
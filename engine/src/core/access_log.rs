@@ -0,0 +1,478 @@
+use chrono::{DateTime, Local};
+use hyper::body::HttpBody;
+use hyper::header::{self, HeaderValue};
+use hyper::{Body, Request, Response};
+use rustls::{ProtocolVersion, ServerConnection};
+use serde_json::{Map, Value};
+use std::net::IpAddr;
+use std::time::Duration;
+
+use crate::{
+    common::enums::access_log_format::AccessLogFormat,
+    constants::Constants,
+    core::{
+        client_auth::ClientIdentity,
+        common_handler::Protocol,
+        metrics::{RouteLabel, UNMATCHED_ROUTE_LABEL},
+        shared_memory::LOGGER,
+    },
+    log_error,
+    models::settings::access_log_settings::AccessLogSettings,
+};
+
+/// Response extension carrying the upstream a request was forwarded to and how long it took
+#[derive(Debug, Clone)]
+pub struct UpstreamExchange {
+    pub address: String,
+    pub latency: Duration,
+}
+
+impl UpstreamExchange {
+    pub fn new(address: &str, latency: Duration) -> Self {
+        Self {
+            address: address.to_string(),
+            latency,
+        }
+    }
+}
+
+/// TLS parameters negotiated by a HTTPS connection, shared by every request of the connection
+#[derive(Debug, Clone, Default)]
+pub struct TlsConnectionInfo {
+    pub version: Option<String>,
+    pub sni: Option<String>,
+    /// Route whose TLS settings were used by the handshake
+    pub route_source: Option<String>,
+    pub client_identity: Option<ClientIdentity>,
+}
+
+impl TlsConnectionInfo {
+    pub fn from_connection(connection: &ServerConnection, route_source: Option<String>) -> Self {
+        let version = connection.protocol_version().map(|version| match version {
+            ProtocolVersion::TLSv1_2 => "TLSv1.2".to_string(),
+            ProtocolVersion::TLSv1_3 => "TLSv1.3".to_string(),
+            other => format!("{:?}", other),
+        });
+
+        Self {
+            version,
+            sni: connection.sni_hostname().map(|sni| sni.to_string()),
+            route_source,
+            client_identity: ClientIdentity::from_connection(connection),
+        }
+    }
+}
+
+/// Request details needed by access log, captured before request is consumed by handlers
+pub struct AccessLogRequest {
+    method: String,
+    uri: String,
+    version: String,
+    host: Option<String>,
+    referer: Option<String>,
+    user_agent: Option<String>,
+    request_id: String,
+}
+
+impl AccessLogRequest {
+    /// Captures the request. Requests without `x-request-id` get a generated one,
+    /// so upstream receives the same id which is written into access log
+    fn capture(req: &mut Request<Body>) -> Self {
+        let request_id = match get_header(req, Constants::REQUEST_ID_HEADER) {
+            Some(request_id) => request_id,
+            None => {
+                let request_id = generate_request_id();
+                if let Ok(header_value) = HeaderValue::from_str(&request_id) {
+                    req.headers_mut().insert(Constants::REQUEST_ID_HEADER, header_value);
+                }
+                request_id
+            }
+        };
+
+        let host = get_header(req, header::HOST).or_else(|| req.uri().host().map(|host| host.to_string()));
+
+        Self {
+            method: req.method().to_string(),
+            uri: req
+                .uri()
+                .path_and_query()
+                .map(|path_and_query| path_and_query.to_string())
+                .unwrap_or_else(|| req.uri().path().to_string()),
+            version: format!("{:?}", req.version()),
+            host,
+            referer: get_header(req, header::REFERER),
+            user_agent: get_header(req, header::USER_AGENT),
+            request_id,
+        }
+    }
+}
+
+/// Fields usable in access log templates as `$field` or `${field}`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AccessLogField {
+    RemoteAddr,
+    TimeLocal,
+    TimeIso8601,
+    Request,
+    Method,
+    Uri,
+    Protocol,
+    Status,
+    BytesSent,
+    HttpReferer,
+    HttpUserAgent,
+    Host,
+    Route,
+    Server,
+    UpstreamAddr,
+    UpstreamLatency,
+    RequestTime,
+    TlsVersion,
+    Sni,
+    RequestId,
+}
+
+impl AccessLogField {
+    const ALL: [AccessLogField; 20] = [
+        AccessLogField::RemoteAddr,
+        AccessLogField::TimeLocal,
+        AccessLogField::TimeIso8601,
+        AccessLogField::Request,
+        AccessLogField::Method,
+        AccessLogField::Uri,
+        AccessLogField::Protocol,
+        AccessLogField::Status,
+        AccessLogField::BytesSent,
+        AccessLogField::HttpReferer,
+        AccessLogField::HttpUserAgent,
+        AccessLogField::Host,
+        AccessLogField::Route,
+        AccessLogField::Server,
+        AccessLogField::UpstreamAddr,
+        AccessLogField::UpstreamLatency,
+        AccessLogField::RequestTime,
+        AccessLogField::TlsVersion,
+        AccessLogField::Sni,
+        AccessLogField::RequestId,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            AccessLogField::RemoteAddr => "remote_addr",
+            AccessLogField::TimeLocal => "time_local",
+            AccessLogField::TimeIso8601 => "time_iso8601",
+            AccessLogField::Request => "request",
+            AccessLogField::Method => "method",
+            AccessLogField::Uri => "uri",
+            AccessLogField::Protocol => "protocol",
+            AccessLogField::Status => "status",
+            AccessLogField::BytesSent => "bytes_sent",
+            AccessLogField::HttpReferer => "http_referer",
+            AccessLogField::HttpUserAgent => "http_user_agent",
+            AccessLogField::Host => "host",
+            AccessLogField::Route => "route",
+            AccessLogField::Server => "server",
+            AccessLogField::UpstreamAddr => "upstream_addr",
+            AccessLogField::UpstreamLatency => "upstream_latency",
+            AccessLogField::RequestTime => "request_time",
+            AccessLogField::TlsVersion => "tls_version",
+            AccessLogField::Sni => "sni",
+            AccessLogField::RequestId => "request_id",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().find(|field| field.name() == name).copied()
+    }
+
+    /// Returns field value of the entry, None if it is unknown for the request (e.g. upstream of a static file)
+    fn get_value(&self, entry: &AccessLogEntry) -> Option<Value> {
+        let request = entry.request;
+        match self {
+            AccessLogField::RemoteAddr => Some(Value::from(entry.client_ip.to_string())),
+            AccessLogField::TimeLocal => Some(Value::from(
+                entry.time.format(Constants::ACCESS_LOG_TIME_FORMAT).to_string(),
+            )),
+            AccessLogField::TimeIso8601 => Some(Value::from(entry.time.to_rfc3339())),
+            AccessLogField::Request => Some(Value::from(format!(
+                "{} {} {}",
+                request.method, request.uri, request.version
+            ))),
+            AccessLogField::Method => Some(Value::from(request.method.clone())),
+            AccessLogField::Uri => Some(Value::from(request.uri.clone())),
+            AccessLogField::Protocol => Some(Value::from(request.version.clone())),
+            AccessLogField::Status => entry.status.map(Value::from),
+            AccessLogField::BytesSent => entry.bytes_sent.map(Value::from),
+            AccessLogField::HttpReferer => request.referer.clone().map(Value::from),
+            AccessLogField::HttpUserAgent => request.user_agent.clone().map(Value::from),
+            AccessLogField::Host => request.host.clone().map(Value::from),
+            AccessLogField::Route => Some(Value::from(entry.route.to_string())),
+            AccessLogField::Server => Some(Value::from(entry.server)),
+            AccessLogField::UpstreamAddr => entry
+                .upstream
+                .map(|upstream| Value::from(upstream.address.clone())),
+            AccessLogField::UpstreamLatency => entry
+                .upstream
+                .map(|upstream| Value::from(get_seconds(upstream.latency))),
+            AccessLogField::RequestTime => Some(Value::from(get_seconds(entry.duration))),
+            AccessLogField::TlsVersion => entry.tls.and_then(|tls| tls.version.clone()).map(Value::from),
+            AccessLogField::Sni => entry.tls.and_then(|tls| tls.sni.clone()).map(Value::from),
+            AccessLogField::RequestId => Some(Value::from(request.request_id.clone())),
+        }
+    }
+}
+
+enum TemplateSegment {
+    Text(String),
+    Field(AccessLogField),
+}
+
+/// Compiled access log line template. `$$` writes a literal `$`
+struct AccessLogTemplate {
+    segments: Vec<TemplateSegment>,
+}
+
+impl AccessLogTemplate {
+    fn parse(template: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(character) = chars.next() {
+            if character != '$' {
+                text.push(character);
+                continue;
+            }
+
+            if chars.peek() == Some(&'$') {
+                chars.next();
+                text.push('$');
+                continue;
+            }
+
+            let is_braced = chars.peek() == Some(&'{');
+            if is_braced {
+                chars.next();
+            }
+
+            let mut name = String::new();
+            while let Some(&next) = chars.peek() {
+                if !(next.is_ascii_alphanumeric() || next == '_') {
+                    break;
+                }
+                name.push(next);
+                chars.next();
+            }
+
+            if is_braced && chars.next() != Some('}') {
+                return Err(format!("Access log field '${{{}' is not closed", name));
+            }
+
+            if name.is_empty() {
+                return Err("Access log template contains '$' without a field name, use '$$' for a literal '$'".to_string());
+            }
+
+            let field = AccessLogField::from_name(&name)
+                .ok_or_else(|| format!("Unknown access log field '${}'", name))?;
+
+            if !text.is_empty() {
+                segments.push(TemplateSegment::Text(std::mem::take(&mut text)));
+            }
+            segments.push(TemplateSegment::Field(field));
+        }
+
+        if !text.is_empty() {
+            segments.push(TemplateSegment::Text(text));
+        }
+
+        Ok(Self { segments })
+    }
+
+    fn render(&self, entry: &AccessLogEntry) -> String {
+        let mut line = String::new();
+        for segment in &self.segments {
+            match segment {
+                TemplateSegment::Text(text) => line.push_str(text),
+                TemplateSegment::Field(field) => match field.get_value(entry) {
+                    Some(Value::String(value)) => line.push_str(&value),
+                    Some(value) => line.push_str(&value.to_string()),
+                    None => line.push('-'),
+                },
+            }
+        }
+        line
+    }
+}
+
+struct AccessLogEntry<'a> {
+    server: &'static str,
+    client_ip: IpAddr,
+    request: &'a AccessLogRequest,
+    time: DateTime<Local>,
+    duration: Duration,
+    status: Option<u16>,
+    bytes_sent: Option<u64>,
+    route: &'a str,
+    upstream: Option<&'a UpstreamExchange>,
+    tls: Option<&'a TlsConnectionInfo>,
+}
+
+/// Writes one line per completed request into the access log file
+pub struct AccessLogger {
+    is_active: bool,
+    format: AccessLogFormat,
+    template: AccessLogTemplate,
+    sample_rate: f64,
+}
+
+impl Default for AccessLogger {
+    fn default() -> Self {
+        Self {
+            is_active: false,
+            format: AccessLogFormat::default(),
+            template: AccessLogTemplate { segments: Vec::new() },
+            sample_rate: Constants::DEFAULT_ACCESS_LOG_SAMPLE_RATE,
+        }
+    }
+}
+
+impl AccessLogger {
+    /// Builds access logger from settings. It stays inactive if access log writer could not be attached,
+    /// and falls back to combined format if the custom template is invalid
+    pub fn build(settings: &AccessLogSettings) -> Self {
+        let is_access_log_attached = LOGGER.read().unwrap().is_access_log_attached;
+        if !settings.is_active || !is_access_log_attached {
+            return Self::default();
+        }
+
+        let mut format = settings.get_format();
+        let template = match format {
+            AccessLogFormat::Common => Constants::ACCESS_LOG_COMMON_TEMPLATE,
+            AccessLogFormat::Custom => match &settings.template {
+                Some(template) => template.as_str(),
+                None => {
+                    log_error!("Access log format is custom but no template is given. Combined format is used instead");
+                    format = AccessLogFormat::Combined;
+                    Constants::ACCESS_LOG_COMBINED_TEMPLATE
+                }
+            },
+            _ => Constants::ACCESS_LOG_COMBINED_TEMPLATE,
+        };
+
+        let template = match AccessLogTemplate::parse(template) {
+            Ok(template) => template,
+            Err(error_message) => {
+                log_error!("{}. Combined access log format is used instead", error_message);
+                format = AccessLogFormat::Combined;
+                AccessLogTemplate::parse(Constants::ACCESS_LOG_COMBINED_TEMPLATE).unwrap()
+            }
+        };
+
+        Self {
+            is_active: true,
+            format,
+            template,
+            sample_rate: settings.get_sample_rate(),
+        }
+    }
+
+    /// Captures request details if access log is active
+    pub fn capture_request(&self, req: &mut Request<Body>) -> Option<AccessLogRequest> {
+        match self.is_active {
+            true => Some(AccessLogRequest::capture(req)),
+            false => None,
+        }
+    }
+
+    /// Writes access log line of a completed request, unless it is left out by sampling.
+    /// Route & upstream are read from response extensions
+    pub fn log(
+        &self,
+        protocol: Protocol,
+        client_ip: IpAddr,
+        request: &AccessLogRequest,
+        response: &Result<Response<Body>, hyper::Error>,
+        duration: Duration,
+        tls: Option<&TlsConnectionInfo>,
+    ) {
+        let response = response.as_ref().ok();
+        let route_label = response.and_then(|response| response.extensions().get::<RouteLabel>());
+
+        let sample_rate = route_label
+            .and_then(|route_label| route_label.access_log_sample_rate)
+            .unwrap_or(self.sample_rate);
+        if !is_sampled(sample_rate) {
+            return;
+        }
+
+        let entry = AccessLogEntry {
+            server: protocol.get_metric_label(),
+            client_ip,
+            request,
+            time: Local::now(),
+            duration,
+            status: response.map(|response| response.status().as_u16()),
+            bytes_sent: response.and_then(get_bytes_sent),
+            route: route_label
+                .map(|route_label| route_label.route_source.as_str())
+                .unwrap_or(UNMATCHED_ROUTE_LABEL),
+            upstream: response.and_then(|response| response.extensions().get::<UpstreamExchange>()),
+            tls,
+        };
+
+        let line = match self.format {
+            AccessLogFormat::Json => {
+                let fields: Map<String, Value> = AccessLogField::ALL
+                    .iter()
+                    .map(|field| {
+                        (
+                            field.name().to_string(),
+                            field.get_value(&entry).unwrap_or(Value::Null),
+                        )
+                    })
+                    .collect();
+                Value::Object(fields).to_string()
+            }
+            _ => self.template.render(&entry),
+        };
+
+        log::info!(target: Constants::ACCESS_LOG_TARGET, "{}", line);
+    }
+}
+
+fn is_sampled(sample_rate: f64) -> bool {
+    if sample_rate >= 1.0 {
+        return true;
+    }
+    if sample_rate <= 0.0 {
+        return false;
+    }
+    rand::random::<f64>() < sample_rate
+}
+
+/// Body size of the response: exact size hint of the body, or its `Content-Length` header
+fn get_bytes_sent(response: &Response<Body>) -> Option<u64> {
+    response.body().size_hint().exact().or_else(|| {
+        response
+            .headers()
+            .get(header::CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+    })
+}
+
+fn get_header<K: header::AsHeaderName>(req: &Request<Body>, name: K) -> Option<String> {
+    req.headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string())
+}
+
+pub fn generate_request_id() -> String {
+    format!("{:032x}", rand::random::<u128>())
+}
+
+/// Seconds with millisecond precision, e.g. `0.012`
+fn get_seconds(duration: Duration) -> f64 {
+    (duration.as_secs_f64() * 1000.0).round() / 1000.0
+}
@@ -0,0 +1,78 @@
+use hyper::{Body, Request, Response, StatusCode};
+use rustls::server::ClientHello;
+use rustls::sign::CertifiedKey;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::constants::Constants;
+
+/// Answers of ACME challenges which are in progress.
+/// HTTP-01 answers are served by HTTP server, TLS-ALPN-01 certificates are served by SNI resolver of HTTPS server
+#[derive(Default)]
+pub struct AcmeChallengeStore {
+    /// Key authorizations by challenge token
+    http_answers: RwLock<HashMap<String, String>>,
+
+    /// Self signed challenge certificates by domain
+    tls_alpn_certificates: RwLock<HashMap<String, Arc<CertifiedKey>>>,
+}
+
+impl AcmeChallengeStore {
+    pub fn add_http_answer(&self, token: &str, key_authorization: &str) {
+        self.http_answers
+            .write()
+            .unwrap()
+            .insert(token.to_string(), key_authorization.to_string());
+    }
+
+    pub fn remove_http_answer(&self, token: &str) {
+        self.http_answers.write().unwrap().remove(token);
+    }
+
+    pub fn add_tls_alpn_certificate(&self, domain: &str, certified_key: CertifiedKey) {
+        self.tls_alpn_certificates
+            .write()
+            .unwrap()
+            .insert(domain.to_lowercase(), Arc::new(certified_key));
+    }
+
+    pub fn remove_tls_alpn_certificate(&self, domain: &str) {
+        self.tls_alpn_certificates
+            .write()
+            .unwrap()
+            .remove(&domain.to_lowercase());
+    }
+
+    /// Answers `/.well-known/acme-challenge/<token>` requests of known tokens. Other requests are left to routing
+    pub fn answer_http_challenge(&self, req: &Request<Body>) -> Option<Response<Body>> {
+        let token = req
+            .uri()
+            .path()
+            .strip_prefix(Constants::ACME_HTTP_CHALLENGE_PATH)?;
+
+        let key_authorization = self.http_answers.read().unwrap().get(token).cloned()?;
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(hyper::header::CONTENT_TYPE, "application/octet-stream")
+            .body(Body::from(key_authorization))
+            .ok()
+    }
+
+    /// Returns challenge certificate of requested domain. Only called for `acme-tls/1` handshakes
+    pub fn find_tls_alpn_certificate(&self, domain: &str) -> Option<Arc<CertifiedKey>> {
+        self.tls_alpn_certificates
+            .read()
+            .unwrap()
+            .get(&domain.to_lowercase())
+            .cloned()
+    }
+}
+
+/// TLS-ALPN-01 validation servers only offer `acme-tls/1` protocol
+pub fn is_acme_tls_alpn_hello(client_hello: &ClientHello) -> bool {
+    client_hello
+        .alpn()
+        .map(|mut protocols| protocols.any(|protocol| protocol == Constants::ACME_TLS_ALPN_PROTOCOL))
+        .unwrap_or(false)
+}
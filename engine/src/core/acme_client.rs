@@ -0,0 +1,503 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use hyper::{body::HttpBody, header, Body, Method, Request, Response, StatusCode};
+use ring::rand::SystemRandom;
+use ring::signature::{
+    EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_ASN1_SIGNING, ECDSA_P256_SHA256_FIXED_SIGNING,
+};
+use rustls::sign::{any_ecdsa_type, CertifiedKey};
+use rustls::{Certificate, PrivateKey};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::{
+    common::enums::acme_challenge_type::AcmeChallengeType,
+    constants::Constants,
+    core::{http_client::HttpClient, shared_memory::ACME_CHALLENGES},
+    log_debug, log_info,
+    models::settings::acme_settings::AcmeSettings,
+    utils::{
+        directory_utility::get_acme_path,
+        file_utility::write_private_file,
+        x509_utility::{create_certificate_signing_request, create_tls_alpn_certificate, encode_pem},
+    },
+};
+
+const BAD_NONCE_ERROR: &str = "urn:ietf:params:acme:error:badNonce";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AcmeDirectory {
+    new_nonce: String,
+    new_account: String,
+    new_order: String,
+}
+
+#[derive(Deserialize)]
+struct AcmeOrder {
+    status: String,
+    #[serde(default)]
+    authorizations: Vec<String>,
+    finalize: String,
+    certificate: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AcmeAuthorization {
+    status: String,
+    #[serde(default)]
+    challenges: Vec<AcmeChallenge>,
+}
+
+#[derive(Deserialize)]
+struct AcmeChallenge {
+    #[serde(rename = "type")]
+    challenge_type: String,
+    url: String,
+    token: String,
+    error: Option<AcmeProblem>,
+}
+
+#[derive(Deserialize)]
+struct AcmeProblem {
+    #[serde(rename = "type", default)]
+    problem_type: String,
+    #[serde(default)]
+    detail: String,
+}
+
+/// Certificate chain & private key issued by ACME server, both PEM encoded
+pub struct IssuedCertificate {
+    pub certificate_pem: String,
+    pub private_key_pem: String,
+}
+
+/// Minimal ACME v2 (RFC 8555) client which orders single domain certificates.
+/// Requests are signed by an ES256 account key, which is created once & kept in ACME runtime directory
+pub struct AcmeClient {
+    http_client: HttpClient,
+    directory: AcmeDirectory,
+    account_key: EcdsaKeyPair,
+    account_url: Option<String>,
+    nonce: Option<String>,
+    rng: SystemRandom,
+}
+
+impl AcmeClient {
+    /// Reads ACME directory and registers (or finds) the account of engine's account key
+    pub async fn connect(acme_settings: &AcmeSettings) -> Result<Self, String> {
+        let http_client = HttpClient::init(
+            Constants::DEFAULT_POOL_IDLE_TIMEOUT,
+            1,
+            &acme_settings.get_tls_settings(),
+        )
+        .map_err(|error| error.message)?;
+
+        let directory_url = acme_settings.get_directory_url();
+        let directory_request = Request::builder()
+            .method(Method::GET)
+            .uri(&directory_url)
+            .body(Body::empty())
+            .map_err(|error| error.to_string())?;
+
+        let directory_response = Self::send(&http_client, directory_request).await?;
+        let directory: AcmeDirectory = read_json(directory_response)
+            .await
+            .map_err(|error| format!("ACME directory '{}' can not be read: {}", directory_url, error))?;
+
+        let mut acme_client = Self {
+            http_client,
+            directory,
+            account_key: load_account_key(&get_acme_path().join(Constants::ACME_ACCOUNT_KEY_FILENAME))?,
+            account_url: None,
+            nonce: None,
+            rng: SystemRandom::new(),
+        };
+
+        acme_client.register_account(&acme_settings.contact_email).await?;
+
+        Ok(acme_client)
+    }
+
+    /// Orders a certificate for given domain and proves control of it by given challenge type
+    pub async fn issue_certificate(
+        &mut self,
+        domain: &str,
+        challenge_type: &AcmeChallengeType,
+    ) -> Result<IssuedCertificate, String> {
+        let new_order_url = self.directory.new_order.clone();
+        let order_response = self
+            .post(
+                &new_order_url,
+                Some(json!({ "identifiers": [{ "type": "dns", "value": domain }] })),
+            )
+            .await?;
+
+        let order_url = get_header(&order_response, header::LOCATION.as_str())
+            .ok_or_else(|| "ACME server did not return order url".to_string())?;
+        let order: AcmeOrder = read_json(order_response).await?;
+
+        for authorization_url in &order.authorizations {
+            self.authorize(authorization_url, domain, challenge_type).await?;
+        }
+
+        let certificate_private_key =
+            EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &self.rng)
+                .map_err(|_| "Certificate private key can not be generated".to_string())?;
+        let signing_key =
+            EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, certificate_private_key.as_ref())
+                .map_err(|error| format!("Certificate private key is rejected: {}", error))?;
+
+        let certificate_signing_request =
+            create_certificate_signing_request(&[domain.to_string()], &signing_key)?;
+
+        self.post(
+            &order.finalize,
+            Some(json!({ "csr": URL_SAFE_NO_PAD.encode(certificate_signing_request) })),
+        )
+        .await?;
+
+        let certificate_url = self.wait_for_order(&order_url).await?;
+        let certificate_response = self.post(&certificate_url, None).await?;
+        let certificate_pem = read_text(certificate_response).await?;
+
+        Ok(IssuedCertificate {
+            certificate_pem,
+            private_key_pem: encode_pem("PRIVATE KEY", certificate_private_key.as_ref()),
+        })
+    }
+
+    async fn register_account(&mut self, contact_email: &Option<String>) -> Result<(), String> {
+        let mut payload = json!({ "termsOfServiceAgreed": true });
+        if let Some(contact_email) = contact_email {
+            payload["contact"] = json!([format!("mailto:{}", contact_email)]);
+        }
+
+        let new_account_url = self.directory.new_account.clone();
+        let account_response = self.post(&new_account_url, Some(payload)).await?;
+
+        let account_url = get_header(&account_response, header::LOCATION.as_str())
+            .ok_or_else(|| "ACME server did not return account url".to_string())?;
+
+        log_debug!("ACME account in use: {}", account_url);
+        self.account_url = Some(account_url);
+
+        Ok(())
+    }
+
+    /// Answers the challenge of an authorization and waits until ACME server validates it
+    async fn authorize(
+        &mut self,
+        authorization_url: &str,
+        domain: &str,
+        challenge_type: &AcmeChallengeType,
+    ) -> Result<(), String> {
+        let authorization: AcmeAuthorization =
+            read_json(self.post(authorization_url, None).await?).await?;
+
+        // Authorizations stay valid for a while, so renewals may not need a new validation
+        if authorization.status == "valid" {
+            return Ok(());
+        }
+
+        let challenge = authorization
+            .challenges
+            .iter()
+            .find(|challenge| challenge.challenge_type == challenge_type.as_str())
+            .ok_or_else(|| {
+                format!(
+                    "ACME server does not offer '{}' challenge for '{}'",
+                    challenge_type.as_str(),
+                    domain
+                )
+            })?;
+
+        let key_authorization = format!("{}.{}", challenge.token, self.get_thumbprint());
+
+        match challenge_type {
+            AcmeChallengeType::Http01 => {
+                ACME_CHALLENGES.add_http_answer(&challenge.token, &key_authorization);
+            }
+            AcmeChallengeType::TlsAlpn01 => {
+                let challenge_certificate = create_challenge_certificate(domain, &key_authorization)?;
+                ACME_CHALLENGES.add_tls_alpn_certificate(domain, challenge_certificate);
+            }
+        }
+
+        let _challenge_answer = ChallengeAnswer {
+            challenge_type,
+            token: &challenge.token,
+            domain,
+        };
+
+        self.validate_challenge(authorization_url, &challenge.url).await
+    }
+
+    async fn validate_challenge(
+        &mut self,
+        authorization_url: &str,
+        challenge_url: &str,
+    ) -> Result<(), String> {
+        self.post(challenge_url, Some(json!({}))).await?;
+
+        for _ in 0..Constants::ACME_MAX_POLL_ATTEMPTS {
+            tokio::time::sleep(Duration::from_secs(Constants::ACME_POLL_INTERVAL)).await;
+
+            let authorization: AcmeAuthorization =
+                read_json(self.post(authorization_url, None).await?).await?;
+
+            match authorization.status.as_str() {
+                "valid" => return Ok(()),
+                "pending" | "processing" => continue,
+                status => {
+                    let detail = authorization
+                        .challenges
+                        .iter()
+                        .find_map(|challenge| challenge.error.as_ref())
+                        .map(|problem| problem.detail.clone())
+                        .unwrap_or_default();
+
+                    return Err(format!("Domain validation is {}: {}", status, detail));
+                }
+            }
+        }
+
+        Err("Domain validation is not completed in time".to_string())
+    }
+
+    /// Polls order until certificate is issued and returns certificate url
+    async fn wait_for_order(&mut self, order_url: &str) -> Result<String, String> {
+        for _ in 0..Constants::ACME_MAX_POLL_ATTEMPTS {
+            let order: AcmeOrder = read_json(self.post(order_url, None).await?).await?;
+
+            match (order.status.as_str(), order.certificate) {
+                ("valid", Some(certificate_url)) => return Ok(certificate_url),
+                ("invalid", _) => return Err("ACME order is invalid".to_string()),
+                _ => tokio::time::sleep(Duration::from_secs(Constants::ACME_POLL_INTERVAL)).await,
+            }
+        }
+
+        Err("Certificate is not issued in time".to_string())
+    }
+
+    /// Sends a JWS signed POST request. Requests without payload are POST-as-GET requests.
+    /// Rejected nonces are retried once with a fresh nonce
+    async fn post(&mut self, url: &str, payload: Option<Value>) -> Result<Response<Body>, String> {
+        let mut is_retried = false;
+
+        loop {
+            let nonce = match self.nonce.take() {
+                Some(nonce) => nonce,
+                None => self.fetch_nonce().await?,
+            };
+
+            let request_body = self.sign(url, &nonce, &payload)?;
+            let request = Request::builder()
+                .method(Method::POST)
+                .uri(url)
+                .header(header::CONTENT_TYPE, "application/jose+json")
+                .body(Body::from(request_body.to_string()))
+                .map_err(|error| error.to_string())?;
+
+            let response = Self::send(&self.http_client, request).await?;
+            self.nonce = get_header(&response, "replay-nonce");
+
+            if response.status().is_success() {
+                return Ok(response);
+            }
+
+            let status = response.status();
+            let problem: AcmeProblem = read_json(response).await.unwrap_or(AcmeProblem {
+                problem_type: String::new(),
+                detail: String::new(),
+            });
+
+            if problem.problem_type == BAD_NONCE_ERROR && !is_retried {
+                is_retried = true;
+                continue;
+            }
+
+            return Err(format!("ACME server responded {} for '{}': {}", status, url, problem.detail));
+        }
+    }
+
+    async fn fetch_nonce(&self) -> Result<String, String> {
+        let request = Request::builder()
+            .method(Method::HEAD)
+            .uri(&self.directory.new_nonce)
+            .body(Body::empty())
+            .map_err(|error| error.to_string())?;
+
+        let response = Self::send(&self.http_client, request).await?;
+
+        get_header(&response, "replay-nonce")
+            .ok_or_else(|| "ACME server did not return a nonce".to_string())
+    }
+
+    /// Builds flattened JWS of given payload. Account is identified by `kid` once it is registered, by `jwk` before
+    fn sign(&self, url: &str, nonce: &str, payload: &Option<Value>) -> Result<Value, String> {
+        let mut protected_header = json!({ "alg": "ES256", "nonce": nonce, "url": url });
+        match &self.account_url {
+            Some(account_url) => protected_header["kid"] = json!(account_url),
+            None => protected_header["jwk"] = self.get_jwk(),
+        }
+
+        let protected_header = URL_SAFE_NO_PAD.encode(protected_header.to_string());
+        let payload = match payload {
+            Some(payload) => URL_SAFE_NO_PAD.encode(payload.to_string()),
+            None => String::new(),
+        };
+
+        let signature = self
+            .account_key
+            .sign(&self.rng, format!("{}.{}", protected_header, payload).as_bytes())
+            .map_err(|_| "ACME request can not be signed".to_string())?;
+
+        Ok(json!({
+            "protected": protected_header,
+            "payload": payload,
+            "signature": URL_SAFE_NO_PAD.encode(signature.as_ref()),
+        }))
+    }
+
+    /// Public account key as JWK
+    fn get_jwk(&self) -> Value {
+        let (x, y) = self.get_public_key_coordinates();
+
+        json!({ "crv": "P-256", "kty": "EC", "x": x, "y": y })
+    }
+
+    /// JWK thumbprint (RFC 7638) hashes required members in lexical order without whitespace
+    fn get_thumbprint(&self) -> String {
+        let (x, y) = self.get_public_key_coordinates();
+        let canonical_jwk = format!(r#"{{"crv":"P-256","kty":"EC","x":"{}","y":"{}"}}"#, x, y);
+
+        URL_SAFE_NO_PAD.encode(Sha256::digest(canonical_jwk.as_bytes()))
+    }
+
+    /// Base64url encoded coordinates of the uncompressed public key point: 0x04 | x (32 bytes) | y (32 bytes)
+    fn get_public_key_coordinates(&self) -> (String, String) {
+        let public_key = self.account_key.public_key().as_ref();
+
+        (
+            URL_SAFE_NO_PAD.encode(&public_key[1..33]),
+            URL_SAFE_NO_PAD.encode(&public_key[33..65]),
+        )
+    }
+
+    async fn send(http_client: &HttpClient, request: Request<Body>) -> Result<Response<Body>, String> {
+        let url = request.uri().to_string();
+        let response = tokio::time::timeout(
+            Duration::from_secs(Constants::ACME_REQUEST_TIMEOUT),
+            http_client.request(request),
+        )
+        .await
+        .map_err(|_| format!("ACME server '{}' did not respond in time", url))?;
+
+        response.map_err(|error| format!("ACME server '{}' can not be reached: {}", url, error))
+    }
+}
+
+/// Answer of a challenge which is served while the challenge is validated.
+/// Answer is removed on drop, so it does not outlive an order cancelled by its deadline
+struct ChallengeAnswer<'a> {
+    challenge_type: &'a AcmeChallengeType,
+    token: &'a str,
+    domain: &'a str,
+}
+
+impl Drop for ChallengeAnswer<'_> {
+    fn drop(&mut self) {
+        match self.challenge_type {
+            AcmeChallengeType::Http01 => ACME_CHALLENGES.remove_http_answer(self.token),
+            AcmeChallengeType::TlsAlpn01 => ACME_CHALLENGES.remove_tls_alpn_certificate(self.domain),
+        }
+    }
+}
+
+/// Loads ACME account key, a new one is created & saved on first use
+fn load_account_key(account_key_path: &Path) -> Result<EcdsaKeyPair, String> {
+    if !account_key_path.is_file() {
+        let private_key = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &SystemRandom::new())
+            .map_err(|_| "ACME account key can not be generated".to_string())?;
+
+        write_private_file(account_key_path, &encode_pem("PRIVATE KEY", private_key.as_ref()), true)
+            .map_err(|error| format!("ACME account key can not be saved: {}", error))?;
+
+        log_info!("New ACME account key is created at {}", account_key_path.display());
+    }
+
+    let account_key_pem = fs::read(account_key_path)
+        .map_err(|error| format!("ACME account key can not be read: {}", error))?;
+
+    let private_key = rustls_pemfile::pkcs8_private_keys(&mut account_key_pem.as_slice())
+        .ok()
+        .and_then(|private_keys| private_keys.into_iter().next())
+        .ok_or_else(|| "ACME account key is not a PKCS#8 private key".to_string())?;
+
+    EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &private_key)
+        .map_err(|error| format!("ACME account key is rejected: {}", error))
+}
+
+/// Creates self signed TLS-ALPN-01 certificate which carries digest of key authorization
+fn create_challenge_certificate(domain: &str, key_authorization: &str) -> Result<CertifiedKey, String> {
+    let private_key = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &SystemRandom::new())
+        .map_err(|_| "Challenge certificate key can not be generated".to_string())?;
+    let signing_key = EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, private_key.as_ref())
+        .map_err(|error| format!("Challenge certificate key is rejected: {}", error))?;
+
+    let acme_identifier = Sha256::digest(key_authorization.as_bytes());
+    let certificate = create_tls_alpn_certificate(domain, &acme_identifier, &signing_key)?;
+
+    let tls_signing_key = any_ecdsa_type(&PrivateKey(private_key.as_ref().to_vec()))
+        .map_err(|_| "Challenge certificate key is not supported by TLS".to_string())?;
+
+    Ok(CertifiedKey::new(vec![Certificate(certificate)], tls_signing_key))
+}
+
+fn get_header(response: &Response<Body>, header_name: &str) -> Option<String> {
+    response
+        .headers()
+        .get(header_name)
+        .and_then(|header_value| header_value.to_str().ok())
+        .map(|header_value| header_value.to_string())
+}
+
+/// Reads response body as text, up to `ACME_MAX_RESPONSE_SIZE` bytes
+async fn read_text(response: Response<Body>) -> Result<String, String> {
+    let mut body = response.into_body();
+
+    let read_body = async {
+        let mut content = Vec::new();
+        while let Some(chunk) = body.data().await {
+            let chunk = chunk.map_err(|error| format!("ACME response can not be read: {}", error))?;
+            if content.len() + chunk.len() > Constants::ACME_MAX_RESPONSE_SIZE {
+                return Err(format!(
+                    "ACME response is larger than {} bytes",
+                    Constants::ACME_MAX_RESPONSE_SIZE
+                ));
+            }
+            content.extend_from_slice(&chunk);
+        }
+        Ok(content)
+    };
+
+    let content = tokio::time::timeout(Duration::from_secs(Constants::ACME_REQUEST_TIMEOUT), read_body)
+        .await
+        .map_err(|_| "ACME response is not read in time".to_string())??;
+
+    String::from_utf8(content).map_err(|_| "ACME response is not valid text".to_string())
+}
+
+async fn read_json<T: for<'de> Deserialize<'de>>(response: Response<Body>) -> Result<T, String> {
+    let status = response.status();
+    let body = read_text(response).await?;
+
+    serde_json::from_str(&body).map_err(|error| match status {
+        StatusCode::OK | StatusCode::CREATED => format!("ACME response can not be parsed: {}", error),
+        status => format!("ACME server responded {}: {}", status, body),
+    })
+}
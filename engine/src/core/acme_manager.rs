@@ -0,0 +1,257 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+use std::time::Duration;
+
+use crate::{
+    common::enums::{acme_certificate_status::AcmeCertificateStatus, route_type::RouteType},
+    constants::Constants,
+    core::{
+        acme_client::AcmeClient,
+        shared_memory::{ACME_MANAGER, HTTPS_ROUTE_SNAPSHOT, ROUTER, RUNTIME_BOOT_INFO, SHUTDOWN_SIGNAL},
+    },
+    log_error, log_info,
+    models::{acme_certificate_report::AcmeCertificateReport, settings::acme_settings::AcmeSettings},
+    utils::{
+        ssl_file_utility::{get_acme_ssl_file_names, read_ssl_file, save_ssl_file},
+        time_utility::get_current_timestamp,
+        x509_utility::parse_pem_certificates,
+    },
+};
+
+#[derive(Default)]
+struct AcmeCertificateState {
+    status: AcmeCertificateStatus,
+    not_after: Option<i64>,
+    last_error: Option<String>,
+    next_attempt_at: Option<i64>,
+    is_renewal_requested: bool,
+}
+
+/// Issues certificates of routes added with `acme` flag and renews them before they expire.
+/// Issued files are written into SSL upload directory and HTTPS routes are republished, so SNI resolver is swapped live
+#[derive(Default)]
+pub struct AcmeManager {
+    states: RwLock<HashMap<String, AcmeCertificateState>>,
+}
+
+impl AcmeManager {
+    /// Background task which checks ACME routes on every tick. Routes are read from the live HTTPS route snapshot
+    pub async fn start() {
+        let mut shutdown_event = SHUTDOWN_SIGNAL.subscriber.clone();
+        let on_shutdown = async move {
+            let _on_shutdown = shutdown_event.wait_for(|&s| s).await;
+        };
+        tokio::pin!(on_shutdown);
+
+        let mut ticker = tokio::time::interval(Duration::from_secs(Constants::ACME_TICK_INTERVAL));
+
+        log_info!("Vanguard Engine ACME certificate manager started");
+
+        loop {
+            tokio::select! {
+                _on_shutdown = &mut on_shutdown => {
+                    break;
+                }
+                _on_tick = ticker.tick() => {
+                    let acme_settings = RUNTIME_BOOT_INFO.read().unwrap().config.acme.clone();
+                    if !acme_settings.is_active {
+                        continue;
+                    }
+
+                    let due_sources = ACME_MANAGER.collect_due_sources(&acme_settings);
+                    if !due_sources.is_empty() {
+                        tokio::spawn(async move {
+                            Self::issue_certificates(due_sources, acme_settings).await;
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns issue state of every ACME route, sorted by source
+    pub fn get_reports(&self) -> Vec<AcmeCertificateReport> {
+        let states = self.states.read().unwrap();
+
+        let mut reports: Vec<AcmeCertificateReport> = states
+            .iter()
+            .map(|(source, state)| AcmeCertificateReport {
+                source: source.clone(),
+                status: state.status.clone(),
+                not_after: state.not_after,
+                last_error: state.last_error.clone(),
+                next_attempt_at: state.next_attempt_at,
+            })
+            .collect();
+
+        reports.sort_by(|first, second| first.source.cmp(&second.source));
+        reports
+    }
+
+    /// Requests a new certificate of given route on next tick, even if current one is not close to expiry
+    pub fn request_renewal(&self, source: &str) -> Result<(), String> {
+        let mut states = self.states.write().unwrap();
+
+        let state = states
+            .get_mut(source)
+            .ok_or_else(|| format!("'{}' is not a route with ACME certificate", source))?;
+
+        if state.status == AcmeCertificateStatus::Issuing {
+            return Err(format!("Certificate of '{}' is already being issued", source));
+        }
+
+        state.is_renewal_requested = true;
+        state.next_attempt_at = None;
+
+        Ok(())
+    }
+
+    /// Syncs states with ACME routes and returns sources whose certificate is missing, expiring or requested
+    fn collect_due_sources(&self, acme_settings: &AcmeSettings) -> Vec<String> {
+        let acme_sources = Self::collect_acme_sources();
+        let now = get_current_timestamp();
+        let renew_before = acme_settings.get_renew_before_days() as i64 * 86_400;
+
+        let mut states = self.states.write().unwrap();
+        states.retain(|source, _| acme_sources.contains(source));
+
+        for source in &acme_sources {
+            states.entry(source.clone()).or_insert_with(|| {
+                let not_after = read_certificate_expiry(source);
+                AcmeCertificateState {
+                    status: match not_after {
+                        Some(_) => AcmeCertificateStatus::Valid,
+                        None => AcmeCertificateStatus::Pending,
+                    },
+                    not_after,
+                    ..Default::default()
+                }
+            });
+        }
+
+        let mut due_sources = Vec::new();
+        for (source, state) in states.iter_mut() {
+            if state.status == AcmeCertificateStatus::Issuing {
+                continue;
+            }
+
+            if state.next_attempt_at.map(|next_attempt_at| now < next_attempt_at).unwrap_or(false) {
+                continue;
+            }
+
+            let is_expiring = match state.not_after {
+                Some(not_after) => not_after - now <= renew_before,
+                None => true,
+            };
+
+            if is_expiring || state.is_renewal_requested {
+                state.status = AcmeCertificateStatus::Issuing;
+                state.is_renewal_requested = false;
+                due_sources.push(source.clone());
+            }
+        }
+
+        due_sources
+    }
+
+    /// Orders certificates one by one with a single ACME account session
+    async fn issue_certificates(sources: Vec<String>, acme_settings: AcmeSettings) {
+        let mut acme_client = match AcmeClient::connect(&acme_settings).await {
+            Ok(acme_client) => acme_client,
+            Err(error_message) => {
+                log_error!("ACME client could not connect: {}", error_message);
+                for source in &sources {
+                    ACME_MANAGER.record_failure(source, &error_message, &acme_settings);
+                }
+                return;
+            }
+        };
+
+        let mut is_any_issued = false;
+        for source in &sources {
+            log_info!("Requesting ACME certificate of '{}'", source);
+
+            // A stalled ACME server can not keep the route in `Issuing` state, so it is retried later
+            let issue_result = tokio::time::timeout(
+                Duration::from_secs(Constants::ACME_ISSUE_TIMEOUT),
+                acme_client.issue_certificate(source, &acme_settings.get_challenge_type()),
+            )
+            .await
+            .unwrap_or_else(|_| Err("Certificate is not issued in time".to_string()))
+            .and_then(|issued_certificate| {
+                let (certificate_file_name, private_key_file_name) = get_acme_ssl_file_names(source);
+
+                // Key is written first, so a published certificate never meets an older key
+                save_ssl_file(&private_key_file_name, &issued_certificate.private_key_pem, true)?;
+                save_ssl_file(&certificate_file_name, &issued_certificate.certificate_pem, true)?;
+
+                read_certificate_expiry(source)
+                    .ok_or_else(|| "Issued certificate can not be read".to_string())
+            });
+
+            match issue_result {
+                Ok(not_after) => {
+                    log_info!("ACME certificate of '{}' is issued", source);
+                    ACME_MANAGER.record_success(source, not_after);
+                    is_any_issued = true;
+                }
+                Err(error_message) => {
+                    log_error!("ACME certificate of '{}' could not be issued: {}", source, error_message);
+                    ACME_MANAGER.record_failure(source, &error_message, &acme_settings);
+                }
+            }
+        }
+
+        // New certificates are applied to next TLS handshakes by republishing HTTPS routes
+        if is_any_issued {
+            ROUTER.read().unwrap().publish(&RouteType::Https);
+        }
+    }
+
+    fn record_success(&self, source: &str, not_after: i64) {
+        if let Some(state) = self.states.write().unwrap().get_mut(source) {
+            state.status = AcmeCertificateStatus::Valid;
+            state.not_after = Some(not_after);
+            state.last_error = None;
+            state.next_attempt_at = None;
+        }
+    }
+
+    fn record_failure(&self, source: &str, error_message: &str, acme_settings: &AcmeSettings) {
+        if let Some(state) = self.states.write().unwrap().get_mut(source) {
+            state.status = AcmeCertificateStatus::Failed;
+            state.last_error = Some(error_message.to_string());
+            state.next_attempt_at =
+                Some(get_current_timestamp() + acme_settings.get_retry_interval() as i64);
+        }
+    }
+
+    fn collect_acme_sources() -> HashSet<String> {
+        let https_route_snapshot = HTTPS_ROUTE_SNAPSHOT.load();
+
+        let https_sources = https_route_snapshot
+            .https_routes
+            .iter()
+            .filter(|(_, https_route)| https_route.ssl_context.is_acme_managed())
+            .map(|(source, _)| source.clone());
+
+        let secure_iws_sources = https_route_snapshot
+            .secure_iws_routes
+            .iter()
+            .filter(|(_, secure_iws_route)| secure_iws_route.ssl_context.is_acme_managed())
+            .map(|(source, _)| source.clone());
+
+        https_sources.chain(secure_iws_sources).collect()
+    }
+}
+
+/// Reads expiry of the leaf certificate issued for given route source, if exists
+fn read_certificate_expiry(source: &str) -> Option<i64> {
+    let (certificate_file_name, _) = get_acme_ssl_file_names(source);
+    let certificate_pem = read_ssl_file(&certificate_file_name).ok()?;
+
+    parse_pem_certificates(&certificate_pem)
+        .ok()?
+        .first()
+        .map(|certificate| certificate.not_after)
+}
@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+use std::fs;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use hyper::{header, Body, Method, Request};
+use serde_json::json;
+
+use crate::{
+    common::enums::{certificate_expiry_status::CertificateExpiryStatus, route_type::RouteType},
+    constants::Constants,
+    core::{
+        http_client::HttpClient,
+        shared_memory::{CERTIFICATE_MONITOR, HTTPS_ROUTE_SNAPSHOT, RUNTIME_BOOT_INFO, SHUTDOWN_SIGNAL},
+    },
+    log_error, log_info, log_warn,
+    models::{
+        certificate_expiry_report::CertificateExpiryReport,
+        settings::{certificate_monitor_settings::CertificateMonitorSettings, upstream_tls_settings::UpstreamTlsSettings},
+    },
+    utils::{
+        ssl_file_utility::resolve_ssl_file_path, time_utility::get_current_timestamp,
+        x509_utility::parse_pem_certificates,
+    },
+};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Most severe alert raised for a certificate. A new alert is raised only when it changes
+#[derive(Clone, Copy, PartialEq)]
+enum ExpiryAlert {
+    Expiring(u64),
+    Expired,
+}
+
+struct CertificateAlertState {
+    not_after: i64,
+    alert: ExpiryAlert,
+}
+
+/// Tracks `notAfter` of route certificates and raises warnings at configured thresholds.
+/// Alerts are kept per route & certificate, so a renewed certificate starts over with its own thresholds
+#[derive(Default)]
+pub struct CertificateMonitor {
+    alert_states: RwLock<HashMap<String, CertificateAlertState>>,
+}
+
+impl CertificateMonitor {
+    /// Background task which checks certificates of routes in the live HTTPS route snapshot on every interval
+    pub async fn start() {
+        let mut shutdown_event = SHUTDOWN_SIGNAL.subscriber.clone();
+        let on_shutdown = async move {
+            let _on_shutdown = shutdown_event.wait_for(|&s| s).await;
+        };
+        tokio::pin!(on_shutdown);
+
+        let monitor_settings = RUNTIME_BOOT_INFO.read().unwrap().config.certificate_monitor.clone();
+        let mut ticker = tokio::time::interval(Duration::from_secs(monitor_settings.get_check_interval()));
+
+        log_info!("Vanguard Engine certificate expiry monitor started");
+
+        loop {
+            tokio::select! {
+                _on_shutdown = &mut on_shutdown => {
+                    break;
+                }
+                _on_tick = ticker.tick() => {
+                    let reports = CERTIFICATE_MONITOR.get_reports();
+                    let alerts = CERTIFICATE_MONITOR.collect_alerts(&reports, &monitor_settings);
+
+                    for (report, alert) in alerts {
+                        log_alert(&report, alert);
+
+                        if let Some(webhook_url) = monitor_settings.webhook_url.clone() {
+                            tokio::spawn(async move {
+                                send_webhook(&webhook_url, &report, alert).await;
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reads certificates of every HTTPS & Secure IWS route and returns their expiry, sorted by days left
+    pub fn get_reports(&self) -> Vec<CertificateExpiryReport> {
+        let warning_days = RUNTIME_BOOT_INFO.read().unwrap().config.certificate_monitor.get_warning_days();
+        let https_route_snapshot = HTTPS_ROUTE_SNAPSHOT.load();
+
+        let https_certificates = https_route_snapshot.https_routes.iter().map(|(source, https_route)| {
+            (source, RouteType::Https, &https_route.ssl_context.certificate_file_path)
+        });
+        let secure_iws_certificates = https_route_snapshot.secure_iws_routes.iter().map(|(source, secure_iws_route)| {
+            (source, RouteType::SecureIws, &secure_iws_route.ssl_context.certificate_file_path)
+        });
+
+        let mut reports: Vec<CertificateExpiryReport> = https_certificates
+            .chain(secure_iws_certificates)
+            .map(|(source, route_type, certificate_file_path)| {
+                create_report(source, route_type, certificate_file_path, &warning_days)
+            })
+            .collect();
+
+        reports.sort_by(|first, second| {
+            let first_not_after = first.not_after.unwrap_or(i64::MIN);
+            let second_not_after = second.not_after.unwrap_or(i64::MIN);
+
+            first_not_after.cmp(&second_not_after).then_with(|| first.source.cmp(&second.source))
+        });
+        reports
+    }
+
+    /// Returns reports whose alert level changed since last check, and records their new alert level
+    fn collect_alerts(
+        &self,
+        reports: &[CertificateExpiryReport],
+        monitor_settings: &CertificateMonitorSettings,
+    ) -> Vec<(CertificateExpiryReport, ExpiryAlert)> {
+        let warning_days = monitor_settings.get_warning_days();
+        let now = get_current_timestamp();
+
+        let mut alert_states = self.alert_states.write().unwrap();
+        alert_states.retain(|source, _| reports.iter().any(|report| &report.source == source));
+
+        let mut alerts = Vec::new();
+        for report in reports {
+            let Some(not_after) = report.not_after else {
+                continue;
+            };
+
+            // States of replaced (e.g. renewed) certificates are dropped
+            if alert_states.get(&report.source).map(|state| state.not_after != not_after).unwrap_or(false) {
+                alert_states.remove(&report.source);
+            }
+
+            let Some(alert) = find_alert(not_after - now, &warning_days) else {
+                continue;
+            };
+
+            let is_already_raised = alert_states
+                .get(&report.source)
+                .map(|state| state.alert == alert)
+                .unwrap_or(false);
+
+            if !is_already_raised {
+                alert_states.insert(report.source.clone(), CertificateAlertState { not_after, alert });
+                alerts.push((report.clone(), alert));
+            }
+        }
+
+        alerts
+    }
+}
+
+/// Validates thresholds, interval and webhook endpoint of certificate monitor
+pub fn validate_certificate_monitor_settings(monitor_settings: &CertificateMonitorSettings) -> Result<(), String> {
+    let has_empty_warning_days = monitor_settings
+        .warning_days
+        .as_ref()
+        .map(|warning_days| warning_days.is_empty())
+        .unwrap_or(false);
+
+    if has_empty_warning_days {
+        return Err("Certificate monitor warning days can not be empty".into());
+    }
+
+    if monitor_settings.check_interval == Some(0) {
+        return Err("Certificate monitor check interval should be a positive number".into());
+    }
+
+    if let Some(ref webhook_url) = monitor_settings.webhook_url {
+        let is_valid_url = webhook_url
+            .parse::<hyper::Uri>()
+            .map(|uri| matches!(uri.scheme_str(), Some("http") | Some("https")) && uri.host().is_some())
+            .unwrap_or(false);
+
+        if !is_valid_url {
+            return Err(format!(
+                "Certificate monitor webhook url '{}' should be an 'http://' or 'https://' url",
+                webhook_url
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn create_report(
+    source: &str,
+    route_type: RouteType,
+    certificate_file_path: &str,
+    warning_days: &[u64],
+) -> CertificateExpiryReport {
+    let mut report = CertificateExpiryReport {
+        source: source.to_string(),
+        route_type,
+        certificate_file_path: certificate_file_path.to_string(),
+        status: CertificateExpiryStatus::Unreadable,
+        subject: None,
+        not_after: None,
+        days_left: None,
+        error: None,
+    };
+
+    let leaf_certificate = resolve_ssl_file_path(certificate_file_path)
+        .and_then(|certificate_path| {
+            fs::read_to_string(&certificate_path)
+                .map_err(|error| format!("File '{}' can not be read: {}", certificate_path.display(), error))
+        })
+        .and_then(|certificate_pem| parse_pem_certificates(&certificate_pem))
+        .and_then(|certificates| {
+            certificates
+                .into_iter()
+                .next()
+                .ok_or_else(|| "No certificate found".to_string())
+        });
+
+    match leaf_certificate {
+        Ok(certificate) => {
+            let remaining_seconds = certificate.not_after - get_current_timestamp();
+
+            report.status = match find_alert(remaining_seconds, warning_days) {
+                None => CertificateExpiryStatus::Valid,
+                Some(ExpiryAlert::Expiring(_)) => CertificateExpiryStatus::Expiring,
+                Some(ExpiryAlert::Expired) => CertificateExpiryStatus::Expired,
+            };
+            report.subject = Some(certificate.subject);
+            report.not_after = Some(certificate.not_after);
+            report.days_left = Some(remaining_seconds.div_euclid(SECONDS_PER_DAY));
+        }
+        Err(error_message) => report.error = Some(error_message),
+    }
+
+    report
+}
+
+/// Finds the lowest threshold crossed by remaining lifetime. `warning_days` should be in descending order
+fn find_alert(remaining_seconds: i64, warning_days: &[u64]) -> Option<ExpiryAlert> {
+    if remaining_seconds <= 0 {
+        return Some(ExpiryAlert::Expired);
+    }
+
+    warning_days
+        .iter()
+        .rev()
+        .find(|&&threshold_days| remaining_seconds <= threshold_days as i64 * SECONDS_PER_DAY)
+        .map(|&threshold_days| ExpiryAlert::Expiring(threshold_days))
+}
+
+fn log_alert(report: &CertificateExpiryReport, alert: ExpiryAlert) {
+    match alert {
+        ExpiryAlert::Expiring(threshold_days) => log_warn!(
+            "Certificate of '{}' expires within {} days ({} days left): {}",
+            report.source,
+            threshold_days,
+            report.days_left.unwrap_or_default(),
+            report.certificate_file_path
+        ),
+        ExpiryAlert::Expired => log_error!(
+            "Certificate of '{}' is expired: {}",
+            report.source,
+            report.certificate_file_path
+        ),
+    }
+}
+
+/// Posts alert to webhook endpoint as JSON. Failures are only logged, since alerts are also written to engine logs
+async fn send_webhook(webhook_url: &str, report: &CertificateExpiryReport, alert: ExpiryAlert) {
+    let (event, threshold_days) = match alert {
+        ExpiryAlert::Expiring(threshold_days) => ("certificate_expiring", Some(threshold_days)),
+        ExpiryAlert::Expired => ("certificate_expired", None),
+    };
+
+    let payload = json!({
+        "event": event,
+        "source": report.source,
+        "route_type": report.route_type,
+        "certificate_file_path": report.certificate_file_path,
+        "subject": report.subject,
+        "not_after": report.not_after,
+        "days_left": report.days_left,
+        "threshold_days": threshold_days,
+    });
+
+    let http_client = match HttpClient::init(Constants::DEFAULT_POOL_IDLE_TIMEOUT, 1, &UpstreamTlsSettings::default()) {
+        Ok(http_client) => http_client,
+        Err(error) => {
+            log_error!("Certificate webhook client can not be created: {}", error.message);
+            return;
+        }
+    };
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(webhook_url)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::USER_AGENT, "Vanguard-Engine")
+        .body(Body::from(payload.to_string()));
+
+    let request = match request {
+        Ok(request) => request,
+        Err(error) => {
+            log_error!("Certificate webhook request can not be created: {}", error);
+            return;
+        }
+    };
+
+    let response = tokio::time::timeout(
+        Duration::from_secs(Constants::CERTIFICATE_WEBHOOK_TIMEOUT),
+        http_client.request(request),
+    )
+    .await;
+
+    match response {
+        Ok(Ok(response)) if response.status().is_success() => {}
+        Ok(Ok(response)) => log_error!(
+            "Certificate webhook '{}' responded with status {}",
+            webhook_url,
+            response.status()
+        ),
+        Ok(Err(error)) => log_error!("Certificate webhook '{}' can not be reached: {}", webhook_url, error),
+        Err(_) => log_error!("Certificate webhook '{}' timed out", webhook_url),
+    }
+}
@@ -0,0 +1,101 @@
+use hyper::header::HeaderValue;
+use hyper::HeaderMap;
+use rustls::ServerConnection;
+
+use crate::{
+    common::enums::client_auth_mode::ClientAuthMode,
+    constants::Constants,
+    core::access_log::TlsConnectionInfo,
+    models::settings::client_auth_settings::ClientAuthSettings,
+    utils::x509_utility::parse_certificate,
+};
+
+/// Identity of the verified client certificate (mTLS) of a HTTPS connection
+#[derive(Debug, Clone, Default)]
+pub struct ClientIdentity {
+    pub subject: String,
+    pub common_name: Option<String>,
+    pub subject_alt_names: Vec<String>,
+}
+
+impl ClientIdentity {
+    /// Reads identity from leaf certificate presented by the client. Certificate chain is already verified by TLS handshake
+    pub fn from_connection(connection: &ServerConnection) -> Option<Self> {
+        let client_certificate = connection.peer_certificates()?.first()?;
+        let certificate_info = parse_certificate(&client_certificate.0).ok()?;
+
+        Some(Self {
+            subject: certificate_info.subject,
+            common_name: certificate_info.common_name,
+            subject_alt_names: certificate_info.subject_alt_names,
+        })
+    }
+
+    /// Checks given identity against subject, common name and subject alternative names
+    pub fn matches(&self, identity: &str) -> bool {
+        self.subject == identity
+            || self.common_name.as_deref() == Some(identity)
+            || self.subject_alt_names.iter().any(|subject_alt_name| subject_alt_name == identity)
+    }
+}
+
+/// Checks client certificate of the connection against mTLS settings of the matched route.
+/// Handshake must be made for the same route, otherwise a client could pass another host in SNI to skip verification
+pub fn check_client_auth(
+    client_auth: &ClientAuthSettings,
+    route_source: &str,
+    tls_connection_info: &TlsConnectionInfo,
+) -> Result<(), String> {
+    if tls_connection_info.route_source.as_deref() != Some(route_source) {
+        return Err(format!(
+            "TLS handshake is not made for '{}', client certificate is not verified",
+            route_source
+        ));
+    }
+
+    let client_identity = match &tls_connection_info.client_identity {
+        Some(client_identity) => client_identity,
+        None if client_auth.get_mode() == ClientAuthMode::Optional && client_auth.allowed_identities.is_none() => {
+            return Ok(());
+        }
+        None => return Err("Client certificate is required".to_string()),
+    };
+
+    if let Some(ref allowed_identities) = client_auth.allowed_identities {
+        if !allowed_identities.iter().any(|identity| client_identity.matches(identity)) {
+            return Err(format!("Client '{}' is not allowed", client_identity.subject));
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes client certificate headers sent by the client, then adds verified identity if route forwards it
+pub fn apply_client_identity_headers(
+    headers: &mut HeaderMap,
+    client_auth: Option<&ClientAuthSettings>,
+    client_identity: Option<&ClientIdentity>,
+) {
+    headers.remove(Constants::CLIENT_CERT_SUBJECT_HEADER);
+    headers.remove(Constants::CLIENT_CERT_SAN_HEADER);
+
+    let is_forwarded = client_auth
+        .map(|client_auth| client_auth.get_forward_identity_headers())
+        .unwrap_or(false);
+
+    let Some(client_identity) = client_identity.filter(|_| is_forwarded) else {
+        return;
+    };
+
+    if let Ok(subject) = HeaderValue::from_str(&client_identity.subject) {
+        headers.insert(Constants::CLIENT_CERT_SUBJECT_HEADER, subject);
+    }
+
+    if client_identity.subject_alt_names.is_empty() {
+        return;
+    }
+
+    if let Ok(subject_alt_names) = HeaderValue::from_str(&client_identity.subject_alt_names.join(", ")) {
+        headers.insert(Constants::CLIENT_CERT_SAN_HEADER, subject_alt_names);
+    }
+}
@@ -1,15 +1,26 @@
-use hyper::header::{self, HeaderValue};
+use hyper::header;
 use hyper::StatusCode;
 use hyper::{Body, Request, Response};
 use std::fs::Metadata;
 use std::net::IpAddr;
 use std::path::PathBuf;
 
-use crate::core::shared_memory::HTTP_PROXY_MANAGER;
+use crate::core::access_log::UpstreamExchange;
+use crate::core::forwarded_headers::{apply_forwarded_headers, ForwardedHop, PeerAddress};
+use crate::core::header_rules::RouteHeaderRules;
+use crate::core::header_sanitizer::{
+    check_request_framing, normalize_request_framing, strip_hop_by_hop_headers,
+};
+use crate::core::metrics::UpstreamErrorKind;
+use crate::core::rate_limiter::RateLimitDecision;
+use crate::core::rewrite_matcher::RewriteResponse;
+use crate::core::shared_memory::{HTTP_PROXY_MANAGER, METRICS};
+use crate::core::upgrade_tunnel::spawn_upgrade_tunnel;
 use crate::models::traffic_policy::scope_traffic_policy::ScopeTrafficPolicy;
-use crate::log_info;
+use crate::{log_error, log_info};
 
 use crate::render::Render;
+use crate::utils::http_utility::{build_upstream_uri, is_upgrade_request};
 use crate::utils::file_utility::{
     generate_file_tag, get_content_type, get_last_modified, is_file_exist, open_file,
 };
@@ -21,6 +32,24 @@ pub enum Protocol {
     HTTPS,
 }
 
+impl Protocol {
+    /// Value of the `server` label of metrics
+    pub fn get_metric_label(&self) -> &'static str {
+        match self {
+            Protocol::HTTP => "http",
+            Protocol::HTTPS => "https",
+        }
+    }
+
+    /// URI scheme of the protocol, reported to upstreams by forwarding headers
+    pub fn get_scheme(&self) -> &'static str {
+        match self {
+            Protocol::HTTP => "http",
+            Protocol::HTTPS => "https",
+        }
+    }
+}
+
 pub struct CommonHandler {}
 
 impl CommonHandler {
@@ -28,9 +57,10 @@ impl CommonHandler {
         protocol: Protocol,
         request_host: &String,
         endpoint_to_navigate: &String,
-        req: Request<Body>,
+        mut req: Request<Body>,
         client_ip: IpAddr,
         traffic_policy: &ScopeTrafficPolicy,
+        route_header_rules: &RouteHeaderRules<'_>,
     ) -> Result<Response<Body>, hyper::Error> {
         let start_time: std::time::Instant = std::time::Instant::now();
 
@@ -39,27 +69,112 @@ impl CommonHandler {
             Protocol::HTTPS => "HTTPS",
         };
 
+        // Ambiguous requests are rejected before anything is sent to upstream, so a request can not be
+        // read in one way here and in another way by upstream (request smuggling)
+        if let Err(framing_error) = check_request_framing(
+            req.headers(),
+            req.uri(),
+            req.version(),
+            traffic_policy.http1_protocol_settings.get_http1_strict_framing(),
+        ) {
+            return CommonHandler::bad_request_error(protocol, request_host, req, client_ip, &framing_error).await;
+        }
+
+        // Client side of a protocol switch (e.g. WebSocket) is taken before request is forwarded.
+        // `Upgrade` header is kept with `Connection: upgrade`, so upstream decides whether to switch
+        let client_upgrade = if is_upgrade_request(&req) {
+            Some(hyper::upgrade::on(&mut req))
+        } else {
+            None
+        };
+
         let original_uri = req.uri().clone();
         let request_method = req.method().clone();
         let request_path = original_uri.path().to_string();
 
-        let mut new_uri = format!("http://{}{}", endpoint_to_navigate, request_path);
-        if let Some(query) = original_uri.query() {
-            new_uri.push('?');
-            new_uri.push_str(query);
-        }
+        let path_and_query = match original_uri.query() {
+            Some(query) => format!("{}?{}", request_path, query),
+            None => request_path.clone(),
+        };
+        let new_uri = build_upstream_uri(endpoint_to_navigate, &path_and_query);
 
         let (mut parts, body) = req.into_parts();
+
+        // Host is read before upstream URI replaces the one requested by client (HTTP/2 requests carry it in URI)
+        let requested_host = parts
+            .uri
+            .authority()
+            .map(|authority| authority.to_string())
+            .or_else(|| {
+                parts
+                    .headers
+                    .get(header::HOST)
+                    .and_then(|host| host.to_str().ok())
+                    .map(|host| host.to_string())
+            });
+
+        let peer_address = parts
+            .extensions
+            .get::<PeerAddress>()
+            .copied()
+            .unwrap_or(PeerAddress {
+                ip: client_ip,
+                is_trusted_proxy: false,
+            });
+
+        normalize_request_framing(&mut parts.headers);
+        strip_hop_by_hop_headers(&mut parts.headers, client_upgrade.is_some());
+
+        apply_forwarded_headers(
+            &mut parts.headers,
+            &ForwardedHop {
+                peer_ip: peer_address.ip,
+                is_peer_trusted: peer_address.is_trusted_proxy,
+                client_ip,
+                protocol: protocol.get_scheme(),
+                host: requested_host.as_deref(),
+                version: parts.version,
+            },
+            &traffic_policy.forwarded_headers,
+        );
+
+        // Route rules run last, so they can also override forwarding headers
+        let header_rule_variables = route_header_rules.apply_request_rules(
+            &mut parts.headers,
+            client_ip,
+            requested_host.as_deref().unwrap_or(request_host),
+        );
+
         parts.uri = new_uri.parse().unwrap();
         parts.version = hyper::Version::HTTP_11;
-        parts.headers.insert(
-            "x-forwarded-for",
-            HeaderValue::from_str(&client_ip.to_string()).unwrap(),
-        );
 
         let new_request = Request::from_parts(parts, body);
 
-        let client = HTTP_PROXY_MANAGER.get(traffic_policy);
+        let client = match HTTP_PROXY_MANAGER.get(traffic_policy) {
+            Ok(client) => client,
+            Err(error) => {
+                log_error!(
+                    "{} |UPSTREAM TLS| {} {} from {} to {}: {}",
+                    protocol_name,
+                    request_method,
+                    request_path,
+                    request_host,
+                    &endpoint_to_navigate,
+                    error.message
+                );
+                let mut response = Response::builder()
+                    .status(StatusCode::BAD_GATEWAY)
+                    .extension(UpstreamErrorKind::TlsConfig)
+                    .body(Body::from(Render::internal_server_error(
+                        request_host,
+                        "Upstream TLS settings are invalid",
+                    )))
+                    .unwrap();
+
+                route_header_rules.apply_response_rules(response.headers_mut(), &header_rule_variables);
+                return Ok(response);
+            }
+        };
 
         let response = run_in_time_buffer(
             traffic_policy.upstream_settings.get_http_client_timeout() * 1000,
@@ -77,18 +192,28 @@ impl CommonHandler {
                 &endpoint_to_navigate,
                 &client_ip
             );
-            return Ok(Response::builder()
+            let mut response = Response::builder()
                 .status(StatusCode::GATEWAY_TIMEOUT)
+                .extension(UpstreamErrorKind::Timeout)
+                .extension(UpstreamExchange::new(endpoint_to_navigate, start_time.elapsed()))
                 .body(Body::from(Render::internal_server_error(
                     request_host,
                     "Upstream request timed out",
                 )))
-                .unwrap());
+                .unwrap();
+
+            route_header_rules.apply_response_rules(response.headers_mut(), &header_rule_variables);
+            return Ok(response);
         }
 
-        let response = response.unwrap()?;
+        let mut response = response.unwrap()?;
 
-        let elapsed_time = start_time.elapsed().as_millis();
+        let upstream_latency = start_time.elapsed();
+        response
+            .extensions_mut()
+            .insert(UpstreamExchange::new(endpoint_to_navigate, upstream_latency));
+
+        let elapsed_time = upstream_latency.as_millis();
 
         log_info!(
             "{} |EXECUTION| {} {} {} ({} ms) from {} to {} via ip {}",
@@ -102,6 +227,29 @@ impl CommonHandler {
             &client_ip
         );
 
+        let is_switching_protocols =
+            client_upgrade.is_some() && response.status() == StatusCode::SWITCHING_PROTOCOLS;
+        strip_hop_by_hop_headers(response.headers_mut(), is_switching_protocols);
+        route_header_rules.apply_response_rules(response.headers_mut(), &header_rule_variables);
+
+        // Upstream accepted the protocol switch. 101 is returned to client and both connections are spliced after it
+        if let Some(client_upgrade) = client_upgrade {
+            if is_switching_protocols {
+                let upstream_upgrade = hyper::upgrade::on(&mut response);
+
+                spawn_upgrade_tunnel(
+                    protocol_name,
+                    request_host.clone(),
+                    endpoint_to_navigate.clone(),
+                    client_upgrade,
+                    upstream_upgrade,
+                    std::time::Duration::from_secs(
+                        traffic_policy.upstream_settings.get_upgraded_connection_idle_timeout(),
+                    ),
+                );
+            }
+        }
+
         Ok(response)
     }
 
@@ -110,8 +258,9 @@ impl CommonHandler {
         request_host: &String,
         serving_path: &PathBuf,
         metadata: &Metadata,
-        req: Request<Body>,
+        mut req: Request<Body>,
         client_ip: IpAddr,
+        route_header_rules: &RouteHeaderRules<'_>,
     ) -> Result<Response<Body>, hyper::Error> {
         let start_time: std::time::Instant = std::time::Instant::now();
 
@@ -120,6 +269,9 @@ impl CommonHandler {
             Protocol::HTTPS => "HTTPS",
         };
 
+        let header_rule_variables =
+            route_header_rules.apply_request_rules(req.headers_mut(), client_ip, request_host);
+
         let original_uri = req.uri().clone();
         let request_method = req.method().clone();
         let request_path = original_uri.path().to_string();
@@ -134,19 +286,25 @@ impl CommonHandler {
         // Is file up to date in client, we should return 301 NOT_MODIFIED
         if let Some(if_none_match) = req.headers().get(header::IF_NONE_MATCH) {
             if if_none_match == file_etag.as_str() {
-                return Ok(Response::builder()
+                let mut response = Response::builder()
                     .status(StatusCode::NOT_MODIFIED)
                     .body(Body::empty())
-                    .unwrap());
+                    .unwrap();
+
+                route_header_rules.apply_response_rules(response.headers_mut(), &header_rule_variables);
+                return Ok(response);
             }
         }
 
         let file_pointer = open_file(serving_path).await;
         if file_pointer.is_none() {
-            return Ok(Response::builder()
+            let mut response = Response::builder()
                 .status(StatusCode::NOT_FOUND)
                 .body(Body::empty())
-                .unwrap());
+                .unwrap();
+
+            route_header_rules.apply_response_rules(response.headers_mut(), &header_rule_variables);
+            return Ok(response);
         }
 
         // Zero-copy streaming body
@@ -167,13 +325,16 @@ impl CommonHandler {
             &client_ip
         );
 
-        Ok(Response::builder()
+        let mut response = Response::builder()
             .header("Content-Type", content_type.as_ref())
             .header("Content-Length", content_length.to_string())
             .header("ETag", file_etag)
             .header("Connection", "keep-alive")
             .body(body)
-            .unwrap())
+            .unwrap();
+
+        route_header_rules.apply_response_rules(response.headers_mut(), &header_rule_variables);
+        Ok(response)
     }
 
     /// If Index.html exist, render index.html as text
@@ -182,8 +343,9 @@ impl CommonHandler {
         protocol: Protocol,
         request_host: &String,
         serving_path: &PathBuf,
-        req: Request<Body>,
+        mut req: Request<Body>,
         client_ip: IpAddr,
+        route_header_rules: &RouteHeaderRules<'_>,
     ) -> Result<Response<Body>, hyper::Error> {
         let start_time = start_clock();
 
@@ -202,11 +364,15 @@ impl CommonHandler {
                     &metadata,
                     req,
                     client_ip,
+                    route_header_rules,
                 )
                 .await;
             }
         }
 
+        let header_rule_variables =
+            route_header_rules.apply_request_rules(req.headers_mut(), client_ip, request_host);
+
         let original_uri = req.uri().clone();
         let url_path = original_uri.path().strip_prefix("/").unwrap_or("");
         let request_method = req.method().clone();
@@ -232,12 +398,84 @@ impl CommonHandler {
 
         let content_length = dir_content.len();
 
-        Ok(Response::builder()
+        let mut response = Response::builder()
             .header("Content-Type", "text/html")
             .header("Content-Length", content_length.to_string())
             .header("Connection", "keep-alive")
             .body(Body::from(dir_content))
-            .unwrap())
+            .unwrap();
+
+        route_header_rules.apply_response_rules(response.headers_mut(), &header_rule_variables);
+        Ok(response)
+    }
+
+    /// Answers a request matched by a redirect or return rule of its route
+    pub async fn rewrite_response(
+        protocol: Protocol,
+        request_host: &String,
+        mut req: Request<Body>,
+        client_ip: IpAddr,
+        rewrite_response: RewriteResponse,
+        route_header_rules: &RouteHeaderRules<'_>,
+    ) -> Result<Response<Body>, hyper::Error> {
+        let protocol_name = match protocol {
+            Protocol::HTTP => "HTTP",
+            Protocol::HTTPS => "HTTPS",
+        };
+
+        let header_rule_variables =
+            route_header_rules.apply_request_rules(req.headers_mut(), client_ip, request_host);
+
+        let mut response_builder = Response::builder().status(rewrite_response.status_code);
+
+        if let Some(ref location) = rewrite_response.location {
+            // Captures of a request path may produce a location which is not a valid header value
+            let Ok(location) = header::HeaderValue::from_str(location) else {
+                log_error!(
+                    "{} |REWRITE| {} {} from {} produced an invalid redirect location: {}",
+                    protocol_name,
+                    req.method(),
+                    req.uri().path(),
+                    request_host,
+                    location
+                );
+
+                return Ok(Response::builder()
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .body(Body::from(Render::internal_server_error(
+                        request_host,
+                        "Invalid redirect location",
+                    )))
+                    .unwrap());
+            };
+
+            response_builder = response_builder.header(header::LOCATION, location);
+        }
+
+        if !rewrite_response.body.is_empty() {
+            response_builder = response_builder.header(header::CONTENT_TYPE, "text/plain; charset=utf-8");
+        }
+
+        log_info!(
+            "{} |{}| {} {} {} from {} via ip {}{}",
+            protocol_name,
+            if rewrite_response.location.is_some() { "REDIRECT" } else { "RETURN" },
+            req.method(),
+            req.uri().path(),
+            rewrite_response.status_code.as_u16(),
+            request_host,
+            &client_ip,
+            rewrite_response
+                .location
+                .as_ref()
+                .map(|location| format!(" to {}", location))
+                .unwrap_or_default()
+        );
+
+        let mut response = response_builder.body(Body::from(rewrite_response.body)).unwrap();
+        route_header_rules.apply_response_rules(response.headers_mut(), &header_rule_variables);
+
+        Ok(response)
     }
 
     pub async fn not_found_error(
@@ -374,4 +612,113 @@ impl CommonHandler {
             .body(Body::from(internal_server_error_content))
             .unwrap())
     }
+
+    /// Rejects a request whose framing or host is ambiguous or invalid
+    pub async fn bad_request_error(
+        protocol: Protocol,
+        request_host: &String,
+        req: Request<Body>,
+        client_ip: IpAddr,
+        reason: &str,
+    ) -> Result<Response<Body>, hyper::Error> {
+        let protocol_name = match protocol {
+            Protocol::HTTP => "HTTP",
+            Protocol::HTTPS => "HTTPS",
+        };
+
+        log_info!(
+            "{} |BAD REQUEST| {} {} {} from {} via ip {}: {}",
+            protocol_name,
+            req.method(),
+            req.uri().path(),
+            StatusCode::BAD_REQUEST.as_u16(),
+            request_host,
+            &client_ip,
+            reason
+        );
+
+        // Rest of an ambiguous request can not be trusted, so connection is not reused
+        Ok(Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(header::CONNECTION, "close")
+            .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
+            .body(Body::from(Render::bad_request_error(request_host, reason)))
+            .unwrap())
+    }
+
+    /// Rejects a request whose client ip is not allowed by ip access lists.
+    /// Custom forbidden page of the matched list is served if configured
+    pub async fn forbidden_error(
+        protocol: Protocol,
+        request_host: &String,
+        req: Request<Body>,
+        client_ip: IpAddr,
+        forbidden_page: Option<&str>,
+    ) -> Result<Response<Body>, hyper::Error> {
+        let protocol_name = match protocol {
+            Protocol::HTTP => "HTTP",
+            Protocol::HTTPS => "HTTPS",
+        };
+
+        let forbidden_content = match forbidden_page {
+            Some(forbidden_page) => forbidden_page.to_string(),
+            None => Render::forbidden_error(request_host),
+        };
+
+        log_info!(
+            "{} |IP FORBIDDEN| {} {} {} from {} via ip {}",
+            protocol_name,
+            req.method(),
+            req.uri().path(),
+            StatusCode::FORBIDDEN.as_u16(),
+            request_host,
+            &client_ip
+        );
+
+        Ok(Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
+            .body(Body::from(forbidden_content))
+            .unwrap())
+    }
+
+    /// Rejects a request which exceeded a rate limit, with `Retry-After` & `RateLimit-*` headers
+    pub async fn too_many_requests_error(
+        protocol: Protocol,
+        request_host: &String,
+        req: Request<Body>,
+        client_ip: IpAddr,
+        rate_limit_scope: &str,
+        rate_limit_decision: &RateLimitDecision,
+    ) -> Result<Response<Body>, hyper::Error> {
+        let protocol_name = match protocol {
+            Protocol::HTTP => "HTTP",
+            Protocol::HTTPS => "HTTPS",
+        };
+
+        METRICS.record_rate_limit_rejection(protocol.get_metric_label(), rate_limit_scope);
+
+        log_info!(
+            "{} |RATE LIMITED| {} {} {} from {} via ip {}",
+            protocol_name,
+            req.method(),
+            req.uri().path(),
+            StatusCode::TOO_MANY_REQUESTS.as_u16(),
+            request_host,
+            &client_ip
+        );
+
+        let mut response = Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
+            .body(Body::from(Render::too_many_requests_error(
+                request_host,
+                "Rate limit exceeded",
+            )))
+            .unwrap();
+
+        rate_limit_decision.apply_headers(response.headers_mut());
+
+        Ok(response)
+    }
 }
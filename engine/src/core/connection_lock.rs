@@ -6,4 +6,12 @@ impl Drop for ConnectionLock {
     fn drop(&mut self) {
         CONNECTION_MANAGER.release_connection();
     }
-}
\ No newline at end of file
+}
+
+pub struct UpgradedConnectionLock;
+
+impl Drop for UpgradedConnectionLock {
+    fn drop(&mut self) {
+        CONNECTION_MANAGER.release_upgraded_connection();
+    }
+}
@@ -1,16 +1,14 @@
 use crate::constants::Constants;
-use crate::core::connection_lock::ConnectionLock;
-use std::collections::HashMap;
-use std::net::IpAddr;
+use crate::core::connection_lock::{ConnectionLock, UpgradedConnectionLock};
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
-use std::sync::RwLock;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 pub struct ConnectionManager {
     active_connections: AtomicUsize,
     total_requests: AtomicU64,
+    active_upgraded_connections: AtomicUsize,
+    total_upgraded_connections: AtomicU64,
     start_time: Instant,
-    rate_limits: RwLock<HashMap<IpAddr, (u32, Instant)>>,
 }
 
 impl Default for ConnectionManager {
@@ -18,8 +16,9 @@ impl Default for ConnectionManager {
         Self {
             active_connections: AtomicUsize::new(0),
             total_requests: AtomicU64::new(0),
+            active_upgraded_connections: AtomicUsize::new(0),
+            total_upgraded_connections: AtomicU64::new(0),
             start_time: Instant::now(),
-            rate_limits: RwLock::new(HashMap::new()),
         }
     }
 }
@@ -46,31 +45,37 @@ impl ConnectionManager {
         self.total_requests.fetch_add(1, Ordering::SeqCst);
     }
 
-    /// Checks if the IP address has exceeded the rate limit
-    pub fn check_rate_limit(&self, ip: IpAddr, limit: u32) -> bool {
-        let mut limits = self.rate_limits.write().unwrap();
-        let now = Instant::now();
-        let (count, start) = limits.entry(ip).or_insert((0, now));
+    /// Returns the number of active connections
+    pub fn get_active_connections(&self) -> usize {
+        self.active_connections.load(Ordering::Relaxed)
+    }
 
-        if now.duration_since(*start).as_secs() >= 60 {
-            *count = 1;
-            *start = now;
-            return true;
-        }
+    /// Registers a connection switched to another protocol (e.g. WebSocket).
+    /// Upgraded connections are long-lived, so they are counted apart from regular ones
+    pub fn acquire_upgraded_connection(&self) -> UpgradedConnectionLock {
+        self.active_upgraded_connections.fetch_add(1, Ordering::SeqCst);
+        self.total_upgraded_connections.fetch_add(1, Ordering::SeqCst);
+        UpgradedConnectionLock
+    }
 
-        if *count >= limit {
-            return false;
-        }
+    /// Releases an upgraded connection
+    pub fn release_upgraded_connection(&self) {
+        self.active_upgraded_connections.fetch_sub(1, Ordering::SeqCst);
+    }
 
-        *count += 1;
-        true
+    /// Returns the number of open upgraded connections
+    pub fn get_active_upgraded_connections(&self) -> usize {
+        self.active_upgraded_connections.load(Ordering::Relaxed)
     }
 
-    /// @todo: Future usage for getting engine metrics
-    /// Returns the number of active connections
-    /// @note: This method is not thread-safe, use it only for logging purposes
-    pub fn get_active_connections(&self) -> usize {
-        self.active_connections.load(Ordering::Relaxed)
+    /// Returns the number of upgraded connections since engine start
+    pub fn get_total_upgraded_connections(&self) -> u64 {
+        self.total_upgraded_connections.load(Ordering::Relaxed)
+    }
+
+    /// Returns the time passed since engine start
+    pub fn get_uptime(&self) -> Duration {
+        self.start_time.elapsed()
     }
 
     /// @todo: Future usage for getting engine metrics
@@ -0,0 +1,467 @@
+use std::net::{IpAddr, SocketAddr};
+
+use hyper::{
+    header::{self, HeaderMap, HeaderName, HeaderValue},
+    http::uri::Authority,
+    Version,
+};
+
+use crate::{
+    common::enums::{forwarded_header::ForwardedHeader, forwarded_header_mode::ForwardedHeaderMode},
+    constants::Constants,
+    core::ip_access_control::IpCidr,
+    models::{
+        settings::forwarded_header_settings::ForwardedHeaderSettings,
+        traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
+    },
+};
+
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+const X_FORWARDED_PROTO: &str = "x-forwarded-proto";
+const X_FORWARDED_HOST: &str = "x-forwarded-host";
+const X_FORWARDED_PORT: &str = "x-forwarded-port";
+
+/// Address of the connection peer, kept in request extensions.
+/// It differs from client ip when request comes through trusted proxies
+#[derive(Debug, Clone, Copy)]
+pub struct PeerAddress {
+    pub ip: IpAddr,
+    pub is_trusted_proxy: bool,
+}
+
+/// Current hop of a proxied request, written into forwarding headers
+pub struct ForwardedHop<'a> {
+    pub peer_ip: IpAddr,
+    /// Incoming forwarding headers of a trusted proxy peer are kept in `append` mode
+    pub is_peer_trusted: bool,
+    pub client_ip: IpAddr,
+    /// `http` or `https`
+    pub protocol: &'static str,
+    /// Host requested by client, including port if given
+    pub host: Option<&'a str>,
+    pub version: Version,
+}
+
+/// Resolves real client ip of a request. If connection peer is a trusted proxy, `X-Forwarded-For`
+/// (or `Forwarded` if it is missing) is walked from right to left and the first address which is
+/// not a trusted proxy is the client
+pub fn resolve_client_ip(headers: &HeaderMap, peer_ip: IpAddr, trusted_proxies: &[IpCidr]) -> IpAddr {
+    if !is_trusted_proxy(trusted_proxies, peer_ip) {
+        return peer_ip;
+    }
+
+    let mut forwarded_chain = get_forwarded_for_chain(headers, X_FORWARDED_FOR, parse_forwarded_address);
+    if forwarded_chain.is_empty() {
+        forwarded_chain = get_forwarded_for_chain(headers, header::FORWARDED.as_str(), parse_forwarded_element);
+    }
+
+    let mut client_ip = peer_ip;
+    for forwarded_address in forwarded_chain.into_iter().rev() {
+        // Obfuscated or malformed entries can not be followed, the last known address is the client
+        let Some(forwarded_ip) = forwarded_address else {
+            break;
+        };
+
+        client_ip = forwarded_ip;
+        if !is_trusted_proxy(trusted_proxies, forwarded_ip) {
+            break;
+        }
+    }
+
+    client_ip
+}
+
+/// Writes forwarding headers of current hop into an upstream request.
+/// In `append` mode current hop is appended to `X-Forwarded-For`, `Forwarded` & `Via` chains, and single value
+/// `X-Forwarded-Proto/Host/Port` headers are kept only if they come from a trusted proxy.
+/// In `overwrite` mode incoming forwarding headers are discarded and client is reported as the resolved client ip
+pub fn apply_forwarded_headers(
+    headers: &mut HeaderMap,
+    forwarded_hop: &ForwardedHop,
+    forwarded_header_settings: &ForwardedHeaderSettings,
+) {
+    let emitted_headers = forwarded_header_settings.get_headers();
+    let is_emitted = |forwarded_header: ForwardedHeader| emitted_headers.contains(&forwarded_header);
+
+    let is_append_mode = forwarded_header_settings.get_mode() == ForwardedHeaderMode::Append;
+
+    // Chain headers get the address of connection peer, unless chain is rebuilt from scratch
+    let forwarded_for = match is_append_mode {
+        true => forwarded_hop.peer_ip,
+        false => forwarded_hop.client_ip,
+    };
+
+    let port = get_forwarded_port(forwarded_hop);
+
+    let chain_values = [
+        (
+            ForwardedHeader::XForwardedFor,
+            HeaderName::from_static(X_FORWARDED_FOR),
+            forwarded_for.to_string(),
+        ),
+        (
+            ForwardedHeader::Forwarded,
+            header::FORWARDED,
+            create_forwarded_element(forwarded_for, forwarded_hop),
+        ),
+        (
+            ForwardedHeader::Via,
+            header::VIA,
+            format!("{} {}", get_via_protocol_version(forwarded_hop.version), Constants::VIA_PSEUDONYM),
+        ),
+    ];
+
+    for (forwarded_header, header_name, value) in chain_values {
+        let incoming_value = match is_append_mode {
+            true => join_header_values(headers, &header_name),
+            false => None,
+        };
+        headers.remove(&header_name);
+
+        if !is_emitted(forwarded_header) {
+            if let Some(incoming_value) = incoming_value {
+                insert_header(headers, header_name, incoming_value);
+            }
+            continue;
+        }
+
+        let value = match incoming_value {
+            Some(incoming_value) => format!("{}, {}", incoming_value, value),
+            None => value,
+        };
+        insert_header(headers, header_name, value);
+    }
+
+    let single_values = [
+        (
+            ForwardedHeader::XForwardedProto,
+            HeaderName::from_static(X_FORWARDED_PROTO),
+            Some(forwarded_hop.protocol.to_string()),
+        ),
+        (
+            ForwardedHeader::XForwardedHost,
+            HeaderName::from_static(X_FORWARDED_HOST),
+            forwarded_hop.host.map(|host| host.to_string()),
+        ),
+        (
+            ForwardedHeader::XForwardedPort,
+            HeaderName::from_static(X_FORWARDED_PORT),
+            Some(port.to_string()),
+        ),
+    ];
+
+    for (forwarded_header, header_name, value) in single_values {
+        if is_append_mode && forwarded_hop.is_peer_trusted && headers.contains_key(&header_name) {
+            continue;
+        }
+        headers.remove(&header_name);
+
+        if let (true, Some(value)) = (is_emitted(forwarded_header), value) {
+            insert_header(headers, header_name, value);
+        }
+    }
+}
+
+/// Validates trusted proxy entries of a global or server traffic policy
+pub fn validate_forwarded_header_settings(forwarded_header_settings: &ForwardedHeaderSettings) -> Result<(), String> {
+    for trusted_proxy in forwarded_header_settings.get_trusted_proxies() {
+        IpCidr::parse(&trusted_proxy).map_err(|error| format!("Invalid trusted proxy: {}", error))?;
+    }
+
+    Ok(())
+}
+
+/// Validates forwarding header settings of given route traffic policy (if any).
+/// Client ip is resolved before routing, so routes can not define their own trusted proxies
+pub fn validate_route_forwarded_header_settings(traffic_policy: &Option<ScopeTrafficPolicy>) -> Result<(), String> {
+    let has_trusted_proxies = traffic_policy
+        .as_ref()
+        .map(|traffic_policy| traffic_policy.forwarded_headers.trusted_proxies.is_some())
+        .unwrap_or(false);
+
+    if has_trusted_proxies {
+        return Err("Trusted proxies can only be defined in global or server traffic policies".into());
+    }
+
+    Ok(())
+}
+
+/// Parses trusted proxies of a global or server traffic policy. Entries are validated when settings are loaded
+pub fn parse_trusted_proxies(forwarded_header_settings: &ForwardedHeaderSettings) -> Vec<IpCidr> {
+    forwarded_header_settings
+        .get_trusted_proxies()
+        .iter()
+        .filter_map(|trusted_proxy| IpCidr::parse(trusted_proxy).ok())
+        .collect()
+}
+
+pub fn is_trusted_proxy(trusted_proxies: &[IpCidr], ip: IpAddr) -> bool {
+    trusted_proxies.iter().any(|trusted_proxy| trusted_proxy.contains(ip))
+}
+
+/// Returns addresses of a comma separated forwarding header, leftmost first. Repeated headers are joined in order
+fn get_forwarded_for_chain(
+    headers: &HeaderMap,
+    header_name: &str,
+    parse_entry: fn(&str) -> Option<IpAddr>,
+) -> Vec<Option<IpAddr>> {
+    headers
+        .get_all(header_name)
+        .iter()
+        .flat_map(|value| value.to_str().unwrap_or_default().split(','))
+        .filter(|entry| !entry.trim().is_empty())
+        .map(parse_entry)
+        .collect()
+}
+
+/// Parses an `X-Forwarded-For` entry. Some proxies add port of the client, e.g. `203.0.113.7:5120` or `[2001:db8::1]:443`
+fn parse_forwarded_address(entry: &str) -> Option<IpAddr> {
+    let entry = entry.trim().trim_matches('"');
+
+    entry
+        .parse::<IpAddr>()
+        .ok()
+        .or_else(|| entry.parse::<SocketAddr>().ok().map(|address| address.ip()))
+        .or_else(|| entry.trim_start_matches('[').trim_end_matches(']').parse::<IpAddr>().ok())
+}
+
+/// Parses `for` parameter of a `Forwarded` element, e.g. `for=192.0.2.60;proto=http`
+fn parse_forwarded_element(element: &str) -> Option<IpAddr> {
+    element
+        .split(';')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("for"))
+        .and_then(|(_, value)| parse_forwarded_address(value))
+}
+
+/// `Forwarded` element of current hop. IPv6 addresses and values with port are quoted (RFC 7239)
+fn create_forwarded_element(forwarded_for: IpAddr, forwarded_hop: &ForwardedHop) -> String {
+    let node = match forwarded_for {
+        IpAddr::V4(ip) => ip.to_string(),
+        IpAddr::V6(ip) => format!("\"[{}]\"", ip),
+    };
+
+    let mut element = format!("for={}", node);
+
+    if let Some(host) = forwarded_hop.host {
+        match host.contains(':') {
+            true => element.push_str(&format!(";host=\"{}\"", host)),
+            false => element.push_str(&format!(";host={}", host)),
+        }
+    }
+
+    element.push_str(&format!(";proto={}", forwarded_hop.protocol));
+    element
+}
+
+/// Port requested by client. Host without port means the default port of the scheme
+fn get_forwarded_port(forwarded_hop: &ForwardedHop) -> u16 {
+    let default_port = match forwarded_hop.protocol {
+        "https" => 443,
+        _ => 80,
+    };
+
+    forwarded_hop
+        .host
+        .and_then(|host| host.parse::<Authority>().ok())
+        .and_then(|authority| authority.port_u16())
+        .unwrap_or(default_port)
+}
+
+fn get_via_protocol_version(version: Version) -> &'static str {
+    match version {
+        Version::HTTP_09 => "0.9",
+        Version::HTTP_10 => "1.0",
+        Version::HTTP_2 => "2",
+        Version::HTTP_3 => "3",
+        _ => "1.1",
+    }
+}
+
+fn join_header_values(headers: &HeaderMap, header_name: &HeaderName) -> Option<String> {
+    let values: Vec<&str> = headers
+        .get_all(header_name)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .filter(|value| !value.trim().is_empty())
+        .collect();
+
+    if values.is_empty() {
+        return None;
+    }
+
+    Some(values.join(", "))
+}
+
+fn insert_header(headers: &mut HeaderMap, header_name: HeaderName, value: String) {
+    if let Ok(value) = HeaderValue::from_str(&value) {
+        headers.insert(header_name, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_headers(headers: &[(&str, &str)]) -> HeaderMap {
+        let mut header_map = HeaderMap::new();
+        for (name, value) in headers {
+            header_map.append(
+                HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        header_map
+    }
+
+    fn parse_proxies(trusted_proxies: &[&str]) -> Vec<IpCidr> {
+        parse_trusted_proxies(&ForwardedHeaderSettings {
+            trusted_proxies: Some(trusted_proxies.iter().map(|proxy| proxy.to_string()).collect()),
+            ..Default::default()
+        })
+    }
+
+    fn ip(address: &str) -> IpAddr {
+        address.parse().unwrap()
+    }
+
+    fn create_hop(peer_ip: &str, client_ip: &str, is_peer_trusted: bool) -> ForwardedHop<'static> {
+        ForwardedHop {
+            peer_ip: ip(peer_ip),
+            is_peer_trusted,
+            client_ip: ip(client_ip),
+            protocol: "https",
+            host: Some("example.com"),
+            version: Version::HTTP_11,
+        }
+    }
+
+    fn create_settings(mode: ForwardedHeaderMode) -> ForwardedHeaderSettings {
+        ForwardedHeaderSettings {
+            mode: Some(mode),
+            headers: Some(vec![
+                ForwardedHeader::XForwardedFor,
+                ForwardedHeader::XForwardedProto,
+                ForwardedHeader::Forwarded,
+                ForwardedHeader::Via,
+            ]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn client_ip_is_peer_when_peer_is_not_trusted() {
+        let trusted_proxies = parse_proxies(&["10.0.0.0/8"]);
+        let headers = build_headers(&[("x-forwarded-for", "198.51.100.7")]);
+
+        assert_eq!(resolve_client_ip(&headers, ip("203.0.113.9"), &trusted_proxies), ip("203.0.113.9"));
+        assert_eq!(resolve_client_ip(&headers, ip("203.0.113.9"), &[]), ip("203.0.113.9"));
+    }
+
+    #[test]
+    fn forwarded_chain_is_walked_from_right_to_left() {
+        let trusted_proxies = parse_proxies(&["10.0.0.0/8", "192.168.1.1"]);
+
+        // Leftmost entry can be spoofed by the client, the first untrusted entry from right is the client
+        let headers = build_headers(&[("x-forwarded-for", "1.1.1.1, 198.51.100.7, 192.168.1.1")]);
+        assert_eq!(resolve_client_ip(&headers, ip("10.0.0.2"), &trusted_proxies), ip("198.51.100.7"));
+
+        // Repeated headers are joined in order, ports are ignored
+        let headers = build_headers(&[
+            ("x-forwarded-for", "198.51.100.7:5120"),
+            ("x-forwarded-for", "10.0.0.3"),
+        ]);
+        assert_eq!(resolve_client_ip(&headers, ip("10.0.0.2"), &trusted_proxies), ip("198.51.100.7"));
+
+        // Chain of only trusted proxies ends at its leftmost entry
+        let headers = build_headers(&[("x-forwarded-for", "10.0.0.4, 10.0.0.3")]);
+        assert_eq!(resolve_client_ip(&headers, ip("10.0.0.2"), &trusted_proxies), ip("10.0.0.4"));
+    }
+
+    #[test]
+    fn malformed_entry_stops_the_walk() {
+        let trusted_proxies = parse_proxies(&["10.0.0.0/8"]);
+        let headers = build_headers(&[("x-forwarded-for", "198.51.100.7, unknown, 10.0.0.3")]);
+
+        assert_eq!(resolve_client_ip(&headers, ip("10.0.0.2"), &trusted_proxies), ip("10.0.0.3"));
+    }
+
+    #[test]
+    fn forwarded_header_is_used_when_x_forwarded_for_is_missing() {
+        let trusted_proxies = parse_proxies(&["10.0.0.0/8", "2001:db8::/32"]);
+        let headers = build_headers(&[(
+            "forwarded",
+            "for=198.51.100.7;proto=https, for=\"[2001:db8::1]:443\"",
+        )]);
+
+        assert_eq!(resolve_client_ip(&headers, ip("10.0.0.2"), &trusted_proxies), ip("198.51.100.7"));
+    }
+
+    #[test]
+    fn append_mode_extends_incoming_chains() {
+        let mut headers = build_headers(&[
+            ("x-forwarded-for", "198.51.100.7"),
+            ("x-forwarded-proto", "http"),
+            ("forwarded", "for=198.51.100.7"),
+            ("via", "1.1 edge"),
+        ]);
+
+        apply_forwarded_headers(
+            &mut headers,
+            &create_hop("10.0.0.2", "198.51.100.7", true),
+            &create_settings(ForwardedHeaderMode::Append),
+        );
+
+        assert_eq!(headers.get(X_FORWARDED_FOR).unwrap(), "198.51.100.7, 10.0.0.2");
+        assert_eq!(
+            headers.get(header::FORWARDED).unwrap(),
+            "for=198.51.100.7, for=10.0.0.2;host=example.com;proto=https"
+        );
+        assert_eq!(headers.get(header::VIA).unwrap(), "1.1 edge, 1.1 vanguard");
+
+        // Single value headers of a trusted proxy are kept
+        assert_eq!(headers.get(X_FORWARDED_PROTO).unwrap(), "http");
+    }
+
+    #[test]
+    fn append_mode_replaces_single_values_of_untrusted_peer() {
+        let mut headers = build_headers(&[("x-forwarded-proto", "http"), ("x-forwarded-for", "1.1.1.1")]);
+
+        apply_forwarded_headers(
+            &mut headers,
+            &create_hop("203.0.113.9", "203.0.113.9", false),
+            &create_settings(ForwardedHeaderMode::Append),
+        );
+
+        assert_eq!(headers.get(X_FORWARDED_PROTO).unwrap(), "https");
+        assert_eq!(headers.get(X_FORWARDED_FOR).unwrap(), "1.1.1.1, 203.0.113.9");
+    }
+
+    #[test]
+    fn overwrite_mode_discards_incoming_values() {
+        let mut headers = build_headers(&[
+            ("x-forwarded-for", "1.1.1.1, 198.51.100.7"),
+            ("x-forwarded-proto", "http"),
+            ("forwarded", "for=1.1.1.1"),
+            ("via", "1.1 edge"),
+            ("x-forwarded-host", "spoofed.example.com"),
+        ]);
+
+        apply_forwarded_headers(
+            &mut headers,
+            &create_hop("10.0.0.2", "198.51.100.7", true),
+            &create_settings(ForwardedHeaderMode::Overwrite),
+        );
+
+        assert_eq!(headers.get(X_FORWARDED_FOR).unwrap(), "198.51.100.7");
+        assert_eq!(headers.get(X_FORWARDED_PROTO).unwrap(), "https");
+        assert_eq!(
+            headers.get(header::FORWARDED).unwrap(),
+            "for=198.51.100.7;host=example.com;proto=https"
+        );
+        assert_eq!(headers.get(header::VIA).unwrap(), "1.1 vanguard");
+
+        // Not emitted headers are removed instead of being passed through
+        assert!(!headers.contains_key(X_FORWARDED_HOST));
+    }
+}
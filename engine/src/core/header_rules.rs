@@ -0,0 +1,388 @@
+use std::net::IpAddr;
+
+use hyper::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::{
+    common::enums::{header_rule_action::HeaderRuleAction, header_rule_phase::HeaderRulePhase},
+    constants::Constants,
+    core::access_log::generate_request_id,
+    models::route::header_rule::HeaderRule,
+};
+
+/// Headers which frame a message or belong to a single connection, they can not be changed by header rules
+const PROTECTED_HEADERS: [&str; 6] = [
+    "content-length",
+    "transfer-encoding",
+    "connection",
+    "keep-alive",
+    "te",
+    "upgrade",
+];
+
+/// Variables usable in header rule values as `$name` or `${name}`
+const HEADER_RULE_VARIABLES: [&str; 4] = ["client_ip", "host", "request_id", "route_source"];
+
+/// Header rules of the host entry which serves a request
+pub struct RouteHeaderRules<'a> {
+    pub route_source: &'a str,
+    pub header_rules: &'a [HeaderRule],
+}
+
+/// Values of header rule variables of a request. Response rules use the values captured by request rules
+#[derive(Debug, Clone, Default)]
+pub struct HeaderRuleVariables {
+    client_ip: String,
+    host: String,
+    request_id: String,
+    route_source: String,
+}
+
+impl<'a> RouteHeaderRules<'a> {
+    pub fn new(route_source: &'a str, header_rules: &'a [HeaderRule]) -> Self {
+        Self {
+            route_source,
+            header_rules,
+        }
+    }
+
+    /// Applies request rules of the route and returns variables of the request.
+    /// Requests without `x-request-id` get a generated one when a rule uses `$request_id`,
+    /// so upstream receives the same id
+    pub fn apply_request_rules(&self, headers: &mut HeaderMap, client_ip: IpAddr, host: &str) -> HeaderRuleVariables {
+        let mut request_id = headers
+            .get(Constants::REQUEST_ID_HEADER)
+            .and_then(|request_id| request_id.to_str().ok())
+            .map(|request_id| request_id.to_string())
+            .unwrap_or_default();
+
+        if request_id.is_empty() && self.uses_variable("request_id") {
+            request_id = generate_request_id();
+            if let Ok(header_value) = HeaderValue::from_str(&request_id) {
+                headers.insert(Constants::REQUEST_ID_HEADER, header_value);
+            }
+        }
+
+        let header_rule_variables = HeaderRuleVariables {
+            client_ip: client_ip.to_string(),
+            host: host.to_string(),
+            request_id,
+            route_source: self.route_source.to_string(),
+        };
+
+        apply_header_rules(headers, self.header_rules, HeaderRulePhase::Request, &header_rule_variables);
+        header_rule_variables
+    }
+
+    /// Applies response rules of the route
+    pub fn apply_response_rules(&self, headers: &mut HeaderMap, header_rule_variables: &HeaderRuleVariables) {
+        apply_header_rules(headers, self.header_rules, HeaderRulePhase::Response, header_rule_variables);
+    }
+
+    fn uses_variable(&self, variable_name: &str) -> bool {
+        self.header_rules.iter().any(|header_rule| {
+            header_rule.value.as_ref().is_some_and(|value| {
+                value.contains(&format!("${}", variable_name)) || value.contains(&format!("${{{}}}", variable_name))
+            })
+        })
+    }
+}
+
+/// Validates header rules of a host entry
+pub fn validate_header_rules(header_rules: &[HeaderRule]) -> Result<(), String> {
+    for header_rule in header_rules {
+        validate_header_name(&header_rule.name)?;
+
+        match header_rule.action {
+            HeaderRuleAction::Add | HeaderRuleAction::Set => {
+                let Some(ref value) = header_rule.value else {
+                    return Err(format!("Header rule of '{}' has no value", header_rule.name));
+                };
+
+                // Variables are validated by expanding them into a placeholder
+                let sample_value = expand_variables(value, &HeaderRuleVariables::default())?;
+                if HeaderValue::from_str(&sample_value).is_err() {
+                    return Err(format!("Invalid value of header rule '{}': {}", header_rule.name, value));
+                }
+            }
+            HeaderRuleAction::Rename => {
+                let Some(ref new_name) = header_rule.new_name else {
+                    return Err(format!("Rename rule of '{}' has no new_name", header_rule.name));
+                };
+                validate_header_name(new_name)?;
+            }
+            HeaderRuleAction::Remove => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn apply_header_rules(
+    headers: &mut HeaderMap,
+    header_rules: &[HeaderRule],
+    phase: HeaderRulePhase,
+    header_rule_variables: &HeaderRuleVariables,
+) {
+    for header_rule in header_rules.iter().filter(|header_rule| header_rule.phase == phase) {
+        let Ok(header_name) = HeaderName::from_bytes(header_rule.name.as_bytes()) else {
+            continue;
+        };
+
+        match header_rule.action {
+            HeaderRuleAction::Add | HeaderRuleAction::Set => {
+                let header_value = header_rule
+                    .value
+                    .as_ref()
+                    .and_then(|value| expand_variables(value, header_rule_variables).ok())
+                    .and_then(|value| HeaderValue::from_str(&value).ok());
+
+                let Some(header_value) = header_value else {
+                    continue;
+                };
+
+                if header_rule.action == HeaderRuleAction::Add {
+                    headers.append(header_name, header_value);
+                } else {
+                    headers.insert(header_name, header_value);
+                }
+            }
+            HeaderRuleAction::Remove => {
+                headers.remove(header_name);
+            }
+            HeaderRuleAction::Rename => {
+                let new_header_name = header_rule
+                    .new_name
+                    .as_ref()
+                    .and_then(|new_name| HeaderName::from_bytes(new_name.as_bytes()).ok());
+
+                let Some(new_header_name) = new_header_name else {
+                    continue;
+                };
+
+                let header_values: Vec<HeaderValue> = headers.get_all(&header_name).iter().cloned().collect();
+                if header_values.is_empty() {
+                    continue;
+                }
+
+                headers.remove(&header_name);
+                headers.remove(&new_header_name);
+                for header_value in header_values {
+                    headers.append(new_header_name.clone(), header_value);
+                }
+            }
+        }
+    }
+}
+
+/// Replaces `$name` & `${name}` variables of a value. `$$` writes a literal `$`
+fn expand_variables(value: &str, header_rule_variables: &HeaderRuleVariables) -> Result<String, String> {
+    let mut expanded_value = String::new();
+    let mut chars = value.chars().peekable();
+
+    while let Some(character) = chars.next() {
+        if character != '$' {
+            expanded_value.push(character);
+            continue;
+        }
+
+        if chars.peek() == Some(&'$') {
+            chars.next();
+            expanded_value.push('$');
+            continue;
+        }
+
+        let is_braced = chars.peek() == Some(&'{');
+        if is_braced {
+            chars.next();
+        }
+
+        let mut name = String::new();
+        while let Some(&next) = chars.peek() {
+            if !(next.is_ascii_alphanumeric() || next == '_') {
+                break;
+            }
+            name.push(next);
+            chars.next();
+        }
+
+        if is_braced && chars.next() != Some('}') {
+            return Err(format!("Header rule variable '${{{}' is not closed", name));
+        }
+
+        let variable_value = match name.as_str() {
+            "client_ip" => &header_rule_variables.client_ip,
+            "host" => &header_rule_variables.host,
+            "request_id" => &header_rule_variables.request_id,
+            "route_source" => &header_rule_variables.route_source,
+            _ => {
+                return Err(format!(
+                    "Unknown header rule variable '${}'. Please use one of following: ${}",
+                    name,
+                    HEADER_RULE_VARIABLES.join(", $")
+                ))
+            }
+        };
+        expanded_value.push_str(variable_value);
+    }
+
+    Ok(expanded_value)
+}
+
+fn validate_header_name(header_name: &str) -> Result<(), String> {
+    if HeaderName::from_bytes(header_name.as_bytes()).is_err() {
+        return Err(format!("Invalid header name in header rule: '{}'", header_name));
+    }
+
+    if PROTECTED_HEADERS.contains(&header_name.to_ascii_lowercase().as_str()) {
+        return Err(format!(
+            "Header '{}' frames the message and can not be changed by header rules",
+            header_name
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_variables() -> HeaderRuleVariables {
+        HeaderRuleVariables {
+            client_ip: "10.0.0.5".to_string(),
+            host: "example.com".to_string(),
+            request_id: "abc123".to_string(),
+            route_source: "*.example.com".to_string(),
+        }
+    }
+
+    fn build_rule(action: HeaderRuleAction, name: &str, value: Option<&str>, new_name: Option<&str>) -> HeaderRule {
+        HeaderRule {
+            action,
+            phase: HeaderRulePhase::Request,
+            name: name.to_string(),
+            value: value.map(|value| value.to_string()),
+            new_name: new_name.map(|new_name| new_name.to_string()),
+        }
+    }
+
+    fn build_headers(headers: &[(&str, &str)]) -> HeaderMap {
+        let mut header_map = HeaderMap::new();
+        for (name, value) in headers {
+            header_map.append(
+                HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        header_map
+    }
+
+    fn get_values(headers: &HeaderMap, name: &str) -> Vec<String> {
+        headers
+            .get_all(name)
+            .iter()
+            .map(|value| value.to_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn variables_are_expanded_with_and_without_braces() {
+        let header_rule_variables = build_variables();
+        let expand = |value: &str| expand_variables(value, &header_rule_variables);
+
+        assert_eq!(expand("$client_ip").unwrap(), "10.0.0.5");
+        assert_eq!(expand("${host}:443").unwrap(), "example.com:443");
+        assert_eq!(expand("id=${request_id};route=$route_source").unwrap(), "id=abc123;route=*.example.com");
+        assert_eq!(expand("$host-$client_ip").unwrap(), "example.com-10.0.0.5");
+        assert_eq!(expand("no variables").unwrap(), "no variables");
+    }
+
+    #[test]
+    fn double_dollar_is_written_as_literal_dollar() {
+        let header_rule_variables = build_variables();
+        let expand = |value: &str| expand_variables(value, &header_rule_variables);
+
+        assert_eq!(expand("$$").unwrap(), "$");
+        assert_eq!(expand("$$host").unwrap(), "$host");
+        assert_eq!(expand("price: 5$$").unwrap(), "price: 5$");
+        assert_eq!(expand("$$$host").unwrap(), "$example.com");
+    }
+
+    #[test]
+    fn unclosed_braces_and_unknown_variables_are_rejected() {
+        let header_rule_variables = build_variables();
+
+        for value in ["${host", "${host-1}", "${", "prefix ${client_ip"] {
+            let error = expand_variables(value, &header_rule_variables).unwrap_err();
+            assert!(error.contains("is not closed"), "{:?}: {}", value, error);
+        }
+
+        for value in ["$hostname", "${unknown}", "$", "${}", "cost $5"] {
+            let error = expand_variables(value, &header_rule_variables).unwrap_err();
+            assert!(error.contains("Unknown header rule variable"), "{:?}: {}", value, error);
+        }
+    }
+
+    #[test]
+    fn rename_moves_every_value_and_replaces_existing_target() {
+        let mut headers = build_headers(&[("x-old", "1"), ("x-old", "2"), ("x-new", "stale"), ("x-other", "kept")]);
+        let header_rules = [build_rule(HeaderRuleAction::Rename, "X-Old", None, Some("X-New"))];
+
+        apply_header_rules(&mut headers, &header_rules, HeaderRulePhase::Request, &build_variables());
+
+        assert!(!headers.contains_key("x-old"));
+        assert_eq!(get_values(&headers, "x-new"), ["1", "2"]);
+        assert_eq!(get_values(&headers, "x-other"), ["kept"]);
+    }
+
+    #[test]
+    fn rename_of_missing_header_keeps_target() {
+        let mut headers = build_headers(&[("x-new", "kept")]);
+        let header_rules = [build_rule(HeaderRuleAction::Rename, "x-old", None, Some("x-new"))];
+
+        apply_header_rules(&mut headers, &header_rules, HeaderRulePhase::Request, &build_variables());
+
+        assert_eq!(get_values(&headers, "x-new"), ["kept"]);
+    }
+
+    #[test]
+    fn rules_of_other_phase_are_ignored_and_add_appends_while_set_replaces() {
+        let mut headers = build_headers(&[("x-added", "1"), ("x-set", "1"), ("x-removed", "1")]);
+        let mut response_rule = build_rule(HeaderRuleAction::Remove, "x-added", None, None);
+        response_rule.phase = HeaderRulePhase::Response;
+
+        let header_rules = [
+            build_rule(HeaderRuleAction::Add, "x-added", Some("$client_ip"), None),
+            build_rule(HeaderRuleAction::Set, "x-set", Some("${host}"), None),
+            build_rule(HeaderRuleAction::Remove, "x-removed", None, None),
+            build_rule(HeaderRuleAction::Set, "x-invalid", Some("$unknown"), None),
+            response_rule,
+        ];
+
+        apply_header_rules(&mut headers, &header_rules, HeaderRulePhase::Request, &build_variables());
+
+        assert_eq!(get_values(&headers, "x-added"), ["1", "10.0.0.5"]);
+        assert_eq!(get_values(&headers, "x-set"), ["example.com"]);
+        assert!(!headers.contains_key("x-removed"));
+        assert!(!headers.contains_key("x-invalid"));
+    }
+
+    #[test]
+    fn invalid_and_protected_header_rules_are_rejected() {
+        let invalid_rules = [
+            build_rule(HeaderRuleAction::Set, "x-value", None, None),
+            build_rule(HeaderRuleAction::Set, "x-value", Some("${host"), None),
+            build_rule(HeaderRuleAction::Add, "bad header", Some("1"), None),
+            build_rule(HeaderRuleAction::Remove, "Content-Length", None, None),
+            build_rule(HeaderRuleAction::Rename, "x-old", None, None),
+            build_rule(HeaderRuleAction::Rename, "x-old", None, Some("transfer-encoding")),
+        ];
+
+        for header_rule in invalid_rules {
+            assert!(validate_header_rules(std::slice::from_ref(&header_rule)).is_err(), "{:?}", header_rule);
+        }
+
+        let valid_rule = build_rule(HeaderRuleAction::Set, "x-id", Some("$$${request_id}"), None);
+        assert!(validate_header_rules(&[valid_rule]).is_ok());
+    }
+}
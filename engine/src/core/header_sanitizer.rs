@@ -0,0 +1,409 @@
+use std::collections::HashSet;
+
+use hyper::{
+    header::{self, HeaderMap, HeaderName, HeaderValue},
+    http::uri::Authority,
+    Uri, Version,
+};
+
+/// Connection specific headers which are never forwarded (RFC 9110 section 7.6.1)
+const HOP_BY_HOP_HEADERS: [&str; 5] = [
+    "connection",
+    "keep-alive",
+    "te",
+    "transfer-encoding",
+    "upgrade",
+];
+
+/// Header prefix of proxy connection headers, e.g. `Proxy-Authorization`, `Proxy-Connection`
+const PROXY_HEADER_PREFIX: &str = "proxy-";
+
+/// Headers which are not removed even if they are named in `Connection`.
+/// Otherwise a client could drop routing or framing of a request by listing them
+const PROTECTED_HEADERS: [&str; 2] = ["host", "content-length"];
+
+/// Checks framing & host of a client request before it is forwarded (RFC 9112 section 3.2 & 6.3).
+/// Requests which can not be interpreted in only one way are always rejected: invalid or differing
+/// `Content-Length` values, `Transfer-Encoding` not ending with `chunked` and invalid or repeated `Host`.
+/// In strict mode requests which can be repaired by `normalize_request_framing` are rejected too:
+/// both `Content-Length` & `Transfer-Encoding`, `Transfer-Encoding` on HTTP/1.0, missing `Host`
+/// and a `Host` which differs from the authority of an absolute request target
+pub fn check_request_framing(headers: &HeaderMap, uri: &Uri, version: Version, is_strict: bool) -> Result<(), String> {
+    let content_lengths = get_content_lengths(headers)?;
+    if content_lengths.len() > 1 {
+        return Err("Request has differing Content-Length values".into());
+    }
+
+    if headers.contains_key(header::TRANSFER_ENCODING) {
+        if !is_chunked_last(headers) {
+            return Err("Transfer-Encoding of request does not end with chunked".into());
+        }
+        if is_strict && !content_lengths.is_empty() {
+            return Err("Request has both Content-Length and Transfer-Encoding".into());
+        }
+        if is_strict && version == Version::HTTP_10 {
+            return Err("Transfer-Encoding is not allowed in HTTP/1.0 requests".into());
+        }
+    }
+
+    // HTTP/2 requests carry the host in `:authority` pseudo header, which is checked by the protocol itself
+    if version != Version::HTTP_10 && version != Version::HTTP_11 {
+        return Ok(());
+    }
+
+    check_request_host(headers, uri, version, is_strict)
+}
+
+/// Repairs framing of a message which passed `check_request_framing` in lenient mode.
+/// `Transfer-Encoding` overrides `Content-Length`, and repeated equal `Content-Length` values are collapsed
+pub fn normalize_request_framing(headers: &mut HeaderMap) {
+    if headers.contains_key(header::TRANSFER_ENCODING) {
+        headers.remove(header::CONTENT_LENGTH);
+        return;
+    }
+
+    let content_length = headers.get(header::CONTENT_LENGTH).cloned();
+    if let Some(content_length) = content_length {
+        let content_length = content_length
+            .to_str()
+            .ok()
+            .and_then(|value| value.split(',').next())
+            .and_then(|value| HeaderValue::from_str(value.trim()).ok());
+
+        if let Some(content_length) = content_length {
+            headers.insert(header::CONTENT_LENGTH, content_length);
+        }
+    }
+}
+
+/// Removes hop-by-hop headers of a request or response: `Connection`, `Keep-Alive`, `TE`, `Transfer-Encoding`,
+/// `Upgrade`, `Proxy-*` and headers named in `Connection`. Body is framed again by the next hop.
+/// For a protocol switch (`is_upgrade`), `Upgrade` is kept and `Connection: upgrade` is set again
+pub fn strip_hop_by_hop_headers(headers: &mut HeaderMap, is_upgrade: bool) {
+    let connection_options: HashSet<String> = headers
+        .get_all(header::CONNECTION)
+        .iter()
+        .filter_map(|connection| connection.to_str().ok())
+        .flat_map(|connection| connection.split(','))
+        .map(|connection_option| connection_option.trim().to_ascii_lowercase())
+        .filter(|connection_option| !connection_option.is_empty())
+        .collect();
+
+    let stripped_headers: Vec<HeaderName> = headers
+        .keys()
+        .filter(|header_name| {
+            let header_name = header_name.as_str();
+
+            if is_upgrade && header_name == header::UPGRADE.as_str() {
+                return false;
+            }
+
+            HOP_BY_HOP_HEADERS.contains(&header_name)
+                || header_name.starts_with(PROXY_HEADER_PREFIX)
+                || (connection_options.contains(header_name) && !PROTECTED_HEADERS.contains(&header_name))
+        })
+        .cloned()
+        .collect();
+
+    for header_name in stripped_headers {
+        headers.remove(header_name);
+    }
+
+    if is_upgrade && headers.contains_key(header::UPGRADE) {
+        headers.insert(header::CONNECTION, HeaderValue::from_static("upgrade"));
+    }
+}
+
+/// Distinct `Content-Length` values of a message. A list value (`5, 5`) counts as repeated headers
+fn get_content_lengths(headers: &HeaderMap) -> Result<HashSet<u64>, String> {
+    let mut content_lengths = HashSet::new();
+
+    for content_length in headers.get_all(header::CONTENT_LENGTH) {
+        let content_length = content_length
+            .to_str()
+            .map_err(|_| "Request has an invalid Content-Length".to_string())?;
+
+        for value in content_length.split(',') {
+            let value = value.trim();
+
+            // `u64::from_str` accepts a leading `+`, which is not valid in HTTP
+            if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Err("Request has an invalid Content-Length".into());
+            }
+
+            let value = value
+                .parse::<u64>()
+                .map_err(|_| "Request has an invalid Content-Length".to_string())?;
+            content_lengths.insert(value);
+        }
+    }
+
+    Ok(content_lengths)
+}
+
+/// Returns true if port of given authority is empty or numeric. `Authority` parses any port lazily
+fn has_valid_port(authority: &Authority) -> bool {
+    match authority.as_str()[authority.host().len()..].strip_prefix(':') {
+        Some(port) => port.bytes().all(|byte| byte.is_ascii_digit()),
+        None => true,
+    }
+}
+
+/// Returns true if the last transfer coding of a message is `chunked`, so the body has a known end
+fn is_chunked_last(headers: &HeaderMap) -> bool {
+    let transfer_codings: Vec<String> = headers
+        .get_all(header::TRANSFER_ENCODING)
+        .iter()
+        .map(|transfer_encoding| transfer_encoding.to_str().unwrap_or_default())
+        .flat_map(|transfer_encoding| transfer_encoding.split(','))
+        .map(|transfer_coding| transfer_coding.trim().to_ascii_lowercase())
+        .collect();
+
+    transfer_codings.last().map(|transfer_coding| transfer_coding == "chunked").unwrap_or(false)
+        && transfer_codings.iter().filter(|transfer_coding| *transfer_coding == "chunked").count() == 1
+}
+
+fn check_request_host(headers: &HeaderMap, uri: &Uri, version: Version, is_strict: bool) -> Result<(), String> {
+    let mut hosts = headers.get_all(header::HOST).iter();
+
+    let host = match (hosts.next(), hosts.next()) {
+        (Some(_), Some(_)) => return Err("Request has more than one Host header".into()),
+        (Some(host), None) => host,
+        (None, _) => {
+            if is_strict && version == Version::HTTP_11 && uri.authority().is_none() {
+                return Err("Request has no Host header".into());
+            }
+            return Ok(());
+        }
+    };
+
+    // Empty host is sent for targets without authority
+    if host.is_empty() {
+        return Ok(());
+    }
+
+    // Host is `uri-host [ ":" port ]`, user info is not allowed
+    let host = host
+        .to_str()
+        .ok()
+        .filter(|host| !host.contains('@'))
+        .and_then(|host| host.parse::<Authority>().ok())
+        .filter(has_valid_port);
+
+    let Some(host) = host else {
+        return Err("Request has an invalid Host header".into());
+    };
+
+    if let Some(authority) = uri.authority() {
+        if is_strict && !authority.as_str().eq_ignore_ascii_case(host.as_str()) {
+            return Err("Host header differs from the authority of request target".into());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_headers(headers: &[(&str, &str)]) -> HeaderMap {
+        let mut header_map = HeaderMap::new();
+        for (name, value) in headers {
+            header_map.append(
+                HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        header_map
+    }
+
+    fn check(headers: &HeaderMap, uri: &str, is_strict: bool) -> Result<(), String> {
+        check_request_framing(headers, &uri.parse::<Uri>().unwrap(), Version::HTTP_11, is_strict)
+    }
+
+    #[test]
+    fn cl_te_is_rejected_in_strict_mode_and_normalized_in_lenient_mode() {
+        let mut headers = build_headers(&[
+            ("host", "example.com"),
+            ("content-length", "6"),
+            ("transfer-encoding", "chunked"),
+        ]);
+
+        assert!(check(&headers, "/", true).is_err());
+        assert!(check(&headers, "/", false).is_ok());
+
+        normalize_request_framing(&mut headers);
+        assert!(!headers.contains_key(header::CONTENT_LENGTH));
+        assert_eq!(headers.get(header::TRANSFER_ENCODING).unwrap(), "chunked");
+    }
+
+    #[test]
+    fn obfuscated_transfer_encoding_is_rejected_in_both_modes() {
+        let payloads: [&[&str]; 4] = [
+            &["xchunked"],
+            &["chunked, identity"],
+            &["chunked", "chunked"],
+            &["chunked-false"],
+        ];
+
+        for transfer_encodings in payloads {
+            let mut headers = build_headers(&[("host", "example.com"), ("content-length", "4")]);
+            for transfer_encoding in transfer_encodings {
+                headers.append(header::TRANSFER_ENCODING, HeaderValue::from_str(transfer_encoding).unwrap());
+            }
+
+            assert!(check(&headers, "/", true).is_err(), "{:?}", transfer_encodings);
+            assert!(check(&headers, "/", false).is_err(), "{:?}", transfer_encodings);
+        }
+    }
+
+    #[test]
+    fn differing_content_lengths_are_rejected_in_both_modes() {
+        let duplicate_headers = build_headers(&[
+            ("host", "example.com"),
+            ("content-length", "5"),
+            ("content-length", "6"),
+        ]);
+        let list_header = build_headers(&[("host", "example.com"), ("content-length", "5, 6")]);
+
+        for headers in [duplicate_headers, list_header] {
+            assert!(check(&headers, "/", true).is_err());
+            assert!(check(&headers, "/", false).is_err());
+        }
+    }
+
+    #[test]
+    fn invalid_content_length_is_rejected_in_both_modes() {
+        for content_length in ["+5", "-1", "5a", "", "99999999999999999999"] {
+            let headers = build_headers(&[("host", "example.com"), ("content-length", content_length)]);
+
+            assert!(check(&headers, "/", true).is_err(), "{:?}", content_length);
+            assert!(check(&headers, "/", false).is_err(), "{:?}", content_length);
+        }
+    }
+
+    #[test]
+    fn repeated_equal_content_lengths_are_collapsed_in_lenient_mode() {
+        let mut headers = build_headers(&[("host", "example.com"), ("content-length", "5, 5")]);
+
+        assert!(check(&headers, "/", false).is_ok());
+
+        normalize_request_framing(&mut headers);
+        assert_eq!(headers.get_all(header::CONTENT_LENGTH).iter().count(), 1);
+        assert_eq!(headers.get(header::CONTENT_LENGTH).unwrap(), "5");
+    }
+
+    #[test]
+    fn transfer_encoding_on_http_10_is_rejected_in_strict_mode() {
+        let headers = build_headers(&[("transfer-encoding", "chunked")]);
+        let uri = "/".parse::<Uri>().unwrap();
+
+        assert!(check_request_framing(&headers, &uri, Version::HTTP_10, true).is_err());
+        assert!(check_request_framing(&headers, &uri, Version::HTTP_10, false).is_ok());
+    }
+
+    #[test]
+    fn missing_host_is_rejected_in_strict_mode() {
+        let headers = build_headers(&[]);
+
+        assert!(check(&headers, "/", true).is_err());
+        assert!(check(&headers, "/", false).is_ok());
+
+        // Absolute form carries the host in request target
+        assert!(check(&headers, "http://example.com/", true).is_ok());
+
+        // Host is optional in HTTP/1.0
+        let uri = "/".parse::<Uri>().unwrap();
+        assert!(check_request_framing(&headers, &uri, Version::HTTP_10, true).is_ok());
+    }
+
+    #[test]
+    fn invalid_or_repeated_host_is_rejected_in_both_modes() {
+        let invalid_hosts = ["user@example.com", "bad host", "example.com:port", "example.com/path"];
+
+        for host in invalid_hosts {
+            let headers = build_headers(&[("host", host)]);
+
+            assert!(check(&headers, "/", true).is_err(), "{:?}", host);
+            assert!(check(&headers, "/", false).is_err(), "{:?}", host);
+        }
+
+        let headers = build_headers(&[("host", "example.com"), ("host", "evil.com")]);
+        assert!(check(&headers, "/", true).is_err());
+        assert!(check(&headers, "/", false).is_err());
+    }
+
+    #[test]
+    fn host_differing_from_absolute_uri_is_rejected_in_strict_mode() {
+        let headers = build_headers(&[("host", "evil.com")]);
+
+        assert!(check(&headers, "http://example.com/", true).is_err());
+        assert!(check(&headers, "http://example.com/", false).is_ok());
+
+        let headers = build_headers(&[("host", "EXAMPLE.com:8080")]);
+        assert!(check(&headers, "http://example.com:8080/", true).is_ok());
+    }
+
+    #[test]
+    fn valid_requests_are_accepted_in_strict_mode() {
+        let headers = build_headers(&[("host", "example.com:8080"), ("content-length", "12")]);
+        assert!(check(&headers, "/upload", true).is_ok());
+
+        let headers = build_headers(&[("host", "[::1]:8080"), ("transfer-encoding", "gzip, chunked")]);
+        assert!(check(&headers, "/upload", true).is_ok());
+
+        // HTTP/2 host is checked by the protocol itself
+        let headers = build_headers(&[]);
+        let uri = "/".parse::<Uri>().unwrap();
+        assert!(check_request_framing(&headers, &uri, Version::HTTP_2, true).is_ok());
+    }
+
+    #[test]
+    fn headers_nominated_by_connection_are_stripped() {
+        let mut headers = build_headers(&[
+            ("host", "example.com"),
+            ("connection", "close, X-Foo"),
+            ("x-foo", "secret"),
+            ("x-bar", "kept"),
+            ("keep-alive", "timeout=5"),
+            ("te", "trailers"),
+            ("transfer-encoding", "chunked"),
+            ("proxy-authorization", "Basic Zm9vOmJhcg=="),
+        ]);
+
+        strip_hop_by_hop_headers(&mut headers, false);
+
+        let remaining_headers: Vec<&str> = headers.keys().map(|header_name| header_name.as_str()).collect();
+        assert_eq!(remaining_headers, vec!["host", "x-bar"]);
+    }
+
+    #[test]
+    fn protected_headers_nominated_by_connection_are_kept() {
+        let mut headers = build_headers(&[
+            ("host", "example.com"),
+            ("content-length", "5"),
+            ("connection", "Host, Content-Length"),
+        ]);
+
+        strip_hop_by_hop_headers(&mut headers, false);
+
+        assert!(headers.contains_key(header::HOST));
+        assert!(headers.contains_key(header::CONTENT_LENGTH));
+        assert!(!headers.contains_key(header::CONNECTION));
+    }
+
+    #[test]
+    fn upgrade_is_kept_for_protocol_switch() {
+        let mut headers = build_headers(&[
+            ("host", "example.com"),
+            ("connection", "keep-alive, Upgrade"),
+            ("upgrade", "websocket"),
+        ]);
+
+        strip_hop_by_hop_headers(&mut headers, true);
+
+        assert_eq!(headers.get(header::UPGRADE).unwrap(), "websocket");
+        assert_eq!(headers.get(header::CONNECTION).unwrap(), "upgrade");
+    }
+}
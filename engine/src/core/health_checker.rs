@@ -0,0 +1,174 @@
+use hyper::{Body, Method, Request};
+use std::collections::HashSet;
+use std::time::Duration;
+
+use crate::{
+    constants::Constants,
+    core::shared_memory::{
+        HTTPS_ROUTE_SNAPSHOT, HTTP_PROXY_MANAGER, HTTP_ROUTE_SNAPSHOT, RUNTIME_BOOT_INFO,
+        SHUTDOWN_SIGNAL, UPSTREAM_HEALTH,
+    },
+    log_debug, log_info,
+    models::{
+        route::upstream_pool::UpstreamPool, settings::health_check_settings::HealthCheckSettings,
+        traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
+    },
+    utils::{http_utility::build_upstream_uri, time_utility::run_in_time_buffer},
+};
+
+/// Background task which probes upstreams of route pools that define health checks.
+/// Pools are read from the live route snapshots on every tick, so route changes are picked up without restart.
+pub struct HealthChecker {}
+
+impl HealthChecker {
+    pub async fn start() {
+        let mut shutdown_event = SHUTDOWN_SIGNAL.subscriber.clone();
+        let on_shutdown = async move {
+            let _on_shutdown = shutdown_event.wait_for(|&s| s).await;
+        };
+        tokio::pin!(on_shutdown);
+
+        let mut ticker =
+            tokio::time::interval(Duration::from_secs(Constants::HEALTH_CHECK_TICK_INTERVAL));
+
+        log_info!("Vanguard Engine upstream health checker started");
+
+        loop {
+            tokio::select! {
+                _on_shutdown = &mut on_shutdown => {
+                    break;
+                }
+                _on_tick = ticker.tick() => {
+                    Self::run_due_checks();
+                }
+            }
+        }
+    }
+
+    fn run_due_checks() {
+        let upstream_pools = Self::collect_upstream_pools();
+
+        let mut targets = HashSet::new();
+        let mut actively_checked_targets = HashSet::new();
+
+        for (upstream_pool, _) in &upstream_pools {
+            for upstream in &upstream_pool.upstreams {
+                targets.insert(upstream.target.clone());
+
+                if upstream_pool.health_check.is_some() {
+                    actively_checked_targets.insert(upstream.target.clone());
+                }
+            }
+        }
+
+        UPSTREAM_HEALTH.sync_targets(&targets, &actively_checked_targets);
+
+        for (upstream_pool, traffic_policy) in upstream_pools {
+            let health_check = match upstream_pool.health_check {
+                Some(health_check) => health_check,
+                None => continue,
+            };
+
+            for upstream in upstream_pool.upstreams {
+                // Targets shared by several pools are probed once per interval
+                if !UPSTREAM_HEALTH.try_schedule_check(&upstream.target, health_check.get_interval()) {
+                    continue;
+                }
+
+                let health_check = health_check.clone();
+                let traffic_policy = traffic_policy.clone();
+
+                tokio::spawn(async move {
+                    let probe_result =
+                        Self::probe(&traffic_policy, &upstream.target, &health_check).await;
+
+                    if let Err(ref error) = probe_result {
+                        log_debug!("Health check of upstream {} failed: {}", &upstream.target, error);
+                    }
+
+                    UPSTREAM_HEALTH.record_probe(&upstream.target, probe_result, &health_check);
+                });
+            }
+        }
+    }
+
+    /// Collects pools of HTTP & HTTPS routes with effective traffic policy of their route.
+    /// Probes use the same upstream client settings (e.g. upstream TLS) as proxied requests
+    fn collect_upstream_pools() -> Vec<(UpstreamPool, ScopeTrafficPolicy)> {
+        let (http_traffic_policy, https_traffic_policy) = {
+            let runtime_info = RUNTIME_BOOT_INFO.read().unwrap();
+            (
+                runtime_info.config.get_http_effective_policy(),
+                runtime_info.config.get_https_effective_policy(),
+            )
+        };
+
+        let http_route_snapshot = HTTP_ROUTE_SNAPSHOT.load();
+        let https_route_snapshot = HTTPS_ROUTE_SNAPSHOT.load();
+
+        let http_pools = http_route_snapshot.http_routes.values().filter_map(|http_route| {
+            let upstream_pool = http_route.upstream_pool.clone()?;
+            Some((
+                upstream_pool,
+                route_traffic_policy(&http_traffic_policy, &http_route.traffic_policy),
+            ))
+        });
+
+        let https_pools = https_route_snapshot.https_routes.values().filter_map(|https_route| {
+            let upstream_pool = https_route.upstream_pool.clone()?;
+            Some((
+                upstream_pool,
+                route_traffic_policy(&https_traffic_policy, &https_route.traffic_policy),
+            ))
+        });
+
+        http_pools.chain(https_pools).collect()
+    }
+
+    /// Sends a GET request to health check path of the target. Any 2xx or 3xx response is a success
+    async fn probe(
+        traffic_policy: &ScopeTrafficPolicy,
+        target: &str,
+        health_check: &HealthCheckSettings,
+    ) -> Result<(), String> {
+        let http_client = HTTP_PROXY_MANAGER
+            .get(traffic_policy)
+            .map_err(|error| error.message)?;
+
+        let probe_uri = build_upstream_uri(target, &health_check.get_path());
+
+        let probe_request = Request::builder()
+            .method(Method::GET)
+            .uri(probe_uri)
+            .body(Body::empty())
+            .map_err(|error| error.to_string())?;
+
+        let response = run_in_time_buffer(
+            health_check.get_timeout() * 1000,
+            http_client.request(probe_request),
+        )
+        .await
+        .map_err(|_| "Health check timed out".to_string())?
+        .map_err(|error| error.to_string())?;
+
+        let status = response.status();
+        if status.is_success() || status.is_redirection() {
+            Ok(())
+        } else {
+            Err(format!("Health check responded with status {}", status.as_u16()))
+        }
+    }
+}
+
+fn route_traffic_policy(
+    scope_traffic_policy: &ScopeTrafficPolicy,
+    route_overrides: &Option<ScopeTrafficPolicy>,
+) -> ScopeTrafficPolicy {
+    let mut traffic_policy = scope_traffic_policy.clone();
+
+    if let Some(route_overrides) = route_overrides {
+        traffic_policy.merge(route_overrides);
+    }
+
+    traffic_policy
+}
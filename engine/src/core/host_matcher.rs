@@ -0,0 +1,320 @@
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Kind of host pattern that can be used as a route source.
+///
+/// * `example.com`    - Exact host match
+/// * `*.example.com`  - Wildcard, matches any subdomain of `example.com` (at any depth)
+/// * `.example.com`   - Suffix, matches `example.com` itself and any of its subdomains
+/// * `~^api-\d+\.example\.com$` - Regex, matched case-insensitively against the host
+/// * `*`              - Default, matches every host that has no other match
+#[derive(Debug, Clone)]
+pub enum HostPattern {
+    Exact(String),
+    Wildcard(String),
+    Suffix(String),
+    Regex(Regex),
+    Default,
+}
+
+impl HostPattern {
+    pub const DEFAULT_SOURCE: &'static str = "*";
+    pub const REGEX_PREFIX: &'static str = "~";
+
+    /// Parses a route source into a host pattern.
+    pub fn parse(source: &str) -> Result<HostPattern, String> {
+        let source = source.trim();
+
+        if source.is_empty() {
+            return Err("Route source can not be empty".to_string());
+        }
+
+        if source == Self::DEFAULT_SOURCE {
+            return Ok(HostPattern::Default);
+        }
+
+        if let Some(expression) = source.strip_prefix(Self::REGEX_PREFIX) {
+            let compiled_regex = Regex::new(&format!("(?i){}", expression)).map_err(|error| {
+                format!("Invalid regex host pattern '{}': {}", source, error)
+            })?;
+
+            return Ok(HostPattern::Regex(compiled_regex));
+        }
+
+        let normalized_source = source.to_lowercase();
+
+        if let Some(suffix) = normalized_source.strip_prefix('*') {
+            if !suffix.starts_with('.') || suffix.len() < 2 || suffix.contains('*') {
+                return Err(format!(
+                    "Invalid wildcard host pattern '{}'. Expected format is '*.example.com'",
+                    source
+                ));
+            }
+
+            return Ok(HostPattern::Wildcard(suffix.to_string()));
+        }
+
+        if normalized_source.starts_with('.') {
+            if normalized_source.len() < 2 || normalized_source.contains('*') {
+                return Err(format!(
+                    "Invalid suffix host pattern '{}'. Expected format is '.example.com'",
+                    source
+                ));
+            }
+
+            return Ok(HostPattern::Suffix(normalized_source));
+        }
+
+        if normalized_source.contains('*') {
+            return Err(format!(
+                "Invalid host pattern '{}'. Wildcard is only allowed as leading label ('*.example.com')",
+                source
+            ));
+        }
+
+        Ok(HostPattern::Exact(normalized_source))
+    }
+
+    /// Returns true if given route source is an exact host (not a pattern)
+    pub fn is_exact(source: &str) -> bool {
+        matches!(HostPattern::parse(source), Ok(HostPattern::Exact(_)))
+    }
+}
+
+/// Validates that given route source is a well formed host or host pattern
+pub fn validate_host_pattern(source: &str) -> Result<(), String> {
+    HostPattern::parse(source).map(|_| ())
+}
+
+/// Resolves request hosts to route entries by using exact, wildcard, suffix and regex host patterns.
+///
+/// Precedence is deterministic: exact > longest wildcard/suffix > regex (ordered by source) > default.
+#[derive(Debug, Clone)]
+pub struct HostMatcher<T> {
+    exact_hosts: HashMap<String, T>,
+    wildcard_hosts: Vec<(String, bool, T)>,
+    regex_hosts: Vec<(String, Regex, T)>,
+    default_host: Option<T>,
+}
+
+impl<T> Default for HostMatcher<T> {
+    fn default() -> Self {
+        Self {
+            exact_hosts: HashMap::new(),
+            wildcard_hosts: Vec::new(),
+            regex_hosts: Vec::new(),
+            default_host: None,
+        }
+    }
+}
+
+impl<T: Clone> HostMatcher<T> {
+    /// Registers a route source with its value. Invalid patterns are skipped and reported as error.
+    /// If same pattern is registered twice, first registration is kept.
+    pub fn insert(&mut self, source: &str, value: T) -> Result<(), String> {
+        match HostPattern::parse(source)? {
+            HostPattern::Exact(host) => {
+                self.exact_hosts.entry(host).or_insert(value);
+            }
+            HostPattern::Wildcard(suffix) => {
+                self.insert_wildcard(suffix, false, value);
+            }
+            HostPattern::Suffix(suffix) => {
+                self.insert_wildcard(suffix, true, value);
+            }
+            HostPattern::Regex(compiled_regex) => {
+                let source = source.trim().to_string();
+                if !self.regex_hosts.iter().any(|(each, _, _)| each == &source) {
+                    self.regex_hosts.push((source, compiled_regex, value));
+                    self.regex_hosts.sort_by(|a, b| a.0.cmp(&b.0));
+                }
+            }
+            HostPattern::Default => {
+                if self.default_host.is_none() {
+                    self.default_host = Some(value);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn insert_wildcard(&mut self, suffix: String, include_apex: bool, value: T) {
+        let is_already_registered = self
+            .wildcard_hosts
+            .iter()
+            .any(|(each, each_include_apex, _)| each == &suffix && *each_include_apex == include_apex);
+
+        if is_already_registered {
+            return;
+        }
+
+        self.wildcard_hosts.push((suffix, include_apex, value));
+
+        // Longest suffix first. On equal length, wildcard ('*.') comes before suffix ('.') pattern
+        self.wildcard_hosts.sort_by(|a, b| {
+            b.0.len()
+                .cmp(&a.0.len())
+                .then(a.1.cmp(&b.1))
+                .then(a.0.cmp(&b.0))
+        });
+    }
+
+    /// Finds the best matching value for the given request host. A trailing ':port' is ignored.
+    pub fn find(&self, request_host: &str) -> Option<&T> {
+        let host = request_host.trim();
+        let host = match host.rsplit_once(':') {
+            Some((name, port)) if !name.contains(':') && port.parse::<u16>().is_ok() => name,
+            _ => host,
+        };
+        let host = host.trim_end_matches('.').to_lowercase();
+
+        if let Some(value) = self.exact_hosts.get(&host) {
+            return Some(value);
+        }
+
+        for (suffix, include_apex, value) in &self.wildcard_hosts {
+            if host.len() > suffix.len() && host.ends_with(suffix.as_str()) {
+                return Some(value);
+            }
+
+            if *include_apex && host == suffix[1..] {
+                return Some(value);
+            }
+        }
+
+        for (_, compiled_regex, value) in &self.regex_hosts {
+            if compiled_regex.is_match(&host) {
+                return Some(value);
+            }
+        }
+
+        self.default_host.as_ref()
+    }
+
+    /// Returns value of default ('*') route if exist
+    pub fn find_default(&self) -> Option<&T> {
+        self.default_host.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_matcher(sources: &[&str]) -> HostMatcher<String> {
+        let mut host_matcher = HostMatcher::default();
+        for source in sources {
+            host_matcher.insert(source, source.to_string()).unwrap();
+        }
+        host_matcher
+    }
+
+    fn find<'a>(host_matcher: &'a HostMatcher<String>, host: &str) -> Option<&'a str> {
+        host_matcher.find(host).map(|value| value.as_str())
+    }
+
+    #[test]
+    fn malformed_sources_are_rejected() {
+        for source in ["", "  ", "*example.com", "a.*.com", "*.", "*.*.com", ".", ".*.com", "~(unclosed"] {
+            assert!(HostPattern::parse(source).is_err(), "{:?}", source);
+        }
+
+        for source in ["example.com", "*.example.com", ".example.com", "~^api\\.", "*"] {
+            assert!(validate_host_pattern(source).is_ok(), "{:?}", source);
+        }
+
+        assert!(HostPattern::is_exact("Example.COM"));
+        assert!(!HostPattern::is_exact("*.example.com"));
+        assert!(!HostPattern::is_exact("*"));
+    }
+
+    #[test]
+    fn exact_host_wins_over_every_pattern() {
+        let host_matcher = build_matcher(&["*", "~example", ".example.com", "*.example.com", "www.example.com"]);
+
+        assert_eq!(find(&host_matcher, "www.example.com"), Some("www.example.com"));
+        assert_eq!(find(&host_matcher, "api.example.com"), Some("*.example.com"));
+        assert_eq!(find(&host_matcher, "example.com"), Some(".example.com"));
+        assert_eq!(find(&host_matcher, "example.org"), Some("~example"));
+        assert_eq!(find(&host_matcher, "other.org"), Some("*"));
+    }
+
+    #[test]
+    fn longest_wildcard_wins_and_matches_nested_subdomains() {
+        let host_matcher = build_matcher(&["*.example.com", "*.api.example.com"]);
+
+        assert_eq!(find(&host_matcher, "v1.api.example.com"), Some("*.api.example.com"));
+        assert_eq!(find(&host_matcher, "a.b.v1.api.example.com"), Some("*.api.example.com"));
+        assert_eq!(find(&host_matcher, "api.example.com"), Some("*.example.com"));
+        assert_eq!(find(&host_matcher, "a.b.c.example.com"), Some("*.example.com"));
+    }
+
+    #[test]
+    fn wildcard_excludes_apex_while_suffix_includes_it() {
+        let wildcard_matcher = build_matcher(&["*.example.com"]);
+        assert_eq!(find(&wildcard_matcher, "example.com"), None);
+        assert_eq!(find(&wildcard_matcher, "badexample.com"), None);
+
+        let suffix_matcher = build_matcher(&[".example.com"]);
+        assert_eq!(find(&suffix_matcher, "example.com"), Some(".example.com"));
+        assert_eq!(find(&suffix_matcher, "www.example.com"), Some(".example.com"));
+        assert_eq!(find(&suffix_matcher, "badexample.com"), None);
+    }
+
+    #[test]
+    fn wildcard_comes_before_suffix_of_same_length() {
+        let host_matcher = build_matcher(&[".example.com", "*.example.com"]);
+
+        assert_eq!(find(&host_matcher, "www.example.com"), Some("*.example.com"));
+        assert_eq!(find(&host_matcher, "example.com"), Some(".example.com"));
+    }
+
+    #[test]
+    fn host_is_case_folded_and_trailing_dot_is_ignored() {
+        let host_matcher = build_matcher(&["WWW.Example.com", "*.API.example.com", "~^cdn-\\d+\\.example\\.com$"]);
+
+        assert_eq!(find(&host_matcher, "www.EXAMPLE.com."), Some("WWW.Example.com"));
+        assert_eq!(find(&host_matcher, " V1.Api.Example.Com "), Some("*.API.example.com"));
+        assert_eq!(find(&host_matcher, "CDN-7.example.com"), Some("~^cdn-\\d+\\.example\\.com$"));
+    }
+
+    #[test]
+    fn port_is_ignored_when_matching() {
+        let host_matcher = build_matcher(&["example.com", "*.example.com"]);
+
+        assert_eq!(find(&host_matcher, "example.com:8080"), Some("example.com"));
+        assert_eq!(find(&host_matcher, "www.example.com:443"), Some("*.example.com"));
+        assert_eq!(find(&host_matcher, "example.com:http"), None);
+        assert_eq!(find(&host_matcher, "::1"), None);
+    }
+
+    #[test]
+    fn regexes_are_tried_in_source_order() {
+        let host_matcher = build_matcher(&["~^www", "~example", "~^api"]);
+
+        assert_eq!(find(&host_matcher, "api.example.com"), Some("~^api"));
+        assert_eq!(find(&host_matcher, "www.example.com"), Some("~^www"));
+        assert_eq!(find(&host_matcher, "cdn.example.com"), Some("~example"));
+    }
+
+    #[test]
+    fn default_is_used_as_fallback_and_first_registration_is_kept() {
+        let mut host_matcher = HostMatcher::default();
+        assert_eq!(host_matcher.find("example.com"), None);
+        assert_eq!(host_matcher.find_default(), None);
+
+        host_matcher.insert("*", 1).unwrap();
+        host_matcher.insert("*", 2).unwrap();
+        host_matcher.insert("example.com", 3).unwrap();
+        host_matcher.insert("EXAMPLE.com", 4).unwrap();
+        host_matcher.insert("*.example.com", 5).unwrap();
+        host_matcher.insert("*.example.com", 6).unwrap();
+        assert!(host_matcher.insert("a.*.com", 7).is_err());
+
+        assert_eq!(host_matcher.find("example.com"), Some(&3));
+        assert_eq!(host_matcher.find("www.example.com"), Some(&5));
+        assert_eq!(host_matcher.find("example.org"), Some(&1));
+        assert_eq!(host_matcher.find_default(), Some(&1));
+    }
+}
@@ -1,31 +1,36 @@
-use crate::constants::Constants;
+use crate::{
+    core::upstream_connector::UpstreamConnector,
+    models::settings::upstream_tls_settings::UpstreamTlsSettings,
+    utils::tls_utility::{create_upstream_tls_config, get_upstream_server_name},
+};
 use hyper::{client::HttpConnector, Body, Client, Request, Response};
+use jsonrpc_core::Error;
 
 pub struct HttpClient {
-    client: Client<HttpConnector>,
-}
-
-impl Default for HttpClient {
-    fn default() -> Self {
-        Self::init(
-            Constants::DEFAULT_POOL_IDLE_TIMEOUT,
-            Constants::DEFAULT_MAX_IDLE_CONNS_PER_HOST,
-        )
-    }
+    client: Client<UpstreamConnector>,
 }
 
 impl HttpClient {
-    pub fn init(pool_idle_timeout: u64, max_idle_conns_per_host: usize) -> Self {
+    /// Creates a pooled client which reaches `http://` upstreams over plain TCP and `https://` upstreams over TLS
+    pub fn init(
+        pool_idle_timeout: u64,
+        max_idle_conns_per_host: usize,
+        tls_settings: &UpstreamTlsSettings,
+    ) -> Result<Self, Error> {
         let mut http = HttpConnector::new();
         http.set_nodelay(true);
         http.set_keepalive(Some(std::time::Duration::from_secs(pool_idle_timeout)));
+        http.enforce_http(false);
+
+        let tls_config = create_upstream_tls_config(tls_settings)?;
+        let server_name_override = get_upstream_server_name(tls_settings)?;
 
         let client = Client::builder()
             .pool_idle_timeout(std::time::Duration::from_secs(pool_idle_timeout))
             .pool_max_idle_per_host(max_idle_conns_per_host)
-            .build(http);
+            .build(UpstreamConnector::new(http, tls_config, server_name_override));
 
-        Self { client }
+        Ok(Self { client })
     }
 
     pub async fn request(&self, req: Request<Body>) -> Result<Response<Body>, hyper::Error> {
@@ -1,5 +1,6 @@
 use crate::core::http_client::HttpClient;
 use crate::models::traffic_policy::scope_traffic_policy::ScopeTrafficPolicy;
+use jsonrpc_core::Error;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
@@ -16,20 +17,25 @@ impl Default for HttpProxyManager {
 }
 
 impl HttpProxyManager {
-    pub fn get(&self, traffic_policy: &ScopeTrafficPolicy) -> Arc<HttpClient> {
-        // Build a unique key for the given traffic policy
+    /// Returns pooled HTTP client of given traffic policy.
+    /// Fails if upstream TLS settings of the policy are invalid (e.g. CA bundle can not be loaded)
+    pub fn get(&self, traffic_policy: &ScopeTrafficPolicy) -> Result<Arc<HttpClient>, Error> {
+        let upstream_tls_settings = traffic_policy.upstream_settings.get_tls();
+
+        // Build a unique key for the given traffic policy.
+        // TLS settings are part of the key, so upstreams with different trust or client identity never share connections
         let key = format!(
-            "{}_{}",
+            "{}_{}_{}",
             traffic_policy.upstream_settings.get_pool_idle_timeout(),
-            traffic_policy.upstream_settings.get_max_idle_conns_per_host()
+            traffic_policy.upstream_settings.get_max_idle_conns_per_host(),
+            upstream_tls_settings.get_pool_key()
         );
 
         // First try to read with a read lock by given key
         {
             let get_clients = self.http_clients.read().unwrap();
-            let pooled_http_client = get_clients.get(&key);
-            if pooled_http_client.is_some() {
-                return Arc::clone(pooled_http_client.unwrap());
+            if let Some(pooled_http_client) = get_clients.get(&key) {
+                return Ok(Arc::clone(pooled_http_client));
             }
         }
 
@@ -38,15 +44,16 @@ impl HttpProxyManager {
 
         // Double check because another thread might have inserted it
         if let Some(client) = http_caller.get(&key) {
-            return Arc::clone(client);
+            return Ok(Arc::clone(client));
         }
 
         let client = Arc::new(HttpClient::init(
             traffic_policy.upstream_settings.get_pool_idle_timeout(),
             traffic_policy.upstream_settings.get_max_idle_conns_per_host(),
-        ));
+            &upstream_tls_settings,
+        )?);
 
         http_caller.insert(key, Arc::clone(&client));
-        client
+        Ok(client)
     }
 }
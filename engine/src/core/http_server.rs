@@ -7,28 +7,32 @@ use hyper::{
     Body, Request, Response, Server,
 };
 
-use std::{path::PathBuf, sync::Arc};
+use std::{path::PathBuf, sync::Arc, time::Instant};
 
-use std::{
-    collections::HashMap,
-    net::{IpAddr, SocketAddr},
-};
+use std::net::{IpAddr, SocketAddr};
 
 use crate::{
+    common::enums::route_type::RouteType,
     constants::Constants,
     core::{
         common_handler::{CommonHandler, Protocol},
         connection_lock::ConnectionLock,
+        forwarded_headers::{is_trusted_proxy, resolve_client_ip, PeerAddress},
+        header_rules::RouteHeaderRules,
+        metrics::with_route_label,
+        rate_limiter::RateLimitDecision,
+        rewrite_matcher::RewriteResult,
+        route_snapshot::HttpRouteSnapshot,
         shared_memory::{
-            CONNECTION_MANAGER, RELOAD_SIGNAL, ROUTER, RUNTIME_BOOT_INFO, SHUTDOWN_SIGNAL,
+            ACCESS_LOGGER, ACME_CHALLENGES, CONNECTION_MANAGER, HTTP_ROUTE_SNAPSHOT, IP_ACCESS_CONTROL, METRICS, RATE_LIMITER,
+            RELOAD_SIGNAL, RUNTIME_BOOT_INFO, SHUTDOWN_SIGNAL,
         },
     },
     log_debug, log_error, log_info,
-    models::route::{http_route::HttpRoute, iws_route::IwsRoute},
     models::traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
     render::Render,
     utils::{
-        http_utility::calculate_content_length,
+        http_utility::{calculate_content_length, rewrite_request_path, rewrite_request_target},
         network_utility::{extract_host, parse_ip_address},
         time_utility::run_in_time_buffer,
     },
@@ -37,8 +41,6 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct HttpServer {
     socket: SocketAddr,
-    http_routes: HashMap<String, HttpRoute>,
-    iws_routes: HashMap<String, IwsRoute>,
 }
 
 impl Default for HttpServer {
@@ -49,13 +51,9 @@ impl Default for HttpServer {
         let default_socket_instance: SocketAddr =
             SocketAddr::from((default_ip_address, default_port));
 
-        let default_http_route_table: HashMap<String, HttpRoute> = HashMap::new();
-        let default_iws_route_table: HashMap<String, IwsRoute> = HashMap::new();
 
         Self {
             socket: default_socket_instance,
-            http_routes: default_http_route_table,
-            iws_routes: default_iws_route_table,
         }
     }
 }
@@ -65,12 +63,8 @@ impl HttpServer {
         let ip = parse_ip_address(ip_address.clone());
         let socket = SocketAddr::from((ip, port));
 
-        let router = ROUTER.read().unwrap();
-
         Self {
             socket,
-            http_routes: router.get_http_routes(),
-            iws_routes: router.get_iws_routes(),
         }
     }
 
@@ -90,29 +84,57 @@ impl HttpServer {
                     let start_new_connection =
                         Arc::new(CONNECTION_MANAGER.try_acquire_connection());
 
-                    Ok::<_, hyper::Error>(service_fn(move |req| {
+                    Ok::<_, hyper::Error>(service_fn(move |mut req: Request<Body>| {
                         let http_server = Arc::clone(&http_server);
-                        let client_ip = client.ip();
                         let connection_lock = Arc::clone(&start_new_connection);
 
+                        // Real client ip is resolved once, so ip access lists, rate limits and logs see the same address
+                        let trusted_proxies = &HTTP_ROUTE_SNAPSHOT.load().trusted_proxies;
+                        let client_ip = resolve_client_ip(req.headers(), client.ip(), trusted_proxies);
+                        req.extensions_mut().insert(PeerAddress {
+                            ip: client.ip(),
+                            is_trusted_proxy: is_trusted_proxy(trusted_proxies, client.ip()),
+                        });
+
                         async move {
-                            http_server
+                            let started_at = Instant::now();
+                            let access_log_request = ACCESS_LOGGER.load().capture_request(&mut req);
+                            let response = http_server
                                 .lifecycle(req, client_ip, &connection_lock)
-                                .await
+                                .await;
+
+                            METRICS.record_request(
+                                Protocol::HTTP.get_metric_label(),
+                                client_ip,
+                                &response,
+                                started_at.elapsed(),
+                            );
+
+                            if let Some(access_log_request) = access_log_request {
+                                ACCESS_LOGGER.load().log(
+                                    Protocol::HTTP,
+                                    client_ip,
+                                    &access_log_request,
+                                    &response,
+                                    started_at.elapsed(),
+                                    None,
+                                );
+                            }
+                            response
                         }
                     }))
                 }
             });
 
             let mut shutdown_event = SHUTDOWN_SIGNAL.subscriber.clone();
-            let mut reload_event = RELOAD_SIGNAL.subscriber.clone();
+            let mut reload_event = RELOAD_SIGNAL.subscribe();
 
             let stop_signal = async move {
                 tokio::select! {
                     _ = shutdown_event.wait_for(|&s| s) => {
                         log_info!("HTTP Server received shutdown signal.");
                     }
-                    _ = reload_event.wait_for(|&r| r) => {
+                    _ = reload_event.changed() => {
                         log_info!("HTTP Server received reload signal. Restarting engine...");
                     }
                 }
@@ -175,8 +197,26 @@ impl HttpServer {
         client_ip: IpAddr,
         connection_lock: &Option<ConnectionLock>,
     ) -> Result<Response<Body>, hyper::Error> {
+        // ACME HTTP-01 validation requests are answered before ip access lists, validation servers have arbitrary addresses
+        if let Some(challenge_response) = ACME_CHALLENGES.answer_http_challenge(&req) {
+            return Ok(challenge_response);
+        }
+
         let request_host = extract_host(&req);
 
+        // Global and server ip access lists are evaluated before any routing
+        let ip_access_result = IP_ACCESS_CONTROL.load().check_http_server(client_ip);
+        if let Err(forbidden_page) = ip_access_result {
+            return CommonHandler::forbidden_error(
+                Protocol::HTTP,
+                &request_host,
+                req,
+                client_ip,
+                forbidden_page.as_deref(),
+            )
+            .await;
+        }
+
         // Get traffic_policy to drop the RwLockReadGuard immediately
         let (traffic_policy, global_rate_limit) = {
             let runtime_info = RUNTIME_BOOT_INFO.read().unwrap();
@@ -230,17 +270,27 @@ impl HttpServer {
             );
         }
 
-        if !CONNECTION_MANAGER.check_rate_limit(client_ip, global_rate_limit) {
-            log_info!("Rate limit exceeded for ip address {:?}", client_ip);
-            return Ok::<_, hyper::Error>(
-                Response::builder()
-                    .status(hyper::StatusCode::TOO_MANY_REQUESTS)
-                    .body(Body::from(Render::internal_server_error(
-                        "/",
-                        "Rate limit exceeded",
-                    )))
-                    .unwrap(),
-            );
+        // Global limit is shared by HTTP & HTTPS servers. Key & algorithm come from the server scope policy
+        let global_rate_limit_decision = RATE_LIMITER.check(
+            "global",
+            global_rate_limit,
+            &traffic_policy.rate_limit_settings,
+            &req,
+            client_ip,
+        );
+
+        if let Some(ref rate_limit_decision) = global_rate_limit_decision {
+            if !rate_limit_decision.is_allowed {
+                return CommonHandler::too_many_requests_error(
+                    Protocol::HTTP,
+                    &request_host,
+                    req,
+                    client_ip,
+                    "global",
+                    rate_limit_decision,
+                )
+                .await;
+            }
         }
 
         let response = run_in_time_buffer(
@@ -266,13 +316,21 @@ impl HttpServer {
             )));
         }
 
-        let result = completed_response.unwrap();
+        let mut result = completed_response.unwrap();
+
+        // Headers of route limits are more specific, global ones are added only if the route has no limit
+        if let Some(ref rate_limit_decision) = global_rate_limit_decision {
+            if !RateLimitDecision::has_headers(result.headers()) {
+                rate_limit_decision.apply_headers(result.headers_mut());
+            }
+        }
+
         Ok::<_, hyper::Error>(result)
     }
 
     async fn handle_request(
         &self,
-        req: Request<Body>,
+        mut req: Request<Body>,
         client_ip: IpAddr,
         traffic_policy: ScopeTrafficPolicy,
     ) -> Result<Response<Body>, hyper::Error> {
@@ -281,22 +339,89 @@ impl HttpServer {
         log_debug!("HTTP outband request received: {:?}", &req);
         log_debug!("HTTP outband request host: {:?}", &request_host);
 
+        // Routes are read from the latest published snapshot, so route changes apply without restart
+        let route_snapshot = HTTP_ROUTE_SNAPSHOT.load_full();
+        let matched_route = route_snapshot.route_matcher.find(&request_host).cloned();
+
+        // Route override of access log sampling wins over the one of server policy
+        let access_log_sample_rate = matched_route
+            .as_ref()
+            .and_then(|(route_type, route_source)| {
+                route_snapshot.find_traffic_policy(route_type.clone(), route_source)
+            })
+            .and_then(|route_policy| route_policy.access_log_sample_rate)
+            .or(traffic_policy.access_log_sample_rate);
+
+        // Ip access list of matched host entry is evaluated before request is forwarded or served
+        if let Some((route_type, route_source)) = &matched_route {
+            let ip_access_result = match route_snapshot.find_ip_access_list(route_type.clone(), route_source) {
+                Some(route_ip_access_list) => {
+                    IP_ACCESS_CONTROL.load().check_route(route_ip_access_list, client_ip)
+                }
+                None => Ok(()),
+            };
+
+            if let Err(forbidden_page) = ip_access_result {
+                let response = CommonHandler::forbidden_error(
+                    Protocol::HTTP,
+                    &request_host,
+                    req,
+                    client_ip,
+                    forbidden_page.as_deref(),
+                )
+                .await;
+
+                return with_route_label(response, route_source, access_log_sample_rate);
+            }
+
+            // Rewrite rules run before path rules, so a rewritten path selects path rule & target
+            match route_snapshot.apply_rewrite_rules(route_type.clone(), route_source, &req, client_ip) {
+                RewriteResult::Forward(Some(path_and_query)) => {
+                    log_debug!("HTTP outband request {:?} is rewritten to {:?}", req.uri(), &path_and_query);
+                    req = rewrite_request_target(req, &path_and_query);
+                }
+                RewriteResult::Forward(None) => {}
+                RewriteResult::Respond(rewrite_response) => {
+                    let route_header_rules = RouteHeaderRules::new(
+                        route_source,
+                        route_snapshot.find_header_rules(route_type.clone(), route_source),
+                    );
+
+                    let response = CommonHandler::rewrite_response(
+                        Protocol::HTTP,
+                        &request_host,
+                        req,
+                        client_ip,
+                        rewrite_response,
+                        &route_header_rules,
+                    )
+                    .await;
+
+                    return with_route_label(response, route_source, access_log_sample_rate);
+                }
+            }
+        }
+
         /* Forwarding HTTP requests */
         log_debug!("Looking for Http route table:");
 
-        if self.http_routes.contains_key(&request_host) {
-            return self
-                .handle_http_route(&request_host, req, client_ip, traffic_policy)
+        if let Some((RouteType::Http, route_source)) = &matched_route {
+            let response = self
+                .handle_http_route(&route_snapshot, route_source, &request_host, req, client_ip, traffic_policy)
                 .await;
+
+            return with_route_label(response, route_source, access_log_sample_rate);
         }
 
         /* Processing IWS requests */
         log_debug!("Looking for IWS route table:");
 
-        if self.iws_routes.contains_key(&request_host) {
-            return self
-                .handle_iws_route(&request_host, req, client_ip, traffic_policy)
+        if let Some((RouteType::Iws, route_source)) = &matched_route {
+            let response = self
+                .handle_iws_route(&route_snapshot, route_source, &request_host, req, client_ip, traffic_policy)
                 .await;
+
+            return with_route_label(response, route_source, access_log_sample_rate);
         }
 
         /* Handle not found */
@@ -310,17 +435,20 @@ impl HttpServer {
 
     async fn handle_http_route(
         &self,
+        route_snapshot: &HttpRouteSnapshot,
+        route_source: &String,
         request_host: &String,
         req: Request<Body>,
         client_ip: IpAddr,
         mut traffic_policy: ScopeTrafficPolicy,
     ) -> Result<Response<Body>, hyper::Error> {
         log_debug!(
-            "HTTP outband request source found in http route registry:  {:?}",
-            request_host
+            "HTTP outband request source {:?} found in http route registry as {:?}",
+            request_host,
+            route_source
         );
 
-        let current_http_route = self.http_routes.get(request_host).unwrap();
+        let current_http_route = route_snapshot.http_routes.get(route_source).unwrap();
 
         // Merge route-specific overrides
         if let Some(ref route_overrides) = current_http_route.traffic_policy {
@@ -332,22 +460,95 @@ impl HttpServer {
             traffic_policy.merge_path_policy(path_overrides);
         }
 
-        if !String::is_empty(&current_http_route.target) {
+        let mut req = req;
+        let mut target = current_http_route.target.clone();
+        let mut has_path_rule_target = false;
+
+        let mut matched_path_rule = None;
+
+        // Resolve path rule of host entry (if any) for target and path specific overrides
+        if let Some(path_match) =
+            route_snapshot.find_path_rule(RouteType::Http, route_source, req.uri().path())
+        {
+            log_debug!(
+                "HTTP outband request path {:?} matched path rule {:?}",
+                req.uri().path(),
+                &path_match.rule.path
+            );
+
+            if let Some(ref rule_target) = path_match.rule.target {
+                target = rule_target.clone();
+                has_path_rule_target = true;
+            }
+
+            if let Some(ref path_overrides) = path_match.rule.path_policy {
+                traffic_policy.merge_path_policy(path_overrides);
+            }
+
+            matched_path_rule = Some(path_match.rule);
+            req = rewrite_request_path(req, &path_match.forward_path);
+        }
+
+        let route_rate_limit = RATE_LIMITER.check_route(
+            "http",
+            route_source,
+            matched_path_rule,
+            &traffic_policy,
+            &req,
+            client_ip,
+        );
+
+        if let Some(rate_limit_decision) = route_rate_limit.get_rejection() {
+            return CommonHandler::too_many_requests_error(
+                Protocol::HTTP,
+                request_host,
+                req,
+                client_ip,
+                &route_rate_limit.scope,
+                rate_limit_decision,
+            )
+            .await;
+        }
+
+        // Distribute request between pool upstreams unless a path rule defined its own target.
+        // Lease is kept until upstream responds, so least-connections strategies see in-flight requests
+        let mut upstream_lease = None;
+        if !has_path_rule_target {
+            if let Some(load_balancer) = route_snapshot.load_balancers.get(route_source) {
+                let selected_upstream = load_balancer.select(&req, client_ip);
+                target = selected_upstream.target.clone();
+                upstream_lease = Some(selected_upstream);
+            }
+        }
+
+        if !String::is_empty(&target) {
             log_debug!(
                 "HTTP outband request source ({}) is known. Forwarding request to {}",
                 &request_host,
-                &current_http_route.target
+                &target
             );
 
-            return CommonHandler::url_execution(
+            let mut response = CommonHandler::url_execution(
                 Protocol::HTTP,
                 request_host,
-                &current_http_route.target,
+                &target,
                 req,
                 client_ip,
                 &traffic_policy,
+                &RouteHeaderRules::new(route_source, &current_http_route.header_rules),
             )
             .await;
+
+            // Result of the request feeds passive outlier detection of the selected upstream
+            if let Some(ref upstream_lease) = upstream_lease {
+                upstream_lease.record_outcome(&response);
+            }
+
+            METRICS.record_upstream_outcome(Protocol::HTTP.get_metric_label(), route_source, &target, &response);
+
+            route_rate_limit.apply_headers(&mut response);
+
+            return response;
         }
 
         log_debug!(
@@ -360,20 +561,102 @@ impl HttpServer {
 
     async fn handle_iws_route(
         &self,
+        route_snapshot: &HttpRouteSnapshot,
+        route_source: &String,
         request_host: &String,
         req: Request<Body>,
         client_ip: IpAddr,
-        _traffic_policy: ScopeTrafficPolicy,
+        mut traffic_policy: ScopeTrafficPolicy,
     ) -> Result<Response<Body>, hyper::Error> {
-        let url_path = req.uri().path().strip_prefix("/").unwrap_or("");
-
         log_debug!(
-            "HTTP outband request source found in IWS registry:  {:?}",
-            &request_host
+            "HTTP outband request source {:?} found in IWS registry as {:?}",
+            &request_host,
+            route_source
         );
 
-        let current_iws_route = self.iws_routes.get(request_host).unwrap();
-        if !std::path::Path::new(&current_iws_route.serving_path).exists() {
+        let current_iws_route = route_snapshot.iws_routes.get(route_source).unwrap();
+
+        // Merge route-specific overrides
+        if let Some(ref route_overrides) = current_iws_route.traffic_policy {
+            traffic_policy.merge(route_overrides);
+        }
+
+        let mut serving_path = current_iws_route.serving_path.clone();
+        let mut request_path = req.uri().path().to_string();
+        let mut matched_path_rule = None;
+
+        // Resolve path rule of host entry (if any) for serving path and path specific overrides
+        if let Some(path_match) =
+            route_snapshot.find_path_rule(RouteType::Iws, route_source, req.uri().path())
+        {
+            log_debug!(
+                "HTTP outband IWS request path {:?} matched path rule {:?}",
+                req.uri().path(),
+                &path_match.rule.path
+            );
+
+            if let Some(ref rule_serving_path) = path_match.rule.serving_path {
+                serving_path = rule_serving_path.clone();
+            }
+
+            if let Some(ref path_overrides) = path_match.rule.path_policy {
+                traffic_policy.merge_path_policy(path_overrides);
+            }
+
+            matched_path_rule = Some(path_match.rule);
+            request_path = path_match.forward_path;
+        }
+
+        // Route & path limits are enforced for static files as for proxied requests
+        let route_rate_limit = RATE_LIMITER.check_route(
+            "iws",
+            route_source,
+            matched_path_rule,
+            &traffic_policy,
+            &req,
+            client_ip,
+        );
+
+        if let Some(rate_limit_decision) = route_rate_limit.get_rejection() {
+            return CommonHandler::too_many_requests_error(
+                Protocol::HTTP,
+                request_host,
+                req,
+                client_ip,
+                &route_rate_limit.scope,
+                rate_limit_decision,
+            )
+            .await;
+        }
+
+        let mut response = self
+            .serve_iws_path(
+                request_host,
+                &serving_path,
+                &request_path,
+                req,
+                client_ip,
+                &RouteHeaderRules::new(route_source, &current_iws_route.header_rules),
+            )
+            .await;
+
+        route_rate_limit.apply_headers(&mut response);
+        response
+    }
+
+    /// Serves requested file or directory under serving path of the host entry
+    async fn serve_iws_path(
+        &self,
+        request_host: &String,
+        serving_path: &str,
+        request_path: &str,
+        req: Request<Body>,
+        client_ip: IpAddr,
+        route_header_rules: &RouteHeaderRules<'_>,
+    ) -> Result<Response<Body>, hyper::Error> {
+        let url_path = request_path.strip_prefix("/").unwrap_or("");
+
+        if !std::path::Path::new(&serving_path).exists() {
             log_debug!(
                 "HTTP outband IWS request source ({}) as domain/target is is unknown",
                 &request_host
@@ -388,7 +671,7 @@ impl HttpServer {
             .await;
         }
 
-        let mut requested_disk_path: PathBuf = PathBuf::from(&current_iws_route.serving_path);
+        let mut requested_disk_path: PathBuf = PathBuf::from(&serving_path);
         requested_disk_path.push(url_path);
 
         let read_metadata = tokio::fs::metadata(&requested_disk_path).await;
@@ -396,7 +679,7 @@ impl HttpServer {
             log_debug!(
                 "HTTP outband IWS request source ({}) is known. But requested path '{}' doesn't exist",
                 &request_host,
-                &current_iws_route.serving_path
+                &serving_path
             );
 
             return CommonHandler::iws_route_not_found_error(
@@ -414,7 +697,7 @@ impl HttpServer {
             log_debug!(
                 "HTTP outband IWS request source ({}) is known. Serving file from disk (IWS registry) at path: {}",
                 &request_host,
-                &current_iws_route.serving_path
+                &serving_path
             );
 
             return CommonHandler::iws_static_file_execution(
@@ -424,6 +707,7 @@ impl HttpServer {
                 &metadata,
                 req,
                 client_ip,
+                route_header_rules,
             )
             .await;
         }
@@ -432,7 +716,7 @@ impl HttpServer {
             log_debug!(
                 "HTTP outband IWS request source ({}) is known. Serving directory from disk (IWS registry) at path: {}",
                 &request_host,
-                &current_iws_route.serving_path
+                &serving_path
             );
 
             return CommonHandler::iws_static_directory_execution(
@@ -441,6 +725,7 @@ impl HttpServer {
                 &requested_disk_path,
                 req,
                 client_ip,
+                route_header_rules,
             )
             .await;
         }
@@ -448,9 +733,10 @@ impl HttpServer {
         log_debug!(
             "HTTP outband IWS request source ({}) is known. But requested path '{}' doesn't exist",
             &request_host,
-            &current_iws_route.serving_path
+            &serving_path
         );
 
         CommonHandler::iws_empty_path_error(Protocol::HTTP, request_host, req, client_ip).await
     }
+
 }
@@ -1,19 +1,33 @@
 use hyper::{server::conn::Http, service::service_fn, Body, Request, Response};
 use std::net::IpAddr;
 use std::path::PathBuf;
-use std::{collections::HashMap, net::SocketAddr, sync::Arc};
+use std::time::Instant;
+use std::{net::SocketAddr, sync::Arc};
+use rustls::server::Acceptor;
+use rustls::ServerConfig;
 use tokio::net::TcpListener;
-use tokio_rustls::TlsAcceptor;
+use tokio_rustls::LazyConfigAcceptor;
 
+use crate::common::enums::route_type::RouteType;
 use crate::constants::Constants;
+use crate::core::access_log::TlsConnectionInfo;
+use crate::core::acme_challenges::is_acme_tls_alpn_hello;
+use crate::core::client_auth::{apply_client_identity_headers, check_client_auth};
 use crate::core::common_handler::{CommonHandler, Protocol};
 use crate::core::connection_lock::ConnectionLock;
-use crate::core::shared_memory::{CONNECTION_MANAGER, ROUTER, RUNTIME_BOOT_INFO, SHUTDOWN_SIGNAL};
-use crate::models::route::secure_iws_route::SecureIwsRoute;
-use crate::models::{
-    route::https_route::HttpsRoute, traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
+use crate::core::forwarded_headers::{is_trusted_proxy, resolve_client_ip, PeerAddress};
+use crate::core::header_rules::RouteHeaderRules;
+use crate::core::metrics::with_route_label;
+use crate::core::rate_limiter::RateLimitDecision;
+use crate::core::rewrite_matcher::RewriteResult;
+use crate::core::route_snapshot::HttpsRouteSnapshot;
+use crate::core::shared_memory::{
+    ACCESS_LOGGER, CONNECTION_MANAGER, HTTPS_ROUTE_SNAPSHOT, IP_ACCESS_CONTROL, METRICS, RATE_LIMITER,
+    RELOAD_SIGNAL, RUNTIME_BOOT_INFO, SHUTDOWN_SIGNAL,
 };
-use crate::utils::http_utility::calculate_content_length;
+use crate::models::settings::tls_settings::TlsSettings;
+use crate::models::traffic_policy::scope_traffic_policy::ScopeTrafficPolicy;
+use crate::utils::http_utility::{calculate_content_length, rewrite_request_path, rewrite_request_target};
 use crate::utils::time_utility::run_in_time_buffer;
 use crate::{log_debug, log_error, log_info};
 
@@ -22,15 +36,12 @@ use crate::utils::network_utility::{extract_host, parse_ip_address};
 use crate::utils::tls_utility::create_ssl_context;
 
 use tokio::net::TcpStream as TokioTcpStream;
-use tokio::sync::oneshot::{self as TokioChannel};
 
 // Global Http Server Instance: Initially empty default config, updated in Runtime init
 
 #[derive(Clone)]
 pub struct HttpsServer {
     socket: SocketAddr,
-    https_routes: HashMap<String, HttpsRoute>,
-    secure_iws_routes: HashMap<String, SecureIwsRoute>,
 }
 
 impl Default for HttpsServer {
@@ -41,13 +52,8 @@ impl Default for HttpsServer {
         let default_socket_instance: SocketAddr =
             SocketAddr::from((default_ip_address, default_port));
 
-        let default_https_route_table: HashMap<String, HttpsRoute> = HashMap::new();
-        let default_secure_iws_route_table: HashMap<String, SecureIwsRoute> = HashMap::new();
-
         Self {
             socket: default_socket_instance,
-            https_routes: default_https_route_table,
-            secure_iws_routes: default_secure_iws_route_table,
         }
     }
 }
@@ -57,18 +63,11 @@ impl HttpsServer {
         let ip = parse_ip_address(ip_address.clone());
         let socket = SocketAddr::from((ip, port));
 
-        let router = ROUTER.read().unwrap();
-
-        Self {
-            socket,
-            https_routes: router.get_https_routes(),
-            secure_iws_routes: router.get_secure_iws_routes(),
-        }
+        Self { socket }
     }
 
     pub async fn start(&self) {
-        let ssl_context: TlsAcceptor =
-            create_ssl_context(self.https_routes.clone(), self.secure_iws_routes.clone());
+        let mut ssl_context: Arc<ServerConfig> = Self::create_default_ssl_context();
 
         let listener: TcpListener = TcpListener::bind(&self.socket).await.unwrap();
 
@@ -80,14 +79,21 @@ impl HttpsServer {
         };
         tokio::pin!(on_shutdown);
 
+        let mut reload_event = RELOAD_SIGNAL.subscribe();
+
         loop {
             tokio::select! {
                 _on_shutdown = &mut on_shutdown => {
                     break;
                 }
+                _on_reload = reload_event.changed() => {
+                    // Listener is kept open, so established & in-flight connections are not dropped
+                    log_info!("HTTPS Server received reload signal. Renewing TLS context...");
+                    ssl_context = Self::create_default_ssl_context();
+                }
                 result = listener.accept() => {
                     let (tcp_stream, client) = result.unwrap();
-                    let tls_acceptor: TlsAcceptor = ssl_context.clone();
+                    let default_ssl_context = Arc::clone(&ssl_context);
                     let https_server = Arc::new(self.clone());
                     let client_ip = client.ip();
 
@@ -95,7 +101,7 @@ impl HttpsServer {
 
                     tokio::spawn(async move {
                         https_server
-                            .execute_request(tls_acceptor, tcp_stream, client_ip, start_new_connection)
+                            .execute_request(default_ssl_context, tcp_stream, client_ip, start_new_connection)
                             .await;
                     });
                 }
@@ -105,46 +111,67 @@ impl HttpsServer {
 
     async fn execute_request(
         &self,
-        tls_acceptor: TlsAcceptor,
+        default_ssl_context: Arc<ServerConfig>,
         tcp_stream: TokioTcpStream,
-        client_ip: IpAddr,
+        peer_ip: IpAddr,
         start_new_connection: Option<ConnectionLock>,
     ) {
         let https_server = Arc::new(self.clone());
         let connection_lock = Arc::new(start_new_connection);
         tokio::spawn(async move {
-            // 1. Creating a "oneshot" channel to receive the negotiated protocol from the TLS handshake.
-            let (transmitter, receiver) = TokioChannel::channel();
+            // 1. Reading the client hello before choosing TLS configuration.
+            // Routes with client certificate authentication (mTLS) are handshaked with their own configuration.
+            let start_handshake = match LazyConfigAcceptor::new(Acceptor::default(), tcp_stream).await {
+                Ok(start_handshake) => start_handshake,
+                Err(e) => {
+                    METRICS.record_tls_handshake_failure();
+                    log_error!("TLS Handshake failed: {:?}", e);
+                    return;
+                }
+            };
+
+            let client_hello = start_handshake.client_hello();
+            let route_snapshot = HTTPS_ROUTE_SNAPSHOT.load();
+            let handshake_route = route_snapshot
+                .sni_resolver
+                .find_route_source(client_hello.server_name())
+                .cloned();
+
+            // ACME validation servers never present client certificates, so they always use the default configuration
+            let ssl_context = match (&handshake_route, is_acme_tls_alpn_hello(&client_hello)) {
+                (Some(route_source), false) => route_snapshot
+                    .sni_resolver
+                    .find_server_config(route_source)
+                    .unwrap_or(default_ssl_context),
+                _ => default_ssl_context,
+            };
+            drop(route_snapshot);
 
             // 2. Performing the TLS handshake.
-            // Here we are using `accept_with` to peek into the session and see if the client wants HTTP/2 (h2).
-            let accept_tls = tls_acceptor
-                .accept_with(tcp_stream, |session| {
-                    let negotiated_protocol = session.alpn_protocol().map(|p| p.to_vec());
-                    let stream_result = transmitter.send(negotiated_protocol);
-
-                    if stream_result.is_err() {
-                        log_error!(
-                            "Unable to send negotiated protocol: {:?}",
-                            stream_result.err().unwrap()
-                        );
-                    }
-                })
-                .await;
+            let accept_tls = start_handshake.into_stream(ssl_context).await;
 
             // 3. Handling the TLS handshake result.
             let tls_stream = match accept_tls {
                 Ok(tls_stream) => tls_stream,
                 Err(e) => {
+                    METRICS.record_tls_handshake_failure();
                     log_error!("TLS Handshake failed: {:?}", e);
                     return;
                 }
             };
 
-            // @todo: get request host and select server engine here
-            // 4. Determining if we should use HTTP/2 or fallback to HTTP/1.1.
+            let tls_connection = tls_stream.get_ref().1;
+            let tls_connection_info = Arc::new(TlsConnectionInfo::from_connection(tls_connection, handshake_route));
+
+            // 4. Determining if we should use HTTP/2 or fallback to HTTP/1.1 by the negotiated protocol (ALPN).
             let mut server_engine = https_server.get_server_engine();
-            if let Ok(Some(protocol)) = receiver.await {
+            if let Some(protocol) = tls_connection.alpn_protocol() {
+                // TLS-ALPN-01 validation is completed by the handshake itself (RFC 8737)
+                if protocol == Constants::ACME_TLS_ALPN_PROTOCOL {
+                    log_debug!("ACME TLS-ALPN-01 validation handshake completed");
+                    return;
+                }
+
                 if protocol == b"h2" {
                     log_debug!("HTTP/2 connection negotiated via ALPN");
                     server_engine.http2_only(true);
@@ -152,23 +179,65 @@ impl HttpsServer {
             }
 
             // 6. Creating a service instance to handle incoming requests.
-            let service = service_fn(move |req: Request<Body>| {
+            let service = service_fn(move |mut req: Request<Body>| {
                 let https_server_instance = Arc::clone(&https_server);
                 let connection_lock = Arc::clone(&connection_lock);
+                let tls_connection_info = Arc::clone(&tls_connection_info);
+
+                // Real client ip is resolved once, so ip access lists, rate limits and logs see the same address
+                let trusted_proxies = &HTTPS_ROUTE_SNAPSHOT.load().trusted_proxies;
+                let client_ip = resolve_client_ip(req.headers(), peer_ip, trusted_proxies);
+                req.extensions_mut().insert(PeerAddress {
+                    ip: peer_ip,
+                    is_trusted_proxy: is_trusted_proxy(trusted_proxies, peer_ip),
+                });
 
                 async move {
-                    https_server_instance
-                        .lifecycle(req, client_ip, &connection_lock)
-                        .await
+                    let started_at = Instant::now();
+                    let access_log_request = ACCESS_LOGGER.load().capture_request(&mut req);
+                    let response = https_server_instance
+                        .lifecycle(req, client_ip, &connection_lock, &tls_connection_info)
+                        .await;
+
+                    METRICS.record_request(
+                        Protocol::HTTPS.get_metric_label(),
+                        client_ip,
+                        &response,
+                        started_at.elapsed(),
+                    );
+
+                    if let Some(access_log_request) = access_log_request {
+                        ACCESS_LOGGER.load().log(
+                            Protocol::HTTPS,
+                            client_ip,
+                            &access_log_request,
+                            &response,
+                            started_at.elapsed(),
+                            Some(&tls_connection_info),
+                        );
+                    }
+                    response
                 }
             });
 
-            if let Err(e) = server_engine.serve_connection(tls_stream, service).await {
+            // Upgrades are enabled, so accepted protocol switches (e.g. WebSocket) take over the TLS stream
+            if let Err(e) = server_engine.serve_connection(tls_stream, service).with_upgrades().await {
                 log_error!("Server error: {}", e);
             }
         });
     }
 
+    /// Returns TLS server configuration with handshake policy of HTTPS server.
+    /// Invalid settings are reported and rustls safe defaults are used instead, so the listener keeps serving
+    fn create_default_ssl_context() -> Arc<ServerConfig> {
+        let tls_settings = RUNTIME_BOOT_INFO.read().unwrap().config.https_server.get_tls_settings();
+
+        create_ssl_context(&tls_settings).unwrap_or_else(|error| {
+            log_error!("{}. Falling back to default TLS settings", error.message);
+            create_ssl_context(&TlsSettings::default()).unwrap()
+        })
+    }
+
     /// Returns a new HTTPS server engine with the current traffic policy
     fn get_server_engine(&self) -> Http {
         // Clone traffic_policy to drop the RwLockReadGuard immediately
@@ -225,9 +294,23 @@ impl HttpsServer {
         req: Request<Body>,
         client_ip: IpAddr,
         connection_lock: &Option<ConnectionLock>,
+        tls_connection_info: &TlsConnectionInfo,
     ) -> Result<Response<Body>, hyper::Error> {
         let request_host = extract_host(&req);
 
+        // Global and server ip access lists are evaluated before any routing
+        let ip_access_result = IP_ACCESS_CONTROL.load().check_https_server(client_ip);
+        if let Err(forbidden_page) = ip_access_result {
+            return CommonHandler::forbidden_error(
+                Protocol::HTTPS,
+                &request_host,
+                req,
+                client_ip,
+                forbidden_page.as_deref(),
+            )
+            .await;
+        }
+
         // Get traffic_policy to drop the RwLockReadGuard immediately
         let (traffic_policy, global_rate_limit) = {
             let runtime_info = RUNTIME_BOOT_INFO.read().unwrap();
@@ -280,23 +363,33 @@ impl HttpsServer {
             );
         }
 
-        if !CONNECTION_MANAGER.check_rate_limit(client_ip, global_rate_limit) {
-            log_info!("Rate limit exceeded for ip address {:?}", client_ip);
-            return Ok::<_, hyper::Error>(
-                Response::builder()
-                    .status(hyper::StatusCode::TOO_MANY_REQUESTS)
-                    .body(Body::from(Render::internal_server_error(
-                        "/",
-                        "Rate limit exceeded",
-                    )))
-                    .unwrap(),
-            );
+        // Global limit is shared by HTTP & HTTPS servers. Key & algorithm come from the server scope policy
+        let global_rate_limit_decision = RATE_LIMITER.check(
+            "global",
+            global_rate_limit,
+            &traffic_policy.rate_limit_settings,
+            &req,
+            client_ip,
+        );
+
+        if let Some(ref rate_limit_decision) = global_rate_limit_decision {
+            if !rate_limit_decision.is_allowed {
+                return CommonHandler::too_many_requests_error(
+                    Protocol::HTTPS,
+                    &request_host,
+                    req,
+                    client_ip,
+                    "global",
+                    rate_limit_decision,
+                )
+                .await;
+            }
         }
 
         CONNECTION_MANAGER.increment_total_requests();
         let response = run_in_time_buffer(
             traffic_policy.upstream_settings.get_http_client_timeout() * 1000,
-            self.handle_request(req, client_ip, traffic_policy),
+            self.handle_request(req, client_ip, traffic_policy, tls_connection_info),
         )
         .await;
 
@@ -317,23 +410,34 @@ impl HttpsServer {
             )));
         }
 
-        let result = completed_response.unwrap();
+        let mut result = completed_response.unwrap();
+
+        // Headers of route limits are more specific, global ones are added only if the route has no limit
+        if let Some(ref rate_limit_decision) = global_rate_limit_decision {
+            if !RateLimitDecision::has_headers(result.headers()) {
+                rate_limit_decision.apply_headers(result.headers_mut());
+            }
+        }
+
         Ok::<_, hyper::Error>(result)
     }
 
     async fn handle_https_route(
         &self,
+        route_snapshot: &HttpsRouteSnapshot,
+        route_source: &String,
         request_host: &String,
         req: Request<Body>,
         client_ip: IpAddr,
         mut traffic_policy: ScopeTrafficPolicy,
     ) -> Result<Response<Body>, hyper::Error> {
         log_debug!(
-            "HTTPS outband request source found in https route registry:  {:?}",
-            request_host
+            "HTTPS outband request source {:?} found in https route registry as {:?}",
+            request_host,
+            route_source
         );
 
-        let current_https_route = self.https_routes.get(request_host).unwrap();
+        let current_https_route = route_snapshot.https_routes.get(route_source).unwrap();
 
         // Merge route-specific overrides
         if let Some(ref route_overrides) = current_https_route.traffic_policy {
@@ -345,22 +449,95 @@ impl HttpsServer {
             traffic_policy.merge_path_policy(path_overrides);
         }
 
+        let mut req = req;
+        let mut target = current_https_route.target.clone();
+        let mut has_path_rule_target = false;
+
+        let mut matched_path_rule = None;
+
+        // Resolve path rule of host entry (if any) for target and path specific overrides
+        if let Some(path_match) =
+            route_snapshot.find_path_rule(RouteType::Https, route_source, req.uri().path())
+        {
+            log_debug!(
+                "HTTPS outband request path {:?} matched path rule {:?}",
+                req.uri().path(),
+                &path_match.rule.path
+            );
+
+            if let Some(ref rule_target) = path_match.rule.target {
+                target = rule_target.clone();
+                has_path_rule_target = true;
+            }
+
+            if let Some(ref path_overrides) = path_match.rule.path_policy {
+                traffic_policy.merge_path_policy(path_overrides);
+            }
+
+            matched_path_rule = Some(path_match.rule);
+            req = rewrite_request_path(req, &path_match.forward_path);
+        }
+
+        let route_rate_limit = RATE_LIMITER.check_route(
+            "https",
+            route_source,
+            matched_path_rule,
+            &traffic_policy,
+            &req,
+            client_ip,
+        );
+
+        if let Some(rate_limit_decision) = route_rate_limit.get_rejection() {
+            return CommonHandler::too_many_requests_error(
+                Protocol::HTTPS,
+                request_host,
+                req,
+                client_ip,
+                &route_rate_limit.scope,
+                rate_limit_decision,
+            )
+            .await;
+        }
+
+        // Distribute request between pool upstreams unless a path rule defined its own target.
+        // Lease is kept until upstream responds, so least-connections strategies see in-flight requests
+        let mut upstream_lease = None;
+        if !has_path_rule_target {
+            if let Some(load_balancer) = route_snapshot.load_balancers.get(route_source) {
+                let selected_upstream = load_balancer.select(&req, client_ip);
+                target = selected_upstream.target.clone();
+                upstream_lease = Some(selected_upstream);
+            }
+        }
+
         if !String::is_empty(request_host) {
             log_debug!(
                 "HTTPS outband request source ({}) is known. Forwarding request to {}",
                 &request_host,
-                &current_https_route.target
+                &target
             );
 
-            return CommonHandler::url_execution(
+            let mut response = CommonHandler::url_execution(
                 Protocol::HTTPS,
                 request_host,
-                &current_https_route.target,
+                &target,
                 req,
                 client_ip,
                 &traffic_policy,
+                &RouteHeaderRules::new(route_source, &current_https_route.header_rules),
             )
             .await;
+
+            // Result of the request feeds passive outlier detection of the selected upstream
+            if let Some(ref upstream_lease) = upstream_lease {
+                upstream_lease.record_outcome(&response);
+            }
+
+            METRICS.record_upstream_outcome(Protocol::HTTPS.get_metric_label(), route_source, &target, &response);
+
+            route_rate_limit.apply_headers(&mut response);
+
+            return response;
         }
 
         log_debug!(
@@ -373,20 +550,102 @@ impl HttpsServer {
 
     async fn handle_secure_iws_route(
         &self,
+        route_snapshot: &HttpsRouteSnapshot,
+        route_source: &String,
         request_host: &String,
         req: Request<Body>,
         client_ip: IpAddr,
-        _traffic_policy: ScopeTrafficPolicy,
+        mut traffic_policy: ScopeTrafficPolicy,
     ) -> Result<Response<Body>, hyper::Error> {
-        let url_path = req.uri().path().strip_prefix("/").unwrap_or("");
-
         log_debug!(
-            "HTTPS outband request source found in Secure IWS registry:  {:?}",
-            &request_host
+            "HTTPS outband request source {:?} found in Secure IWS registry as {:?}",
+            &request_host,
+            route_source
         );
 
-        let current_iws_route = self.secure_iws_routes.get(request_host).unwrap();
-        if !std::path::Path::new(&current_iws_route.serving_path).exists() {
+        let current_iws_route = route_snapshot.secure_iws_routes.get(route_source).unwrap();
+
+        // Merge route-specific overrides
+        if let Some(ref route_overrides) = current_iws_route.traffic_policy {
+            traffic_policy.merge(route_overrides);
+        }
+
+        let mut serving_path = current_iws_route.serving_path.clone();
+        let mut request_path = req.uri().path().to_string();
+        let mut matched_path_rule = None;
+
+        // Resolve path rule of host entry (if any) for serving path and path specific overrides
+        if let Some(path_match) =
+            route_snapshot.find_path_rule(RouteType::SecureIws, route_source, req.uri().path())
+        {
+            log_debug!(
+                "HTTPS outband IWS request path {:?} matched path rule {:?}",
+                req.uri().path(),
+                &path_match.rule.path
+            );
+
+            if let Some(ref rule_serving_path) = path_match.rule.serving_path {
+                serving_path = rule_serving_path.clone();
+            }
+
+            if let Some(ref path_overrides) = path_match.rule.path_policy {
+                traffic_policy.merge_path_policy(path_overrides);
+            }
+
+            matched_path_rule = Some(path_match.rule);
+            request_path = path_match.forward_path;
+        }
+
+        // Route & path limits are enforced for static files as for proxied requests
+        let route_rate_limit = RATE_LIMITER.check_route(
+            "secure-iws",
+            route_source,
+            matched_path_rule,
+            &traffic_policy,
+            &req,
+            client_ip,
+        );
+
+        if let Some(rate_limit_decision) = route_rate_limit.get_rejection() {
+            return CommonHandler::too_many_requests_error(
+                Protocol::HTTPS,
+                request_host,
+                req,
+                client_ip,
+                &route_rate_limit.scope,
+                rate_limit_decision,
+            )
+            .await;
+        }
+
+        let mut response = self
+            .serve_secure_iws_path(
+                request_host,
+                &serving_path,
+                &request_path,
+                req,
+                client_ip,
+                &RouteHeaderRules::new(route_source, &current_iws_route.header_rules),
+            )
+            .await;
+
+        route_rate_limit.apply_headers(&mut response);
+        response
+    }
+
+    /// Serves requested file or directory under serving path of the host entry
+    async fn serve_secure_iws_path(
+        &self,
+        request_host: &String,
+        serving_path: &str,
+        request_path: &str,
+        req: Request<Body>,
+        client_ip: IpAddr,
+        route_header_rules: &RouteHeaderRules<'_>,
+    ) -> Result<Response<Body>, hyper::Error> {
+        let url_path = request_path.strip_prefix("/").unwrap_or("");
+
+        if !std::path::Path::new(&serving_path).exists() {
             log_debug!(
                 "HTTPS outband IWS request source ({}) as domain/target is is unknown",
                 &request_host
@@ -401,7 +660,7 @@ impl HttpsServer {
             .await;
         }
 
-        let mut requested_disk_path: PathBuf = PathBuf::from(&current_iws_route.serving_path);
+        let mut requested_disk_path: PathBuf = PathBuf::from(&serving_path);
         requested_disk_path.push(url_path);
 
         let read_metadata = tokio::fs::metadata(&requested_disk_path).await;
@@ -409,7 +668,7 @@ impl HttpsServer {
             log_debug!(
                 "HTTPS outband IWS request source ({}) is known. But requested path '{}' doesn't exist",
                 &request_host,
-                &current_iws_route.serving_path
+                &serving_path
             );
 
             return CommonHandler::iws_route_not_found_error(
@@ -427,7 +686,7 @@ impl HttpsServer {
             log_debug!(
                 "HTTPS outband IWS request source ({}) is known. Serving file from disk (Secure IWS registry) at path: {}",
                 &request_host,
-                &current_iws_route.serving_path
+                &serving_path
             );
 
             return CommonHandler::iws_static_file_execution(
@@ -437,6 +696,7 @@ impl HttpsServer {
                 &metadata,
                 req,
                 client_ip,
+                route_header_rules,
             )
             .await;
         }
@@ -445,7 +705,7 @@ impl HttpsServer {
             log_debug!(
                 "HTTPS outband IWS request source ({}) is known. Serving directory from disk (Secure IWS registry) at path: {}",
                 &request_host,
-                &current_iws_route.serving_path
+                &serving_path
             );
 
             return CommonHandler::iws_static_directory_execution(
@@ -454,6 +714,7 @@ impl HttpsServer {
                 &requested_disk_path,
                 req,
                 client_ip,
+                route_header_rules,
             )
             .await;
         }
@@ -461,39 +722,127 @@ impl HttpsServer {
         log_debug!(
             "HTTPS outband IWS request source ({}) is known. But requested path '{}' doesn't exist",
             &request_host,
-            &current_iws_route.serving_path
+            &serving_path
         );
 
         CommonHandler::iws_empty_path_error(Protocol::HTTPS, request_host, req, client_ip).await
     }
 
+
     async fn handle_request(
         &self,
-        req: Request<Body>,
+        mut req: Request<Body>,
         client_ip: IpAddr,
         traffic_policy: ScopeTrafficPolicy,
+        tls_connection_info: &TlsConnectionInfo,
     ) -> Result<Response<Body>, hyper::Error> {
         let request_host = extract_host(&req);
 
         log_debug!("HTTPS outband request received: {:?}", &req);
         log_debug!("HTTPS outband request host: {:?}", &request_host);
 
+        // Routes are read from the latest published snapshot, so route changes apply without restart
+        let route_snapshot = HTTPS_ROUTE_SNAPSHOT.load_full();
+        let matched_route = route_snapshot.route_matcher.find(&request_host).cloned();
+
+        // Route override of access log sampling wins over the one of server policy
+        let access_log_sample_rate = matched_route
+            .as_ref()
+            .and_then(|(route_type, route_source)| {
+                route_snapshot.find_traffic_policy(route_type.clone(), route_source)
+            })
+            .and_then(|route_policy| route_policy.access_log_sample_rate)
+            .or(traffic_policy.access_log_sample_rate);
+
+        // Ip access list of matched host entry is evaluated before request is forwarded or served
+        if let Some((route_type, route_source)) = &matched_route {
+            let ip_access_result = match route_snapshot.find_ip_access_list(route_type.clone(), route_source) {
+                Some(route_ip_access_list) => {
+                    IP_ACCESS_CONTROL.load().check_route(route_ip_access_list, client_ip)
+                }
+                None => Ok(()),
+            };
+
+            if let Err(forbidden_page) = ip_access_result {
+                let response = CommonHandler::forbidden_error(
+                    Protocol::HTTPS,
+                    &request_host,
+                    req,
+                    client_ip,
+                    forbidden_page.as_deref(),
+                )
+                .await;
+
+                return with_route_label(response, route_source, access_log_sample_rate);
+            }
+
+            // Client certificate of the connection is checked against mTLS settings of matched host entry
+            if let Some(client_auth) = route_snapshot.find_client_auth(route_type.clone(), route_source) {
+                if let Err(reason) = check_client_auth(client_auth, route_source, tls_connection_info) {
+                    log_debug!("HTTPS client certificate of {:?} is rejected: {}", client_ip, reason);
+
+                    let response =
+                        CommonHandler::forbidden_error(Protocol::HTTPS, &request_host, req, client_ip, None).await;
+
+                    return with_route_label(response, route_source, access_log_sample_rate);
+                }
+            }
+
+            // Rewrite rules run before path rules, so a rewritten path selects path rule & target
+            match route_snapshot.apply_rewrite_rules(route_type.clone(), route_source, &req, client_ip) {
+                RewriteResult::Forward(Some(path_and_query)) => {
+                    log_debug!("HTTPS outband request {:?} is rewritten to {:?}", req.uri(), &path_and_query);
+                    req = rewrite_request_target(req, &path_and_query);
+                }
+                RewriteResult::Forward(None) => {}
+                RewriteResult::Respond(rewrite_response) => {
+                    let route_header_rules = RouteHeaderRules::new(
+                        route_source,
+                        route_snapshot.find_header_rules(route_type.clone(), route_source),
+                    );
+
+                    let response = CommonHandler::rewrite_response(
+                        Protocol::HTTPS,
+                        &request_host,
+                        req,
+                        client_ip,
+                        rewrite_response,
+                        &route_header_rules,
+                    )
+                    .await;
+
+                    return with_route_label(response, route_source, access_log_sample_rate);
+                }
+            }
+        }
+
         /* Forwarding HTTPS requests */
         log_debug!("Looking for Https route table:");
 
-        if self.https_routes.contains_key(&request_host) {
-            return self
-                .handle_https_route(&request_host, req, client_ip, traffic_policy)
+        if let Some((RouteType::Https, route_source)) = &matched_route {
+            // Client certificate headers are only set by Vanguard, values sent by clients are never forwarded
+            apply_client_identity_headers(
+                req.headers_mut(),
+                route_snapshot.find_client_auth(RouteType::Https, route_source),
+                tls_connection_info.client_identity.as_ref(),
+            );
+
+            let response = self
+                .handle_https_route(&route_snapshot, route_source, &request_host, req, client_ip, traffic_policy)
                 .await;
+
+            return with_route_label(response, route_source, access_log_sample_rate);
         }
 
         /* Processing IWS requests */
         log_debug!("Looking for Secure IWS route table:");
 
-        if self.secure_iws_routes.contains_key(&request_host) {
-            return self
-                .handle_secure_iws_route(&request_host, req, client_ip, traffic_policy)
+        if let Some((RouteType::SecureIws, route_source)) = &matched_route {
+            let response = self
+                .handle_secure_iws_route(&route_snapshot, route_source, &request_host, req, client_ip, traffic_policy)
                 .await;
+
+            return with_route_label(response, route_source, access_log_sample_rate);
         }
 
         /* Handle not found */
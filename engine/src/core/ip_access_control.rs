@@ -0,0 +1,261 @@
+use std::fs;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use crate::{
+    boot::Boot,
+    common::enums::ip_access_scope::IpAccessScope,
+    core::shared_memory::{IP_ACCESS_CONTROL, RUNTIME_BOOT_INFO},
+    log_error,
+    models::{config::Config, settings::ip_access_settings::IpAccessSettings},
+};
+
+/// IPv4 or IPv6 network in CIDR notation. Plain addresses are single host networks
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpCidr {
+    network: IpAddr,
+    prefix_length: u8,
+}
+
+impl IpCidr {
+    pub fn parse(definition: &str) -> Result<IpCidr, String> {
+        let definition = definition.trim();
+        let invalid_error = || format!("Invalid ip address or CIDR block '{}'", definition);
+
+        let (address, prefix_length) = match definition.split_once('/') {
+            Some((address, prefix_length)) => (
+                address,
+                Some(prefix_length.parse::<u8>().map_err(|_| invalid_error())?),
+            ),
+            None => (definition, None),
+        };
+
+        let address: IpAddr = address.parse().map_err(|_| invalid_error())?;
+        let max_prefix_length = match address {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+
+        let prefix_length = prefix_length.unwrap_or(max_prefix_length);
+        if prefix_length > max_prefix_length {
+            return Err(invalid_error());
+        }
+
+        Ok(IpCidr {
+            network: mask_address(address, prefix_length),
+            prefix_length,
+        })
+    }
+
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let is_same_family = matches!(
+            (self.network, ip),
+            (IpAddr::V4(_), IpAddr::V4(_)) | (IpAddr::V6(_), IpAddr::V6(_))
+        );
+
+        is_same_family && mask_address(ip, self.prefix_length) == self.network
+    }
+
+    /// Canonical text of the network. Host bits are cleared and single hosts are written without prefix
+    pub fn to_canonical_string(&self) -> String {
+        let max_prefix_length = match self.network {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+
+        if self.prefix_length == max_prefix_length {
+            return self.network.to_string();
+        }
+
+        format!("{}/{}", self.network, self.prefix_length)
+    }
+}
+
+/// Validates an allow/deny list entry and returns its canonical form
+pub fn normalize_ip_entry(definition: &str) -> Result<String, String> {
+    IpCidr::parse(definition).map(|ip_cidr| ip_cidr.to_canonical_string())
+}
+
+fn mask_address(address: IpAddr, prefix_length: u8) -> IpAddr {
+    match address {
+        IpAddr::V4(address) => {
+            let mask = u32::MAX.checked_shl(32 - prefix_length as u32).unwrap_or(0);
+            IpAddr::from((u32::from(address) & mask).to_be_bytes())
+        }
+        IpAddr::V6(address) => {
+            let mask = u128::MAX.checked_shl(128 - prefix_length as u32).unwrap_or(0);
+            IpAddr::from((u128::from(address) & mask).to_be_bytes())
+        }
+    }
+}
+
+/// Compiled form of `IpAccessSettings`
+#[derive(Debug, Clone, Default)]
+pub struct IpAccessList {
+    allow: Vec<IpCidr>,
+    deny: Vec<IpCidr>,
+    forbidden_page: Option<String>,
+}
+
+impl IpAccessList {
+    /// Compiles given settings. Invalid entries are skipped and returned as errors
+    pub fn build(ip_access_settings: &IpAccessSettings) -> (Self, Vec<String>) {
+        let mut errors = Vec::new();
+
+        let mut parse_entries = |entries: &[String]| -> Vec<IpCidr> {
+            entries
+                .iter()
+                .filter_map(|entry| IpCidr::parse(entry).map_err(|error| errors.push(error)).ok())
+                .collect()
+        };
+
+        let allow = parse_entries(&ip_access_settings.allow);
+        let deny = parse_entries(&ip_access_settings.deny);
+
+        // Custom page is read once here, so rejected requests never touch the disk
+        let forbidden_page = ip_access_settings
+            .forbidden_page_path
+            .as_ref()
+            .and_then(|forbidden_page_path| match fs::read_to_string(forbidden_page_path) {
+                Ok(content) => Some(content),
+                Err(error) => {
+                    errors.push(format!(
+                        "Forbidden page '{}' can not be read: {}",
+                        forbidden_page_path, error
+                    ));
+                    None
+                }
+            });
+
+        (
+            Self {
+                allow,
+                deny,
+                forbidden_page,
+            },
+            errors,
+        )
+    }
+
+    /// Returns whether given client ip passes allow & deny lists
+    pub fn is_allowed(&self, client_ip: IpAddr) -> bool {
+        // Dual stack listeners report IPv4 clients as IPv4-mapped IPv6 addresses
+        let client_ip = client_ip.to_canonical();
+
+        if self.deny.iter().any(|ip_cidr| ip_cidr.contains(client_ip)) {
+            return false;
+        }
+
+        self.allow.is_empty() || self.allow.iter().any(|ip_cidr| ip_cidr.contains(client_ip))
+    }
+
+    /// Returns forbidden page of the list (if configured) as error when client ip is rejected
+    pub fn check(&self, client_ip: IpAddr) -> Result<(), Option<String>> {
+        if self.is_allowed(client_ip) {
+            Ok(())
+        } else {
+            Err(self.forbidden_page.clone())
+        }
+    }
+}
+
+/// Global and per server ip access lists. Rebuilt and swapped whenever engine config changes
+#[derive(Debug, Clone, Default)]
+pub struct IpAccessControl {
+    pub global: IpAccessList,
+    pub http_server: IpAccessList,
+    pub https_server: IpAccessList,
+}
+
+impl IpAccessControl {
+    pub fn build(config: &Config) -> Self {
+        let default_settings = IpAccessSettings::default();
+
+        Self {
+            global: build_ip_access_list("global", &config.global_traffic_policy.ip_access),
+            http_server: build_ip_access_list(
+                "HTTP server",
+                config.http_server.ip_access.as_ref().unwrap_or(&default_settings),
+            ),
+            https_server: build_ip_access_list(
+                "HTTPS server",
+                config.https_server.ip_access.as_ref().unwrap_or(&default_settings),
+            ),
+        }
+    }
+
+    pub fn check_http_server(&self, client_ip: IpAddr) -> Result<(), Option<String>> {
+        self.check_server(&self.http_server, client_ip)
+    }
+
+    pub fn check_https_server(&self, client_ip: IpAddr) -> Result<(), Option<String>> {
+        self.check_server(&self.https_server, client_ip)
+    }
+
+    /// Checks a route list. Global forbidden page is used if the route does not define its own
+    pub fn check_route(&self, route_list: &IpAccessList, client_ip: IpAddr) -> Result<(), Option<String>> {
+        route_list
+            .check(client_ip)
+            .map_err(|forbidden_page| forbidden_page.or_else(|| self.global.forbidden_page.clone()))
+    }
+
+    fn check_server(&self, server_list: &IpAccessList, client_ip: IpAddr) -> Result<(), Option<String>> {
+        self.global.check(client_ip)?;
+        self.check_route(server_list, client_ip)
+    }
+}
+
+/// Compiles ip access settings of given scope, logging entries which are skipped
+pub fn build_ip_access_list(
+    scope_name: &str,
+    ip_access_settings: &IpAccessSettings,
+) -> IpAccessList {
+    let (ip_access_list, errors) = IpAccessList::build(ip_access_settings);
+
+    for error_message in errors {
+        log_error!("IP access entry of {} is skipped: {}", scope_name, error_message);
+    }
+
+    ip_access_list
+}
+
+/// Applies a change to ip access settings of a config level (global or server).
+/// Settings file is saved and compiled lists are republished only if the change modified the settings
+pub fn update_config_ip_access(
+    scope: &IpAccessScope,
+    update: impl FnOnce(&mut IpAccessSettings) -> bool,
+) -> Result<bool, String> {
+    let mut runtime_info = RUNTIME_BOOT_INFO.write().unwrap();
+    let config_path = runtime_info.config_path.clone();
+    let config = &mut runtime_info.config;
+
+    let ip_access_settings = config
+        .get_ip_access_mut(scope)
+        .ok_or_else(|| "Route level ip access lists are not part of engine settings".to_string())?;
+
+    let is_updated = update(ip_access_settings);
+
+    // Empty server lists are not kept in settings file
+    for server_ip_access in [
+        &mut config.http_server.ip_access,
+        &mut config.https_server.ip_access,
+    ] {
+        if server_ip_access.as_ref().is_some_and(|ip_access| ip_access.is_empty()) {
+            *server_ip_access = None;
+        }
+    }
+
+    if !is_updated {
+        return Ok(false);
+    }
+
+    if !Boot::save_config(config_path, config) {
+        return Err("Engine settings could not be saved".to_string());
+    }
+
+    let ip_access_control = IpAccessControl::build(config);
+    drop(runtime_info);
+
+    IP_ACCESS_CONTROL.store(Arc::new(ip_access_control));
+    Ok(true)
+}
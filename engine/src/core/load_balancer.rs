@@ -0,0 +1,343 @@
+use hyper::{header::COOKIE, Body, Request, Response};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    common::enums::load_balancing_strategy::LoadBalancingStrategy,
+    constants::Constants,
+    core::shared_memory::UPSTREAM_HEALTH,
+    models::{
+        route::upstream_pool::UpstreamPool,
+        settings::outlier_detection_settings::OutlierDetectionSettings,
+    },
+};
+
+/// Request attribute which is hashed by consistent hash strategy
+#[derive(Debug, Clone, PartialEq)]
+pub enum HashKey {
+    ClientIp,
+    Header(String),
+    Cookie(String),
+}
+
+impl HashKey {
+    /// Parses hash key definition: `ip`, `header:<name>` or `cookie:<name>`
+    pub fn parse(definition: &str) -> Result<HashKey, String> {
+        let definition = definition.trim();
+
+        if definition.eq_ignore_ascii_case("ip") {
+            return Ok(HashKey::ClientIp);
+        }
+
+        let parsed_definition = definition
+            .split_once(':')
+            .map(|(kind, name)| (kind.trim().to_lowercase(), name.trim().to_string()));
+
+        match parsed_definition {
+            Some((kind, name)) if kind == "header" && !name.is_empty() => Ok(HashKey::Header(name)),
+            Some((kind, name)) if kind == "cookie" && !name.is_empty() => Ok(HashKey::Cookie(name)),
+            _ => Err(format!(
+                "Invalid hash key '{}'. Expected 'ip', 'header:<name>' or 'cookie:<name>'",
+                definition
+            )),
+        }
+    }
+
+    /// Extracts hash input from request. Falls back to client ip if header or cookie is missing
+    fn extract(&self, req: &Request<Body>, client_ip: IpAddr) -> String {
+        let extracted_value = match self {
+            HashKey::ClientIp => None,
+            HashKey::Header(header_name) => req
+                .headers()
+                .get(header_name.as_str())
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.to_string()),
+            HashKey::Cookie(cookie_name) => find_cookie(req, cookie_name),
+        };
+
+        extracted_value.unwrap_or_else(|| client_ip.to_string())
+    }
+}
+
+struct UpstreamState {
+    target: String,
+    weight: u32,
+    active_connections: Arc<AtomicUsize>,
+}
+
+/// Upstream selected for a single request.
+/// Active connection count of the upstream is released when the lease is dropped.
+pub struct UpstreamLease {
+    pub target: String,
+    active_connections: Arc<AtomicUsize>,
+    outlier_detection: Option<OutlierDetectionSettings>,
+}
+
+impl UpstreamLease {
+    /// Feeds passive outlier detection with result of the proxied request.
+    /// Connection errors and 5xx responses (including upstream timeouts) count as failures
+    pub fn record_outcome(&self, response: &Result<Response<Body>, hyper::Error>) {
+        let outlier_detection = match &self.outlier_detection {
+            Some(outlier_detection) => outlier_detection,
+            None => return,
+        };
+
+        let is_failure = match response {
+            Ok(response) => response.status().is_server_error(),
+            Err(_) => true,
+        };
+
+        UPSTREAM_HEALTH.record_passive(&self.target, is_failure, outlier_detection);
+    }
+}
+
+impl Drop for UpstreamLease {
+    fn drop(&mut self) {
+        self.active_connections.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Distributes requests of a route between the upstreams of its pool
+pub struct LoadBalancer {
+    strategy: LoadBalancingStrategy,
+    hash_key: HashKey,
+    upstreams: Vec<UpstreamState>,
+    round_robin_cursor: AtomicUsize,
+    current_weights: Mutex<Vec<i64>>,
+    hash_ring: Vec<(u64, usize)>,
+    outlier_detection: Option<OutlierDetectionSettings>,
+}
+
+impl LoadBalancer {
+    pub fn build(upstream_pool: &UpstreamPool) -> Result<Self, String> {
+        if upstream_pool.upstreams.is_empty() {
+            return Err("Upstream pool should contain at least one upstream".to_string());
+        }
+
+        let hash_key = match &upstream_pool.hash_key {
+            Some(definition) => HashKey::parse(definition)?,
+            None => HashKey::ClientIp,
+        };
+
+        if let Some(health_check) = &upstream_pool.health_check {
+            if !health_check.get_path().starts_with('/') {
+                return Err(format!(
+                    "Health check path '{}' should start with '/'",
+                    health_check.get_path()
+                ));
+            }
+        }
+
+        let mut upstreams = Vec::new();
+        for upstream in &upstream_pool.upstreams {
+            if upstream.target.trim().is_empty() {
+                return Err("Upstream target can not be empty".to_string());
+            }
+
+            if upstream.weight == 0 {
+                return Err(format!(
+                    "Weight of upstream '{}' should be greater than zero",
+                    upstream.target
+                ));
+            }
+
+            if upstream.weight > Constants::MAX_UPSTREAM_WEIGHT {
+                return Err(format!(
+                    "Weight of upstream '{}' can not be greater than {}",
+                    upstream.target,
+                    Constants::MAX_UPSTREAM_WEIGHT
+                ));
+            }
+
+            upstreams.push(UpstreamState {
+                target: upstream.target.clone(),
+                weight: upstream.weight,
+                active_connections: Arc::new(AtomicUsize::new(0)),
+            });
+        }
+
+        let hash_ring = match upstream_pool.strategy {
+            LoadBalancingStrategy::ConsistentHash => build_hash_ring(&upstreams),
+            _ => Vec::new(),
+        };
+
+        Ok(Self {
+            strategy: upstream_pool.strategy.clone(),
+            hash_key,
+            current_weights: Mutex::new(vec![0; upstreams.len()]),
+            upstreams,
+            round_robin_cursor: AtomicUsize::new(0),
+            hash_ring,
+            outlier_detection: upstream_pool.outlier_detection.clone(),
+        })
+    }
+
+    /// Selects an upstream for given request by the strategy of the pool.
+    /// Unhealthy and ejected upstreams are skipped. If none of them is available, all upstreams are used
+    /// since failing open is better than rejecting every request of the route
+    pub fn select(&self, req: &Request<Body>, client_ip: IpAddr) -> UpstreamLease {
+        let mut candidates: Vec<usize> = (0..self.upstreams.len())
+            .filter(|index| UPSTREAM_HEALTH.is_available(&self.upstreams[*index].target))
+            .collect();
+
+        if candidates.is_empty() {
+            candidates = (0..self.upstreams.len()).collect();
+        }
+
+        let upstream_index = match self.strategy {
+            LoadBalancingStrategy::RoundRobin => self.select_round_robin(&candidates),
+            LoadBalancingStrategy::WeightedRoundRobin => {
+                self.select_weighted_round_robin(&candidates)
+            }
+            LoadBalancingStrategy::LeastConnections => self.select_least_connections(&candidates),
+            LoadBalancingStrategy::RandomTwoChoices => self.select_random_two_choices(&candidates),
+            LoadBalancingStrategy::ConsistentHash => {
+                let hash_input = self.hash_key.extract(req, client_ip);
+                self.select_consistent_hash(&hash_input, &candidates)
+            }
+        };
+
+        let upstream = &self.upstreams[upstream_index];
+        upstream.active_connections.fetch_add(1, Ordering::Relaxed);
+
+        UpstreamLease {
+            target: upstream.target.clone(),
+            active_connections: Arc::clone(&upstream.active_connections),
+            outlier_detection: self.outlier_detection.clone(),
+        }
+    }
+
+    fn select_round_robin(&self, candidates: &[usize]) -> usize {
+        candidates[self.round_robin_cursor.fetch_add(1, Ordering::Relaxed) % candidates.len()]
+    }
+
+    /// Smooth weighted round robin: spreads picks of heavy upstreams instead of sending them in bursts
+    fn select_weighted_round_robin(&self, candidates: &[usize]) -> usize {
+        let mut current_weights = self.current_weights.lock().unwrap();
+        let total_weight: i64 = candidates
+            .iter()
+            .map(|index| self.upstreams[*index].weight as i64)
+            .sum();
+
+        let mut selected_index = candidates[0];
+        for index in candidates {
+            current_weights[*index] += self.upstreams[*index].weight as i64;
+
+            if current_weights[*index] > current_weights[selected_index] {
+                selected_index = *index;
+            }
+        }
+
+        current_weights[selected_index] -= total_weight;
+        selected_index
+    }
+
+    /// Picks upstream with the lowest active connection per weight ratio.
+    /// Search starts from a rotating offset so that ties are distributed evenly
+    fn select_least_connections(&self, candidates: &[usize]) -> usize {
+        let candidate_count = candidates.len();
+        let offset = self.round_robin_cursor.fetch_add(1, Ordering::Relaxed);
+
+        let mut selected_index = candidates[offset % candidate_count];
+        for step in 1..candidate_count {
+            let index = candidates[(offset + step) % candidate_count];
+
+            if self.is_less_loaded(index, selected_index) {
+                selected_index = index;
+            }
+        }
+
+        selected_index
+    }
+
+    /// Power of two choices: compares two random upstreams and picks the less loaded one
+    fn select_random_two_choices(&self, candidates: &[usize]) -> usize {
+        let candidate_count = candidates.len();
+        if candidate_count == 1 {
+            return candidates[0];
+        }
+
+        let mut random = rand::thread_rng();
+        let first_position = random.gen_range(0..candidate_count);
+        let second_position =
+            (first_position + random.gen_range(1..candidate_count)) % candidate_count;
+
+        let (first_index, second_index) = (candidates[first_position], candidates[second_position]);
+
+        if self.is_less_loaded(second_index, first_index) {
+            second_index
+        } else {
+            first_index
+        }
+    }
+
+    /// Walks the ring clockwise from the hash of the input until an available upstream is found,
+    /// so keys of an unavailable upstream move to its neighbours while other keys stay in place
+    fn select_consistent_hash(&self, hash_input: &str, candidates: &[usize]) -> usize {
+        let hash = hash_of(hash_input);
+        let ring_position = self.hash_ring.partition_point(|(point, _)| *point < hash);
+        let ring_size = self.hash_ring.len();
+
+        (0..ring_size)
+            .map(|step| self.hash_ring[(ring_position + step) % ring_size].1)
+            .find(|upstream_index| candidates.contains(upstream_index))
+            .unwrap_or(candidates[0])
+    }
+
+    fn is_less_loaded(&self, index: usize, other_index: usize) -> bool {
+        let upstream = &self.upstreams[index];
+        let other_upstream = &self.upstreams[other_index];
+
+        let load = upstream.active_connections.load(Ordering::Relaxed) as u64 * other_upstream.weight as u64;
+        let other_load =
+            other_upstream.active_connections.load(Ordering::Relaxed) as u64 * upstream.weight as u64;
+
+        load < other_load
+    }
+}
+
+/// Validates that given upstream pool is well formed
+pub fn validate_upstream_pool(upstream_pool: &UpstreamPool) -> Result<(), String> {
+    LoadBalancer::build(upstream_pool).map(|_| ())
+}
+
+/// Each upstream is placed on the ring with virtual nodes proportional to its weight,
+/// so removing an upstream only remaps the keys that were assigned to it
+fn build_hash_ring(upstreams: &[UpstreamState]) -> Vec<(u64, usize)> {
+    let mut hash_ring = Vec::new();
+
+    for (index, upstream) in upstreams.iter().enumerate() {
+        let virtual_node_count = Constants::DEFAULT_CONSISTENT_HASH_VIRTUAL_NODES.saturating_mul(upstream.weight);
+
+        for virtual_node in 0..virtual_node_count {
+            let point = hash_of(&format!("{}#{}", upstream.target, virtual_node));
+            hash_ring.push((point, index));
+        }
+    }
+
+    hash_ring.sort();
+    hash_ring
+}
+
+fn hash_of(input: &str) -> u64 {
+    let digest = Sha256::digest(input.as_bytes());
+
+    let mut hash_bytes = [0u8; 8];
+    hash_bytes.copy_from_slice(&digest[..8]);
+
+    u64::from_be_bytes(hash_bytes)
+}
+
+fn find_cookie(req: &Request<Body>, cookie_name: &str) -> Option<String> {
+    req.headers()
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|header_value| header_value.to_str().ok())
+        .flat_map(|cookie_header| cookie_header.split(';'))
+        .filter_map(|cookie| cookie.trim().split_once('='))
+        .find(|(name, _)| *name == cookie_name)
+        .map(|(_, value)| value.to_string())
+}
@@ -0,0 +1,124 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use crate::{
+    constants::Constants,
+    models::log_record::{LogFilter, LogRecord},
+    utils::time_utility::get_current_timestamp,
+};
+
+/// Records returned by a query, with the cursor which a subscriber continues from
+pub struct LogQueryResult {
+    pub records: Vec<LogRecord>,
+    pub cursor: u64,
+    pub dropped_count: u64,
+}
+
+struct LogBufferState {
+    records: VecDeque<LogRecord>,
+    last_id: u64,
+}
+
+/// In-memory ring buffer of the most recent log records.
+/// Every record gets an increasing id, so clients can follow the buffer by sending the last id they have seen
+pub struct LogBuffer {
+    state: Mutex<LogBufferState>,
+    capacity: usize,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(LogBufferState {
+                records: VecDeque::with_capacity(Constants::LOG_BUFFER_CAPACITY),
+                last_id: 0,
+            }),
+            capacity: Constants::LOG_BUFFER_CAPACITY,
+        }
+    }
+}
+
+impl LogBuffer {
+    /// Appends a record, dropping the oldest one when buffer is full
+    pub fn push(&self, level: &str, message: &str) {
+        let mut state = self.state.lock().unwrap();
+
+        if state.records.len() >= self.capacity {
+            state.records.pop_front();
+        }
+
+        state.last_id += 1;
+        let record = LogRecord {
+            id: state.last_id,
+            timestamp: get_current_timestamp(),
+            level: level.to_string(),
+            message: message.to_string(),
+        };
+        state.records.push_back(record);
+    }
+
+    /// Returns the newest `limit` records matching the filter, in chronological order
+    pub fn query(&self, filter: &LogFilter, limit: usize) -> LogQueryResult {
+        let state = self.state.lock().unwrap();
+
+        let mut records: Vec<LogRecord> = state
+            .records
+            .iter()
+            .rev()
+            .filter(|record| filter.matches(record))
+            .take(limit)
+            .cloned()
+            .collect();
+        records.reverse();
+
+        LogQueryResult {
+            records,
+            cursor: state.last_id,
+            dropped_count: 0,
+        }
+    }
+
+    /// Returns records written after `cursor` which match the filter, oldest first.
+    /// If more than `limit` records are waiting, returned cursor points to the last delivered one,
+    /// so the rest is delivered by the next call. Without a cursor, subscription starts from the newest record
+    pub fn read_after(&self, cursor: Option<u64>, filter: &LogFilter, limit: usize) -> LogQueryResult {
+        let state = self.state.lock().unwrap();
+
+        let cursor = match cursor {
+            Some(cursor) => cursor.min(state.last_id),
+            None => {
+                return LogQueryResult {
+                    records: Vec::new(),
+                    cursor: state.last_id,
+                    dropped_count: 0,
+                }
+            }
+        };
+
+        // Records between the cursor and the oldest buffered one were overwritten before subscriber read them
+        let oldest_id = state.records.front().map(|record| record.id).unwrap_or(state.last_id + 1);
+        let dropped_count = oldest_id.saturating_sub(cursor + 1);
+
+        let mut next_cursor = state.last_id;
+        let mut records = Vec::new();
+
+        for record in state.records.iter().filter(|record| record.id > cursor) {
+            if !filter.matches(record) {
+                continue;
+            }
+
+            if records.len() >= limit {
+                next_cursor = records.last().map(|record: &LogRecord| record.id).unwrap_or(cursor);
+                break;
+            }
+
+            records.push(record.clone());
+        }
+
+        LogQueryResult {
+            records,
+            cursor: next_cursor,
+            dropped_count,
+        }
+    }
+}
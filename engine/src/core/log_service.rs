@@ -1,71 +1,191 @@
-use flexi_logger::{Age, Cleanup, Criterion, FileSpec, Logger as FlexiLogger, Naming};
-use log::{debug, error, info, warn};
-use std::fmt::Debug;
-use std::path::PathBuf;
+use flexi_logger::writers::FileLogWriter;
+use flexi_logger::{
+    Age, Cleanup, Criterion, DeferredNow, FileSpec, FlexiLoggerError, Logger as FlexiLogger,
+    LoggerHandle, Naming,
+};
+use log::{debug, error, info, warn, Record};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
+use crate::boot::Boot;
 use crate::constants::Constants;
-use crate::models::settings::logger_settings::LoggerSettings;
+use crate::core::shared_memory::{LOGGER, LOG_BUFFER, RUNTIME_BOOT_INFO};
+use crate::models::settings::{
+    access_log_settings::AccessLogSettings, logger_settings::LoggerSettings,
+};
 
 // Global Logger Instance: Initially empty default config, updated in Runtime init
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 #[derive(Default)]
 pub struct LogService {
     pub settings: LoggerSettings,
+    /// Whether access log writer is attached, access log lines are only written when it is
+    pub is_access_log_attached: bool,
+    log_dir_path: PathBuf,
+    handle: Option<LoggerHandle>,
 }
 
 
 impl LogService {
-    pub fn init(runtime_path: &PathBuf, logger_settings: LoggerSettings) -> Self {
+    pub fn init(
+        runtime_path: &PathBuf,
+        logger_settings: LoggerSettings,
+        access_log_settings: &AccessLogSettings,
+    ) -> Self {
         let log_dir_path = runtime_path.join(&logger_settings.log_dir_name);
 
-        FlexiLogger::try_with_str(Constants::LOG_LEVEL)
+        // Access log has its own file & rotation, lines are routed to it by `Constants::ACCESS_LOG_TARGET`
+        let access_log_writer = match access_log_settings.is_active {
+            true => Some(Self::create_access_log_writer(&log_dir_path, access_log_settings)),
+            false => None,
+        };
+
+        let mut flexi_logger = FlexiLogger::try_with_str(Constants::LOG_LEVEL)
             .unwrap()
-            .log_to_file(
-                FileSpec::default()
-                    .directory(log_dir_path)
-                    .basename(Constants::LOG_FILE_BASE_NAME)
-                    .suffix(Constants::LOG_SUFFIX),
-            )
-            .format(|write, now, record| {
-                write!(
-                    write,
-                    "[{}] {:<5}: {}",
-                    now.format(Constants::LOG_TIMESTAMP_FORMAT),
-                    record.level(),
-                    record.args()
-                )
-            })
+            .log_to_file(Self::get_log_file_spec(&log_dir_path))
+            .format(format_log_line)
             .rotate(
                 Criterion::AgeOrSize(Age::Day, logger_settings.log_file_size),
                 Naming::Timestamps,
                 Cleanup::KeepLogFiles(logger_settings.keep_last_logs),
-            )
-            .start()
-            .unwrap();
+            );
+
+        let mut is_access_log_attached = false;
+        let mut access_log_error = None;
+        match access_log_writer {
+            Some(Ok(access_log_writer)) => {
+                flexi_logger =
+                    flexi_logger.add_writer(Constants::ACCESS_LOG_WRITER_NAME, Box::new(access_log_writer));
+                is_access_log_attached = true;
+            }
+            Some(Err(error)) => access_log_error = Some(error),
+            None => {}
+        }
+
+        let handle = flexi_logger.start().unwrap();
+
+        if let Some(error) = access_log_error {
+            error!("Access log file can not be created: {}", error);
+        }
+
         Self {
             settings: logger_settings,
+            is_access_log_attached,
+            log_dir_path,
+            handle: Some(handle),
+        }
+    }
+
+    /// Applies new log levels & rotation of engine log file to the running logger.
+    /// Log directory is only read at start, so it is kept as is
+    pub fn reconfigure(&mut self, logger_settings: LoggerSettings) -> Result<(), String> {
+        let is_rotation_changed = self.settings.log_file_size != logger_settings.log_file_size
+            || self.settings.keep_last_logs != logger_settings.keep_last_logs;
+
+        if is_rotation_changed {
+            let handle = self
+                .handle
+                .as_ref()
+                .ok_or_else(|| "Logger is not started yet".to_string())?;
+
+            let file_log_writer_builder = FileLogWriter::builder(Self::get_log_file_spec(&self.log_dir_path))
+                .format(format_log_line)
+                .rotate(
+                    Criterion::AgeOrSize(Age::Day, logger_settings.log_file_size),
+                    Naming::Timestamps,
+                    Cleanup::KeepLogFiles(logger_settings.keep_last_logs),
+                );
+
+            handle
+                .reset_flw(&file_log_writer_builder)
+                .map_err(|error| format!("Log file rotation can not be changed: {}", error))?;
         }
+
+        self.settings = LoggerSettings {
+            log_dir_name: self.settings.log_dir_name.clone(),
+            ..logger_settings
+        };
+        Ok(())
+    }
+
+    fn get_log_file_spec(log_dir_path: &Path) -> FileSpec {
+        FileSpec::default()
+            .directory(log_dir_path)
+            .basename(Constants::LOG_FILE_BASE_NAME)
+            .suffix(Constants::LOG_SUFFIX)
+    }
+
+    fn create_access_log_writer(
+        log_dir_path: &Path,
+        access_log_settings: &AccessLogSettings,
+    ) -> Result<FileLogWriter, FlexiLoggerError> {
+        FileLogWriter::builder(
+            FileSpec::default()
+                .directory(log_dir_path)
+                .basename(access_log_settings.get_file_name())
+                .suffix(Constants::LOG_SUFFIX),
+        )
+        // Access log lines are already formatted by `AccessLogger`
+        .format(|write, _now, record| write!(write, "{}", record.args()))
+        .rotate(
+            Criterion::AgeOrSize(Age::Day, access_log_settings.get_log_file_size()),
+            Naming::Timestamps,
+            Cleanup::KeepLogFiles(access_log_settings.get_keep_last_logs()),
+        )
+        .try_build()
     }
 
     pub fn info<T: AsRef<str>>(&self, msg: T) {
         if self.settings.log_levels.contains(&"INFO".to_string()) {
             info!("{}", msg.as_ref());
+            LOG_BUFFER.push("INFO", msg.as_ref());
         }
     }
     pub fn warn<T: AsRef<str>>(&self, msg: T) {
         if self.settings.log_levels.contains(&"WARNING".to_string()) {
             warn!("{}", msg.as_ref());
+            LOG_BUFFER.push("WARNING", msg.as_ref());
         }
     }
     pub fn error<T: AsRef<str>>(&self, msg: T) {
         if self.settings.log_levels.contains(&"ERROR".to_string()) {
             error!("{}", msg.as_ref());
+            LOG_BUFFER.push("ERROR", msg.as_ref());
         }
     }
     pub fn debug<T: AsRef<str>>(&self, msg: T) {
         if self.settings.log_levels.contains(&"DEBUG".to_string()) {
             debug!("{}", msg.as_ref());
+            LOG_BUFFER.push("DEBUG", msg.as_ref());
         }
     }
 }
+
+fn format_log_line(write: &mut dyn Write, now: &mut DeferredNow, record: &Record) -> std::io::Result<()> {
+    write!(
+        write,
+        "[{}] {:<5}: {}",
+        now.format(Constants::LOG_TIMESTAMP_FORMAT),
+        record.level(),
+        record.args()
+    )
+}
+
+/// Applies logger settings to the running logger and saves them into settings file
+pub fn update_logger_settings(logger_settings: LoggerSettings) -> Result<LoggerSettings, String> {
+    let mut logger = LOGGER.write().unwrap();
+    logger.reconfigure(logger_settings)?;
+    let applied_settings = logger.settings.clone();
+    drop(logger);
+
+    let mut runtime_info = RUNTIME_BOOT_INFO.write().unwrap();
+    let config_path = runtime_info.config_path.clone();
+    runtime_info.config.logger = applied_settings.clone();
+
+    if !Boot::save_config(config_path, &runtime_info.config) {
+        return Err("Logger settings are applied but engine settings could not be saved".to_string());
+    }
+
+    Ok(applied_settings)
+}
@@ -0,0 +1,655 @@
+use hyper::{Body, Response};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::hash::Hash;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::time::Duration;
+
+use crate::{
+    constants::Constants,
+    core::shared_memory::CONNECTION_MANAGER,
+    models::metrics_snapshot::{
+        ClientMetrics, LatencyBucket, LatencySummary, MetricsSnapshot, RouteMetrics,
+    },
+};
+
+/// Route label of requests which did not match any route (rejected before routing or unknown host)
+pub const UNMATCHED_ROUTE_LABEL: &str = "none";
+
+/// Response extension carrying the matched route source, used as `route` label of request metrics.
+/// It also carries access log sample rate of the route, if route or its server overrides it
+#[derive(Debug, Clone)]
+pub struct RouteLabel {
+    pub route_source: String,
+    pub access_log_sample_rate: Option<f64>,
+}
+
+/// Attaches route label to a response produced for given route
+pub fn with_route_label(
+    response: Result<Response<Body>, hyper::Error>,
+    route_source: &str,
+    access_log_sample_rate: Option<f64>,
+) -> Result<Response<Body>, hyper::Error> {
+    response.map(|mut response| {
+        response.extensions_mut().insert(RouteLabel {
+            route_source: route_source.to_string(),
+            access_log_sample_rate,
+        });
+        response
+    })
+}
+
+/// Reason of a failed upstream request. Proxy generated error responses carry it as a response extension
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UpstreamErrorKind {
+    Connection,
+    Timeout,
+    TlsConfig,
+    ServerError,
+}
+
+impl UpstreamErrorKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UpstreamErrorKind::Connection => "connection",
+            UpstreamErrorKind::Timeout => "timeout",
+            UpstreamErrorKind::TlsConfig => "tls_config",
+            UpstreamErrorKind::ServerError => "server_error",
+        }
+    }
+
+    /// Classifies result of a proxied request. Returns None for successful requests
+    pub fn classify(response: &Result<Response<Body>, hyper::Error>) -> Option<Self> {
+        match response {
+            Err(_) => Some(UpstreamErrorKind::Connection),
+            Ok(response) => response
+                .extensions()
+                .get::<UpstreamErrorKind>()
+                .copied()
+                .or_else(|| {
+                    response
+                        .status()
+                        .is_server_error()
+                        .then_some(UpstreamErrorKind::ServerError)
+                }),
+        }
+    }
+}
+
+fn get_status_class(response: &Result<Response<Body>, hyper::Error>) -> &'static str {
+    let status = match response {
+        Ok(response) => response.status().as_u16(),
+        Err(_) => return "error",
+    };
+
+    match status {
+        100..=199 => "1xx",
+        200..=299 => "2xx",
+        300..=399 => "3xx",
+        400..=499 => "4xx",
+        _ => "5xx",
+    }
+}
+
+/// Counters grouped by label set. Existing label sets are incremented under read lock
+struct LabeledCounters<K> {
+    counters: RwLock<HashMap<K, AtomicU64>>,
+}
+
+impl<K: Hash + Eq + Clone> LabeledCounters<K> {
+    fn new() -> Self {
+        Self {
+            counters: RwLock::new(HashMap::new()),
+        }
+    }
+
+    fn increment(&self, labels: K) {
+        if let Some(counter) = self.counters.read().unwrap().get(&labels) {
+            counter.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+
+        self.counters
+            .write()
+            .unwrap()
+            .entry(labels)
+            .or_insert_with(|| AtomicU64::new(0))
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn collect(&self) -> Vec<(K, u64)> {
+        self.counters
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(labels, counter)| (labels.clone(), counter.load(Ordering::Relaxed)))
+            .collect()
+    }
+
+    fn sum(&self) -> u64 {
+        self.counters
+            .read()
+            .unwrap()
+            .values()
+            .map(|counter| counter.load(Ordering::Relaxed))
+            .sum()
+    }
+}
+
+/// Latency histogram with fixed buckets (`Constants::METRICS_LATENCY_BUCKETS`)
+pub struct Histogram {
+    buckets: Vec<AtomicU64>,
+    count: AtomicU64,
+    sum_micros: AtomicU64,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            buckets: Constants::METRICS_LATENCY_BUCKETS
+                .iter()
+                .map(|_| AtomicU64::new(0))
+                .collect(),
+            count: AtomicU64::new(0),
+            sum_micros: AtomicU64::new(0),
+        }
+    }
+}
+
+impl Histogram {
+    pub fn observe(&self, duration: Duration) {
+        let seconds = duration.as_secs_f64();
+
+        if let Some(bucket_index) = Constants::METRICS_LATENCY_BUCKETS
+            .iter()
+            .position(|upper_bound| seconds <= *upper_bound)
+        {
+            self.buckets[bucket_index].fetch_add(1, Ordering::Relaxed);
+        }
+
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_micros
+            .fetch_add(duration.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// Cumulative bucket counts, as exposed by Prometheus `_bucket` series
+    pub fn get_cumulative_buckets(&self) -> Vec<(f64, u64)> {
+        let mut cumulative_count = 0;
+
+        Constants::METRICS_LATENCY_BUCKETS
+            .iter()
+            .zip(&self.buckets)
+            .map(|(upper_bound, bucket)| {
+                cumulative_count += bucket.load(Ordering::Relaxed);
+                (*upper_bound, cumulative_count)
+            })
+            .collect()
+    }
+
+    pub fn get_count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    pub fn get_sum_seconds(&self) -> f64 {
+        self.sum_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0
+    }
+}
+
+/// Request counts of the most active client ips. Table size is bounded; when it is full the least active ip
+/// is replaced and the newcomer inherits its count (space-saving), so heavy hitters stay visible during ip floods
+struct ClientCounters {
+    counters: Mutex<HashMap<IpAddr, u64>>,
+}
+
+impl ClientCounters {
+    fn new() -> Self {
+        Self {
+            counters: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn increment(&self, client_ip: IpAddr) {
+        let client_ip = client_ip.to_canonical();
+        let mut counters = self.counters.lock().unwrap();
+
+        if let Some(counter) = counters.get_mut(&client_ip) {
+            *counter += 1;
+            return;
+        }
+
+        let mut initial_count = 1;
+        if counters.len() >= Constants::METRICS_MAX_TRACKED_CLIENTS {
+            let least_active_client = counters
+                .iter()
+                .min_by_key(|(_, count)| **count)
+                .map(|(ip, count)| (*ip, *count));
+
+            if let Some((least_active_ip, least_count)) = least_active_client {
+                counters.remove(&least_active_ip);
+                initial_count = least_count + 1;
+            }
+        }
+
+        counters.insert(client_ip, initial_count);
+    }
+
+    fn get_top(&self, limit: usize) -> Vec<(IpAddr, u64)> {
+        let mut clients: Vec<(IpAddr, u64)> = self
+            .counters
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(ip, count)| (*ip, *count))
+            .collect();
+
+        clients.sort_by(|first, second| second.1.cmp(&first.1).then(first.0.cmp(&second.0)));
+        clients.truncate(limit);
+        clients
+    }
+}
+
+/// Estimates a quantile (0.0 - 1.0) from cumulative buckets by linear interpolation inside the matching bucket.
+/// Values above the last bucket are reported as its upper bound
+fn estimate_quantile(cumulative_buckets: &[(f64, u64)], total_count: u64, quantile: f64) -> f64 {
+    if total_count == 0 {
+        return 0.0;
+    }
+
+    let rank = quantile * total_count as f64;
+    let mut lower_bound = 0.0;
+    let mut lower_count = 0;
+
+    for (upper_bound, cumulative_count) in cumulative_buckets {
+        if *cumulative_count as f64 >= rank {
+            let bucket_count = cumulative_count - lower_count;
+            if bucket_count == 0 {
+                return *upper_bound;
+            }
+
+            let position = (rank - lower_count as f64) / bucket_count as f64;
+            return lower_bound + (upper_bound - lower_bound) * position;
+        }
+
+        lower_bound = *upper_bound;
+        lower_count = *cumulative_count;
+    }
+
+    lower_bound
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct RequestLabels {
+    server: &'static str,
+    route: String,
+    status_class: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct UpstreamErrorLabels {
+    server: &'static str,
+    route: String,
+    upstream: String,
+    kind: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct RateLimitLabels {
+    server: &'static str,
+    scope: String,
+}
+
+/// Engine wide metrics. Labels are limited to server, route source, status class and upstream target,
+/// so series count is bound by the route table instead of client input
+pub struct MetricsRegistry {
+    requests: LabeledCounters<RequestLabels>,
+    request_durations: RwLock<HashMap<(&'static str, String), Histogram>>,
+    upstream_errors: LabeledCounters<UpstreamErrorLabels>,
+    rate_limit_rejections: LabeledCounters<RateLimitLabels>,
+    tls_handshake_failures: AtomicU64,
+    clients: ClientCounters,
+}
+
+impl Default for MetricsRegistry {
+    fn default() -> Self {
+        Self {
+            requests: LabeledCounters::new(),
+            request_durations: RwLock::new(HashMap::new()),
+            upstream_errors: LabeledCounters::new(),
+            rate_limit_rejections: LabeledCounters::new(),
+            tls_handshake_failures: AtomicU64::new(0),
+            clients: ClientCounters::new(),
+        }
+    }
+}
+
+impl MetricsRegistry {
+    /// Records a completed request. Route label is read from response extensions
+    pub fn record_request(
+        &self,
+        server: &'static str,
+        client_ip: IpAddr,
+        response: &Result<Response<Body>, hyper::Error>,
+        duration: Duration,
+    ) {
+        // Client ips are kept out of Prometheus labels, they are only reported by `get_metrics`
+        self.clients.increment(client_ip);
+
+        let route = response
+            .as_ref()
+            .ok()
+            .and_then(|response| response.extensions().get::<RouteLabel>())
+            .map(|route_label| route_label.route_source.clone())
+            .unwrap_or_else(|| UNMATCHED_ROUTE_LABEL.to_string());
+
+        self.requests.increment(RequestLabels {
+            server,
+            route: route.clone(),
+            status_class: get_status_class(response),
+        });
+
+        let duration_key = (server, route);
+        if let Some(histogram) = self.request_durations.read().unwrap().get(&duration_key) {
+            histogram.observe(duration);
+            return;
+        }
+
+        self.request_durations
+            .write()
+            .unwrap()
+            .entry(duration_key)
+            .or_default()
+            .observe(duration);
+    }
+
+    /// Records result of a proxied request if it failed
+    pub fn record_upstream_outcome(
+        &self,
+        server: &'static str,
+        route_source: &str,
+        upstream: &str,
+        response: &Result<Response<Body>, hyper::Error>,
+    ) {
+        if let Some(upstream_error_kind) = UpstreamErrorKind::classify(response) {
+            self.upstream_errors.increment(UpstreamErrorLabels {
+                server,
+                route: route_source.to_string(),
+                upstream: upstream.to_string(),
+                kind: upstream_error_kind.as_str(),
+            });
+        }
+    }
+
+    /// Records a request rejected by global (`global`) or route limit (route source)
+    pub fn record_rate_limit_rejection(&self, server: &'static str, scope: &str) {
+        self.rate_limit_rejections.increment(RateLimitLabels {
+            server,
+            scope: scope.to_string(),
+        });
+    }
+
+    pub fn record_tls_handshake_failure(&self) {
+        self.tls_handshake_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns cumulative totals, latency percentiles of all requests, per route counts and `top_limit` most active clients
+    pub fn get_snapshot(&self, top_limit: usize) -> MetricsSnapshot {
+        let mut status_classes: BTreeMap<String, u64> = BTreeMap::new();
+        let mut routes: BTreeMap<(&'static str, String), RouteMetrics> = BTreeMap::new();
+
+        for (labels, value) in self.requests.collect() {
+            *status_classes.entry(labels.status_class.to_string()).or_default() += value;
+
+            let route_metrics = routes
+                .entry((labels.server, labels.route.clone()))
+                .or_insert_with(|| RouteMetrics {
+                    server: labels.server.to_string(),
+                    route: labels.route.clone(),
+                    requests: 0,
+                    client_errors: 0,
+                    server_errors: 0,
+                });
+
+            route_metrics.requests += value;
+            match labels.status_class {
+                "4xx" => route_metrics.client_errors += value,
+                "5xx" | "error" => route_metrics.server_errors += value,
+                _ => {}
+            }
+        }
+
+        let mut routes: Vec<RouteMetrics> = routes.into_values().collect();
+        routes.sort_by_key(|route_metrics| Reverse(route_metrics.requests));
+        routes.truncate(top_limit);
+
+        let mut latency_buckets: Vec<(f64, u64)> = Constants::METRICS_LATENCY_BUCKETS
+            .iter()
+            .map(|upper_bound| (*upper_bound, 0))
+            .collect();
+        let mut latency_count = 0;
+
+        for histogram in self.request_durations.read().unwrap().values() {
+            for (index, (_, cumulative_count)) in histogram.get_cumulative_buckets().into_iter().enumerate() {
+                latency_buckets[index].1 += cumulative_count;
+            }
+            latency_count += histogram.get_count();
+        }
+
+        let latency = LatencySummary {
+            p50_ms: estimate_quantile(&latency_buckets, latency_count, 0.50) * 1000.0,
+            p95_ms: estimate_quantile(&latency_buckets, latency_count, 0.95) * 1000.0,
+            p99_ms: estimate_quantile(&latency_buckets, latency_count, 0.99) * 1000.0,
+            buckets: latency_buckets
+                .iter()
+                .map(|(upper_bound, count)| LatencyBucket {
+                    upper_bound_ms: upper_bound * 1000.0,
+                    count: *count,
+                })
+                .collect(),
+        };
+
+        let top_clients = self
+            .clients
+            .get_top(top_limit)
+            .into_iter()
+            .map(|(ip, requests)| ClientMetrics {
+                ip: ip.to_string(),
+                requests,
+            })
+            .collect();
+
+        MetricsSnapshot {
+            uptime_seconds: CONNECTION_MANAGER.get_uptime().as_secs(),
+            active_connections: CONNECTION_MANAGER.get_active_connections(),
+            active_upgraded_connections: CONNECTION_MANAGER.get_active_upgraded_connections(),
+            total_upgraded_connections: CONNECTION_MANAGER.get_total_upgraded_connections(),
+            total_requests: status_classes.values().sum(),
+            status_classes,
+            latency,
+            routes,
+            top_clients,
+            upstream_errors: self.upstream_errors.sum(),
+            rate_limit_rejections: self.rate_limit_rejections.sum(),
+            tls_handshake_failures: self.tls_handshake_failures.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Renders all metrics in Prometheus text exposition format (version 0.0.4)
+    pub fn render_prometheus(&self) -> String {
+        let mut output = String::new();
+
+        write_header(
+            &mut output,
+            "vanguard_requests_total",
+            "Total requests handled by HTTP & HTTPS servers",
+            "counter",
+        );
+        let mut requests = self.requests.collect();
+        requests.sort_by(|(first, _), (second, _)| {
+            (first.server, &first.route, first.status_class)
+                .cmp(&(second.server, &second.route, second.status_class))
+        });
+        for (labels, value) in requests {
+            let _ = writeln!(
+                output,
+                "vanguard_requests_total{{server=\"{}\",route=\"{}\",status_class=\"{}\"}} {}",
+                labels.server,
+                escape_label_value(&labels.route),
+                labels.status_class,
+                value
+            );
+        }
+
+        write_header(
+            &mut output,
+            "vanguard_request_duration_seconds",
+            "Request latency from request arrival to response headers",
+            "histogram",
+        );
+        let request_durations = self.request_durations.read().unwrap();
+        let mut duration_keys: Vec<&(&'static str, String)> = request_durations.keys().collect();
+        duration_keys.sort();
+        for duration_key in duration_keys {
+            let (server, route) = duration_key;
+            let histogram = &request_durations[duration_key];
+            let route = escape_label_value(route);
+
+            for (upper_bound, cumulative_count) in histogram.get_cumulative_buckets() {
+                let _ = writeln!(
+                    output,
+                    "vanguard_request_duration_seconds_bucket{{server=\"{}\",route=\"{}\",le=\"{}\"}} {}",
+                    server, route, upper_bound, cumulative_count
+                );
+            }
+            let _ = writeln!(
+                output,
+                "vanguard_request_duration_seconds_bucket{{server=\"{}\",route=\"{}\",le=\"+Inf\"}} {}",
+                server,
+                route,
+                histogram.get_count()
+            );
+            let _ = writeln!(
+                output,
+                "vanguard_request_duration_seconds_sum{{server=\"{}\",route=\"{}\"}} {}",
+                server,
+                route,
+                histogram.get_sum_seconds()
+            );
+            let _ = writeln!(
+                output,
+                "vanguard_request_duration_seconds_count{{server=\"{}\",route=\"{}\"}} {}",
+                server,
+                route,
+                histogram.get_count()
+            );
+        }
+        drop(request_durations);
+
+        write_header(
+            &mut output,
+            "vanguard_upstream_errors_total",
+            "Failed upstream requests by failure kind",
+            "counter",
+        );
+        let mut upstream_errors = self.upstream_errors.collect();
+        upstream_errors.sort_by(|(first, _), (second, _)| {
+            (first.server, &first.route, &first.upstream, first.kind)
+                .cmp(&(second.server, &second.route, &second.upstream, second.kind))
+        });
+        for (labels, value) in upstream_errors {
+            let _ = writeln!(
+                output,
+                "vanguard_upstream_errors_total{{server=\"{}\",route=\"{}\",upstream=\"{}\",kind=\"{}\"}} {}",
+                labels.server,
+                escape_label_value(&labels.route),
+                escape_label_value(&labels.upstream),
+                labels.kind,
+                value
+            );
+        }
+
+        write_header(
+            &mut output,
+            "vanguard_rate_limit_rejections_total",
+            "Requests rejected with 429 by global or route rate limits",
+            "counter",
+        );
+        let mut rate_limit_rejections = self.rate_limit_rejections.collect();
+        rate_limit_rejections.sort_by(|(first, _), (second, _)| {
+            (first.server, &first.scope).cmp(&(second.server, &second.scope))
+        });
+        for (labels, value) in rate_limit_rejections {
+            let _ = writeln!(
+                output,
+                "vanguard_rate_limit_rejections_total{{server=\"{}\",scope=\"{}\"}} {}",
+                labels.server,
+                escape_label_value(&labels.scope),
+                value
+            );
+        }
+
+        write_header(
+            &mut output,
+            "vanguard_tls_handshake_failures_total",
+            "Failed TLS handshakes of HTTPS server",
+            "counter",
+        );
+        let _ = writeln!(
+            output,
+            "vanguard_tls_handshake_failures_total {}",
+            self.tls_handshake_failures.load(Ordering::Relaxed)
+        );
+
+        write_header(
+            &mut output,
+            "vanguard_active_connections",
+            "Currently open client connections",
+            "gauge",
+        );
+        let _ = writeln!(
+            output,
+            "vanguard_active_connections {}",
+            CONNECTION_MANAGER.get_active_connections()
+        );
+
+        write_header(
+            &mut output,
+            "vanguard_active_upgraded_connections",
+            "Currently open upgraded (e.g. WebSocket) connections",
+            "gauge",
+        );
+        let _ = writeln!(
+            output,
+            "vanguard_active_upgraded_connections {}",
+            CONNECTION_MANAGER.get_active_upgraded_connections()
+        );
+
+        write_header(
+            &mut output,
+            "vanguard_upgraded_connections_total",
+            "Upgraded (e.g. WebSocket) connections since engine start",
+            "counter",
+        );
+        let _ = writeln!(
+            output,
+            "vanguard_upgraded_connections_total {}",
+            CONNECTION_MANAGER.get_total_upgraded_connections()
+        );
+
+        output
+    }
+}
+
+fn write_header(output: &mut String, name: &str, help: &str, metric_type: &str) {
+    let _ = writeln!(output, "# HELP {} {}", name, help);
+    let _ = writeln!(output, "# TYPE {} {}", name, metric_type);
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
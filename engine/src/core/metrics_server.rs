@@ -0,0 +1,92 @@
+use hyper::{
+    header,
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+
+use crate::{
+    constants::Constants,
+    core::shared_memory::{METRICS, SHUTDOWN_SIGNAL},
+    log_error, log_info,
+    models::metrics_server_config::MetricsServerConfig,
+    utils::network_utility::parse_ip_address,
+};
+
+/// Local listener for Prometheus scrapers. Serves only `GET /metrics`
+#[derive(Debug, Clone)]
+pub struct MetricsServer {
+    is_active: bool,
+    socket: SocketAddr,
+}
+
+impl MetricsServer {
+    pub fn init(metrics_server_config: &MetricsServerConfig) -> Self {
+        let ip = parse_ip_address(metrics_server_config.ip_address.clone());
+
+        Self {
+            is_active: metrics_server_config.is_active,
+            socket: SocketAddr::from((ip, metrics_server_config.port)),
+        }
+    }
+
+    /// Starts the metrics listener. Returns immediately if metrics server is disabled in engine settings
+    pub async fn start(&self) {
+        if !self.is_active {
+            log_info!("Vanguard Engine metrics server is disabled");
+            return;
+        }
+
+        let make_svc = make_service_fn(|_connection| async {
+            Ok::<_, Infallible>(service_fn(Self::handle_request))
+        });
+
+        let server = match Server::try_bind(&self.socket) {
+            Ok(server) => server,
+            Err(error) => {
+                log_error!(
+                    "Vanguard Engine metrics server can not bind to {:?}: {}",
+                    &self.socket,
+                    error
+                );
+                return;
+            }
+        };
+
+        log_info!(
+            "Vanguard Engine metrics server started on {:?}{}",
+            &self.socket,
+            Constants::METRICS_PATH
+        );
+
+        let mut shutdown_event = SHUTDOWN_SIGNAL.subscriber.clone();
+        let stop_signal = async move {
+            let _on_shutdown = shutdown_event.wait_for(|&s| s).await;
+        };
+
+        let execution_result = server
+            .serve(make_svc)
+            .with_graceful_shutdown(stop_signal)
+            .await;
+
+        if let Err(error) = execution_result {
+            log_error!("Vanguard Engine metrics server error {:?}", error);
+        }
+    }
+
+    async fn handle_request(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        if req.method() != Method::GET || req.uri().path() != Constants::METRICS_PATH {
+            return Ok(Response::builder()
+                .status(StatusCode::NOT_FOUND)
+                .body(Body::empty())
+                .unwrap());
+        }
+
+        Ok(Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")
+            .body(Body::from(METRICS.render_prometheus()))
+            .unwrap())
+    }
+}
@@ -6,6 +6,31 @@ pub mod common_handler;
 pub mod rpc_session;
 pub mod shared_memory;
 pub mod connection_manager;
+pub mod upgrade_tunnel;
+pub mod forwarded_headers;
+pub mod header_sanitizer;
+pub mod header_rules;
 pub mod http_proxy_manager;
 pub mod connection_lock;
 pub mod http_client;
+
+pub mod host_matcher;
+pub mod sni_resolver;
+pub mod path_matcher;
+pub mod rewrite_matcher;
+pub mod route_snapshot;
+pub mod load_balancer;
+pub mod upstream_health;
+pub mod health_checker;
+pub mod upstream_connector;
+pub mod ip_access_control;
+pub mod rate_limiter;
+pub mod metrics;
+pub mod metrics_server;
+pub mod log_buffer;
+pub mod access_log;
+pub mod acme_challenges;
+pub mod acme_client;
+pub mod acme_manager;
+pub mod certificate_monitor;
+pub mod client_auth;
@@ -0,0 +1,114 @@
+use regex::Regex;
+
+use crate::{common::enums::path_match_type::PathMatchType, models::route::path_rule::PathRule};
+
+/// Result of a successful path rule lookup
+#[derive(Debug, Clone)]
+pub struct PathMatch<'a> {
+    pub rule: &'a PathRule,
+
+    /// Request path that should be forwarded to the rule destination (prefix stripped if requested)
+    pub forward_path: String,
+}
+
+/// Resolves request paths of a single host entry into its ordered path rules.
+/// Rules are evaluated in given order and the first matching rule wins.
+#[derive(Debug, Clone, Default)]
+pub struct PathMatcher {
+    rules: Vec<(PathRule, Option<Regex>)>,
+}
+
+impl PathMatcher {
+    /// Compiles given path rules. Rules with an invalid regex are skipped and reported as error
+    pub fn build(path_rules: &[PathRule]) -> (Self, Vec<String>) {
+        let mut rules = Vec::new();
+        let mut errors = Vec::new();
+
+        for path_rule in path_rules {
+            match compile_path_rule(path_rule) {
+                Ok(compiled_regex) => rules.push((path_rule.clone(), compiled_regex)),
+                Err(error_message) => errors.push(error_message),
+            }
+        }
+
+        (Self { rules }, errors)
+    }
+
+    /// Finds the first path rule that matches given request path
+    pub fn find(&self, request_path: &str) -> Option<PathMatch<'_>> {
+        for (path_rule, compiled_regex) in &self.rules {
+            let matched_length = match path_rule.match_type {
+                PathMatchType::Exact => (request_path == path_rule.path).then_some(request_path.len()),
+                PathMatchType::Prefix => match_prefix(&path_rule.path, request_path),
+                PathMatchType::Regex => compiled_regex
+                    .as_ref()
+                    .and_then(|each| each.find(request_path))
+                    .map(|found| if found.start() == 0 { found.end() } else { 0 }),
+            };
+
+            let Some(matched_length) = matched_length else {
+                continue;
+            };
+
+            let forward_path = if path_rule.strip_prefix {
+                normalize_forward_path(&request_path[matched_length..])
+            } else {
+                request_path.to_string()
+            };
+
+            return Some(PathMatch {
+                rule: path_rule,
+                forward_path,
+            });
+        }
+
+        None
+    }
+}
+
+/// Validates that given path rule is well formed
+pub fn validate_path_rule(path_rule: &PathRule) -> Result<(), String> {
+    compile_path_rule(path_rule).map(|_| ())
+}
+
+fn compile_path_rule(path_rule: &PathRule) -> Result<Option<Regex>, String> {
+    if path_rule.path.is_empty() {
+        return Err("Path of path rule can not be empty".to_string());
+    }
+
+    if path_rule.match_type != PathMatchType::Regex {
+        if !path_rule.path.starts_with('/') {
+            return Err(format!(
+                "Invalid path rule '{}'. Path should start with '/'",
+                path_rule.path
+            ));
+        }
+
+        return Ok(None);
+    }
+
+    Regex::new(&path_rule.path)
+        .map(Some)
+        .map_err(|error| format!("Invalid regex path rule '{}': {}", path_rule.path, error))
+}
+
+/// Prefix match is segment aware: '/v1' matches '/v1' and '/v1/users' but not '/v10'
+fn match_prefix(prefix: &str, request_path: &str) -> Option<usize> {
+    if !request_path.starts_with(prefix) {
+        return None;
+    }
+
+    let is_segment_boundary = prefix.ends_with('/')
+        || request_path.len() == prefix.len()
+        || request_path[prefix.len()..].starts_with('/');
+
+    is_segment_boundary.then_some(prefix.len())
+}
+
+fn normalize_forward_path(path: &str) -> String {
+    if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("/{}", path)
+    }
+}
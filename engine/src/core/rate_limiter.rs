@@ -0,0 +1,649 @@
+use hyper::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, RETRY_AFTER};
+use hyper::{Body, Request, Response};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::{
+    common::enums::rate_limit_algorithm::RateLimitAlgorithm,
+    constants::Constants,
+    models::{
+        route::path_rule::PathRule,
+        settings::rate_limit_settings::RateLimitSettings,
+        traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
+    },
+};
+
+static RATE_LIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+static RATE_LIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+static RATE_LIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+
+/// Request attribute which requests are counted by
+#[derive(Debug, Clone, PartialEq)]
+pub enum RateLimitKey {
+    ClientIp,
+    /// All clients of the scope share the same limit
+    Route,
+    Header(String),
+    /// API key header, falling back to bearer token of `Authorization` header
+    ApiKey(String),
+}
+
+impl RateLimitKey {
+    /// Parses key definition: `ip`, `route`, `header:<name>`, `api_key` or `api_key:<header name>`
+    pub fn parse(definition: &str) -> Result<RateLimitKey, String> {
+        let definition = definition.trim();
+        let invalid_error = || {
+            format!(
+                "Invalid rate limit key '{}'. Expected 'ip', 'route', 'header:<name>', 'api_key' or 'api_key:<header name>'",
+                definition
+            )
+        };
+
+        let (kind, name) = match definition.split_once(':') {
+            Some((kind, name)) => (kind.trim().to_lowercase(), Some(name.trim().to_string())),
+            None => (definition.to_lowercase(), None),
+        };
+
+        match (kind.replace('-', "_").as_str(), name) {
+            ("ip", None) => Ok(RateLimitKey::ClientIp),
+            ("route", None) => Ok(RateLimitKey::Route),
+            ("header", Some(name)) if !name.is_empty() => Ok(RateLimitKey::Header(name)),
+            ("api_key", None) => Ok(RateLimitKey::ApiKey(
+                Constants::DEFAULT_RATE_LIMIT_API_KEY_HEADER.to_string(),
+            )),
+            ("api_key", Some(name)) if !name.is_empty() => Ok(RateLimitKey::ApiKey(name)),
+            _ => Err(invalid_error()),
+        }
+    }
+
+    /// Extracts key value of request. Falls back to client ip if header is missing
+    fn extract(&self, req: &Request<Body>, client_ip: IpAddr) -> String {
+        let extracted_value = match self {
+            RateLimitKey::ClientIp => None,
+            RateLimitKey::Route => return String::new(),
+            RateLimitKey::Header(header_name) => get_header_value(req, header_name),
+            RateLimitKey::ApiKey(header_name) => get_header_value(req, header_name).or_else(|| {
+                get_header_value(req, AUTHORIZATION.as_str())
+                    .and_then(|authorization| {
+                        authorization
+                            .strip_prefix("Bearer ")
+                            .map(|token| token.trim().to_string())
+                    })
+            }),
+        };
+
+        extracted_value.unwrap_or_else(|| client_ip.to_string())
+    }
+}
+
+fn get_header_value(req: &Request<Body>, header_name: &str) -> Option<String> {
+    req.headers()
+        .get(header_name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string())
+}
+
+/// Validates rate limit key of given route traffic policy (if any)
+pub fn validate_rate_limit_settings(traffic_policy: &Option<ScopeTrafficPolicy>) -> Result<(), String> {
+    match traffic_policy
+        .as_ref()
+        .and_then(|traffic_policy| traffic_policy.rate_limit_settings.key.as_ref())
+    {
+        Some(key) => RateLimitKey::parse(key).map(|_| ()),
+        None => Ok(()),
+    }
+}
+
+/// `limit` requests are allowed per `period`, up to `burst` of them at once
+#[derive(Debug, Clone)]
+pub struct RateLimitQuota {
+    pub limit: u32,
+    pub burst: u32,
+    pub period: Duration,
+}
+
+/// Result of a rate limit check
+#[derive(Debug, Clone)]
+pub struct RateLimitDecision {
+    pub is_allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    /// Time until the limit is fully available again
+    pub reset_after: Duration,
+    /// Time until next request is allowed. Zero for allowed requests
+    pub retry_after: Duration,
+}
+
+impl RateLimitDecision {
+    /// Adds `RateLimit-*` headers, plus `Retry-After` for rejected requests
+    pub fn apply_headers(&self, headers: &mut HeaderMap) {
+        headers.insert(RATE_LIMIT_LIMIT.clone(), HeaderValue::from(self.limit));
+        headers.insert(RATE_LIMIT_REMAINING.clone(), HeaderValue::from(self.remaining));
+        headers.insert(
+            RATE_LIMIT_RESET.clone(),
+            HeaderValue::from(ceil_seconds(self.reset_after)),
+        );
+
+        if !self.is_allowed {
+            headers.insert(RETRY_AFTER, HeaderValue::from(ceil_seconds(self.retry_after).max(1)));
+        }
+    }
+
+    pub fn has_headers(headers: &HeaderMap) -> bool {
+        headers.contains_key(&RATE_LIMIT_LIMIT)
+    }
+}
+
+/// Limit of a host entry, or of its matched path rule if the rule defines its own `max_requests_per_minute`
+#[derive(Debug, Clone)]
+pub struct RouteRateLimit {
+    pub scope: String,
+    pub decision: Option<RateLimitDecision>,
+}
+
+impl RouteRateLimit {
+    /// Decision of a request which exceeded the limit
+    pub fn get_rejection(&self) -> Option<&RateLimitDecision> {
+        self.decision.as_ref().filter(|decision| !decision.is_allowed)
+    }
+
+    /// Adds `RateLimit-*` headers of the limit into response of an allowed request
+    pub fn apply_headers(&self, response: &mut Result<Response<Body>, hyper::Error>) {
+        if let (Some(ref decision), Ok(ref mut response)) = (&self.decision, response) {
+            decision.apply_headers(response.headers_mut());
+        }
+    }
+}
+
+fn ceil_seconds(duration: Duration) -> u64 {
+    duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
+
+/// State of a single rate limit bucket
+pub enum RateLimitState {
+    TokenBucket { tokens: f64, refilled_at: Instant },
+    Gcra { theoretical_arrival_at: Instant },
+    SlidingLog { requests: VecDeque<Instant> },
+}
+
+/// Rate limiting algorithm. Takes the previous state of a bucket (if any) and returns its next state.
+/// States of other algorithms are ignored, so algorithm of a scope can be changed at runtime
+pub trait RateLimitStrategy: Send + Sync {
+    fn acquire(
+        &self,
+        state: Option<RateLimitState>,
+        quota: &RateLimitQuota,
+        now: Instant,
+    ) -> (RateLimitState, RateLimitDecision);
+}
+
+/// Bucket of `burst` tokens refilled continuously at `limit / period` rate
+pub struct TokenBucket;
+
+impl RateLimitStrategy for TokenBucket {
+    fn acquire(
+        &self,
+        state: Option<RateLimitState>,
+        quota: &RateLimitQuota,
+        now: Instant,
+    ) -> (RateLimitState, RateLimitDecision) {
+        let capacity = quota.burst as f64;
+        let refill_rate = quota.limit as f64 / quota.period.as_secs_f64();
+
+        let mut tokens = match state {
+            Some(RateLimitState::TokenBucket { tokens, refilled_at }) => {
+                let elapsed = now.saturating_duration_since(refilled_at).as_secs_f64();
+                (tokens + elapsed * refill_rate).min(capacity)
+            }
+            _ => capacity,
+        };
+
+        let is_allowed = tokens >= 1.0;
+        if is_allowed {
+            tokens -= 1.0;
+        }
+
+        let retry_after = if is_allowed {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((1.0 - tokens) / refill_rate)
+        };
+
+        let decision = RateLimitDecision {
+            is_allowed,
+            limit: quota.limit,
+            remaining: tokens.floor() as u32,
+            reset_after: Duration::from_secs_f64((capacity - tokens) / refill_rate),
+            retry_after,
+        };
+
+        (
+            RateLimitState::TokenBucket {
+                tokens,
+                refilled_at: now,
+            },
+            decision,
+        )
+    }
+}
+
+/// Generic cell rate algorithm. Behaves like token bucket but keeps a single timestamp per bucket
+pub struct Gcra;
+
+impl RateLimitStrategy for Gcra {
+    fn acquire(
+        &self,
+        state: Option<RateLimitState>,
+        quota: &RateLimitQuota,
+        now: Instant,
+    ) -> (RateLimitState, RateLimitDecision) {
+        let emission_interval = quota.period / quota.limit;
+        let burst_tolerance = emission_interval * quota.burst;
+
+        let theoretical_arrival_at = match state {
+            Some(RateLimitState::Gcra {
+                theoretical_arrival_at,
+            }) => theoretical_arrival_at.max(now),
+            _ => now,
+        };
+
+        let delay = theoretical_arrival_at.saturating_duration_since(now);
+        let next_delay = delay + emission_interval;
+
+        if next_delay > burst_tolerance {
+            let decision = RateLimitDecision {
+                is_allowed: false,
+                limit: quota.limit,
+                remaining: 0,
+                reset_after: delay,
+                retry_after: next_delay - burst_tolerance,
+            };
+
+            return (
+                RateLimitState::Gcra {
+                    theoretical_arrival_at,
+                },
+                decision,
+            );
+        }
+
+        let remaining =
+            (burst_tolerance - next_delay).as_nanos() / emission_interval.as_nanos().max(1);
+
+        let decision = RateLimitDecision {
+            is_allowed: true,
+            limit: quota.limit,
+            remaining: remaining as u32,
+            reset_after: next_delay,
+            retry_after: Duration::ZERO,
+        };
+
+        (
+            RateLimitState::Gcra {
+                theoretical_arrival_at: theoretical_arrival_at + emission_interval,
+            },
+            decision,
+        )
+    }
+}
+
+/// Keeps timestamps of requests in the last period. Exact, but memory grows with the limit
+pub struct SlidingLog;
+
+impl RateLimitStrategy for SlidingLog {
+    fn acquire(
+        &self,
+        state: Option<RateLimitState>,
+        quota: &RateLimitQuota,
+        now: Instant,
+    ) -> (RateLimitState, RateLimitDecision) {
+        let mut requests = match state {
+            Some(RateLimitState::SlidingLog { requests }) => requests,
+            _ => VecDeque::new(),
+        };
+
+        while let Some(oldest_request) = requests.front() {
+            if now.saturating_duration_since(*oldest_request) < quota.period {
+                break;
+            }
+            requests.pop_front();
+        }
+
+        let capacity = quota.limit as usize;
+        let is_allowed = requests.len() < capacity;
+        if is_allowed {
+            requests.push_back(now);
+        }
+
+        let reset_after = requests
+            .front()
+            .map(|oldest_request| {
+                quota
+                    .period
+                    .saturating_sub(now.saturating_duration_since(*oldest_request))
+            })
+            .unwrap_or_default();
+
+        let decision = RateLimitDecision {
+            is_allowed,
+            limit: quota.limit,
+            remaining: capacity.saturating_sub(requests.len()) as u32,
+            reset_after,
+            retry_after: if is_allowed { Duration::ZERO } else { reset_after },
+        };
+
+        (RateLimitState::SlidingLog { requests }, decision)
+    }
+}
+
+fn get_strategy(algorithm: &RateLimitAlgorithm) -> &'static dyn RateLimitStrategy {
+    match algorithm {
+        RateLimitAlgorithm::TokenBucket => &TokenBucket,
+        RateLimitAlgorithm::Gcra => &Gcra,
+        RateLimitAlgorithm::SlidingLog => &SlidingLog,
+    }
+}
+
+struct RateLimitEntry {
+    state: Option<RateLimitState>,
+    expires_at: Instant,
+}
+
+struct RateLimitShard {
+    entries: HashMap<String, RateLimitEntry>,
+    swept_at: Instant,
+}
+
+impl RateLimitShard {
+    /// Drops buckets which would be full again. Runs at most once per sweep interval
+    fn sweep(&mut self, now: Instant) {
+        if now.saturating_duration_since(self.swept_at).as_secs() < Constants::RATE_LIMIT_SWEEP_INTERVAL {
+            return;
+        }
+
+        self.entries.retain(|_, entry| entry.expires_at > now);
+        self.swept_at = now;
+    }
+}
+
+/// Rate limit buckets of every scope & key.
+/// Buckets are spread over independently locked shards, so concurrent requests rarely wait for each other
+pub struct RateLimiter {
+    shards: Vec<Mutex<RateLimitShard>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        let now = Instant::now();
+
+        Self {
+            shards: (0..Constants::RATE_LIMIT_SHARD_COUNT)
+                .map(|_| {
+                    Mutex::new(RateLimitShard {
+                        entries: HashMap::new(),
+                        swept_at: now,
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+impl RateLimiter {
+    /// Counts request against `limit` requests per minute of given scope.
+    /// Returns None if the limit is zero (rate limiting disabled)
+    pub fn check(
+        &self,
+        scope: &str,
+        limit: u32,
+        rate_limit_settings: &RateLimitSettings,
+        req: &Request<Body>,
+        client_ip: IpAddr,
+    ) -> Option<RateLimitDecision> {
+        if limit == 0 {
+            return None;
+        }
+
+        let rate_limit_key = RateLimitKey::parse(&rate_limit_settings.get_key())
+            .unwrap_or(RateLimitKey::ClientIp);
+
+        let quota = RateLimitQuota {
+            limit,
+            burst: rate_limit_settings.get_burst(limit),
+            period: Duration::from_secs(Constants::RATE_LIMIT_PERIOD),
+        };
+
+        let bucket_key = format!("{}|{}", scope, rate_limit_key.extract(req, client_ip));
+
+        Some(self.acquire(bucket_key, &rate_limit_settings.get_algorithm(), &quota))
+    }
+
+    /// Counts request against route & path limits, which come from `max_requests_per_minute` of route and path policies
+    /// merged into `traffic_policy`. Route limit is counted per host entry, unless matched path rule defines its own limit
+    pub fn check_route(
+        &self,
+        route_type: &str,
+        route_source: &str,
+        path_rule: Option<&PathRule>,
+        traffic_policy: &ScopeTrafficPolicy,
+        req: &Request<Body>,
+        client_ip: IpAddr,
+    ) -> RouteRateLimit {
+        let mut scope = format!("{}:{}", route_type, route_source);
+
+        let has_path_limit = path_rule
+            .and_then(|path_rule| path_rule.path_policy.as_ref())
+            .is_some_and(|path_policy| path_policy.max_requests_per_minute.is_some());
+
+        if let (true, Some(path_rule)) = (has_path_limit, path_rule) {
+            scope = format!("{}:{}", scope, path_rule.path);
+        }
+
+        let decision = traffic_policy
+            .rate_limit_settings
+            .max_requests_per_minute
+            .and_then(|limit| self.check(&scope, limit, &traffic_policy.rate_limit_settings, req, client_ip));
+
+        RouteRateLimit { scope, decision }
+    }
+
+    /// Consumes a request from the bucket with given key
+    pub fn acquire(
+        &self,
+        bucket_key: String,
+        algorithm: &RateLimitAlgorithm,
+        quota: &RateLimitQuota,
+    ) -> RateLimitDecision {
+        let now = Instant::now();
+        let mut shard = self.get_shard(&bucket_key).lock().unwrap();
+        shard.sweep(now);
+
+        let entry = shard.entries.entry(bucket_key).or_insert(RateLimitEntry {
+            state: None,
+            expires_at: now,
+        });
+
+        let (state, decision) = get_strategy(algorithm).acquire(entry.state.take(), quota, now);
+
+        entry.state = Some(state);
+        entry.expires_at = now + quota.period.max(decision.reset_after);
+
+        decision
+    }
+
+    fn get_shard(&self, bucket_key: &str) -> &Mutex<RateLimitShard> {
+        let mut hasher = DefaultHasher::new();
+        bucket_key.hash(&mut hasher);
+
+        &self.shards[hasher.finish() as usize % self.shards.len()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 60 requests per minute (one per second), up to 3 at once
+    fn build_quota() -> RateLimitQuota {
+        RateLimitQuota {
+            limit: 60,
+            burst: 3,
+            period: Duration::from_secs(60),
+        }
+    }
+
+    /// Runs requests at given offsets (in milliseconds) from `start` against a single bucket
+    fn run(strategy: &dyn RateLimitStrategy, start: Instant, offsets: &[u64]) -> Vec<RateLimitDecision> {
+        let quota = build_quota();
+        let mut state = None;
+
+        offsets
+            .iter()
+            .map(|offset| {
+                let (next_state, decision) =
+                    strategy.acquire(state.take(), &quota, start + Duration::from_millis(*offset));
+                state = Some(next_state);
+                decision
+            })
+            .collect()
+    }
+
+    fn assert_near(actual: Duration, expected: Duration) {
+        assert!(actual.abs_diff(expected) < Duration::from_micros(1), "{:?} != {:?}", actual, expected);
+    }
+
+    #[test]
+    fn token_bucket_allows_burst_then_refills_one_token_per_emission_interval() {
+        let decisions = run(&TokenBucket, Instant::now(), &[0, 0, 0, 0, 999, 1000, 1000]);
+
+        let allowed: Vec<bool> = decisions.iter().map(|decision| decision.is_allowed).collect();
+        assert_eq!(allowed, [true, true, true, false, false, true, false]);
+
+        let remaining: Vec<u32> = decisions.iter().map(|decision| decision.remaining).collect();
+        assert_eq!(remaining, [2, 1, 0, 0, 0, 0, 0]);
+
+        assert_eq!(decisions[0].retry_after, Duration::ZERO);
+        assert_near(decisions[0].reset_after, Duration::from_secs(1));
+        assert_near(decisions[3].retry_after, Duration::from_secs(1));
+        assert_near(decisions[3].reset_after, Duration::from_secs(3));
+        assert_near(decisions[4].retry_after, Duration::from_millis(1));
+        assert_near(decisions[6].retry_after, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn token_bucket_never_refills_above_burst() {
+        let decisions = run(&TokenBucket, Instant::now(), &[0, 600_000, 600_000, 600_000, 600_000]);
+
+        let allowed: Vec<bool> = decisions.iter().map(|decision| decision.is_allowed).collect();
+        assert_eq!(allowed, [true, true, true, true, false]);
+        assert_eq!(decisions[1].remaining, 2);
+    }
+
+    #[test]
+    fn gcra_allows_burst_then_one_request_per_emission_interval() {
+        let decisions = run(&Gcra, Instant::now(), &[0, 0, 0, 0, 999, 1000, 1000]);
+
+        let allowed: Vec<bool> = decisions.iter().map(|decision| decision.is_allowed).collect();
+        assert_eq!(allowed, [true, true, true, false, false, true, false]);
+
+        let remaining: Vec<u32> = decisions.iter().map(|decision| decision.remaining).collect();
+        assert_eq!(remaining, [2, 1, 0, 0, 0, 0, 0]);
+
+        assert_eq!(decisions[0].reset_after, Duration::from_secs(1));
+        assert_eq!(decisions[2].reset_after, Duration::from_secs(3));
+        assert_eq!(decisions[3].retry_after, Duration::from_secs(1));
+        assert_eq!(decisions[3].reset_after, Duration::from_secs(3));
+        assert_eq!(decisions[4].retry_after, Duration::from_millis(1));
+        assert_eq!(decisions[6].retry_after, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn gcra_forgets_history_after_idle_period() {
+        let decisions = run(&Gcra, Instant::now(), &[0, 0, 0, 600_000, 600_000, 600_000, 600_000]);
+
+        let allowed: Vec<bool> = decisions.iter().map(|decision| decision.is_allowed).collect();
+        assert_eq!(allowed, [true, true, true, true, true, true, false]);
+    }
+
+    #[test]
+    fn sliding_log_allows_limit_per_period_and_expires_requests_at_period_boundary() {
+        let quota = RateLimitQuota {
+            limit: 3,
+            burst: 3,
+            period: Duration::from_secs(60),
+        };
+        let start = Instant::now();
+        let mut state = None;
+        let mut acquire = |offset: u64| {
+            let (next_state, decision) =
+                SlidingLog.acquire(state.take(), &quota, start + Duration::from_millis(offset));
+            state = Some(next_state);
+            decision
+        };
+
+        for (offset, remaining) in [(0, 2), (10_000, 1), (20_000, 0)] {
+            let decision = acquire(offset);
+            assert!(decision.is_allowed, "{:?}", decision);
+            assert_eq!(decision.remaining, remaining, "{:?}", decision);
+        }
+
+        let decision = acquire(30_000);
+        assert!(!decision.is_allowed, "{:?}", decision);
+        assert_eq!(decision.retry_after, Duration::from_secs(30));
+
+        let decision = acquire(59_999);
+        assert!(!decision.is_allowed, "{:?}", decision);
+        assert_eq!(decision.retry_after, Duration::from_millis(1));
+
+        let decision = acquire(60_000);
+        assert!(decision.is_allowed, "{:?}", decision);
+        assert_eq!(decision.remaining, 0);
+        assert_eq!(decision.reset_after, Duration::from_secs(10));
+    }
+
+    #[test]
+    fn state_of_another_algorithm_is_ignored() {
+        let quota = build_quota();
+        let now = Instant::now();
+
+        let (state, _) = Gcra.acquire(None, &quota, now);
+        let (_, decision) = TokenBucket.acquire(Some(state), &quota, now);
+
+        assert!(decision.is_allowed);
+        assert_eq!(decision.remaining, 2);
+    }
+
+    #[test]
+    fn sweep_drops_expired_entries_at_most_once_per_interval() {
+        let start = Instant::now();
+        let sweep_interval = Duration::from_secs(Constants::RATE_LIMIT_SWEEP_INTERVAL);
+        let build_entry = |expires_at| RateLimitEntry {
+            state: None,
+            expires_at,
+        };
+
+        let mut shard = RateLimitShard {
+            entries: HashMap::new(),
+            swept_at: start,
+        };
+        shard.entries.insert("expired".to_string(), build_entry(start + Duration::from_secs(1)));
+        shard.entries.insert("active".to_string(), build_entry(start + sweep_interval + Duration::from_secs(1)));
+
+        shard.sweep(start + sweep_interval - Duration::from_millis(1));
+        assert_eq!(shard.entries.len(), 2);
+
+        shard.sweep(start + sweep_interval);
+        assert!(shard.entries.contains_key("active"));
+        assert!(!shard.entries.contains_key("expired"));
+
+        shard.sweep(start + sweep_interval + Duration::from_secs(2));
+        assert_eq!(shard.entries.len(), 1, "sweep should wait for the next interval");
+
+        shard.sweep(start + sweep_interval * 2);
+        assert!(shard.entries.is_empty());
+    }
+}
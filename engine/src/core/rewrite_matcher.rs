@@ -0,0 +1,542 @@
+use std::net::IpAddr;
+
+use hyper::{header::HeaderName, Body, Request, StatusCode};
+use regex::Regex;
+
+use crate::{
+    common::enums::{rewrite_action::RewriteAction, rewrite_condition_type::RewriteConditionType},
+    core::ip_access_control::IpCidr,
+    models::route::rewrite_rule::{RewriteCondition, RewriteRule},
+};
+
+/// Status codes accepted by redirect rules
+const REDIRECT_STATUS_CODES: [u16; 4] = [301, 302, 307, 308];
+
+/// Result of rewrite rules of a request
+#[derive(Debug, Clone, PartialEq)]
+pub enum RewriteResult {
+    /// Request continues to path rules & target. Contains new path & query if a rewrite rule matched
+    Forward(Option<String>),
+    /// Request is answered by a redirect or return rule
+    Respond(RewriteResponse),
+}
+
+/// Response of a redirect or return rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct RewriteResponse {
+    pub status_code: StatusCode,
+
+    /// `Location` of redirect rules
+    pub location: Option<String>,
+
+    pub body: String,
+}
+
+/// Evaluates ordered rewrite rules of a single host entry.
+/// Rewrite rules change the path seen by following rules, first redirect or return rule ends evaluation.
+#[derive(Debug, Clone, Default)]
+pub struct RewriteMatcher {
+    rules: Vec<CompiledRewriteRule>,
+}
+
+#[derive(Debug, Clone)]
+struct CompiledRewriteRule {
+    rule: RewriteRule,
+    pattern: Regex,
+    conditions: Vec<CompiledRewriteCondition>,
+}
+
+#[derive(Debug, Clone)]
+struct CompiledRewriteCondition {
+    matcher: ConditionMatcher,
+    negate: bool,
+}
+
+#[derive(Debug, Clone)]
+enum ConditionMatcher {
+    Method(Vec<String>),
+    Header(HeaderName, Option<Regex>),
+    Query(String, Option<Regex>),
+    ClientIp(Vec<IpCidr>),
+}
+
+impl RewriteMatcher {
+    /// Compiles given rewrite rules. Invalid rules are skipped and reported as error
+    pub fn build(rewrite_rules: &[RewriteRule]) -> (Self, Vec<String>) {
+        let mut rules = Vec::new();
+        let mut errors = Vec::new();
+
+        for rewrite_rule in rewrite_rules {
+            match compile_rewrite_rule(rewrite_rule) {
+                Ok(compiled_rule) => rules.push(compiled_rule),
+                Err(error_message) => errors.push(error_message),
+            }
+        }
+
+        (Self { rules }, errors)
+    }
+
+    /// Applies rewrite rules to path & query of given request
+    pub fn evaluate(&self, req: &Request<Body>, client_ip: IpAddr) -> RewriteResult {
+        let mut path = req.uri().path().to_string();
+        let mut query = req.uri().query().map(|query| query.to_string());
+        let mut is_rewritten = false;
+
+        for compiled_rule in &self.rules {
+            let Some(captures) = compiled_rule.pattern.captures(&path) else {
+                continue;
+            };
+
+            let is_matched = compiled_rule
+                .conditions
+                .iter()
+                .all(|condition| condition.matches(req, query.as_deref(), client_ip));
+
+            if !is_matched {
+                continue;
+            }
+
+            let rule = &compiled_rule.rule;
+            let mut replacement = String::new();
+            captures.expand(rule.replacement.as_deref().unwrap_or_default(), &mut replacement);
+
+            match rule.action {
+                RewriteAction::Rewrite => {
+                    // Replacement with a query replaces query of the request, `/path?` drops it
+                    match replacement.split_once('?') {
+                        Some((new_path, new_query)) => {
+                            path = new_path.to_string();
+                            query = (!new_query.is_empty()).then(|| new_query.to_string());
+                        }
+                        None => path = replacement,
+                    }
+                    is_rewritten = true;
+                }
+                RewriteAction::Redirect => {
+                    let location = match (&query, replacement.contains('?')) {
+                        (Some(query), false) => format!("{}?{}", replacement, query),
+                        _ => replacement,
+                    };
+
+                    return RewriteResult::Respond(RewriteResponse {
+                        status_code: get_status_code(rule),
+                        location: Some(location),
+                        body: String::new(),
+                    });
+                }
+                RewriteAction::Return => {
+                    let mut body = String::new();
+                    captures.expand(rule.body.as_deref().unwrap_or_default(), &mut body);
+
+                    return RewriteResult::Respond(RewriteResponse {
+                        status_code: get_status_code(rule),
+                        location: None,
+                        body,
+                    });
+                }
+            }
+        }
+
+        if !is_rewritten {
+            return RewriteResult::Forward(None);
+        }
+
+        match query {
+            Some(query) => RewriteResult::Forward(Some(format!("{}?{}", path, query))),
+            None => RewriteResult::Forward(Some(path)),
+        }
+    }
+}
+
+impl CompiledRewriteCondition {
+    fn matches(&self, req: &Request<Body>, query: Option<&str>, client_ip: IpAddr) -> bool {
+        let is_matched = match &self.matcher {
+            ConditionMatcher::Method(methods) => methods
+                .iter()
+                .any(|method| method == req.method().as_str()),
+            ConditionMatcher::Header(header_name, value_regex) => {
+                let mut header_values = req
+                    .headers()
+                    .get_all(header_name)
+                    .iter()
+                    .map(|header_value| String::from_utf8_lossy(header_value.as_bytes()).to_string())
+                    .peekable();
+
+                match value_regex {
+                    Some(value_regex) => header_values.any(|header_value| value_regex.is_match(&header_value)),
+                    None => header_values.peek().is_some(),
+                }
+            }
+            ConditionMatcher::Query(name, value_regex) => {
+                let mut parameter_values = query
+                    .unwrap_or_default()
+                    .split('&')
+                    .map(|parameter| parameter.split_once('=').unwrap_or((parameter, "")))
+                    .filter(|(parameter_name, _)| decode_query_component(parameter_name) == *name)
+                    .map(|(_, parameter_value)| decode_query_component(parameter_value))
+                    .peekable();
+
+                match value_regex {
+                    Some(value_regex) => parameter_values.any(|parameter_value| value_regex.is_match(&parameter_value)),
+                    None => parameter_values.peek().is_some(),
+                }
+            }
+            ConditionMatcher::ClientIp(networks) => networks.iter().any(|network| network.contains(client_ip)),
+        };
+
+        is_matched != self.negate
+    }
+}
+
+/// Validates that given rewrite rule is well formed
+pub fn validate_rewrite_rule(rewrite_rule: &RewriteRule) -> Result<(), String> {
+    compile_rewrite_rule(rewrite_rule).map(|_| ())
+}
+
+fn compile_rewrite_rule(rewrite_rule: &RewriteRule) -> Result<CompiledRewriteRule, String> {
+    let pattern = Regex::new(&rewrite_rule.pattern)
+        .map_err(|error| format!("Invalid rewrite rule pattern '{}': {}", rewrite_rule.pattern, error))?;
+
+    match rewrite_rule.action {
+        RewriteAction::Rewrite => {
+            let is_valid_path = rewrite_rule
+                .replacement
+                .as_ref()
+                .is_some_and(|replacement| replacement.starts_with('/'));
+
+            if !is_valid_path {
+                return Err(format!(
+                    "Rewrite rule '{}' needs a replacement path starting with '/'",
+                    rewrite_rule.pattern
+                ));
+            }
+        }
+        RewriteAction::Redirect => {
+            let is_valid_location = rewrite_rule.replacement.as_ref().is_some_and(|replacement| {
+                replacement.starts_with('/')
+                    || replacement.starts_with("http://")
+                    || replacement.starts_with("https://")
+            });
+
+            if !is_valid_location {
+                return Err(format!(
+                    "Redirect rule '{}' needs a replacement path or an absolute http(s) url",
+                    rewrite_rule.pattern
+                ));
+            }
+
+            if let Some(status_code) = rewrite_rule.status_code {
+                if !REDIRECT_STATUS_CODES.contains(&status_code) {
+                    return Err(format!(
+                        "Invalid redirect status {}. Please use one of following: 301, 302, 307, 308",
+                        status_code
+                    ));
+                }
+            }
+        }
+        RewriteAction::Return => {
+            if let Some(status_code) = rewrite_rule.status_code {
+                if !(100..=599).contains(&status_code) {
+                    return Err(format!("Invalid return status {}", status_code));
+                }
+            }
+        }
+    }
+
+    let conditions = rewrite_rule
+        .conditions
+        .iter()
+        .map(compile_rewrite_condition)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(CompiledRewriteRule {
+        rule: rewrite_rule.clone(),
+        pattern,
+        conditions,
+    })
+}
+
+fn compile_rewrite_condition(condition: &RewriteCondition) -> Result<CompiledRewriteCondition, String> {
+    let compile_value_regex = || {
+        condition
+            .value
+            .as_ref()
+            .map(|value| Regex::new(value).map_err(|error| format!("Invalid rewrite condition regex '{}': {}", value, error)))
+            .transpose()
+    };
+
+    let split_values = || -> Result<Vec<String>, String> {
+        let values: Vec<String> = condition
+            .value
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .collect();
+
+        if values.is_empty() {
+            return Err(format!("Rewrite condition '{:?}' needs a value", condition.condition_type));
+        }
+
+        Ok(values)
+    };
+
+    let matcher = match condition.condition_type {
+        RewriteConditionType::Method => ConditionMatcher::Method(
+            split_values()?
+                .iter()
+                .map(|method| method.to_ascii_uppercase())
+                .collect(),
+        ),
+        RewriteConditionType::Header => {
+            let header_name = condition
+                .name
+                .as_ref()
+                .and_then(|name| HeaderName::from_bytes(name.as_bytes()).ok())
+                .ok_or_else(|| "Header condition of rewrite rule needs a valid header name".to_string())?;
+
+            ConditionMatcher::Header(header_name, compile_value_regex()?)
+        }
+        RewriteConditionType::Query => {
+            let Some(name) = condition.name.clone().filter(|name| !name.is_empty()) else {
+                return Err("Query condition of rewrite rule needs a parameter name".into());
+            };
+
+            ConditionMatcher::Query(name, compile_value_regex()?)
+        }
+        RewriteConditionType::ClientIp => ConditionMatcher::ClientIp(
+            split_values()?
+                .iter()
+                .map(|network| IpCidr::parse(network))
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+    };
+
+    Ok(CompiledRewriteCondition {
+        matcher,
+        negate: condition.negate,
+    })
+}
+
+fn get_status_code(rewrite_rule: &RewriteRule) -> StatusCode {
+    let default_status_code = match rewrite_rule.action {
+        RewriteAction::Redirect => StatusCode::FOUND,
+        _ => StatusCode::OK,
+    };
+
+    rewrite_rule
+        .status_code
+        .and_then(|status_code| StatusCode::from_u16(status_code).ok())
+        .unwrap_or(default_status_code)
+}
+
+/// Decodes `+` and percent encoded bytes of a query parameter name or value
+fn decode_query_component(component: &str) -> String {
+    let bytes = component.as_bytes();
+    let mut decoded_bytes = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let encoded_byte = bytes
+            .get(index + 1..index + 3)
+            .filter(|_| bytes[index] == b'%')
+            .and_then(|hex_digits| std::str::from_utf8(hex_digits).ok())
+            .and_then(|hex_digits| u8::from_str_radix(hex_digits, 16).ok());
+
+        match (bytes[index], encoded_byte) {
+            (_, Some(encoded_byte)) => {
+                decoded_bytes.push(encoded_byte);
+                index += 3;
+                continue;
+            }
+            (b'+', None) => decoded_bytes.push(b' '),
+            (byte, None) => decoded_bytes.push(byte),
+        }
+        index += 1;
+    }
+
+    String::from_utf8_lossy(&decoded_bytes).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_rule(pattern: &str, action: RewriteAction, replacement: Option<&str>) -> RewriteRule {
+        RewriteRule {
+            pattern: pattern.to_string(),
+            action,
+            replacement: replacement.map(|replacement| replacement.to_string()),
+            status_code: None,
+            body: None,
+            conditions: Vec::new(),
+        }
+    }
+
+    fn build_condition(
+        condition_type: RewriteConditionType,
+        name: Option<&str>,
+        value: Option<&str>,
+        negate: bool,
+    ) -> RewriteCondition {
+        RewriteCondition {
+            condition_type,
+            name: name.map(|name| name.to_string()),
+            value: value.map(|value| value.to_string()),
+            negate,
+        }
+    }
+
+    fn build_request(method: &str, uri: &str, headers: &[(&str, &str)]) -> Request<Body> {
+        let mut builder = Request::builder().method(method).uri(uri);
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    fn evaluate(rules: &[RewriteRule], req: &Request<Body>) -> RewriteResult {
+        let (rewrite_matcher, errors) = RewriteMatcher::build(rules);
+        assert!(errors.is_empty(), "{:?}", errors);
+
+        rewrite_matcher.evaluate(req, "10.0.0.5".parse().unwrap())
+    }
+
+    fn forward(path: &str) -> RewriteResult {
+        RewriteResult::Forward(Some(path.to_string()))
+    }
+
+    fn redirect(status_code: StatusCode, location: &str) -> RewriteResult {
+        RewriteResult::Respond(RewriteResponse {
+            status_code,
+            location: Some(location.to_string()),
+            body: String::new(),
+        })
+    }
+
+    #[test]
+    fn request_without_matching_rule_is_forwarded_unchanged() {
+        let rules = [build_rule("^/old/(.*)$", RewriteAction::Rewrite, Some("/new/$1"))];
+
+        assert_eq!(
+            evaluate(&rules, &build_request("GET", "/other?a=1", &[])),
+            RewriteResult::Forward(None)
+        );
+    }
+
+    #[test]
+    fn captures_are_expanded_and_rewrites_are_chained() {
+        let rules = [
+            build_rule("^/old/(.*)$", RewriteAction::Rewrite, Some("/v1/$1")),
+            build_rule("^/v1/(?P<name>[a-z]+)$", RewriteAction::Rewrite, Some("/api/${name}/index")),
+            build_rule("^/api/users/index$", RewriteAction::Redirect, Some("https://users.example.com/")),
+        ];
+
+        assert_eq!(
+            evaluate(&rules, &build_request("GET", "/old/items", &[])),
+            forward("/api/items/index")
+        );
+        assert_eq!(
+            evaluate(&rules, &build_request("GET", "/old/users", &[])),
+            redirect(StatusCode::FOUND, "https://users.example.com/")
+        );
+    }
+
+    #[test]
+    fn query_is_kept_replaced_or_dropped_by_rewrite_replacement() {
+        let keep_rules = [build_rule("^/a$", RewriteAction::Rewrite, Some("/b"))];
+        let replace_rules = [build_rule("^/a$", RewriteAction::Rewrite, Some("/b?c=2"))];
+        let drop_rules = [build_rule("^/a$", RewriteAction::Rewrite, Some("/b?"))];
+        let req = build_request("GET", "/a?q=1", &[]);
+
+        assert_eq!(evaluate(&keep_rules, &req), forward("/b?q=1"));
+        assert_eq!(evaluate(&replace_rules, &req), forward("/b?c=2"));
+        assert_eq!(evaluate(&drop_rules, &req), forward("/b"));
+    }
+
+    #[test]
+    fn query_is_appended_to_redirect_location_unless_location_has_one() {
+        let mut redirect_rule =
+            build_rule("^/docs/(.*)$", RewriteAction::Redirect, Some("https://docs.example.com/$1"));
+        redirect_rule.status_code = Some(301);
+        let mut query_rule = build_rule("^/docs/(.*)$", RewriteAction::Redirect, Some("/help?page=$1"));
+        query_rule.status_code = Some(308);
+
+        assert_eq!(
+            evaluate(&[redirect_rule.clone()], &build_request("GET", "/docs/intro?lang=en", &[])),
+            redirect(StatusCode::MOVED_PERMANENTLY, "https://docs.example.com/intro?lang=en")
+        );
+        assert_eq!(
+            evaluate(&[redirect_rule], &build_request("GET", "/docs/intro", &[])),
+            redirect(StatusCode::MOVED_PERMANENTLY, "https://docs.example.com/intro")
+        );
+        assert_eq!(
+            evaluate(&[query_rule], &build_request("GET", "/docs/intro?lang=en", &[])),
+            redirect(StatusCode::PERMANENT_REDIRECT, "/help?page=intro")
+        );
+    }
+
+    #[test]
+    fn return_rule_answers_with_expanded_body() {
+        let mut return_rule = build_rule("^/status/(\\w+)$", RewriteAction::Return, None);
+        return_rule.status_code = Some(503);
+        return_rule.body = Some("$1 is down".to_string());
+
+        assert_eq!(
+            evaluate(&[return_rule], &build_request("GET", "/status/billing", &[])),
+            RewriteResult::Respond(RewriteResponse {
+                status_code: StatusCode::SERVICE_UNAVAILABLE,
+                location: None,
+                body: "billing is down".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn conditions_are_matched_and_negated() {
+        let mut rule = build_rule("^/admin", RewriteAction::Return, None);
+        rule.status_code = Some(403);
+        rule.conditions = vec![
+            build_condition(RewriteConditionType::Method, None, Some("get, post"), false),
+            build_condition(RewriteConditionType::ClientIp, None, Some("192.168.0.0/16"), true),
+            build_condition(RewriteConditionType::Header, Some("x-debug"), None, true),
+            build_condition(RewriteConditionType::Query, Some("user name"), Some("^a"), false),
+        ];
+        let rules = [rule];
+        let is_forbidden = |req: &Request<Body>| matches!(evaluate(&rules, req), RewriteResult::Respond(_));
+
+        assert!(is_forbidden(&build_request("GET", "/admin?user+name=alice", &[])));
+        assert!(is_forbidden(&build_request("POST", "/admin?user%20name=adam", &[])));
+        assert!(!is_forbidden(&build_request("PUT", "/admin?user+name=alice", &[])));
+        assert!(!is_forbidden(&build_request("GET", "/admin?user+name=bob", &[])));
+        assert!(!is_forbidden(&build_request("GET", "/admin", &[])));
+        assert!(!is_forbidden(&build_request("GET", "/admin?user+name=alice", &[("x-debug", "1")])));
+
+        let (rewrite_matcher, _) = RewriteMatcher::build(&rules);
+        let req = build_request("GET", "/admin?user+name=alice", &[]);
+        assert_eq!(rewrite_matcher.evaluate(&req, "192.168.1.10".parse().unwrap()), RewriteResult::Forward(None));
+    }
+
+    #[test]
+    fn invalid_rules_are_reported_and_skipped() {
+        let mut bad_status_rule = build_rule("^/a$", RewriteAction::Redirect, Some("/b"));
+        bad_status_rule.status_code = Some(200);
+        let mut bad_condition_rule = build_rule("^/a$", RewriteAction::Rewrite, Some("/b"));
+        bad_condition_rule.conditions = vec![build_condition(RewriteConditionType::ClientIp, None, Some("x"), false)];
+
+        let rules = [
+            build_rule("^/(a$", RewriteAction::Rewrite, Some("/b")),
+            build_rule("^/a$", RewriteAction::Rewrite, Some("b")),
+            build_rule("^/a$", RewriteAction::Redirect, Some("ftp://example.com")),
+            bad_status_rule,
+            bad_condition_rule,
+            build_rule("^/a$", RewriteAction::Rewrite, Some("/c")),
+        ];
+
+        let (rewrite_matcher, errors) = RewriteMatcher::build(&rules);
+        assert_eq!(errors.len(), 5, "{:?}", errors);
+        let req = build_request("GET", "/a", &[]);
+        assert_eq!(rewrite_matcher.evaluate(&req, "10.0.0.5".parse().unwrap()), forward("/c"));
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use hyper::{Body, Request};
+
+use crate::{
+    common::enums::route_type::RouteType,
+    core::{
+        forwarded_headers::parse_trusted_proxies,
+        host_matcher::HostMatcher,
+        ip_access_control::{IpAccessList, IpCidr},
+        load_balancer::LoadBalancer,
+        path_matcher::{PathMatch, PathMatcher},
+        rewrite_matcher::{RewriteMatcher, RewriteResult},
+        router::Router,
+        shared_memory::RUNTIME_BOOT_INFO,
+        sni_resolver::SniResolver,
+    },
+    models::{
+        route::{
+            header_rule::HeaderRule, http_route::HttpRoute, https_route::HttpsRoute, iws_route::IwsRoute,
+            secure_iws_route::SecureIwsRoute,
+        },
+        settings::client_auth_settings::ClientAuthSettings,
+        traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
+    },
+    utils::tls_utility::create_sni_resolver,
+};
+
+/// Immutable view of routes served by HTTP server.
+/// A new snapshot is published on every route change, requests keep using the snapshot they started with.
+#[derive(Default)]
+pub struct HttpRouteSnapshot {
+    pub http_routes: HashMap<String, HttpRoute>,
+    pub iws_routes: HashMap<String, IwsRoute>,
+    pub route_matcher: HostMatcher<(RouteType, String)>,
+    pub load_balancers: HashMap<String, LoadBalancer>,
+    /// Trusted proxies of HTTP server policy, parsed once instead of on every request
+    pub trusted_proxies: Vec<IpCidr>,
+    path_matchers: HashMap<(RouteType, String), PathMatcher>,
+    rewrite_matchers: HashMap<(RouteType, String), RewriteMatcher>,
+    ip_access_lists: HashMap<(RouteType, String), IpAccessList>,
+}
+
+impl HttpRouteSnapshot {
+    pub fn build(router: &Router) -> Self {
+        let forwarded_header_settings = RUNTIME_BOOT_INFO
+            .read()
+            .unwrap()
+            .config
+            .get_http_effective_policy()
+            .forwarded_headers;
+
+        Self {
+            http_routes: router.get_http_routes(),
+            iws_routes: router.get_iws_routes(),
+            route_matcher: router.get_http_host_matcher(),
+            load_balancers: router.get_http_load_balancers(),
+            trusted_proxies: parse_trusted_proxies(&forwarded_header_settings),
+            path_matchers: router.get_http_path_matchers(),
+            rewrite_matchers: router.get_http_rewrite_matchers(),
+            ip_access_lists: router.get_http_ip_access_lists(),
+        }
+    }
+
+    pub fn find_path_rule(
+        &self,
+        route_type: RouteType,
+        route_source: &str,
+        request_path: &str,
+    ) -> Option<PathMatch<'_>> {
+        find_path_rule(&self.path_matchers, route_type, route_source, request_path)
+    }
+
+    /// Applies rewrite rules of given route to the request. Routes without rewrite rules forward the request as is
+    pub fn apply_rewrite_rules(
+        &self,
+        route_type: RouteType,
+        route_source: &str,
+        req: &Request<Body>,
+        client_ip: IpAddr,
+    ) -> RewriteResult {
+        apply_rewrite_rules(&self.rewrite_matchers, route_type, route_source, req, client_ip)
+    }
+
+    /// Returns header rules of given route
+    pub fn find_header_rules(&self, route_type: RouteType, route_source: &str) -> &[HeaderRule] {
+        let header_rules = match route_type {
+            RouteType::Http => self.http_routes.get(route_source).map(|route| &route.header_rules),
+            RouteType::Iws => self.iws_routes.get(route_source).map(|route| &route.header_rules),
+            _ => None,
+        };
+
+        header_rules.map(|header_rules| header_rules.as_slice()).unwrap_or_default()
+    }
+
+    pub fn find_ip_access_list(&self, route_type: RouteType, route_source: &str) -> Option<&IpAccessList> {
+        self.ip_access_lists.get(&(route_type, route_source.to_string()))
+    }
+
+    /// Returns traffic policy overrides of given route, if route defines any
+    pub fn find_traffic_policy(&self, route_type: RouteType, route_source: &str) -> Option<&ScopeTrafficPolicy> {
+        match route_type {
+            RouteType::Http => self.http_routes.get(route_source)?.traffic_policy.as_ref(),
+            RouteType::Iws => self.iws_routes.get(route_source)?.traffic_policy.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Immutable view of routes served by HTTPS server, including certificates resolved by SNI.
+#[derive(Default)]
+pub struct HttpsRouteSnapshot {
+    pub https_routes: HashMap<String, HttpsRoute>,
+    pub secure_iws_routes: HashMap<String, SecureIwsRoute>,
+    pub route_matcher: HostMatcher<(RouteType, String)>,
+    pub sni_resolver: Arc<SniResolver>,
+    pub load_balancers: HashMap<String, LoadBalancer>,
+    /// Trusted proxies of HTTPS server policy, parsed once instead of on every request
+    pub trusted_proxies: Vec<IpCidr>,
+    path_matchers: HashMap<(RouteType, String), PathMatcher>,
+    rewrite_matchers: HashMap<(RouteType, String), RewriteMatcher>,
+    ip_access_lists: HashMap<(RouteType, String), IpAccessList>,
+}
+
+impl HttpsRouteSnapshot {
+    /// Builds snapshot with its SNI resolver. Routes whose certificate can not be loaded are kept in snapshot as degraded
+    pub fn build(router: &Router) -> Self {
+        let https_routes = router.get_https_routes();
+        let secure_iws_routes = router.get_secure_iws_routes();
+
+        let (https_server_config, forwarded_header_settings) = {
+            let runtime_info = RUNTIME_BOOT_INFO.read().unwrap();
+            (
+                runtime_info.config.https_server.clone(),
+                runtime_info.config.get_https_effective_policy().forwarded_headers,
+            )
+        };
+
+        let sni_resolver = create_sni_resolver(&https_routes, &secure_iws_routes, &https_server_config);
+
+        Self {
+            https_routes,
+            secure_iws_routes,
+            route_matcher: router.get_https_host_matcher(),
+            sni_resolver: Arc::new(sni_resolver),
+            load_balancers: router.get_https_load_balancers(),
+            trusted_proxies: parse_trusted_proxies(&forwarded_header_settings),
+            path_matchers: router.get_https_path_matchers(),
+            rewrite_matchers: router.get_https_rewrite_matchers(),
+            ip_access_lists: router.get_https_ip_access_lists(),
+        }
+    }
+
+    pub fn find_path_rule(
+        &self,
+        route_type: RouteType,
+        route_source: &str,
+        request_path: &str,
+    ) -> Option<PathMatch<'_>> {
+        find_path_rule(&self.path_matchers, route_type, route_source, request_path)
+    }
+
+    /// Applies rewrite rules of given route to the request. Routes without rewrite rules forward the request as is
+    pub fn apply_rewrite_rules(
+        &self,
+        route_type: RouteType,
+        route_source: &str,
+        req: &Request<Body>,
+        client_ip: IpAddr,
+    ) -> RewriteResult {
+        apply_rewrite_rules(&self.rewrite_matchers, route_type, route_source, req, client_ip)
+    }
+
+    /// Returns header rules of given route
+    pub fn find_header_rules(&self, route_type: RouteType, route_source: &str) -> &[HeaderRule] {
+        let header_rules = match route_type {
+            RouteType::Https => self.https_routes.get(route_source).map(|route| &route.header_rules),
+            RouteType::SecureIws => self.secure_iws_routes.get(route_source).map(|route| &route.header_rules),
+            _ => None,
+        };
+
+        header_rules.map(|header_rules| header_rules.as_slice()).unwrap_or_default()
+    }
+
+    pub fn find_ip_access_list(&self, route_type: RouteType, route_source: &str) -> Option<&IpAccessList> {
+        self.ip_access_lists.get(&(route_type, route_source.to_string()))
+    }
+
+    /// Returns traffic policy overrides of given route, if route defines any
+    pub fn find_traffic_policy(&self, route_type: RouteType, route_source: &str) -> Option<&ScopeTrafficPolicy> {
+        match route_type {
+            RouteType::Https => self.https_routes.get(route_source)?.traffic_policy.as_ref(),
+            RouteType::SecureIws => self.secure_iws_routes.get(route_source)?.traffic_policy.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns client certificate authentication (mTLS) settings of given route, if route defines any
+    pub fn find_client_auth(&self, route_type: RouteType, route_source: &str) -> Option<&ClientAuthSettings> {
+        match route_type {
+            RouteType::Https => self.https_routes.get(route_source)?.ssl_context.client_auth.as_ref(),
+            RouteType::SecureIws => self.secure_iws_routes.get(route_source)?.ssl_context.client_auth.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+fn find_path_rule<'a>(
+    path_matchers: &'a HashMap<(RouteType, String), PathMatcher>,
+    route_type: RouteType,
+    route_source: &str,
+    request_path: &str,
+) -> Option<PathMatch<'a>> {
+    path_matchers
+        .get(&(route_type, route_source.to_string()))
+        .and_then(|path_matcher| path_matcher.find(request_path))
+}
+
+fn apply_rewrite_rules(
+    rewrite_matchers: &HashMap<(RouteType, String), RewriteMatcher>,
+    route_type: RouteType,
+    route_source: &str,
+    req: &Request<Body>,
+    client_ip: IpAddr,
+) -> RewriteResult {
+    rewrite_matchers
+        .get(&(route_type, route_source.to_string()))
+        .map(|rewrite_matcher| rewrite_matcher.evaluate(req, client_ip))
+        .unwrap_or(RewriteResult::Forward(None))
+}
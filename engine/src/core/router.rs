@@ -1,13 +1,27 @@
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, path::PathBuf};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
 use crate::{
+    common::enums::{ip_access_list_type::IpAccessListType, route_type::RouteType},
     constants::Constants,
+    core::{
+        host_matcher::HostMatcher,
+        ip_access_control::{build_ip_access_list, IpAccessList},
+        load_balancer::LoadBalancer,
+        path_matcher::PathMatcher,
+        rewrite_matcher::RewriteMatcher,
+        route_snapshot::{HttpRouteSnapshot, HttpsRouteSnapshot},
+        shared_memory::{HTTPS_ROUTE_SNAPSHOT, HTTP_ROUTE_SNAPSHOT},
+    },
+    log_error, log_info,
     models::{
         route::{
-            http_route::HttpRoute, https_route::HttpsRoute, iws_route::IwsRoute,
-            json_route::JsonRoute, secure_iws_route::SecureIwsRoute,
+            header_rule::HeaderRule, http_route::HttpRoute, https_route::HttpsRoute, iws_route::IwsRoute,
+            json_route::JsonRoute, path_rule::PathRule, rewrite_rule::RewriteRule, secure_iws_route::SecureIwsRoute,
+            upstream_pool::UpstreamPool,
         },
+        config::Config,
+        settings::{ip_access_settings::IpAccessSettings, upstream_tls_settings::UpstreamTlsSettings},
         ssl_context::SslContext,
         traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
     },
@@ -79,6 +93,20 @@ impl Router {
         }
     }
 
+    /// Publishes a new route snapshot for the server which serves given route type.
+    /// New requests use the new snapshot immediately, in-flight requests complete with the snapshot they started with.
+    pub fn publish(&self, route_type: &RouteType) {
+        if matches!(route_type, RouteType::Http | RouteType::Iws | RouteType::All) {
+            HTTP_ROUTE_SNAPSHOT.store(Arc::new(HttpRouteSnapshot::build(self)));
+            log_info!("HTTP route changes applied");
+        }
+
+        if matches!(route_type, RouteType::Https | RouteType::SecureIws | RouteType::All) {
+            HTTPS_ROUTE_SNAPSHOT.store(Arc::new(HttpsRouteSnapshot::build(self)));
+            log_info!("HTTPS route changes applied");
+        }
+    }
+
     fn convert_to_json_route_vec(&self) -> Vec<JsonRoute> {
         let mut json_route_vec = <Vec<JsonRoute>>::new();
 
@@ -90,6 +118,11 @@ impl Router {
                 ssl: None,
                 serving_path: None,
                 traffic_policy: http_route.traffic_policy.clone(),
+                path_rules: http_route.path_rules.clone(),
+                upstream_pool: http_route.upstream_pool.clone(),
+                ip_access: http_route.ip_access.clone(),
+                header_rules: http_route.header_rules.clone(),
+                rewrite_rules: http_route.rewrite_rules.clone(),
             });
         }
 
@@ -101,6 +134,11 @@ impl Router {
                 ssl: Some(https_route.ssl_context.clone()),
                 serving_path: None,
                 traffic_policy: https_route.traffic_policy.clone(),
+                path_rules: https_route.path_rules.clone(),
+                upstream_pool: https_route.upstream_pool.clone(),
+                ip_access: https_route.ip_access.clone(),
+                header_rules: https_route.header_rules.clone(),
+                rewrite_rules: https_route.rewrite_rules.clone(),
             })
         }
 
@@ -112,6 +150,11 @@ impl Router {
                 ssl: None,
                 serving_path: Some(iws_route.serving_path.clone()),
                 traffic_policy: iws_route.traffic_policy.clone(),
+                path_rules: iws_route.path_rules.clone(),
+                upstream_pool: None,
+                ip_access: iws_route.ip_access.clone(),
+                header_rules: iws_route.header_rules.clone(),
+                rewrite_rules: iws_route.rewrite_rules.clone(),
             })
         }
 
@@ -123,6 +166,11 @@ impl Router {
                 ssl: Some(secure_iws_route.ssl_context.clone()),
                 serving_path: Some(secure_iws_route.serving_path.clone()),
                 traffic_policy: secure_iws_route.traffic_policy.clone(),
+                path_rules: secure_iws_route.path_rules.clone(),
+                upstream_pool: None,
+                ip_access: secure_iws_route.ip_access.clone(),
+                header_rules: secure_iws_route.header_rules.clone(),
+                rewrite_rules: secure_iws_route.rewrite_rules.clone(),
             })
         }
 
@@ -141,45 +189,75 @@ impl Router {
         source: &String,
         target: &String,
         input_traffic_policy: Option<ScopeTrafficPolicy>,
+        upstream_pool: Option<UpstreamPool>,
+        header_rules: Vec<HeaderRule>,
     ) {
-        if self.http_route_table.contains_key(source) {
-            self.http_route_table.remove(source);
-        }
+        // Path rules, rewrite rules and ip access lists are kept when a host entry is overwritten
+        let (path_rules, rewrite_rules, ip_access) = self
+            .http_route_table
+            .remove(source)
+            .map(|existing_route| {
+                (
+                    existing_route.path_rules,
+                    existing_route.rewrite_rules,
+                    existing_route.ip_access,
+                )
+            })
+            .unwrap_or_default();
 
         let new_route = HttpRoute {
             target: target.to_owned(),
             traffic_policy: input_traffic_policy,
             path_policy: None,
+            path_rules,
+            rewrite_rules,
+            upstream_pool,
+            ip_access,
+            header_rules,
         };
 
         self.http_route_table.insert(source.to_owned(), new_route);
         self.save();
+        self.publish(&RouteType::Http);
     }
 
     pub fn add_https_route(
         &mut self,
         source: &String,
         target: &String,
-        ssl_cert_path: &String,
-        ssl_private_key_path: &String,
+        ssl_context: SslContext,
         input_traffic_policy: Option<ScopeTrafficPolicy>,
+        upstream_pool: Option<UpstreamPool>,
+        header_rules: Vec<HeaderRule>,
     ) {
-        if self.https_route_table.contains_key(source) {
-            self.https_route_table.remove(source);
-        }
+        // Path rules, rewrite rules and ip access lists are kept when a host entry is overwritten
+        let (path_rules, rewrite_rules, ip_access) = self
+            .https_route_table
+            .remove(source)
+            .map(|existing_route| {
+                (
+                    existing_route.path_rules,
+                    existing_route.rewrite_rules,
+                    existing_route.ip_access,
+                )
+            })
+            .unwrap_or_default();
 
         let new_route = HttpsRoute {
             target: target.to_owned(),
-            ssl_context: SslContext {
-                certificate_file_path: ssl_cert_path.to_owned(),
-                private_key_file_path: ssl_private_key_path.to_owned(),
-            },
+            ssl_context,
             traffic_policy: input_traffic_policy,
             path_policy: None,
+            path_rules,
+            rewrite_rules,
+            upstream_pool,
+            ip_access,
+            header_rules,
         };
 
         self.https_route_table.insert(source.to_owned(), new_route);
         self.save();
+        self.publish(&RouteType::Https);
     }
 
     pub fn add_iws_route(
@@ -187,45 +265,70 @@ impl Router {
         source: &String,
         serving_path: &String,
         input_traffic_policy: Option<ScopeTrafficPolicy>,
+        header_rules: Vec<HeaderRule>,
     ) {
-        if self.iws_route_table.contains_key(source) {
-            self.iws_route_table.remove(source);
-        }
+        // Path rules, rewrite rules and ip access lists are kept when a host entry is overwritten
+        let (path_rules, rewrite_rules, ip_access) = self
+            .iws_route_table
+            .remove(source)
+            .map(|existing_route| {
+                (
+                    existing_route.path_rules,
+                    existing_route.rewrite_rules,
+                    existing_route.ip_access,
+                )
+            })
+            .unwrap_or_default();
 
         let new_route = IwsRoute {
             serving_path: serving_path.to_owned(),
             traffic_policy: input_traffic_policy,
+            path_rules,
+            rewrite_rules,
+            ip_access,
+            header_rules,
         };
 
         self.iws_route_table.insert(source.to_owned(), new_route);
         self.save();
+        self.publish(&RouteType::Iws);
     }
 
     pub fn add_secure_iws_route(
         &mut self,
         source: &String,
         serving_path: &String,
-        ssl_cert_path: &String,
-        ssl_private_key_path: &String,
+        ssl_context: SslContext,
         input_traffic_policy: Option<ScopeTrafficPolicy>,
+        header_rules: Vec<HeaderRule>,
     ) {
-        if self.secure_iws_route_table.contains_key(source) {
-            self.secure_iws_route_table.remove(source);
-        }
+        // Path rules, rewrite rules and ip access lists are kept when a host entry is overwritten
+        let (path_rules, rewrite_rules, ip_access) = self
+            .secure_iws_route_table
+            .remove(source)
+            .map(|existing_route| {
+                (
+                    existing_route.path_rules,
+                    existing_route.rewrite_rules,
+                    existing_route.ip_access,
+                )
+            })
+            .unwrap_or_default();
 
         let new_route: SecureIwsRoute = SecureIwsRoute {
             serving_path: serving_path.to_owned(),
-
-            ssl_context: SslContext {
-                certificate_file_path: ssl_cert_path.to_owned(),
-                private_key_file_path: ssl_private_key_path.to_owned(),
-            },
+            ssl_context,
             traffic_policy: input_traffic_policy,
+            path_rules,
+            rewrite_rules,
+            ip_access,
+            header_rules,
         };
 
         self.secure_iws_route_table
             .insert(source.to_owned(), new_route);
         self.save();
+        self.publish(&RouteType::SecureIws);
     }
 
     pub fn delete_http_route(&mut self, source: String) {
@@ -234,6 +337,7 @@ impl Router {
         }
 
         self.save();
+        self.publish(&RouteType::Http);
     }
 
     pub fn delete_https_route(&mut self, source: String) {
@@ -242,6 +346,7 @@ impl Router {
         }
 
         self.save();
+        self.publish(&RouteType::Https);
     }
 
     pub fn delete_iws_route(&mut self, source: String) {
@@ -250,6 +355,7 @@ impl Router {
         }
 
         self.save();
+        self.publish(&RouteType::Iws);
     }
 
     pub fn delete_secure_iws_route(&mut self, source: String) {
@@ -258,6 +364,224 @@ impl Router {
         }
 
         self.save();
+        self.publish(&RouteType::SecureIws);
+    }
+
+    /// Adds a path rule into host entry of given route. If a rule with same path & match type exists, it is replaced in place.
+    /// Returns false if route does not exist
+    pub fn add_path_rule(
+        &mut self,
+        route_type: &RouteType,
+        source: &String,
+        path_rule: PathRule,
+    ) -> bool {
+        let Some(path_rules) = self.get_path_rules_mut(route_type, source) else {
+            return false;
+        };
+
+        let existing_rule_index = path_rules.iter().position(|each_rule| {
+            each_rule.path == path_rule.path && each_rule.match_type == path_rule.match_type
+        });
+
+        match existing_rule_index {
+            Some(index) => path_rules[index] = path_rule,
+            None => path_rules.push(path_rule),
+        }
+
+        self.save();
+        self.publish(route_type);
+        true
+    }
+
+    /// Removes path rules which have given path from host entry of given route.
+    /// Returns false if route or path rule does not exist
+    pub fn delete_path_rule(&mut self, route_type: &RouteType, source: &String, path: &String) -> bool {
+        let Some(path_rules) = self.get_path_rules_mut(route_type, source) else {
+            return false;
+        };
+
+        let rule_count = path_rules.len();
+        path_rules.retain(|each_rule| &each_rule.path != path);
+
+        if rule_count == path_rules.len() {
+            return false;
+        }
+
+        self.save();
+        self.publish(route_type);
+        true
+    }
+
+    /// Adds a rewrite rule at the end of host entry of given route. If a rule with same pattern exists, it is replaced in place.
+    /// Returns false if route does not exist
+    pub fn add_rewrite_rule(
+        &mut self,
+        route_type: &RouteType,
+        source: &String,
+        rewrite_rule: RewriteRule,
+    ) -> bool {
+        let Some(rewrite_rules) = self.get_rewrite_rules_mut(route_type, source) else {
+            return false;
+        };
+
+        let existing_rule_index = rewrite_rules
+            .iter()
+            .position(|each_rule| each_rule.pattern == rewrite_rule.pattern);
+
+        match existing_rule_index {
+            Some(index) => rewrite_rules[index] = rewrite_rule,
+            None => rewrite_rules.push(rewrite_rule),
+        }
+
+        self.save();
+        self.publish(route_type);
+        true
+    }
+
+    /// Removes rewrite rule which has given pattern from host entry of given route.
+    /// Returns false if route or rewrite rule does not exist
+    pub fn delete_rewrite_rule(&mut self, route_type: &RouteType, source: &String, pattern: &String) -> bool {
+        let Some(rewrite_rules) = self.get_rewrite_rules_mut(route_type, source) else {
+            return false;
+        };
+
+        let rule_count = rewrite_rules.len();
+        rewrite_rules.retain(|each_rule| &each_rule.pattern != pattern);
+
+        if rule_count == rewrite_rules.len() {
+            return false;
+        }
+
+        self.save();
+        self.publish(route_type);
+        true
+    }
+
+    /// Adds an entry into allow or deny list of given route.
+    /// Returns None if route does not exist, Some(false) if entry already exists
+    pub fn add_ip_access_entry(
+        &mut self,
+        route_type: &RouteType,
+        source: &String,
+        list_type: &IpAccessListType,
+        entry: &str,
+    ) -> Option<bool> {
+        let ip_access = self.get_ip_access_mut(route_type, source)?;
+        let is_added = ip_access
+            .get_or_insert_with(IpAccessSettings::default)
+            .add_entry(list_type, entry);
+
+        if is_added {
+            self.save();
+            self.publish(route_type);
+        }
+
+        Some(is_added)
+    }
+
+    /// Removes an entry from allow or deny list of given route.
+    /// Returns None if route does not exist, Some(false) if entry does not exist
+    pub fn remove_ip_access_entry(
+        &mut self,
+        route_type: &RouteType,
+        source: &String,
+        list_type: &IpAccessListType,
+        entry: &str,
+    ) -> Option<bool> {
+        let ip_access = self.get_ip_access_mut(route_type, source)?;
+        let is_removed = ip_access
+            .as_mut()
+            .is_some_and(|ip_access_settings| ip_access_settings.remove_entry(list_type, entry));
+
+        // Empty lists are not kept in route file
+        if ip_access.as_ref().is_some_and(|ip_access_settings| ip_access_settings.is_empty()) {
+            *ip_access = None;
+        }
+
+        if is_removed {
+            self.save();
+            self.publish(route_type);
+        }
+
+        Some(is_removed)
+    }
+
+    fn get_ip_access_mut(
+        &mut self,
+        route_type: &RouteType,
+        source: &String,
+    ) -> Option<&mut Option<IpAccessSettings>> {
+        match route_type {
+            RouteType::Http => self
+                .http_route_table
+                .get_mut(source)
+                .map(|route| &mut route.ip_access),
+            RouteType::Https => self
+                .https_route_table
+                .get_mut(source)
+                .map(|route| &mut route.ip_access),
+            RouteType::Iws => self
+                .iws_route_table
+                .get_mut(source)
+                .map(|route| &mut route.ip_access),
+            RouteType::SecureIws => self
+                .secure_iws_route_table
+                .get_mut(source)
+                .map(|route| &mut route.ip_access),
+            RouteType::All => None,
+        }
+    }
+
+    fn get_path_rules_mut(
+        &mut self,
+        route_type: &RouteType,
+        source: &String,
+    ) -> Option<&mut Vec<PathRule>> {
+        match route_type {
+            RouteType::Http => self
+                .http_route_table
+                .get_mut(source)
+                .map(|route| &mut route.path_rules),
+            RouteType::Https => self
+                .https_route_table
+                .get_mut(source)
+                .map(|route| &mut route.path_rules),
+            RouteType::Iws => self
+                .iws_route_table
+                .get_mut(source)
+                .map(|route| &mut route.path_rules),
+            RouteType::SecureIws => self
+                .secure_iws_route_table
+                .get_mut(source)
+                .map(|route| &mut route.path_rules),
+            RouteType::All => None,
+        }
+    }
+
+    fn get_rewrite_rules_mut(
+        &mut self,
+        route_type: &RouteType,
+        source: &String,
+    ) -> Option<&mut Vec<RewriteRule>> {
+        match route_type {
+            RouteType::Http => self
+                .http_route_table
+                .get_mut(source)
+                .map(|route| &mut route.rewrite_rules),
+            RouteType::Https => self
+                .https_route_table
+                .get_mut(source)
+                .map(|route| &mut route.rewrite_rules),
+            RouteType::Iws => self
+                .iws_route_table
+                .get_mut(source)
+                .map(|route| &mut route.rewrite_rules),
+            RouteType::SecureIws => self
+                .secure_iws_route_table
+                .get_mut(source)
+                .map(|route| &mut route.rewrite_rules),
+            RouteType::All => None,
+        }
     }
 
     pub fn get_http_routes(&self) -> HashMap<String, HttpRoute> {
@@ -275,4 +599,351 @@ impl Router {
     pub fn get_secure_iws_routes(&self) -> HashMap<String, SecureIwsRoute> {
         self.secure_iws_route_table.clone()
     }
+
+    /// Returns users of given file reference (e.g. `@vanguard/site.pem`): sources of routes whose certificate,
+    /// private key, client CA bundle or upstream TLS files are the file, then settings of given config which load it
+    pub fn find_ssl_file_users(&self, file_reference: &str, config: &Config) -> Vec<String> {
+        let ssl_contexts = self
+            .https_route_table
+            .iter()
+            .map(|(source, route)| (source, &route.ssl_context))
+            .chain(
+                self.secure_iws_route_table
+                    .iter()
+                    .map(|(source, route)| (source, &route.ssl_context)),
+            );
+
+        let ssl_context_users = ssl_contexts
+            .filter(|(_, ssl_context)| {
+                ssl_context.certificate_file_path == file_reference
+                    || ssl_context.private_key_file_path == file_reference
+                    || ssl_context
+                        .client_auth
+                        .as_ref()
+                        .map(|client_auth| client_auth.ca_bundle_path == file_reference)
+                        .unwrap_or(false)
+            })
+            .map(|(source, _)| source);
+
+        let traffic_policies = self
+            .http_route_table
+            .iter()
+            .map(|(source, route)| (source, &route.traffic_policy))
+            .chain(
+                self.https_route_table
+                    .iter()
+                    .map(|(source, route)| (source, &route.traffic_policy)),
+            );
+
+        let upstream_tls_users = traffic_policies
+            .filter(|(_, traffic_policy)| {
+                let upstream_tls_settings = traffic_policy
+                    .as_ref()
+                    .and_then(|traffic_policy| traffic_policy.upstream_settings.tls.as_ref());
+
+                Self::is_upstream_tls_file(upstream_tls_settings, file_reference)
+            })
+            .map(|(source, _)| source);
+
+        let mut users: Vec<String> = ssl_context_users.chain(upstream_tls_users).cloned().collect();
+        users.sort();
+        users.dedup();
+
+        let server_upstream_tls_settings = [
+            ("http_server.traffic_policy", &config.http_server.traffic_policy),
+            ("https_server.traffic_policy", &config.https_server.traffic_policy),
+        ];
+
+        if Self::is_upstream_tls_file(config.global_traffic_policy.upstream_settings.tls.as_ref(), file_reference) {
+            users.push("global_traffic_policy".to_string());
+        }
+
+        for (setting_name, traffic_policy) in server_upstream_tls_settings {
+            let upstream_tls_settings = traffic_policy
+                .as_ref()
+                .and_then(|traffic_policy| traffic_policy.upstream_settings.tls.as_ref());
+
+            if Self::is_upstream_tls_file(upstream_tls_settings, file_reference) {
+                users.push(setting_name.to_string());
+            }
+        }
+
+        let is_fallback_certificate = config
+            .https_server
+            .fallback_certificate
+            .as_ref()
+            .map(|fallback_certificate| {
+                fallback_certificate.certificate_file_path == file_reference
+                    || fallback_certificate.private_key_file_path == file_reference
+            })
+            .unwrap_or(false);
+
+        if is_fallback_certificate {
+            users.push("https_server.fallback_certificate".to_string());
+        }
+
+        if config.acme.ca_bundle_path.as_deref() == Some(file_reference) {
+            users.push("acme".to_string());
+        }
+
+        users
+    }
+
+    /// Returns true if CA bundle, client certificate or client private key of upstream TLS settings is given file
+    fn is_upstream_tls_file(upstream_tls_settings: Option<&UpstreamTlsSettings>, file_reference: &str) -> bool {
+        upstream_tls_settings
+            .map(|upstream_tls_settings| {
+                [
+                    &upstream_tls_settings.ca_bundle_path,
+                    &upstream_tls_settings.client_certificate_path,
+                    &upstream_tls_settings.client_private_key_path,
+                ]
+                .iter()
+                .any(|file_path| file_path.as_deref() == Some(file_reference))
+            })
+            .unwrap_or(false)
+    }
+
+    /// Builds host matcher for HTTP server which resolves request hosts into HTTP or IWS route sources
+    pub fn get_http_host_matcher(&self) -> HostMatcher<(RouteType, String)> {
+        let mut host_matcher = HostMatcher::default();
+
+        Self::register_sources(&mut host_matcher, RouteType::Http, self.http_route_table.keys());
+        Self::register_sources(&mut host_matcher, RouteType::Iws, self.iws_route_table.keys());
+
+        host_matcher
+    }
+
+    /// Builds host matcher for HTTPS server which resolves request hosts into HTTPS or Secure IWS route sources
+    pub fn get_https_host_matcher(&self) -> HostMatcher<(RouteType, String)> {
+        let mut host_matcher = HostMatcher::default();
+
+        Self::register_sources(&mut host_matcher, RouteType::Https, self.https_route_table.keys());
+        Self::register_sources(
+            &mut host_matcher,
+            RouteType::SecureIws,
+            self.secure_iws_route_table.keys(),
+        );
+
+        host_matcher
+    }
+
+    /// Builds path matchers of HTTP & IWS host entries which have path rules
+    pub fn get_http_path_matchers(&self) -> HashMap<(RouteType, String), PathMatcher> {
+        let mut path_matchers = HashMap::new();
+
+        for (source, http_route) in &self.http_route_table {
+            Self::register_path_rules(&mut path_matchers, RouteType::Http, source, &http_route.path_rules);
+        }
+
+        for (source, iws_route) in &self.iws_route_table {
+            Self::register_path_rules(&mut path_matchers, RouteType::Iws, source, &iws_route.path_rules);
+        }
+
+        path_matchers
+    }
+
+    /// Builds path matchers of HTTPS & Secure IWS host entries which have path rules
+    pub fn get_https_path_matchers(&self) -> HashMap<(RouteType, String), PathMatcher> {
+        let mut path_matchers = HashMap::new();
+
+        for (source, https_route) in &self.https_route_table {
+            Self::register_path_rules(&mut path_matchers, RouteType::Https, source, &https_route.path_rules);
+        }
+
+        for (source, secure_iws_route) in &self.secure_iws_route_table {
+            Self::register_path_rules(
+                &mut path_matchers,
+                RouteType::SecureIws,
+                source,
+                &secure_iws_route.path_rules,
+            );
+        }
+
+        path_matchers
+    }
+
+    /// Builds rewrite matchers of HTTP & IWS host entries which have rewrite rules
+    pub fn get_http_rewrite_matchers(&self) -> HashMap<(RouteType, String), RewriteMatcher> {
+        let mut rewrite_matchers = HashMap::new();
+
+        for (source, http_route) in &self.http_route_table {
+            Self::register_rewrite_rules(&mut rewrite_matchers, RouteType::Http, source, &http_route.rewrite_rules);
+        }
+
+        for (source, iws_route) in &self.iws_route_table {
+            Self::register_rewrite_rules(&mut rewrite_matchers, RouteType::Iws, source, &iws_route.rewrite_rules);
+        }
+
+        rewrite_matchers
+    }
+
+    /// Builds rewrite matchers of HTTPS & Secure IWS host entries which have rewrite rules
+    pub fn get_https_rewrite_matchers(&self) -> HashMap<(RouteType, String), RewriteMatcher> {
+        let mut rewrite_matchers = HashMap::new();
+
+        for (source, https_route) in &self.https_route_table {
+            Self::register_rewrite_rules(
+                &mut rewrite_matchers,
+                RouteType::Https,
+                source,
+                &https_route.rewrite_rules,
+            );
+        }
+
+        for (source, secure_iws_route) in &self.secure_iws_route_table {
+            Self::register_rewrite_rules(
+                &mut rewrite_matchers,
+                RouteType::SecureIws,
+                source,
+                &secure_iws_route.rewrite_rules,
+            );
+        }
+
+        rewrite_matchers
+    }
+
+    /// Builds ip access lists of HTTP & IWS host entries which define them
+    pub fn get_http_ip_access_lists(&self) -> HashMap<(RouteType, String), IpAccessList> {
+        let http_ip_access = self
+            .http_route_table
+            .iter()
+            .map(|(source, route)| (RouteType::Http, source, &route.ip_access));
+
+        let iws_ip_access = self
+            .iws_route_table
+            .iter()
+            .map(|(source, route)| (RouteType::Iws, source, &route.ip_access));
+
+        Self::build_ip_access_lists(http_ip_access.chain(iws_ip_access))
+    }
+
+    /// Builds ip access lists of HTTPS & Secure IWS host entries which define them
+    pub fn get_https_ip_access_lists(&self) -> HashMap<(RouteType, String), IpAccessList> {
+        let https_ip_access = self
+            .https_route_table
+            .iter()
+            .map(|(source, route)| (RouteType::Https, source, &route.ip_access));
+
+        let secure_iws_ip_access = self
+            .secure_iws_route_table
+            .iter()
+            .map(|(source, route)| (RouteType::SecureIws, source, &route.ip_access));
+
+        Self::build_ip_access_lists(https_ip_access.chain(secure_iws_ip_access))
+    }
+
+    fn build_ip_access_lists<'a>(
+        route_ip_access: impl Iterator<Item = (RouteType, &'a String, &'a Option<IpAccessSettings>)>,
+    ) -> HashMap<(RouteType, String), IpAccessList> {
+        route_ip_access
+            .filter_map(|(route_type, source, ip_access)| {
+                let ip_access_settings = ip_access.as_ref()?;
+                let scope_name = format!("route '{}'", source);
+
+                Some((
+                    (route_type, source.clone()),
+                    build_ip_access_list(&scope_name, ip_access_settings),
+                ))
+            })
+            .collect()
+    }
+
+    /// Builds load balancers of HTTP routes which have an upstream pool
+    pub fn get_http_load_balancers(&self) -> HashMap<String, LoadBalancer> {
+        let upstream_pools = self
+            .http_route_table
+            .iter()
+            .filter_map(|(source, route)| Some((source, route.upstream_pool.as_ref()?)));
+
+        Self::build_load_balancers(upstream_pools)
+    }
+
+    /// Builds load balancers of HTTPS routes which have an upstream pool
+    pub fn get_https_load_balancers(&self) -> HashMap<String, LoadBalancer> {
+        let upstream_pools = self
+            .https_route_table
+            .iter()
+            .filter_map(|(source, route)| Some((source, route.upstream_pool.as_ref()?)));
+
+        Self::build_load_balancers(upstream_pools)
+    }
+
+    fn build_load_balancers<'a>(
+        upstream_pools: impl Iterator<Item = (&'a String, &'a UpstreamPool)>,
+    ) -> HashMap<String, LoadBalancer> {
+        let mut load_balancers = HashMap::new();
+
+        for (source, upstream_pool) in upstream_pools {
+            match LoadBalancer::build(upstream_pool) {
+                Ok(load_balancer) => {
+                    load_balancers.insert(source.clone(), load_balancer);
+                }
+                Err(error_message) => {
+                    log_error!(
+                        "Upstream pool of route '{}' is skipped, route target is used instead: {}",
+                        source,
+                        error_message
+                    );
+                }
+            }
+        }
+
+        load_balancers
+    }
+
+    fn register_path_rules(
+        path_matchers: &mut HashMap<(RouteType, String), PathMatcher>,
+        route_type: RouteType,
+        source: &String,
+        path_rules: &[PathRule],
+    ) {
+        if path_rules.is_empty() {
+            return;
+        }
+
+        let (path_matcher, errors) = PathMatcher::build(path_rules);
+        for error_message in errors {
+            log_error!("Path rule of route '{}' is skipped: {}", source, error_message);
+        }
+
+        path_matchers.insert((route_type, source.clone()), path_matcher);
+    }
+
+    fn register_rewrite_rules(
+        rewrite_matchers: &mut HashMap<(RouteType, String), RewriteMatcher>,
+        route_type: RouteType,
+        source: &String,
+        rewrite_rules: &[RewriteRule],
+    ) {
+        if rewrite_rules.is_empty() {
+            return;
+        }
+
+        let (rewrite_matcher, errors) = RewriteMatcher::build(rewrite_rules);
+        for error_message in errors {
+            log_error!("Rewrite rule of route '{}' is skipped: {}", source, error_message);
+        }
+
+        rewrite_matchers.insert((route_type, source.clone()), rewrite_matcher);
+    }
+
+    fn register_sources<'a>(
+        host_matcher: &mut HostMatcher<(RouteType, String)>,
+        route_type: RouteType,
+        sources: impl Iterator<Item = &'a String>,
+    ) {
+        // Sorting sources keeps registration order (and so precedence on duplicates) deterministic
+        let mut sorted_sources: Vec<&String> = sources.collect();
+        sorted_sources.sort();
+
+        for source in sorted_sources {
+            let register_operation =
+                host_matcher.insert(source, (route_type.clone(), source.clone()));
+
+            if let Err(error_message) = register_operation {
+                log_error!("Route '{}' is skipped: {}", source, error_message);
+            }
+        }
+    }
 }
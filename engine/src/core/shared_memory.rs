@@ -1,11 +1,14 @@
+use arc_swap::ArcSwap;
 use once_cell::sync::Lazy;
 use std::sync::{Arc, RwLock};
 
 use crate::{
     core::{
-        connection_manager::ConnectionManager, http_proxy_manager::HttpProxyManager,
-        http_server::HttpServer, https_server::HttpsServer, log_service::LogService,
-        router::Router
+        access_log::AccessLogger, acme_challenges::AcmeChallengeStore, acme_manager::AcmeManager, certificate_monitor::CertificateMonitor, connection_manager::ConnectionManager, http_proxy_manager::HttpProxyManager,
+        http_server::HttpServer, https_server::HttpsServer, ip_access_control::IpAccessControl,
+        log_buffer::LogBuffer, log_service::LogService, metrics::MetricsRegistry, rate_limiter::RateLimiter,
+        route_snapshot::{HttpRouteSnapshot, HttpsRouteSnapshot}, router::Router,
+        upstream_health::UpstreamHealthRegistry
     },
     models::{boot_result::BootResult, reload_signal::ReloadSignal, shutdown_signal::ShutdownSignal},
     rpc_service::rpc_server::RPCServer,
@@ -37,3 +40,38 @@ pub static HTTP_PROXY_MANAGER: Lazy<HttpProxyManager> = Lazy::new(|| HttpProxyMa
 pub static SHUTDOWN_SIGNAL: Lazy<ShutdownSignal> = Lazy::new(|| ShutdownSignal::new());
 
 pub static RELOAD_SIGNAL: Lazy<ReloadSignal> = Lazy::new(|| ReloadSignal::new());
+
+// Route snapshots are read lock-free by servers on every request and swapped atomically by Router on route changes
+pub static HTTP_ROUTE_SNAPSHOT: Lazy<ArcSwap<HttpRouteSnapshot>> =
+    Lazy::new(|| ArcSwap::from_pointee(HttpRouteSnapshot::default()));
+
+pub static HTTPS_ROUTE_SNAPSHOT: Lazy<ArcSwap<HttpsRouteSnapshot>> =
+    Lazy::new(|| ArcSwap::from_pointee(HttpsRouteSnapshot::default()));
+
+pub static UPSTREAM_HEALTH: Lazy<UpstreamHealthRegistry> = Lazy::new(UpstreamHealthRegistry::default);
+
+// Global and per server ip access lists, swapped on every engine config change
+pub static IP_ACCESS_CONTROL: Lazy<ArcSwap<IpAccessControl>> =
+    Lazy::new(|| ArcSwap::from_pointee(IpAccessControl::default()));
+
+// Rate limit buckets of global, server and route scopes
+pub static RATE_LIMITER: Lazy<RateLimiter> = Lazy::new(RateLimiter::default);
+
+// Request, upstream and TLS counters exposed by the metrics listener
+pub static METRICS: Lazy<MetricsRegistry> = Lazy::new(MetricsRegistry::default);
+
+// Recent log records served to RPC clients (`get_logs` & `subscribe_logs`)
+pub static LOG_BUFFER: Lazy<LogBuffer> = Lazy::new(LogBuffer::default);
+
+// Access log format & sampling, compiled from access log settings at boot
+pub static ACCESS_LOGGER: Lazy<ArcSwap<AccessLogger>> =
+    Lazy::new(|| ArcSwap::from_pointee(AccessLogger::default()));
+
+// Answers of in-progress ACME challenges, served by HTTP (HTTP-01) and HTTPS (TLS-ALPN-01) servers
+pub static ACME_CHALLENGES: Lazy<AcmeChallengeStore> = Lazy::new(AcmeChallengeStore::default);
+
+// Issue & renewal state of ACME managed certificates
+pub static ACME_MANAGER: Lazy<AcmeManager> = Lazy::new(AcmeManager::default);
+
+// Alert state of route certificates which are close to expiry
+pub static CERTIFICATE_MONITOR: Lazy<CertificateMonitor> = Lazy::new(CertificateMonitor::default);
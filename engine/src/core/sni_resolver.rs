@@ -0,0 +1,91 @@
+use rustls::server::{ClientHello, ResolvesServerCert};
+use rustls::sign::CertifiedKey;
+use rustls::ServerConfig;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::core::{
+    acme_challenges::is_acme_tls_alpn_hello,
+    host_matcher::HostMatcher,
+    shared_memory::{ACME_CHALLENGES, HTTPS_ROUTE_SNAPSHOT},
+};
+use crate::models::degraded_route_report::DegradedRouteReport;
+
+/// Resolves TLS certificates by SNI (Server Name Indication) using same host pattern rules with router.
+/// Unlike `ResolvesServerCertUsingSni`, allows wildcard, suffix, regex and default ('*') route sources.
+/// Clients which do not send SNI (e.g. direct IP access) are served with default route's certificate if exist.
+/// Routes with client certificate authentication (mTLS) also keep their own TLS server configuration.
+/// Routes whose certificate can not be loaded are kept as degraded, so they do not break handshakes of other routes.
+#[derive(Default)]
+pub struct SniResolver {
+    certified_keys: HashMap<String, Arc<CertifiedKey>>,
+    server_configs: HashMap<String, Arc<ServerConfig>>,
+    degraded_routes: HashMap<String, DegradedRouteReport>,
+    host_matcher: HostMatcher<String>,
+}
+
+impl SniResolver {
+    /// Registers certified key for given route source (host or host pattern)
+    pub fn add(&mut self, source: &str, certified_key: Arc<CertifiedKey>) -> Result<(), String> {
+        self.host_matcher.insert(source, source.to_string())?;
+        self.certified_keys.insert(source.to_string(), certified_key);
+
+        Ok(())
+    }
+
+    /// Registers TLS server configuration used by handshakes of given route source instead of the server default
+    pub fn add_server_config(&mut self, source: &str, server_config: Arc<ServerConfig>) -> Result<(), String> {
+        self.host_matcher.insert(source, source.to_string())?;
+        self.server_configs.insert(source.to_string(), server_config);
+
+        Ok(())
+    }
+
+    /// Finds route source of given SNI server name, or default route if client did not send SNI
+    pub fn find_route_source(&self, server_name: Option<&str>) -> Option<&String> {
+        match server_name {
+            Some(server_name) => self.host_matcher.find(server_name),
+            None => self.host_matcher.find_default(),
+        }
+    }
+
+    pub fn find_server_config(&self, route_source: &str) -> Option<Arc<ServerConfig>> {
+        self.server_configs.get(route_source).cloned()
+    }
+
+    /// Marks route as degraded. Its certificate should be registered separately if a fallback is served
+    pub fn add_degraded_route(&mut self, degraded_route: DegradedRouteReport) {
+        self.degraded_routes.insert(degraded_route.source.clone(), degraded_route);
+    }
+
+    /// Returns degraded routes sorted by source
+    pub fn get_degraded_routes(&self) -> Vec<DegradedRouteReport> {
+        let mut degraded_routes: Vec<DegradedRouteReport> = self.degraded_routes.values().cloned().collect();
+
+        degraded_routes.sort_by(|first, second| first.source.cmp(&second.source));
+        degraded_routes
+    }
+}
+
+impl ResolvesServerCert for SniResolver {
+    fn resolve(&self, client_hello: ClientHello) -> Option<Arc<CertifiedKey>> {
+        let route_source = self.find_route_source(client_hello.server_name())?;
+
+        self.certified_keys.get(route_source).cloned()
+    }
+}
+
+/// Resolves certificates by the SNI resolver of the currently published HTTPS route snapshot,
+/// so that certificate changes are applied to new TLS handshakes without restarting the listener.
+/// TLS-ALPN-01 validation handshakes are answered with ACME challenge certificates instead.
+pub struct LiveSniResolver;
+
+impl ResolvesServerCert for LiveSniResolver {
+    fn resolve(&self, client_hello: ClientHello) -> Option<Arc<CertifiedKey>> {
+        if is_acme_tls_alpn_hello(&client_hello) {
+            return ACME_CHALLENGES.find_tls_alpn_certificate(client_hello.server_name()?);
+        }
+
+        HTTPS_ROUTE_SNAPSHOT.load().sni_resolver.resolve(client_hello)
+    }
+}
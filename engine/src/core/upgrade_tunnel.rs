@@ -0,0 +1,150 @@
+use std::io;
+use std::time::Duration;
+
+use hyper::upgrade::{OnUpgrade, Upgraded};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use crate::{constants::Constants, core::shared_memory::CONNECTION_MANAGER, log_error, log_info};
+
+/// Reason of a closed upgraded connection
+enum TunnelClose {
+    Completed,
+    IdleTimeout,
+}
+
+/// Waits until both client and upstream connections are switched to the new protocol (after `101 Switching Protocols`),
+/// then copies bytes between them in both directions. Connection is closed when both sides close it, or
+/// no byte passes in any direction within `idle_timeout`
+pub fn spawn_upgrade_tunnel(
+    protocol_name: &'static str,
+    request_host: String,
+    upstream_target: String,
+    client_upgrade: OnUpgrade,
+    upstream_upgrade: OnUpgrade,
+    idle_timeout: Duration,
+) {
+    tokio::spawn(async move {
+        let (client_connection, upstream_connection) = match tokio::try_join!(client_upgrade, upstream_upgrade) {
+            Ok(upgraded_connections) => upgraded_connections,
+            Err(error) => {
+                log_error!(
+                    "{} |UPGRADE FAILED| from {} to {}: {}",
+                    protocol_name,
+                    request_host,
+                    upstream_target,
+                    error
+                );
+                return;
+            }
+        };
+
+        let _upgraded_connection_lock = CONNECTION_MANAGER.acquire_upgraded_connection();
+
+        log_info!(
+            "{} |UPGRADE OPENED| from {} to {}",
+            protocol_name,
+            request_host,
+            upstream_target
+        );
+
+        let mut transferred_bytes = (0, 0);
+        let splice_result = splice(
+            client_connection,
+            upstream_connection,
+            idle_timeout,
+            &mut transferred_bytes,
+        )
+        .await;
+
+        let (client_bytes, upstream_bytes) = transferred_bytes;
+        match splice_result {
+            Ok(TunnelClose::Completed) => log_info!(
+                "{} |UPGRADE CLOSED| from {} to {} ({} bytes sent, {} bytes received)",
+                protocol_name,
+                request_host,
+                upstream_target,
+                client_bytes,
+                upstream_bytes
+            ),
+            Ok(TunnelClose::IdleTimeout) => log_info!(
+                "{} |UPGRADE IDLE TIMEOUT| from {} to {} ({} bytes sent, {} bytes received)",
+                protocol_name,
+                request_host,
+                upstream_target,
+                client_bytes,
+                upstream_bytes
+            ),
+            Err(error) => log_info!(
+                "{} |UPGRADE ABORTED| from {} to {}: {} ({} bytes sent, {} bytes received)",
+                protocol_name,
+                request_host,
+                upstream_target,
+                error,
+                client_bytes,
+                upstream_bytes
+            ),
+        }
+    });
+}
+
+/// Copies bytes in both directions. A side which finishes sending is half closed on the other side,
+/// so protocols relying on half close keep working. `transferred_bytes` is (client to upstream, upstream to client)
+async fn splice(
+    client_connection: Upgraded,
+    upstream_connection: Upgraded,
+    idle_timeout: Duration,
+    transferred_bytes: &mut (u64, u64),
+) -> io::Result<TunnelClose> {
+    let (mut client_reader, mut client_writer) = tokio::io::split(client_connection);
+    let (mut upstream_reader, mut upstream_writer) = tokio::io::split(upstream_connection);
+
+    let mut client_buffer = vec![0u8; Constants::UPGRADED_CONNECTION_BUFFER_SIZE];
+    let mut upstream_buffer = vec![0u8; Constants::UPGRADED_CONNECTION_BUFFER_SIZE];
+
+    let mut is_client_open = true;
+    let mut is_upstream_open = true;
+
+    while is_client_open || is_upstream_open {
+        // Reads are cancel safe, so the side which did not win the race loses no data
+        let next_read = tokio::time::timeout(idle_timeout, async {
+            tokio::select! {
+                read = client_reader.read(&mut client_buffer), if is_client_open => (true, read),
+                read = upstream_reader.read(&mut upstream_buffer), if is_upstream_open => (false, read),
+            }
+        })
+        .await;
+
+        let (is_client_read, read) = match next_read {
+            Ok(next_read) => next_read,
+            Err(_) => return Ok(TunnelClose::IdleTimeout),
+        };
+
+        // Clients often close TLS without `close_notify`, which is the same as a closed connection here
+        let read_bytes = match read {
+            Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => 0,
+            read => read?,
+        };
+
+        match (is_client_read, read_bytes) {
+            // Shutdown fails if the other side is already closed, which needs no handling
+            (true, 0) => {
+                is_client_open = false;
+                let _ = upstream_writer.shutdown().await;
+            }
+            (false, 0) => {
+                is_upstream_open = false;
+                let _ = client_writer.shutdown().await;
+            }
+            (true, read_bytes) => {
+                upstream_writer.write_all(&client_buffer[..read_bytes]).await?;
+                transferred_bytes.0 += read_bytes as u64;
+            }
+            (false, read_bytes) => {
+                client_writer.write_all(&upstream_buffer[..read_bytes]).await?;
+                transferred_bytes.1 += read_bytes as u64;
+            }
+        }
+    }
+
+    Ok(TunnelClose::Completed)
+}
@@ -0,0 +1,75 @@
+use hyper::client::HttpConnector;
+use hyper::http::uri::Scheme;
+use hyper::service::Service;
+use hyper::Uri;
+use hyper_rustls::MaybeHttpsStream;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::net::TcpStream;
+use tokio_rustls::rustls::{ClientConfig, ServerName};
+use tokio_rustls::TlsConnector;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Connector of upstream HTTP clients.
+/// Opens plain TCP connections for `http://` upstreams and TLS connections for `https://` upstreams.
+/// Unlike `hyper_rustls::HttpsConnector`, server name of TLS handshake can be overridden (SNI override).
+#[derive(Clone)]
+pub struct UpstreamConnector {
+    http_connector: HttpConnector,
+    tls_connector: TlsConnector,
+    server_name_override: Option<ServerName>,
+}
+
+impl UpstreamConnector {
+    pub fn new(
+        http_connector: HttpConnector,
+        tls_config: ClientConfig,
+        server_name_override: Option<ServerName>,
+    ) -> Self {
+        Self {
+            http_connector,
+            tls_connector: TlsConnector::from(Arc::new(tls_config)),
+            server_name_override,
+        }
+    }
+}
+
+impl Service<Uri> for UpstreamConnector {
+    type Response = MaybeHttpsStream<TcpStream>;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.http_connector.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        let is_tls = uri.scheme() == Some(&Scheme::HTTPS);
+
+        let server_name = match &self.server_name_override {
+            Some(server_name) => Ok(server_name.clone()),
+            None => {
+                let host = uri.host().unwrap_or_default().trim_matches(|c| c == '[' || c == ']');
+                ServerName::try_from(host)
+                    .map_err(|_| format!("Upstream host '{}' is not a valid server name", host))
+            }
+        };
+
+        let connecting = self.http_connector.call(uri);
+        let tls_connector = self.tls_connector.clone();
+
+        Box::pin(async move {
+            let tcp_stream = connecting.await?;
+
+            if !is_tls {
+                return Ok(MaybeHttpsStream::Http(tcp_stream));
+            }
+
+            let tls_stream = tls_connector.connect(server_name?, tcp_stream).await?;
+            Ok(MaybeHttpsStream::Https(tls_stream))
+        })
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use crate::{
+    common::enums::{route_type::RouteType, upstream_health_status::UpstreamHealthStatus},
+    log_info, log_warn,
+    models::{
+        settings::{
+            health_check_settings::HealthCheckSettings,
+            outlier_detection_settings::OutlierDetectionSettings,
+        },
+        upstream_health_report::UpstreamHealthReport,
+    },
+    utils::time_utility::get_current_timestamp,
+};
+
+struct UpstreamHealthState {
+    is_active_check_enabled: bool,
+    is_probed: bool,
+    is_healthy: bool,
+    consecutive_successes: u32,
+    consecutive_failures: u32,
+    passive_failures: u32,
+    ejected_until: Option<Instant>,
+    next_check_at: Instant,
+    last_checked_at: Option<i64>,
+    last_error: Option<String>,
+}
+
+impl Default for UpstreamHealthState {
+    fn default() -> Self {
+        Self {
+            is_active_check_enabled: false,
+            is_probed: false,
+            is_healthy: true,
+            consecutive_successes: 0,
+            consecutive_failures: 0,
+            passive_failures: 0,
+            ejected_until: None,
+            next_check_at: Instant::now(),
+            last_checked_at: None,
+            last_error: None,
+        }
+    }
+}
+
+impl UpstreamHealthState {
+    fn is_ejected(&self) -> bool {
+        matches!(self.ejected_until, Some(ejected_until) if ejected_until > Instant::now())
+    }
+
+    fn get_status(&self) -> UpstreamHealthStatus {
+        if self.is_ejected() {
+            UpstreamHealthStatus::Ejected
+        } else if !self.is_healthy {
+            UpstreamHealthStatus::Unhealthy
+        } else if self.is_active_check_enabled && !self.is_probed {
+            UpstreamHealthStatus::Unknown
+        } else {
+            UpstreamHealthStatus::Healthy
+        }
+    }
+}
+
+/// Keeps health of every upstream target referenced by route pools.
+/// Active checks flip targets between healthy and unhealthy, passive outlier detection ejects them for a while.
+/// Targets which are not tracked are considered available.
+#[derive(Default)]
+pub struct UpstreamHealthRegistry {
+    states: RwLock<HashMap<String, UpstreamHealthState>>,
+}
+
+impl UpstreamHealthRegistry {
+    /// Returns whether the target can be selected by load balancers
+    pub fn is_available(&self, target: &str) -> bool {
+        let states = self.states.read().unwrap();
+
+        match states.get(target) {
+            Some(state) => state.is_healthy && !state.is_ejected(),
+            None => true,
+        }
+    }
+
+    /// Drops targets which are not used by any pool anymore and resets active check state
+    /// of targets whose pools do not define health checks
+    pub fn sync_targets(&self, targets: &HashSet<String>, actively_checked_targets: &HashSet<String>) {
+        let mut states = self.states.write().unwrap();
+
+        states.retain(|target, _| targets.contains(target));
+
+        for target in targets {
+            let state = states.entry(target.clone()).or_default();
+            let is_active_check_enabled = actively_checked_targets.contains(target);
+
+            if state.is_active_check_enabled && !is_active_check_enabled {
+                state.is_probed = false;
+                state.is_healthy = true;
+                state.consecutive_successes = 0;
+                state.consecutive_failures = 0;
+            }
+
+            state.is_active_check_enabled = is_active_check_enabled;
+        }
+    }
+
+    /// Reserves the next active check of the target. Returns false if the target is not due yet
+    pub fn try_schedule_check(&self, target: &str, interval: u64) -> bool {
+        let mut states = self.states.write().unwrap();
+        let state = states.entry(target.to_string()).or_default();
+
+        let now = Instant::now();
+        if state.next_check_at > now {
+            return false;
+        }
+
+        state.next_check_at = now + Duration::from_secs(interval);
+        true
+    }
+
+    /// Records result of an active health check probe
+    pub fn record_probe(
+        &self,
+        target: &str,
+        probe_result: Result<(), String>,
+        health_check: &HealthCheckSettings,
+    ) {
+        let mut states = self.states.write().unwrap();
+        let state = match states.get_mut(target) {
+            Some(state) => state,
+            None => return,
+        };
+
+        state.is_probed = true;
+        state.last_checked_at = Some(get_current_timestamp());
+
+        match probe_result {
+            Ok(()) => {
+                state.consecutive_successes += 1;
+                state.consecutive_failures = 0;
+                state.last_error = None;
+
+                if !state.is_healthy
+                    && state.consecutive_successes >= health_check.get_healthy_threshold()
+                {
+                    state.is_healthy = true;
+                    log_info!("Upstream {} is healthy again", target);
+                }
+            }
+            Err(error) => {
+                state.consecutive_failures += 1;
+                state.consecutive_successes = 0;
+
+                if state.is_healthy
+                    && state.consecutive_failures >= health_check.get_unhealthy_threshold()
+                {
+                    state.is_healthy = false;
+                    log_warn!("Upstream {} is marked as unhealthy: {}", target, &error);
+                }
+
+                state.last_error = Some(error);
+            }
+        }
+    }
+
+    /// Records outcome of a proxied request. Consecutive failures eject the target for the configured time
+    pub fn record_passive(
+        &self,
+        target: &str,
+        is_failure: bool,
+        outlier_detection: &OutlierDetectionSettings,
+    ) {
+        // Successful requests of a target without failures are the common case, avoid taking write lock for them
+        if !is_failure {
+            let states = self.states.read().unwrap();
+            let has_failures = states
+                .get(target)
+                .is_some_and(|state| state.passive_failures > 0);
+
+            if !has_failures {
+                return;
+            }
+        }
+
+        let mut states = self.states.write().unwrap();
+        let state = states.entry(target.to_string()).or_default();
+
+        if !is_failure {
+            state.passive_failures = 0;
+            return;
+        }
+
+        state.passive_failures += 1;
+
+        if state.passive_failures >= outlier_detection.get_consecutive_failures() {
+            let ejection_time = outlier_detection.get_ejection_time();
+
+            state.passive_failures = 0;
+            state.ejected_until = Some(Instant::now() + Duration::from_secs(ejection_time));
+
+            log_warn!(
+                "Upstream {} is ejected for {} seconds after {} consecutive failed requests",
+                target,
+                ejection_time,
+                outlier_detection.get_consecutive_failures()
+            );
+        }
+    }
+
+    /// Builds health report of a target used by given route
+    pub fn get_report(&self, route_type: RouteType, source: &str, target: &str) -> UpstreamHealthReport {
+        let states = self.states.read().unwrap();
+        let default_state = UpstreamHealthState::default();
+        let state = states.get(target).unwrap_or(&default_state);
+
+        let ejection_remaining_seconds = state
+            .ejected_until
+            .filter(|_| state.is_ejected())
+            .map(|ejected_until| ejected_until.saturating_duration_since(Instant::now()).as_secs());
+
+        UpstreamHealthReport {
+            route_type,
+            source: source.to_string(),
+            target: target.to_string(),
+            status: state.get_status(),
+            is_active_check_enabled: state.is_active_check_enabled,
+            consecutive_successes: state.consecutive_successes,
+            consecutive_failures: state.consecutive_failures,
+            passive_failures: state.passive_failures,
+            ejection_remaining_seconds,
+            last_checked_at: state.last_checked_at,
+            last_error: state.last_error.clone(),
+        }
+    }
+}
@@ -15,6 +15,10 @@ use clap::Parser;
 use crate::assets::banner::print_banner;
 use crate::assets::startup_disclaimer::print_startup_disclaimer;
 
+use crate::core::acme_manager::AcmeManager;
+use crate::core::certificate_monitor::CertificateMonitor;
+use crate::core::health_checker::HealthChecker;
+use crate::core::metrics_server::MetricsServer;
 use crate::core::shared_memory::{HTTPS_SERVER, HTTP_SERVER, RPC_SERVER, SHUTDOWN_SIGNAL};
 use crate::models::application_parameters::ApplicationParameters;
 use crate::models::boot_result::BootResult;
@@ -48,6 +52,23 @@ async fn main() {
         jrpc_server.start().await;
     });
 
+    let metrics_server = MetricsServer::init(&boot_result.config.metrics_server);
+    let metrics_handle = tokio::spawn(async move {
+        metrics_server.start().await;
+    });
+
+    let health_checker_handle = tokio::spawn(async move {
+        HealthChecker::start().await;
+    });
+
+    let acme_manager_handle = tokio::spawn(async move {
+        AcmeManager::start().await;
+    });
+
+    let certificate_monitor_handle = tokio::spawn(async move {
+        CertificateMonitor::start().await;
+    });
+
     tokio::select! {
         _on_console_interrupt = tokio::signal::ctrl_c() => {
             println!("\n[Vanguard] SIGINT System Call received. Initiating graceful shutdown...");
@@ -61,7 +82,15 @@ async fn main() {
         }
     }
 
-    let _wait_for_join_all = tokio::join!(http_handle, https_handle, jrpc_handle);
+    let _wait_for_join_all = tokio::join!(
+        http_handle,
+        https_handle,
+        jrpc_handle,
+        metrics_handle,
+        health_checker_handle,
+        acme_manager_handle,
+        certificate_monitor_handle
+    );
     println!("[Vanguard] All servers closed cleanly.");
     println!("[Vanguard] Engine process exit.");
 }
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::enums::acme_certificate_status::AcmeCertificateStatus;
+
+/// Issue state of the ACME certificate of a route. Times are unix timestamps
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AcmeCertificateReport {
+    pub source: String,
+    pub status: AcmeCertificateStatus,
+    pub not_after: Option<i64>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: Option<i64>,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::enums::{certificate_expiry_status::CertificateExpiryStatus, route_type::RouteType};
+
+/// Expiry of the leaf certificate served by an HTTPS or Secure IWS route. Times are unix timestamps
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CertificateExpiryReport {
+    pub source: String,
+    pub route_type: RouteType,
+    pub certificate_file_path: String,
+    pub status: CertificateExpiryStatus,
+    pub subject: Option<String>,
+    pub not_after: Option<i64>,
+    pub days_left: Option<i64>,
+    pub error: Option<String>,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Details of a X.509 certificate. Validity times are unix timestamps
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CertificateInfo {
+    pub subject: String,
+    pub common_name: Option<String>,
+    pub issuer: String,
+    pub subject_alt_names: Vec<String>,
+    pub serial_number: String,
+    pub not_before: i64,
+    pub not_after: i64,
+}
@@ -1,10 +1,23 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::settings::logger_settings::LoggerSettings;
+use crate::{
+    common::enums::ip_access_scope::IpAccessScope,
+    models::settings::{
+        access_log_settings::AccessLogSettings, acme_settings::AcmeSettings,
+        certificate_monitor_settings::CertificateMonitorSettings, ip_access_settings::IpAccessSettings,
+        logger_settings::LoggerSettings,
+    },
+    core::{
+        certificate_monitor::validate_certificate_monitor_settings,
+        forwarded_headers::validate_forwarded_header_settings,
+    },
+    utils::tls_utility::validate_tls_settings,
+};
 
 use super::{
     http_server_config::HttpServerConfig,
     https_server_config::HttpsServerConfig,
+    metrics_server_config::MetricsServerConfig,
     rpc_server_config::RpcServerConfig,
     traffic_policy::{
         global_traffic_policy::GlobalTrafficPolicy, scope_traffic_policy::ScopeTrafficPolicy,
@@ -18,6 +31,18 @@ pub struct Config {
     pub rpc_server: RpcServerConfig,
     pub logger: LoggerSettings,
 
+    #[serde(default)]
+    pub access_log: AccessLogSettings,
+
+    #[serde(default)]
+    pub metrics_server: MetricsServerConfig,
+
+    #[serde(default)]
+    pub acme: AcmeSettings,
+
+    #[serde(default)]
+    pub certificate_monitor: CertificateMonitorSettings,
+
     #[serde(default = "default_global_traffic_policy")]
     pub global_traffic_policy: GlobalTrafficPolicy,
 }
@@ -29,6 +54,10 @@ impl Default for Config {
             https_server: Default::default(),
             rpc_server: Default::default(),
             logger: Default::default(),
+            access_log: Default::default(),
+            metrics_server: Default::default(),
+            acme: Default::default(),
+            certificate_monitor: Default::default(),
             global_traffic_policy: GlobalTrafficPolicy::global(),
         }
     }
@@ -39,6 +68,20 @@ fn default_global_traffic_policy() -> GlobalTrafficPolicy {
 }
 
 impl Config {
+    /// Returns ip access settings of given config level. Route level lists are kept by Router
+    pub fn get_ip_access_mut(&mut self, scope: &IpAccessScope) -> Option<&mut IpAccessSettings> {
+        match scope {
+            IpAccessScope::Global => Some(&mut self.global_traffic_policy.ip_access),
+            IpAccessScope::HttpServer => {
+                Some(self.http_server.ip_access.get_or_insert_with(IpAccessSettings::default))
+            }
+            IpAccessScope::HttpsServer => {
+                Some(self.https_server.ip_access.get_or_insert_with(IpAccessSettings::default))
+            }
+            IpAccessScope::Route => None,
+        }
+    }
+
     pub fn validate(&self) -> Result<(), String> {
         if self.http_server.ip_address.is_empty() {
             return Err("HTTP server IP address is empty".into());
@@ -53,6 +96,9 @@ impl Config {
         if self.https_server.port == 0 {
             return Err("HTTPS server port is 0".into());
         }
+        if let Err(error) = validate_tls_settings(&self.https_server.get_tls_settings()) {
+            return Err(error.message);
+        }
 
         if self.rpc_server.ip_address.is_empty() {
             return Err("RPC server IP address is empty".into());
@@ -66,6 +112,24 @@ impl Config {
             return Err("RPC server private key is empty".into());
         }
 
+        if !(0.0..=1.0).contains(&self.access_log.get_sample_rate()) {
+            return Err("Access log sample rate should be between 0 and 1".into());
+        }
+
+        if self.acme.is_active && self.acme.renew_before_days == Some(0) {
+            return Err("ACME renew before days should be a positive number".into());
+        }
+
+        validate_certificate_monitor_settings(&self.certificate_monitor)?;
+
+        validate_forwarded_header_settings(&self.global_traffic_policy.forwarded_headers)?;
+        for server_traffic_policy in [&self.http_server.traffic_policy, &self.https_server.traffic_policy]
+            .into_iter()
+            .flatten()
+        {
+            validate_forwarded_header_settings(&server_traffic_policy.forwarded_headers)?;
+        }
+
         Ok(())
     }
 
@@ -76,6 +140,9 @@ impl Config {
             http1_protocol_settings: self.global_traffic_policy.http1_protocol_settings.clone(),
             http2_protocol_settings: self.global_traffic_policy.http2_protocol_settings.clone(),
             upstream_settings: self.global_traffic_policy.upstream_settings.clone(),
+            rate_limit_settings: self.global_traffic_policy.rate_limit_settings.clone(),
+            forwarded_headers: self.global_traffic_policy.forwarded_headers.clone(),
+            access_log_sample_rate: None,
         };
 
         if let Some(ref overrides) = self.http_server.traffic_policy {
@@ -92,6 +159,9 @@ impl Config {
             http1_protocol_settings: self.global_traffic_policy.http1_protocol_settings.clone(),
             http2_protocol_settings: self.global_traffic_policy.http2_protocol_settings.clone(),
             upstream_settings: self.global_traffic_policy.upstream_settings.clone(),
+            rate_limit_settings: self.global_traffic_policy.rate_limit_settings.clone(),
+            forwarded_headers: self.global_traffic_policy.forwarded_headers.clone(),
+            access_log_sample_rate: None,
         };
 
         if let Some(ref overrides) = self.https_server.traffic_policy {
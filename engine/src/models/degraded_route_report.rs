@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::enums::route_type::RouteType;
+
+/// HTTPS or Secure IWS route whose certificate or TLS settings can not be loaded.
+/// Other routes are served as usual, degraded route is served with fallback (or default) certificate if possible
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DegradedRouteReport {
+    pub source: String,
+    pub route_type: RouteType,
+    pub reason: String,
+    pub is_fallback_certificate_served: bool,
+}
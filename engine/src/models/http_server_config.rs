@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    constants::Constants, models::traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
+    constants::Constants,
+    models::{
+        settings::ip_access_settings::IpAccessSettings,
+        traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
+    },
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -13,6 +17,10 @@ pub struct HttpServerConfig {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub traffic_policy: Option<ScopeTrafficPolicy>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_access: Option<IpAccessSettings>,
 }
 
 impl Default for HttpServerConfig {
@@ -22,6 +30,7 @@ impl Default for HttpServerConfig {
             ip_address: Constants::DEFAULT_HTTP_IP.to_string(),
             port: Constants::DEFAULT_HTTP_PORT,
             traffic_policy: None,
+            ip_access: None,
         }
     }
 }
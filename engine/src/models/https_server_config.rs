@@ -1,7 +1,14 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    constants::Constants, models::traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
+    constants::Constants,
+    models::{
+        settings::{
+            fallback_certificate_settings::FallbackCertificateSettings, ip_access_settings::IpAccessSettings,
+            tls_settings::TlsSettings,
+        },
+        traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
+    },
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -13,6 +20,18 @@ pub struct HttpsServerConfig {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub traffic_policy: Option<ScopeTrafficPolicy>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_access: Option<IpAccessSettings>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsSettings>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback_certificate: Option<FallbackCertificateSettings>,
 }
 
 impl Default for HttpsServerConfig {
@@ -22,6 +41,9 @@ impl Default for HttpsServerConfig {
             ip_address: Constants::DEFAULT_HTTPS_IP.to_string(),
             port: Constants::DEFAULT_HTTPS_PORT,
             traffic_policy: None,
+            ip_access: None,
+            tls: None,
+            fallback_certificate: None,
         }
     }
 }
@@ -30,4 +52,8 @@ impl HttpsServerConfig {
     pub fn get_endpoint(&self) -> String {
         format!("{}:{}", self.ip_address, self.port)
     }
+
+    pub fn get_tls_settings(&self) -> TlsSettings {
+        self.tls.clone().unwrap_or_default()
+    }
 }
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// Single log record kept in memory for RPC clients
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LogRecord {
+    pub id: u64,
+    pub timestamp: i64,
+    pub level: String,
+    pub message: String,
+}
+
+/// Conditions of `get_logs` & `subscribe_logs`. Empty fields match every record
+#[derive(Debug, Clone, Default)]
+pub struct LogFilter {
+    pub levels: Vec<String>,
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+    /// Lowercase text searched case-insensitively in record messages
+    pub contains: Option<String>,
+}
+
+impl LogFilter {
+    pub fn matches(&self, record: &LogRecord) -> bool {
+        if !self.levels.is_empty() && !self.levels.contains(&record.level) {
+            return false;
+        }
+
+        if self.since.is_some_and(|since| record.timestamp < since) {
+            return false;
+        }
+
+        if self.until.is_some_and(|until| record.timestamp > until) {
+            return false;
+        }
+
+        match &self.contains {
+            Some(text) => record.message.to_lowercase().contains(text),
+            None => true,
+        }
+    }
+}
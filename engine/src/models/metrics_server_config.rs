@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::constants::Constants;
+
+/// Local listener which exposes engine metrics in Prometheus text format on `/metrics`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct MetricsServerConfig {
+    pub is_active: bool,
+    pub ip_address: String,
+    pub port: u16,
+}
+
+impl Default for MetricsServerConfig {
+    fn default() -> Self {
+        Self {
+            is_active: Constants::DEFAULT_METRICS_IS_ACTIVE,
+            ip_address: Constants::DEFAULT_METRICS_IP.to_string(),
+            port: Constants::DEFAULT_METRICS_PORT,
+        }
+    }
+}
+
+impl MetricsServerConfig {
+    pub fn get_endpoint(&self) -> String {
+        format!("{}:{}", self.ip_address, self.port)
+    }
+}
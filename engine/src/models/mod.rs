@@ -4,9 +4,18 @@ pub mod config;
 pub mod http_server_config;
 pub mod https_server_config;
 pub mod rpc_server_config;
+pub mod metrics_server_config;
+pub mod metrics_snapshot;
 pub mod ssl_context;
 pub mod shutdown_signal;
 pub mod settings;
 pub mod traffic_policy;
 pub mod reload_signal;
-pub mod route;
\ No newline at end of file
+pub mod route;
+pub mod upstream_health_report;
+pub mod log_record;
+pub mod certificate_info;
+pub mod ssl_file;
+pub mod acme_certificate_report;
+pub mod degraded_route_report;
+pub mod certificate_expiry_report;
@@ -2,24 +2,25 @@ use tokio::sync::watch::channel as TokioChannel;
 use tokio::sync::watch::Receiver as TokioReceiver;
 use tokio::sync::watch::Sender as TokioSender;
 
+/// Reload events are published as an increasing generation number.
+/// Subscribers wait for `changed()`, so a trigger can not be missed between two subscriber polls.
 #[derive(Clone)]
 pub struct ReloadSignal {
-    pub publisher: TokioSender<bool>,
-    pub subscriber: TokioReceiver<bool>,
+    pub publisher: TokioSender<u64>,
 }
 
 impl ReloadSignal {
     pub fn new() -> Self {
-        let (publisher, subscriber) = TokioChannel(false);
-        Self {
-            publisher,
-            subscriber,
-        }
+        let (publisher, _) = TokioChannel(0);
+        Self { publisher }
     }
 
     pub fn trigger(&self) {
-        let _ = self.publisher.send(true);
-        // Reset to false so future waits can trigger again
-        let _ = self.publisher.send(false);
+        self.publisher.send_modify(|generation| *generation += 1);
+    }
+
+    /// Returns a receiver which only observes reloads triggered after subscription
+    pub fn subscribe(&self) -> TokioReceiver<u64> {
+        self.publisher.subscribe()
     }
 }
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::enums::{header_rule_action::HeaderRuleAction, header_rule_phase::HeaderRulePhase};
+
+/// A header change of a host entry. Rules are applied in their order.
+/// `value` may contain `$client_ip`, `$host`, `$request_id` and `$route_source` variables
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct HeaderRule {
+    pub action: HeaderRuleAction,
+
+    #[serde(default)]
+    pub phase: HeaderRulePhase,
+
+    pub name: String,
+
+    /// Value of `add` & `set` rules
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Header name of `rename` rules
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_name: Option<String>,
+}
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::models::{
+    route::{header_rule::HeaderRule, path_rule::PathRule, rewrite_rule::RewriteRule, upstream_pool::UpstreamPool},
     ssl_context::SslContext,
+    settings::ip_access_settings::IpAccessSettings,
     traffic_policy::{
         scope_traffic_policy::ScopeTrafficPolicy, path_traffic_policy::PathTrafficPolicy,
     },
@@ -19,4 +21,28 @@ pub struct HttpsRoute {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path_policy: Option<PathTrafficPolicy>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub path_rules: Vec<PathRule>,
+
+    /// URL rewrite, redirect & return rules evaluated before path rules, in their order
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rewrite_rules: Vec<RewriteRule>,
+
+    /// When defined, requests are distributed between pool upstreams instead of `target`
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream_pool: Option<UpstreamPool>,
+
+    /// Allow & deny lists evaluated for requests of this route, after global and server lists
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_access: Option<IpAccessSettings>,
+
+    /// Header changes applied to requests & responses of this route, in their order
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub header_rules: Vec<HeaderRule>,
 }
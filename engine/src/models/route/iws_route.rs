@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::traffic_policy::scope_traffic_policy::ScopeTrafficPolicy;
+use crate::models::{
+    route::{header_rule::HeaderRule, path_rule::PathRule, rewrite_rule::RewriteRule},
+    settings::ip_access_settings::IpAccessSettings,
+    traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
 pub struct IwsRoute {
@@ -9,4 +13,23 @@ pub struct IwsRoute {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub traffic_policy: Option<ScopeTrafficPolicy>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub path_rules: Vec<PathRule>,
+
+    /// URL rewrite, redirect & return rules evaluated before path rules, in their order
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rewrite_rules: Vec<RewriteRule>,
+
+    /// Allow & deny lists evaluated for requests of this route, after global and server lists
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_access: Option<IpAccessSettings>,
+
+    /// Header changes applied to requests & responses of this route, in their order
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub header_rules: Vec<HeaderRule>,
 }
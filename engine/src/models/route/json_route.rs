@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::{ssl_context::SslContext, traffic_policy::scope_traffic_policy::ScopeTrafficPolicy};
+use crate::models::{
+    route::{header_rule::HeaderRule, path_rule::PathRule, rewrite_rule::RewriteRule, upstream_pool::UpstreamPool},
+    settings::ip_access_settings::IpAccessSettings,
+    ssl_context::SslContext,
+    traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
 pub struct JsonRoute {
@@ -19,4 +24,19 @@ pub struct JsonRoute {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub traffic_policy: Option<ScopeTrafficPolicy>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub path_rules: Vec<PathRule>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream_pool: Option<UpstreamPool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_access: Option<IpAccessSettings>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub header_rules: Vec<HeaderRule>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rewrite_rules: Vec<RewriteRule>,
 }
@@ -3,3 +3,7 @@ pub mod https_route;
 pub mod iws_route;
 pub mod secure_iws_route;
 pub mod json_route;
+pub mod path_rule;
+pub mod header_rule;
+pub mod rewrite_rule;
+pub mod upstream_pool;
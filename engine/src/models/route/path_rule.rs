@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::enums::path_match_type::PathMatchType,
+    models::traffic_policy::path_traffic_policy::PathTrafficPolicy,
+};
+
+/// A path based sub-route of a host entry.
+/// Http & Https routes use `target`, IWS & Secure IWS routes use `serving_path` as destination.
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+pub struct PathRule {
+    pub path: String,
+
+    #[serde(default)]
+    pub match_type: PathMatchType,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serving_path: Option<String>,
+
+    #[serde(default)]
+    pub strip_prefix: bool,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_policy: Option<PathTrafficPolicy>,
+}
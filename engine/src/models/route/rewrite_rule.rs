@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::enums::{rewrite_action::RewriteAction, rewrite_condition_type::RewriteConditionType};
+
+/// An URL rewrite, redirect or return rule of a host entry. Rules are evaluated in their order.
+/// `pattern` is a regex matched against request path, its captures are written into `replacement` & `body` as `$1` or `${name}`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct RewriteRule {
+    pub pattern: String,
+
+    pub action: RewriteAction,
+
+    /// New path of `rewrite` rules, location of `redirect` rules
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+
+    /// Status of `redirect` (default 302) and `return` (default 200) rules
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+
+    /// Response body of `return` rules
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// All conditions should match for the rule to apply
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<RewriteCondition>,
+}
+
+/// A condition of a rewrite rule
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct RewriteCondition {
+    pub condition_type: RewriteConditionType,
+
+    /// Header or query parameter name
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Comma separated methods or ip addresses/CIDR blocks, a regex for header & query values.
+    /// Header & query conditions without value only check that the header or parameter exists
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Inverts result of the condition
+    #[serde(default)]
+    pub negate: bool,
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::models::{
+    route::{header_rule::HeaderRule, path_rule::PathRule, rewrite_rule::RewriteRule},
+    settings::ip_access_settings::IpAccessSettings,
     ssl_context::SslContext, traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
 };
 
@@ -12,4 +14,23 @@ pub struct SecureIwsRoute {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub traffic_policy: Option<ScopeTrafficPolicy>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub path_rules: Vec<PathRule>,
+
+    /// URL rewrite, redirect & return rules evaluated before path rules, in their order
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rewrite_rules: Vec<RewriteRule>,
+
+    /// Allow & deny lists evaluated for requests of this route, after global and server lists
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_access: Option<IpAccessSettings>,
+
+    /// Header changes applied to requests & responses of this route, in their order
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub header_rules: Vec<HeaderRule>,
 }
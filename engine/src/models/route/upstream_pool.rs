@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::enums::load_balancing_strategy::LoadBalancingStrategy,
+    models::settings::{
+        health_check_settings::HealthCheckSettings,
+        outlier_detection_settings::OutlierDetectionSettings,
+    },
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Upstream {
+    pub target: String,
+
+    /// Share of the upstream in weighted strategies, between 1 and `Constants::MAX_UPSTREAM_WEIGHT`
+    #[serde(default = "Upstream::default_weight")]
+    pub weight: u32,
+}
+
+impl Upstream {
+    fn default_weight() -> u32 {
+        1
+    }
+}
+
+/// Set of upstream targets which share the traffic of a route.
+///
+/// `hash_key` is only used by `consistent_hash` strategy and can be one of:
+/// `ip` (default), `header:<header name>` or `cookie:<cookie name>`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct UpstreamPool {
+    #[serde(default)]
+    pub strategy: LoadBalancingStrategy,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash_key: Option<String>,
+
+    pub upstreams: Vec<Upstream>,
+
+    /// Active health checks. Upstreams are not probed if not defined
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check: Option<HealthCheckSettings>,
+
+    /// Passive health checks driven by proxied request results. Disabled if not defined
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outlier_detection: Option<OutlierDetectionSettings>,
+}
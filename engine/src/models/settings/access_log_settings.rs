@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{common::enums::access_log_format::AccessLogFormat, constants::Constants};
+
+/// Access log is written into its own rotated file in logger directory, separately from engine logs.
+/// `template` is used by `custom` format, e.g. `$remote_addr "$request" $status $upstream_latency`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct AccessLogSettings {
+    #[serde(default)]
+    pub is_active: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<AccessLogFormat>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_file_size: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_last_logs: Option<usize>,
+
+    /// Share of requests written (0.0 - 1.0). Routes can override it by `access_log_sample_rate` of their traffic policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_rate: Option<f64>,
+}
+
+impl AccessLogSettings {
+    // Getters
+    pub fn get_format(&self) -> AccessLogFormat {
+        self.format.clone().unwrap_or_default()
+    }
+
+    pub fn get_file_name(&self) -> String {
+        self.file_name
+            .clone()
+            .unwrap_or_else(|| Constants::DEFAULT_ACCESS_LOG_FILE_NAME.to_string())
+    }
+
+    pub fn get_log_file_size(&self) -> u64 {
+        self.log_file_size.unwrap_or(Constants::DEFAULT_LOG_FILE_SIZE)
+    }
+
+    pub fn get_keep_last_logs(&self) -> usize {
+        self.keep_last_logs.unwrap_or(Constants::DEFAULT_KEEP_LAST_LOGS)
+    }
+
+    pub fn get_sample_rate(&self) -> f64 {
+        self.sample_rate.unwrap_or(Constants::DEFAULT_ACCESS_LOG_SAMPLE_RATE)
+    }
+}
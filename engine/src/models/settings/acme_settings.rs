@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::enums::acme_challenge_type::AcmeChallengeType, constants::Constants,
+    models::settings::upstream_tls_settings::UpstreamTlsSettings,
+};
+
+/// ACME v2 client which issues & renews certificates of routes added with `acme` flag.
+/// `directory_url` & `ca_bundle_path` can point to a local test CA (e.g. Pebble) instead of Let's Encrypt
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct AcmeSettings {
+    #[serde(default)]
+    pub is_active: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub directory_url: Option<String>,
+
+    /// Contact address registered into ACME account, used by CA for expiry notices
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_email: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenge_type: Option<AcmeChallengeType>,
+
+    /// Certificates are renewed when they expire within given days
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renew_before_days: Option<u64>,
+
+    /// Seconds waited before retrying a failed issuance
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_interval: Option<u64>,
+
+    /// PEM bundle of CA certificates trusted for ACME directory connections. Web PKI roots are used when undefined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ca_bundle_path: Option<String>,
+
+    /// Disables certificate verification of ACME directory connections. Only meant for test CAs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insecure_skip_verify: Option<bool>,
+}
+
+impl AcmeSettings {
+    /// Builds TLS settings of the client which connects ACME directory
+    pub fn get_tls_settings(&self) -> UpstreamTlsSettings {
+        UpstreamTlsSettings {
+            ca_bundle_path: self.ca_bundle_path.clone(),
+            insecure_skip_verify: self.insecure_skip_verify,
+            ..Default::default()
+        }
+    }
+
+    // Getters
+    pub fn get_directory_url(&self) -> String {
+        self.directory_url
+            .clone()
+            .unwrap_or_else(|| Constants::DEFAULT_ACME_DIRECTORY_URL.to_string())
+    }
+
+    pub fn get_challenge_type(&self) -> AcmeChallengeType {
+        self.challenge_type.clone().unwrap_or_default()
+    }
+
+    pub fn get_renew_before_days(&self) -> u64 {
+        self.renew_before_days
+            .unwrap_or(Constants::DEFAULT_ACME_RENEW_BEFORE_DAYS)
+    }
+
+    pub fn get_retry_interval(&self) -> u64 {
+        self.retry_interval
+            .unwrap_or(Constants::DEFAULT_ACME_RETRY_INTERVAL)
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use crate::constants::Constants;
+
+/// Expiry monitoring of route certificates. A warning is logged (and posted to `webhook_url` if defined)
+/// once for each threshold crossed, e.g. 30, 7 and 1 days before `notAfter`, and once more when certificate expires
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct CertificateMonitorSettings {
+    /// Days before expiry at which warnings are raised
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning_days: Option<Vec<u64>>,
+
+    /// Seconds between expiry checks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_interval: Option<u64>,
+
+    /// `http://` or `https://` endpoint which receives expiry alerts as JSON by POST requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+}
+
+impl CertificateMonitorSettings {
+    // Getters
+    /// Returns thresholds in descending order, without duplicates
+    pub fn get_warning_days(&self) -> Vec<u64> {
+        let mut warning_days = self
+            .warning_days
+            .clone()
+            .unwrap_or_else(|| Constants::DEFAULT_CERTIFICATE_WARNING_DAYS.to_vec());
+
+        warning_days.sort_unstable_by(|first, second| second.cmp(first));
+        warning_days.dedup();
+        warning_days
+    }
+
+    pub fn get_check_interval(&self) -> u64 {
+        self.check_interval
+            .unwrap_or(Constants::DEFAULT_CERTIFICATE_CHECK_INTERVAL)
+    }
+}
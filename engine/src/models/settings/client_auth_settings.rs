@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::enums::client_auth_mode::ClientAuthMode;
+
+/// Mutual TLS settings of an HTTPS or Secure IWS route. Client certificates are verified against `ca_bundle_path`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct ClientAuthSettings {
+    /// PEM bundle of CA certificates which issue client certificates. Absolute path or `@vanguard/<name>` reference
+    pub ca_bundle_path: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<ClientAuthMode>,
+
+    /// Client identities allowed to reach the route. Matched against subject, common name or any subject alternative name.
+    /// Every verified client is allowed when undefined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_identities: Option<Vec<String>>,
+
+    /// Forwards verified client subject & SANs to upstream by `X-Client-Cert-*` headers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_identity_headers: Option<bool>,
+}
+
+impl ClientAuthSettings {
+    // Getters
+    pub fn get_mode(&self) -> ClientAuthMode {
+        self.mode.clone().unwrap_or_default()
+    }
+
+    pub fn get_forward_identity_headers(&self) -> bool {
+        self.forward_identity_headers.unwrap_or(true)
+    }
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// Certificate served by HTTPS routes whose own certificate or private key can not be loaded (degraded routes).
+/// Paths may be `@vanguard/<name>` references of uploaded files
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct FallbackCertificateSettings {
+    pub certificate_file_path: String,
+    pub private_key_file_path: String,
+}
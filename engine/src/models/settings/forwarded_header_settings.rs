@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::enums::{forwarded_header::ForwardedHeader, forwarded_header_mode::ForwardedHeaderMode};
+
+/// Forwarding headers (`X-Forwarded-*`, `Forwarded`, `Via`) sent to upstreams.
+/// `trusted_proxies` is a list of ip addresses or CIDR blocks whose forwarding headers are believed
+/// while resolving real client ip. It is evaluated before routing, so it is read from global and server policies only
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct ForwardedHeaderSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<ForwardedHeaderMode>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trusted_proxies: Option<Vec<String>>,
+
+    /// Emitted headers. Defaults to `X-Forwarded-*` headers and `Via`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Vec<ForwardedHeader>>,
+}
+
+impl ForwardedHeaderSettings {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Merges another settings into this one. Values in `other` take precedence.
+    pub fn merge(&mut self, other: &Self) {
+        if other.mode.is_some() {
+            self.mode = other.mode.clone();
+        }
+        if other.trusted_proxies.is_some() {
+            self.trusted_proxies = other.trusted_proxies.clone();
+        }
+        if other.headers.is_some() {
+            self.headers = other.headers.clone();
+        }
+    }
+
+    // Getters
+    pub fn get_mode(&self) -> ForwardedHeaderMode {
+        self.mode.clone().unwrap_or_default()
+    }
+
+    pub fn get_trusted_proxies(&self) -> Vec<String> {
+        self.trusted_proxies.clone().unwrap_or_default()
+    }
+
+    pub fn get_headers(&self) -> Vec<ForwardedHeader> {
+        self.headers.clone().unwrap_or_else(|| {
+            vec![
+                ForwardedHeader::XForwardedFor,
+                ForwardedHeader::XForwardedProto,
+                ForwardedHeader::XForwardedHost,
+                ForwardedHeader::XForwardedPort,
+                ForwardedHeader::Via,
+            ]
+        })
+    }
+}
@@ -0,0 +1,54 @@
+use crate::constants::Constants;
+use serde::{Deserialize, Serialize};
+
+/// Active health check settings of an upstream pool. Each upstream is probed with a HTTP GET request.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct HealthCheckSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub healthy_threshold: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unhealthy_threshold: Option<u32>,
+}
+
+impl HealthCheckSettings {
+    // Getters
+    pub fn get_path(&self) -> String {
+        self.path
+            .clone()
+            .unwrap_or(Constants::DEFAULT_HEALTH_CHECK_PATH.to_string())
+    }
+
+    pub fn get_interval(&self) -> u64 {
+        self.interval
+            .unwrap_or(Constants::DEFAULT_HEALTH_CHECK_INTERVAL)
+            .max(1)
+    }
+
+    pub fn get_timeout(&self) -> u64 {
+        self.timeout
+            .unwrap_or(Constants::DEFAULT_HEALTH_CHECK_TIMEOUT)
+            .max(1)
+    }
+
+    pub fn get_healthy_threshold(&self) -> u32 {
+        self.healthy_threshold
+            .unwrap_or(Constants::DEFAULT_HEALTHY_THRESHOLD)
+            .max(1)
+    }
+
+    pub fn get_unhealthy_threshold(&self) -> u32 {
+        self.unhealthy_threshold
+            .unwrap_or(Constants::DEFAULT_UNHEALTHY_THRESHOLD)
+            .max(1)
+    }
+}
@@ -20,6 +20,10 @@ pub struct Http1ProtocolSettings {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub http1_only: Option<bool>,
+
+    /// Rejects requests with ambiguous framing (e.g. both `Content-Length` & `Transfer-Encoding`) instead of repairing them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http1_strict_framing: Option<bool>,
 }
 
 impl Default for Http1ProtocolSettings {
@@ -31,6 +35,7 @@ impl Default for Http1ProtocolSettings {
             tcp_keepalive: None,
             http1_max_buf_size: None,
             http1_only: None,
+            http1_strict_framing: None,
         }
     }
 }
@@ -44,6 +49,7 @@ impl Http1ProtocolSettings {
             tcp_keepalive: Some(Constants::DEFAULT_POOL_IDLE_TIMEOUT),
             http1_max_buf_size: Some(Constants::DEFAULT_MAX_REQUEST_BODY_SIZE as usize),
             http1_only: Some(true),
+            http1_strict_framing: Some(false),
         }
     }
 
@@ -66,6 +72,9 @@ impl Http1ProtocolSettings {
         if other.http1_only.is_some() {
             self.http1_only = other.http1_only;
         }
+        if other.http1_strict_framing.is_some() {
+            self.http1_strict_framing = other.http1_strict_framing;
+        }
     }
 
     // Getters
@@ -85,4 +94,8 @@ impl Http1ProtocolSettings {
     pub fn get_http1_only(&self) -> bool {
         self.http1_only.unwrap_or(true)
     }
+
+    pub fn get_http1_strict_framing(&self) -> bool {
+        self.http1_strict_framing.unwrap_or(false)
+    }
 }
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::enums::ip_access_list_type::IpAccessListType;
+
+/// IP allow & deny lists. Entries are IPv4/IPv6 addresses or CIDR blocks.
+/// Deny list is evaluated first. If allow list is not empty, only the addresses in it are accepted
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct IpAccessSettings {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<String>,
+
+    /// HTML file served with 403 responses instead of the built-in page
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forbidden_page_path: Option<String>,
+}
+
+impl IpAccessSettings {
+    pub fn is_empty(&self) -> bool {
+        self.allow.is_empty() && self.deny.is_empty() && self.forbidden_page_path.is_none()
+    }
+
+    /// Adds entry into given list. Returns false if entry already exists
+    pub fn add_entry(&mut self, list_type: &IpAccessListType, entry: &str) -> bool {
+        let entries = self.get_entries_mut(list_type);
+
+        if entries.iter().any(|each_entry| each_entry == entry) {
+            return false;
+        }
+
+        entries.push(entry.to_string());
+        true
+    }
+
+    /// Removes entry from given list. Returns false if entry does not exist
+    pub fn remove_entry(&mut self, list_type: &IpAccessListType, entry: &str) -> bool {
+        let entries = self.get_entries_mut(list_type);
+        let entry_count = entries.len();
+
+        entries.retain(|each_entry| each_entry != entry);
+        entry_count != entries.len()
+    }
+
+    fn get_entries_mut(&mut self, list_type: &IpAccessListType) -> &mut Vec<String> {
+        match list_type {
+            IpAccessListType::Allow => &mut self.allow,
+            IpAccessListType::Deny => &mut self.deny,
+        }
+    }
+}
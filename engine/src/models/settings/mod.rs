@@ -2,4 +2,16 @@ pub mod server_settings;
 pub mod http1_protocol_settings;
 pub mod http2_protocol_settings;
 pub mod upstream_settings;
-pub mod logger_settings;
\ No newline at end of file
+pub mod logger_settings;
+pub mod health_check_settings;
+pub mod outlier_detection_settings;
+pub mod upstream_tls_settings;
+pub mod ip_access_settings;
+pub mod rate_limit_settings;
+pub mod access_log_settings;
+pub mod acme_settings;
+pub mod client_auth_settings;
+pub mod tls_settings;
+pub mod fallback_certificate_settings;
+pub mod certificate_monitor_settings;
+pub mod forwarded_header_settings;
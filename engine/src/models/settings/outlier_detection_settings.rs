@@ -0,0 +1,27 @@
+use crate::constants::Constants;
+use serde::{Deserialize, Serialize};
+
+/// Passive health check settings of an upstream pool.
+/// Upstreams which fail consecutive proxied requests (timeout, connection error or 5xx) are ejected for a while.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct OutlierDetectionSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consecutive_failures: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ejection_time: Option<u64>,
+}
+
+impl OutlierDetectionSettings {
+    // Getters
+    pub fn get_consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+            .unwrap_or(Constants::DEFAULT_OUTLIER_CONSECUTIVE_FAILURES)
+            .max(1)
+    }
+
+    pub fn get_ejection_time(&self) -> u64 {
+        self.ejection_time
+            .unwrap_or(Constants::DEFAULT_OUTLIER_EJECTION_TIME)
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{common::enums::rate_limit_algorithm::RateLimitAlgorithm, constants::Constants};
+use serde::{Deserialize, Serialize};
+
+/// Rate limiting settings of a scope.
+/// `max_requests_per_minute` is the per route limit, global limit is `server.max_requests_per_minute`.
+/// Key is one of `ip`, `route`, `header:<name>`, `api_key` or `api_key:<header name>`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct RateLimitSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<RateLimitAlgorithm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+
+    /// Requests which can be sent at once. Token bucket & GCRA only, defaults to the limit itself
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub burst: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_requests_per_minute: Option<u32>,
+}
+
+impl RateLimitSettings {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Merges another settings into this one. Values in `other` take precedence.
+    pub fn merge(&mut self, other: &Self) {
+        if other.algorithm.is_some() {
+            self.algorithm = other.algorithm.clone();
+        }
+        if other.key.is_some() {
+            self.key = other.key.clone();
+        }
+        if other.burst.is_some() {
+            self.burst = other.burst;
+        }
+        if other.max_requests_per_minute.is_some() {
+            self.max_requests_per_minute = other.max_requests_per_minute;
+        }
+    }
+
+    // Getters
+    pub fn get_algorithm(&self) -> RateLimitAlgorithm {
+        self.algorithm.clone().unwrap_or_default()
+    }
+
+    pub fn get_key(&self) -> String {
+        self.key
+            .clone()
+            .unwrap_or(Constants::DEFAULT_RATE_LIMIT_KEY.to_string())
+    }
+
+    pub fn get_burst(&self, limit: u32) -> u32 {
+        self.burst.unwrap_or(limit).max(1)
+    }
+}
@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+use crate::constants::Constants;
+
+/// TLS handshake policy of HTTPS server. Routes can override any field, undefined fields fall back to rustls safe defaults
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct TlsSettings {
+    /// Lowest accepted protocol version: `1.2` or `1.3`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_version: Option<String>,
+
+    /// Highest accepted protocol version: `1.2` or `1.3`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_version: Option<String>,
+
+    /// Cipher suites in preference order, e.g. `TLS13_AES_128_GCM_SHA256`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cipher_suites: Option<Vec<String>>,
+
+    /// Key exchange groups in preference order: `X25519`, `secp256r1`, `secp384r1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kx_groups: Option<Vec<String>>,
+
+    /// Protocols offered by ALPN, e.g. `["http/1.1"]` disables HTTP/2
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpn_protocols: Option<Vec<String>>,
+
+    /// Issues session tickets, so clients resume sessions without server side state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_tickets: Option<bool>,
+
+    /// Keeps sessions in server side cache for resumption by session id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_resumption: Option<bool>,
+
+    /// Maximum sessions kept in server side cache
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_cache_size: Option<usize>,
+}
+
+impl TlsSettings {
+    pub fn merge(&mut self, other: &Self) {
+        if other.min_version.is_some() {
+            self.min_version = other.min_version.clone();
+        }
+        if other.max_version.is_some() {
+            self.max_version = other.max_version.clone();
+        }
+        if other.cipher_suites.is_some() {
+            self.cipher_suites = other.cipher_suites.clone();
+        }
+        if other.kx_groups.is_some() {
+            self.kx_groups = other.kx_groups.clone();
+        }
+        if other.alpn_protocols.is_some() {
+            self.alpn_protocols = other.alpn_protocols.clone();
+        }
+        if other.session_tickets.is_some() {
+            self.session_tickets = other.session_tickets;
+        }
+        if other.session_resumption.is_some() {
+            self.session_resumption = other.session_resumption;
+        }
+        if other.session_cache_size.is_some() {
+            self.session_cache_size = other.session_cache_size;
+        }
+    }
+
+    // Getters
+    pub fn get_min_version(&self) -> String {
+        self.min_version
+            .clone()
+            .unwrap_or_else(|| Constants::DEFAULT_TLS_MIN_VERSION.to_string())
+    }
+
+    pub fn get_max_version(&self) -> String {
+        self.max_version
+            .clone()
+            .unwrap_or_else(|| Constants::DEFAULT_TLS_MAX_VERSION.to_string())
+    }
+
+    pub fn get_alpn_protocols(&self) -> Vec<String> {
+        self.alpn_protocols.clone().unwrap_or_else(|| {
+            Constants::DEFAULT_ALPN_PROTOCOLS
+                .iter()
+                .map(|protocol| protocol.to_string())
+                .collect()
+        })
+    }
+
+    pub fn get_session_tickets(&self) -> bool {
+        self.session_tickets.unwrap_or(false)
+    }
+
+    pub fn get_session_resumption(&self) -> bool {
+        self.session_resumption.unwrap_or(true)
+    }
+
+    pub fn get_session_cache_size(&self) -> usize {
+        self.session_cache_size
+            .unwrap_or(Constants::DEFAULT_TLS_SESSION_CACHE_SIZE)
+    }
+}
@@ -1,4 +1,6 @@
-use crate::constants::Constants;
+use crate::{
+    constants::Constants, models::settings::upstream_tls_settings::UpstreamTlsSettings,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -14,6 +16,14 @@ pub struct UpstreamSettings {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_request_body_size: Option<u64>,
+
+    /// Seconds an upgraded (e.g. WebSocket) connection is kept open without traffic in any direction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upgraded_connection_idle_timeout: Option<u64>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls: Option<UpstreamTlsSettings>,
 }
 
 impl Default for UpstreamSettings {
@@ -23,6 +33,8 @@ impl Default for UpstreamSettings {
             pool_idle_timeout: None,
             max_idle_conns_per_host: None,
             max_request_body_size: None,
+            upgraded_connection_idle_timeout: None,
+            tls: None,
         }
     }
 }
@@ -34,6 +46,8 @@ impl UpstreamSettings {
             pool_idle_timeout: Some(Constants::DEFAULT_POOL_IDLE_TIMEOUT),
             max_idle_conns_per_host: Some(Constants::DEFAULT_MAX_IDLE_CONNS_PER_HOST),
             max_request_body_size: Some(Constants::DEFAULT_MAX_REQUEST_BODY_SIZE),
+            upgraded_connection_idle_timeout: Some(Constants::DEFAULT_UPGRADED_CONNECTION_IDLE_TIMEOUT),
+            tls: None,
         }
     }
 
@@ -50,6 +64,14 @@ impl UpstreamSettings {
         if other.max_request_body_size.is_some() {
             self.max_request_body_size = other.max_request_body_size;
         }
+        if other.upgraded_connection_idle_timeout.is_some() {
+            self.upgraded_connection_idle_timeout = other.upgraded_connection_idle_timeout;
+        }
+        if let Some(other_tls) = &other.tls {
+            self.tls
+                .get_or_insert_with(UpstreamTlsSettings::default)
+                .merge(other_tls);
+        }
     }
 
     // Getters
@@ -72,4 +94,13 @@ impl UpstreamSettings {
         self.max_request_body_size
             .unwrap_or(Constants::DEFAULT_MAX_REQUEST_BODY_SIZE)
     }
+
+    pub fn get_upgraded_connection_idle_timeout(&self) -> u64 {
+        self.upgraded_connection_idle_timeout
+            .unwrap_or(Constants::DEFAULT_UPGRADED_CONNECTION_IDLE_TIMEOUT)
+    }
+
+    pub fn get_tls(&self) -> UpstreamTlsSettings {
+        self.tls.clone().unwrap_or_default()
+    }
 }
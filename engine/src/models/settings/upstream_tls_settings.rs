@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+/// TLS settings used when the upstream target has `https://` scheme
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct UpstreamTlsSettings {
+    /// PEM bundle of trusted CA certificates. Replaces the built-in web PKI roots when defined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ca_bundle_path: Option<String>,
+
+    /// Server name sent by SNI and verified against upstream certificate instead of target host
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sni_override: Option<String>,
+
+    /// Client certificate presented to upstream for mutual TLS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_certificate_path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_private_key_path: Option<String>,
+
+    /// Disables upstream certificate verification. Only meant for lab setups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insecure_skip_verify: Option<bool>,
+}
+
+impl UpstreamTlsSettings {
+    pub fn merge(&mut self, other: &Self) {
+        if other.ca_bundle_path.is_some() {
+            self.ca_bundle_path = other.ca_bundle_path.clone();
+        }
+        if other.sni_override.is_some() {
+            self.sni_override = other.sni_override.clone();
+        }
+        if other.client_certificate_path.is_some() {
+            self.client_certificate_path = other.client_certificate_path.clone();
+        }
+        if other.client_private_key_path.is_some() {
+            self.client_private_key_path = other.client_private_key_path.clone();
+        }
+        if other.insecure_skip_verify.is_some() {
+            self.insecure_skip_verify = other.insecure_skip_verify;
+        }
+    }
+
+    /// Builds a key which identifies HTTP clients that can share connections
+    pub fn get_pool_key(&self) -> String {
+        format!(
+            "{}|{}|{}|{}|{}",
+            self.ca_bundle_path.as_deref().unwrap_or_default(),
+            self.sni_override.as_deref().unwrap_or_default(),
+            self.client_certificate_path.as_deref().unwrap_or_default(),
+            self.client_private_key_path.as_deref().unwrap_or_default(),
+            self.get_insecure_skip_verify()
+        )
+    }
+
+    // Getters
+    pub fn get_insecure_skip_verify(&self) -> bool {
+        self.insecure_skip_verify.unwrap_or(false)
+    }
+}
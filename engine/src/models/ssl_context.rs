@@ -1,7 +1,30 @@
 use serde::{Deserialize, Serialize};
 
+use crate::models::settings::{client_auth_settings::ClientAuthSettings, tls_settings::TlsSettings};
+
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
 pub struct SslContext {
     pub certificate_file_path: String,
     pub private_key_file_path: String,
+
+    /// Certificate & private key are issued and renewed by ACME client into `@vanguard/acme-<source>.*` files
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acme: Option<bool>,
+
+    /// Client certificate authentication (mTLS) of the route
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_auth: Option<ClientAuthSettings>,
+
+    /// TLS handshake policy of the route, merged over HTTPS server settings
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsSettings>,
+}
+
+impl SslContext {
+    pub fn is_acme_managed(&self) -> bool {
+        self.acme.unwrap_or(false)
+    }
 }
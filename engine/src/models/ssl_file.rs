@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::enums::ssl_file_type::SslFileType;
+
+/// File in SSL upload directory. Routes use `reference` (`@vanguard/<name>`) instead of absolute file path
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SslFile {
+    pub name: String,
+    pub reference: String,
+    pub file_type: SslFileType,
+    pub size: u64,
+}
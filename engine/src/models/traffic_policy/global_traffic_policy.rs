@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::models::settings::{
-    http1_protocol_settings::Http1ProtocolSettings, http2_protocol_settings::Http2ProtocolSettings,
+    forwarded_header_settings::ForwardedHeaderSettings, http1_protocol_settings::Http1ProtocolSettings, http2_protocol_settings::Http2ProtocolSettings,
+    ip_access_settings::IpAccessSettings, rate_limit_settings::RateLimitSettings,
     server_settings::ServerSettings, upstream_settings::UpstreamSettings,
 };
 
@@ -18,6 +19,18 @@ pub struct GlobalTrafficPolicy {
 
     #[serde(default)]
     pub upstream_settings: UpstreamSettings,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "IpAccessSettings::is_empty")]
+    pub ip_access: IpAccessSettings,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "RateLimitSettings::is_empty")]
+    pub rate_limit_settings: RateLimitSettings,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "ForwardedHeaderSettings::is_empty")]
+    pub forwarded_headers: ForwardedHeaderSettings,
 }
 
 impl GlobalTrafficPolicy {
@@ -28,6 +41,9 @@ impl GlobalTrafficPolicy {
             http1_protocol_settings: Http1ProtocolSettings::global(),
             http2_protocol_settings: Http2ProtocolSettings::global(),
             upstream_settings: UpstreamSettings::global(),
+            ip_access: IpAccessSettings::default(),
+            rate_limit_settings: RateLimitSettings::default(),
+            forwarded_headers: ForwardedHeaderSettings::default(),
         }
     }
 }
@@ -2,8 +2,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::models::{
     settings::{
-        http1_protocol_settings::Http1ProtocolSettings,
-        http2_protocol_settings::Http2ProtocolSettings, upstream_settings::UpstreamSettings,
+        forwarded_header_settings::ForwardedHeaderSettings, http1_protocol_settings::Http1ProtocolSettings,
+        http2_protocol_settings::Http2ProtocolSettings, rate_limit_settings::RateLimitSettings,
+        upstream_settings::UpstreamSettings,
     },
     traffic_policy::path_traffic_policy::PathTrafficPolicy,
 };
@@ -23,6 +24,19 @@ pub struct ScopeTrafficPolicy {
 
     #[serde(default)]
     pub upstream_settings: UpstreamSettings,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "RateLimitSettings::is_empty")]
+    pub rate_limit_settings: RateLimitSettings,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "ForwardedHeaderSettings::is_empty")]
+    pub forwarded_headers: ForwardedHeaderSettings,
+
+    /// Share of requests written into access log (0.0 - 1.0), overrides `sample_rate` of access log settings
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_log_sample_rate: Option<f64>,
 }
 
 impl ScopeTrafficPolicy {
@@ -33,6 +47,12 @@ impl ScopeTrafficPolicy {
         self.http2_protocol_settings
             .merge(&other.http2_protocol_settings);
         self.upstream_settings.merge(&other.upstream_settings);
+        self.rate_limit_settings.merge(&other.rate_limit_settings);
+        self.forwarded_headers.merge(&other.forwarded_headers);
+
+        if other.access_log_sample_rate.is_some() {
+            self.access_log_sample_rate = other.access_log_sample_rate;
+        }
     }
 
     /// Merges a path policy into this scope policy.
@@ -43,6 +63,8 @@ impl ScopeTrafficPolicy {
         if path_policy.max_request_body_size.is_some() {
             self.upstream_settings.max_request_body_size = path_policy.max_request_body_size;
         }
-        // max_requests_per_minute is handled at the connection manager level
+        if path_policy.max_requests_per_minute.is_some() {
+            self.rate_limit_settings.max_requests_per_minute = path_policy.max_requests_per_minute;
+        }
     }
 }
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::enums::{route_type::RouteType, upstream_health_status::UpstreamHealthStatus};
+
+/// Health state of a single upstream of a route pool
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UpstreamHealthReport {
+    pub route_type: RouteType,
+    pub source: String,
+    pub target: String,
+    pub status: UpstreamHealthStatus,
+    pub is_active_check_enabled: bool,
+    pub consecutive_successes: u32,
+    pub consecutive_failures: u32,
+    pub passive_failures: u32,
+    pub ejection_remaining_seconds: Option<u64>,
+    pub last_checked_at: Option<i64>,
+    pub last_error: Option<String>,
+}
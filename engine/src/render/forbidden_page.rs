@@ -0,0 +1,48 @@
+use tera::{Context, Tera};
+
+use crate::resources::vanguard_logo::VanguardLogo;
+
+pub struct ForbiddenPage {
+    url_path: String,
+    context: Option<Context>,
+}
+
+impl ForbiddenPage {
+    pub const HTML_TEMPLATE: &'static str = r#"
+    <html>
+        <head>
+            <title>403 Forbidden: {{ url_path }}</title>
+        </head>
+        <body>
+            <img src="data:image/png;base64,{{ logo }}" width="400px" alt="Vanguard">
+            <h1>403 Forbidden</h1>
+
+            <p>Vanguard Web Server does not allow your ip address to access:<br/> <b>{{ url_path }}</b></p>
+        </body>
+    </html>
+    "#;
+
+    pub fn new(url_path: &str) -> Self {
+        let mut context = Context::new();
+
+        context.insert("logo", VanguardLogo::BASE64_CONTENT);
+        context.insert("url_path", &url_path);
+
+        ForbiddenPage {
+            context: Some(context),
+            url_path: format!("/{}", url_path),
+        }
+    }
+
+    pub fn render(&self) -> String {
+        if self.context.is_none() {
+            return format!("Error while rendering path: {:?}", self.url_path);
+        }
+
+        let context = self.context.clone().unwrap();
+        match Tera::one_off(ForbiddenPage::HTML_TEMPLATE, &context, true) {
+            Ok(content) => content,
+            Err(error) => format!("Error: {:?}", error),
+        }
+    }
+}
@@ -1,12 +1,16 @@
 use std::path::PathBuf;
 
+mod bad_request_page;
 mod dir_index_page;
+mod forbidden_page;
 mod internal_error_page;
 mod not_found_page;
+mod too_many_requests_page;
 
 use crate::render::{
-    dir_index_page::DirIndexPage, internal_error_page::InternalErrorPage,
-    not_found_page::NotFoundPage,
+    bad_request_page::BadRequestPage, dir_index_page::DirIndexPage, forbidden_page::ForbiddenPage,
+    internal_error_page::InternalErrorPage, not_found_page::NotFoundPage,
+    too_many_requests_page::TooManyRequestsPage,
 };
 
 pub struct Render {}
@@ -29,4 +33,22 @@ impl Render {
 
         content.render()
     }
+
+    pub fn forbidden_error(url_path: &str) -> String {
+        let content = ForbiddenPage::new(url_path);
+
+        content.render()
+    }
+
+    pub fn bad_request_error(url_path: &str, reason: &str) -> String {
+        let content = BadRequestPage::new(url_path, reason);
+
+        content.render()
+    }
+
+    pub fn too_many_requests_error(url_path: &str, reason: &str) -> String {
+        let content = TooManyRequestsPage::new(url_path, reason);
+
+        content.render()
+    }
 }
@@ -0,0 +1,49 @@
+use tera::{Context, Tera};
+
+use crate::resources::vanguard_logo::VanguardLogo;
+
+pub struct TooManyRequestsPage {
+    url_path: String,
+    context: Option<Context>,
+}
+
+impl TooManyRequestsPage {
+    pub const HTML_TEMPLATE: &'static str = r#"
+    <html>
+        <head>
+            <title>429 Too Many Requests</title>
+        </head>
+        <body>
+            <img src="data:image/png;base64,{{ logo }}" width="400px" alt="Vanguard">
+            <h1>429 Too Many Requests</h1>
+
+            <p>{{ reason }}<br/> : <b>{{ url_path }}</b></p>
+        </body>
+    </html>
+    "#;
+
+    pub fn new(url_path: &str, reason: &str) -> Self {
+        let mut context = Context::new();
+
+        context.insert("logo", VanguardLogo::BASE64_CONTENT);
+        context.insert("url_path", &url_path);
+        context.insert("reason", &reason);
+
+        TooManyRequestsPage {
+            context: Some(context),
+            url_path: format!("/{}", url_path),
+        }
+    }
+
+    pub fn render(&self) -> String {
+        if self.context.is_none() {
+            return format!("Error while rendering path: {:?}", self.url_path);
+        }
+
+        let context = self.context.clone().unwrap();
+        match Tera::one_off(TooManyRequestsPage::HTML_TEMPLATE, &context, true) {
+            Ok(content) => content,
+            Err(error) => format!("Error: {:?}", error),
+        }
+    }
+}
@@ -14,9 +14,11 @@ pub fn add_http_route(payload: Value) -> Result<Value, Error> {
     let source = request.get_source();
     let target = request.get_target();
     let traffic_policy = request.get_traffic_policy();
+    let header_rules = request.get_header_rules();
+    let upstream_pool = request.get_upstream_pool();
 
     let mut router = ROUTER.write().unwrap();
-    router.add_http_route(&source, &target, traffic_policy);
+    router.add_http_route(&source, &target, traffic_policy, upstream_pool, header_rules);
 
     let response = AddHttpRouteResponse::build()?;
     Ok(response)
@@ -10,6 +10,7 @@ use jsonrpc_core::{Error, Value};
 
 /// Checks that given ssl cert file and private key file path.
 /// Remember that this function also considers relative (Upload path included, as @vanguard) check
+/// Routes added with `acme` flag skip this check, their certificate is issued by ACME client afterwards
 /// At the last step, this function also   ssl ceretificate is valid and compatible with given domain & IP address
 /// Is ssl context is valid, creates HTTPS Route
 pub fn add_https_route(params: Value) -> Result<Value, Error> {
@@ -17,11 +18,19 @@ pub fn add_https_route(params: Value) -> Result<Value, Error> {
 
     let source: String = request.get_source();
     let target: String = request.get_target();
-    let ssl_cert_path: String = request.get_ssl_cert_path();
-    let ssl_private_key_path: String = request.get_ssl_private_key_path();
+    let ssl_context = request.get_ssl_context();
     let traffic_policy = request.get_traffic_policy();
+    let header_rules = request.get_header_rules();
+    let upstream_pool = request.get_upstream_pool();
 
-    validate_ssl_context(&source, &ssl_cert_path, &ssl_private_key_path)?;
+    // Certificates of ACME routes are issued after the route is added, by ACME manager
+    if !ssl_context.is_acme_managed() {
+        validate_ssl_context(
+            &source,
+            &ssl_context.certificate_file_path,
+            &ssl_context.private_key_file_path,
+        )?;
+    }
 
     check_route_already_used(&source)?;
 
@@ -29,9 +38,10 @@ pub fn add_https_route(params: Value) -> Result<Value, Error> {
     router.add_https_route(
         &source,
         &target,
-        &ssl_cert_path,
-        &ssl_private_key_path,
+        ssl_context,
         traffic_policy,
+        upstream_pool,
+        header_rules,
     );
 
     Ok(AddHttpsRouteResponse::build(None))
@@ -0,0 +1,25 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::core::shared_memory::ROUTER;
+use crate::rpc_service::models::add_path_rule_request::AddPathRuleRequest;
+use crate::rpc_service::models::add_path_rule_response::AddPathRuleResponse;
+use crate::rpc_service::rpc_error::RPCError;
+
+pub fn add_path_rule(params: Value) -> Result<Value, Error> {
+    let request = AddPathRuleRequest::new(params)?;
+
+    let route_type = request.get_route_type();
+    let source = request.get_source();
+    let path_rule = request.get_path_rule();
+
+    let mut router = ROUTER.write().unwrap();
+    if !router.add_path_rule(&route_type, &source, path_rule) {
+        return Err(RPCError::build(
+            &StatusCode::NOT_FOUND,
+            &format!("Route '{}' does not exist", source),
+        ));
+    }
+
+    AddPathRuleResponse::build()
+}
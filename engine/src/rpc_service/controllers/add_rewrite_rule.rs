@@ -0,0 +1,25 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::core::shared_memory::ROUTER;
+use crate::rpc_service::models::add_rewrite_rule_request::AddRewriteRuleRequest;
+use crate::rpc_service::models::add_rewrite_rule_response::AddRewriteRuleResponse;
+use crate::rpc_service::rpc_error::RPCError;
+
+pub fn add_rewrite_rule(params: Value) -> Result<Value, Error> {
+    let request = AddRewriteRuleRequest::new(params)?;
+
+    let route_type = request.get_route_type();
+    let source = request.get_source();
+    let rewrite_rule = request.get_rewrite_rule();
+
+    let mut router = ROUTER.write().unwrap();
+    if !router.add_rewrite_rule(&route_type, &source, rewrite_rule) {
+        return Err(RPCError::build(
+            &StatusCode::NOT_FOUND,
+            &format!("Route '{}' does not exist", source),
+        ));
+    }
+
+    AddRewriteRuleResponse::build()
+}
@@ -15,11 +15,18 @@ pub fn add_secure_iws_route(params: Value) -> Result<Value, Error> {
 
     let source = request.get_source();
     let serving_path = request.get_serving_path();
-    let ssl_cert_path: String = request.get_ssl_cert_path();
-    let ssl_private_key_path: String = request.get_ssl_private_key_path();
+    let ssl_context = request.get_ssl_context();
     let traffic_policy = request.get_traffic_policy();
+    let header_rules = request.get_header_rules();
 
-    validate_ssl_context(&source, &ssl_cert_path, &ssl_private_key_path)?;
+    // Certificates of ACME routes are issued after the route is added, by ACME manager
+    if !ssl_context.is_acme_managed() {
+        validate_ssl_context(
+            &source,
+            &ssl_context.certificate_file_path,
+            &ssl_context.private_key_file_path,
+        )?;
+    }
 
     check_route_already_used(&source, &serving_path)?;
 
@@ -27,9 +34,9 @@ pub fn add_secure_iws_route(params: Value) -> Result<Value, Error> {
     router.add_secure_iws_route(
         &source,
         &serving_path,
-        &ssl_cert_path,
-        &ssl_private_key_path,
+        ssl_context,
         traffic_policy,
+        header_rules,
     );
 
     Ok(AddSecureIwsRouteResponse::build())
@@ -0,0 +1,46 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::common::enums::ip_access_scope::IpAccessScope;
+use crate::core::ip_access_control::update_config_ip_access;
+use crate::core::shared_memory::ROUTER;
+use crate::rpc_service::models::block_ip_response::BlockIpResponse;
+use crate::rpc_service::models::ip_access_entry_request::IpAccessEntryRequest;
+use crate::rpc_service::rpc_error::RPCError;
+
+/// This JRPC service adds an ip address or CIDR block into allow or deny list of given scope
+pub fn block_ip(params: Value) -> Result<Value, Error> {
+    let request = IpAccessEntryRequest::new(params)?;
+
+    let ip = request.get_ip();
+    let list_type = request.get_list_type();
+
+    let is_added = match (request.get_scope(), request.get_route()) {
+        (IpAccessScope::Route, Some((route_type, source))) => {
+            let mut router = ROUTER.write().unwrap();
+            router
+                .add_ip_access_entry(&route_type, &source, &list_type, &ip)
+                .ok_or_else(|| {
+                    RPCError::build(
+                        &StatusCode::NOT_FOUND,
+                        &format!("Route '{}' does not exist", source),
+                    )
+                })?
+        }
+        (scope, _) => update_config_ip_access(&scope, |ip_access| {
+            ip_access.add_entry(&list_type, &ip)
+        })
+        .map_err(|error_message| {
+            RPCError::build(&StatusCode::INTERNAL_SERVER_ERROR, &error_message)
+        })?,
+    };
+
+    if !is_added {
+        return Err(RPCError::build(
+            &StatusCode::CONFLICT,
+            &format!("'{}' is already in {} list", ip, list_type),
+        ));
+    }
+
+    BlockIpResponse::build()
+}
@@ -0,0 +1,25 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::core::shared_memory::ROUTER;
+use crate::rpc_service::models::delete_path_rule_request::DeletePathRuleRequest;
+use crate::rpc_service::models::delete_path_rule_response::DeletePathRuleResponse;
+use crate::rpc_service::rpc_error::RPCError;
+
+pub fn delete_path_rule(params: Value) -> Result<Value, Error> {
+    let request = DeletePathRuleRequest::new(params)?;
+
+    let route_type = request.get_route_type();
+    let source = request.get_source();
+    let path = request.get_path();
+
+    let mut router = ROUTER.write().unwrap();
+    if !router.delete_path_rule(&route_type, &source, &path) {
+        return Err(RPCError::build(
+            &StatusCode::NOT_FOUND,
+            &format!("Path rule '{}' of route '{}' does not exist", path, source),
+        ));
+    }
+
+    DeletePathRuleResponse::build()
+}
@@ -0,0 +1,25 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::core::shared_memory::ROUTER;
+use crate::rpc_service::models::delete_rewrite_rule_request::DeleteRewriteRuleRequest;
+use crate::rpc_service::models::delete_rewrite_rule_response::DeleteRewriteRuleResponse;
+use crate::rpc_service::rpc_error::RPCError;
+
+pub fn delete_rewrite_rule(params: Value) -> Result<Value, Error> {
+    let request = DeleteRewriteRuleRequest::new(params)?;
+
+    let route_type = request.get_route_type();
+    let source = request.get_source();
+    let pattern = request.get_pattern();
+
+    let mut router = ROUTER.write().unwrap();
+    if !router.delete_rewrite_rule(&route_type, &source, &pattern) {
+        return Err(RPCError::build(
+            &StatusCode::NOT_FOUND,
+            &format!("Rewrite rule '{}' of route '{}' does not exist", pattern, source),
+        ));
+    }
+
+    DeleteRewriteRuleResponse::build()
+}
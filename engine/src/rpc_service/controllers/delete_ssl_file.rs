@@ -0,0 +1,43 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    core::shared_memory::{ROUTER, RUNTIME_BOOT_INFO},
+    log_info,
+    rpc_service::{
+        models::{delete_ssl_file_response::DeleteSslFileResponse, ssl_file_request::SslFileRequest},
+        rpc_error::RPCError,
+    },
+    utils::ssl_file_utility::{delete_ssl_file as remove_ssl_file, get_ssl_file_reference},
+};
+
+/// This JRPC service removes an uploaded file. Files used by routes or engine settings can not be removed
+pub fn delete_ssl_file(params: Value) -> Result<Value, Error> {
+    let request = SslFileRequest::new(params)?;
+    let name = request.get_name();
+    let file_reference = get_ssl_file_reference(&name);
+
+    let config = RUNTIME_BOOT_INFO.read().unwrap().config.clone();
+
+    // Router lock is kept until file is removed, so no route can start using it meanwhile
+    let router = ROUTER.read().unwrap();
+    let used_by = router.find_ssl_file_users(&file_reference, &config);
+    if !used_by.is_empty() {
+        return Err(RPCError::build(
+            &StatusCode::CONFLICT,
+            &format!(
+                "SSL file '{}' is used by following routes or settings: {}",
+                name,
+                used_by.join(", ")
+            ),
+        ));
+    }
+
+    remove_ssl_file(&name)
+        .map_err(|error_message| RPCError::build(&StatusCode::NOT_FOUND, &error_message))?;
+    drop(router);
+
+    log_info!("SSL file '{}' is deleted", name);
+
+    DeleteSslFileResponse::build()
+}
@@ -0,0 +1,13 @@
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    core::shared_memory::ACME_MANAGER,
+    rpc_service::models::get_acme_certificates_response::GetAcmeCertificatesResponse,
+};
+
+/// This JRPC service is responsible of returning issue & renewal state of every ACME managed route
+pub fn get_acme_certificates(_payload: Value) -> Result<Value, Error> {
+    let acme_certificates = ACME_MANAGER.get_reports();
+
+    GetAcmeCertificatesResponse::build(acme_certificates)
+}
@@ -0,0 +1,13 @@
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    core::shared_memory::CERTIFICATE_MONITOR,
+    rpc_service::models::get_certificate_expiry_response::GetCertificateExpiryResponse,
+};
+
+/// This JRPC service is responsible of returning expiry of certificates served by every HTTPS & Secure IWS route
+pub fn get_certificate_expiry(_payload: Value) -> Result<Value, Error> {
+    let certificates = CERTIFICATE_MONITOR.get_reports();
+
+    GetCertificateExpiryResponse::build(certificates)
+}
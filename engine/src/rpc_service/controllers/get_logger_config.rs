@@ -0,0 +1,13 @@
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    core::shared_memory::LOGGER,
+    rpc_service::models::get_logger_config_response::GetLoggerConfigResponse,
+};
+
+/// This JRPC service is responsible of returning logger settings in effect (log levels & log file rotation)
+pub fn get_logger_config(_params: Value) -> Result<Value, Error> {
+    let logger_settings = LOGGER.read().unwrap().settings.clone();
+
+    GetLoggerConfigResponse::build(logger_settings)
+}
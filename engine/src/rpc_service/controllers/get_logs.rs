@@ -0,0 +1,13 @@
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    core::shared_memory::LOG_BUFFER,
+    rpc_service::models::{get_logs_request::GetLogsRequest, get_logs_response::GetLogsResponse},
+};
+
+/// This JRPC service is responsible of returning recent engine log records filtered by level, time range and text
+pub fn get_logs(params: Value) -> Result<Value, Error> {
+    let request = GetLogsRequest::new(params)?;
+
+    GetLogsResponse::build(LOG_BUFFER.query(request.get_filter(), request.get_limit()))
+}
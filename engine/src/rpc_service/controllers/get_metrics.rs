@@ -0,0 +1,15 @@
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    core::shared_memory::METRICS,
+    rpc_service::models::{
+        get_metrics_request::GetMetricsRequest, get_metrics_response::GetMetricsResponse,
+    },
+};
+
+/// This JRPC service is responsible of returning a snapshot of engine metrics (request counts, latency, top routes & clients)
+pub fn get_metrics(params: Value) -> Result<Value, Error> {
+    let request = GetMetricsRequest::new(params)?;
+
+    GetMetricsResponse::build(METRICS.get_snapshot(request.get_top_limit()))
+}
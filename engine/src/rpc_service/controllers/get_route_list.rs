@@ -1,7 +1,8 @@
 use crate::common::enums::route_type::RouteType;
 use crate::rpc_service::models::get_route_list_response::GetRouteListResponse;
 use crate::{
-    core::shared_memory::ROUTER, rpc_service::models::get_route_list_request::GetRouteListRequest,
+    core::shared_memory::{HTTPS_ROUTE_SNAPSHOT, ROUTER},
+    rpc_service::models::get_route_list_request::GetRouteListRequest,
 };
 use jsonrpc_core::{Error, Value};
 
@@ -29,5 +30,18 @@ pub fn get_route_list(params: Value) -> Result<Value, Error> {
         response.secure_iws_routes = Some(router.get_secure_iws_routes());
     }
 
+    // Degraded state belongs to the published snapshot, since certificates are loaded while publishing
+    if matches!(route_type, RouteType::All | RouteType::Https | RouteType::SecureIws) {
+        let degraded_routes = HTTPS_ROUTE_SNAPSHOT
+            .load()
+            .sni_resolver
+            .get_degraded_routes()
+            .into_iter()
+            .filter(|degraded_route| route_type == RouteType::All || degraded_route.route_type == route_type)
+            .collect();
+
+        response.degraded_routes = Some(degraded_routes);
+    }
+
     Ok(response.build())
 }
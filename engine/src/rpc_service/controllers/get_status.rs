@@ -1,7 +1,7 @@
 use jsonrpc_core::{Error, Value};
 
 use crate::{
-    core::shared_memory::{ROUTER, RUNTIME_BOOT_INFO},
+    core::shared_memory::{HTTPS_ROUTE_SNAPSHOT, ROUTER, RUNTIME_BOOT_INFO},
     rpc_service::models::get_status_response::GetStatusResponse,
 };
 
@@ -11,7 +11,8 @@ pub fn get_status(_payload: Value) -> Result<Value, Error> {
     let router = ROUTER.read().unwrap().clone();
 
     let boot_info = status_data.clone();
+    let degraded_routes = HTTPS_ROUTE_SNAPSHOT.load().sni_resolver.get_degraded_routes();
 
-    let response = GetStatusResponse::build(boot_info, router)?;
+    let response = GetStatusResponse::build(boot_info, router, degraded_routes)?;
     Ok(response)
 }
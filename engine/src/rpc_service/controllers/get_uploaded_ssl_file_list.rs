@@ -0,0 +1,11 @@
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    rpc_service::models::get_uploaded_ssl_file_list_response::GetUploadedSslFileListResponse,
+    utils::ssl_file_utility::list_ssl_files,
+};
+
+/// This JRPC service is responsible of returning certificates & private keys in SSL upload directory
+pub fn get_uploaded_ssl_file_list(_params: Value) -> Result<Value, Error> {
+    GetUploadedSslFileListResponse::build(list_ssl_files())
+}
@@ -0,0 +1,39 @@
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    common::enums::route_type::RouteType,
+    core::shared_memory::{HTTPS_ROUTE_SNAPSHOT, HTTP_ROUTE_SNAPSHOT, UPSTREAM_HEALTH},
+    models::upstream_health_report::UpstreamHealthReport,
+    rpc_service::models::get_upstream_health_response::GetUpstreamHealthResponse,
+};
+
+/// This JRPC service is responsible of returning health of every upstream used by HTTP & HTTPS route pools
+pub fn get_upstream_health(_payload: Value) -> Result<Value, Error> {
+    let http_route_snapshot = HTTP_ROUTE_SNAPSHOT.load();
+    let https_route_snapshot = HTTPS_ROUTE_SNAPSHOT.load();
+
+    let http_pools = http_route_snapshot
+        .http_routes
+        .iter()
+        .filter_map(|(source, route)| Some((RouteType::Http, source, route.upstream_pool.as_ref()?)));
+
+    let https_pools = https_route_snapshot
+        .https_routes
+        .iter()
+        .filter_map(|(source, route)| Some((RouteType::Https, source, route.upstream_pool.as_ref()?)));
+
+    let mut upstreams: Vec<UpstreamHealthReport> = http_pools
+        .chain(https_pools)
+        .flat_map(|(route_type, source, upstream_pool)| {
+            upstream_pool.upstreams.iter().map(move |upstream| {
+                UPSTREAM_HEALTH.get_report(route_type.clone(), source, &upstream.target)
+            })
+        })
+        .collect();
+
+    upstreams.sort_by(|first, second| {
+        (&first.source, &first.target).cmp(&(&second.source, &second.target))
+    });
+
+    GetUpstreamHealthResponse::build(upstreams)
+}
@@ -0,0 +1,49 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    common::enums::ssl_file_type::SslFileType,
+    core::shared_memory::{ROUTER, RUNTIME_BOOT_INFO},
+    rpc_service::{
+        models::{
+            inspect_ssl_file_response::InspectSslFileResponse, ssl_file_request::SslFileRequest,
+        },
+        rpc_error::RPCError,
+    },
+    utils::{
+        ssl_file_utility::{get_private_key_format, get_ssl_file, read_ssl_file},
+        x509_utility::parse_pem_certificates,
+    },
+};
+
+/// This JRPC service returns details of an uploaded file: subject, SANs, issuer & validity of certificates,
+/// format of private keys and routes which use the file
+pub fn inspect_ssl_file(params: Value) -> Result<Value, Error> {
+    let request = SslFileRequest::new(params)?;
+    let name = request.get_name();
+
+    let content = read_ssl_file(&name)
+        .map_err(|error_message| RPCError::build(&StatusCode::NOT_FOUND, &error_message))?;
+    let ssl_file = get_ssl_file(&name)
+        .map_err(|error_message| RPCError::build(&StatusCode::NOT_FOUND, &error_message))?;
+
+    let certificates = match ssl_file.file_type {
+        SslFileType::PemCertificate => parse_pem_certificates(&content).map_err(|error_message| {
+            RPCError::build(
+                &StatusCode::UNPROCESSABLE_ENTITY,
+                &format!("Certificate can not be read: {}", error_message),
+            )
+        })?,
+        _ => Vec::new(),
+    };
+
+    let config = RUNTIME_BOOT_INFO.read().unwrap().config.clone();
+    let used_by = ROUTER.read().unwrap().find_ssl_file_users(&ssl_file.reference, &config);
+
+    InspectSslFileResponse::build(
+        ssl_file,
+        certificates,
+        get_private_key_format(&content),
+        used_by,
+    )
+}
@@ -0,0 +1,61 @@
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    common::enums::route_type::RouteType,
+    core::shared_memory::{HTTPS_ROUTE_SNAPSHOT, HTTP_ROUTE_SNAPSHOT, RUNTIME_BOOT_INFO},
+    rpc_service::models::list_blocked_response::{ListBlockedResponse, RouteIpAccess},
+};
+
+/// This JRPC service is responsible of returning global, server and route level ip access lists
+pub fn list_blocked(_payload: Value) -> Result<Value, Error> {
+    let (global, http_server, https_server) = {
+        let runtime_info = RUNTIME_BOOT_INFO.read().unwrap();
+        let config = &runtime_info.config;
+
+        (
+            config.global_traffic_policy.ip_access.clone(),
+            config.http_server.ip_access.clone().unwrap_or_default(),
+            config.https_server.ip_access.clone().unwrap_or_default(),
+        )
+    };
+
+    let http_route_snapshot = HTTP_ROUTE_SNAPSHOT.load();
+    let https_route_snapshot = HTTPS_ROUTE_SNAPSHOT.load();
+
+    let http_routes = http_route_snapshot
+        .http_routes
+        .iter()
+        .map(|(source, route)| (RouteType::Http, source, &route.ip_access));
+
+    let iws_routes = http_route_snapshot
+        .iws_routes
+        .iter()
+        .map(|(source, route)| (RouteType::Iws, source, &route.ip_access));
+
+    let https_routes = https_route_snapshot
+        .https_routes
+        .iter()
+        .map(|(source, route)| (RouteType::Https, source, &route.ip_access));
+
+    let secure_iws_routes = https_route_snapshot
+        .secure_iws_routes
+        .iter()
+        .map(|(source, route)| (RouteType::SecureIws, source, &route.ip_access));
+
+    let mut routes: Vec<RouteIpAccess> = http_routes
+        .chain(iws_routes)
+        .chain(https_routes)
+        .chain(secure_iws_routes)
+        .filter_map(|(route_type, source, ip_access)| {
+            Some(RouteIpAccess {
+                route_type,
+                source: source.clone(),
+                ip_access: ip_access.clone()?,
+            })
+        })
+        .collect();
+
+    routes.sort_by(|first, second| first.source.cmp(&second.source));
+
+    ListBlockedResponse::build(global, http_server, https_server, routes)
+}
@@ -13,7 +13,30 @@ pub mod delete_iws_route;
 pub mod add_secure_iws_route;
 pub mod delete_secure_iws_route;
 
+pub mod add_path_rule;
+pub mod delete_path_rule;
+
+pub mod add_rewrite_rule;
+pub mod delete_rewrite_rule;
+
 pub mod get_build_version;
 pub mod get_status;
+pub mod get_upstream_health;
 
 pub mod get_config;
+
+pub mod block_ip;
+pub mod unblock_ip;
+pub mod list_blocked;
+pub mod get_metrics;
+pub mod get_logs;
+pub mod subscribe_logs;
+pub mod get_logger_config;
+pub mod set_logger_config;
+pub mod upload_ssl_file;
+pub mod get_uploaded_ssl_file_list;
+pub mod inspect_ssl_file;
+pub mod delete_ssl_file;
+pub mod get_acme_certificates;
+pub mod renew_acme_certificate;
+pub mod get_certificate_expiry;
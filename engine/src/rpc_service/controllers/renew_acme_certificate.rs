@@ -0,0 +1,35 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    core::shared_memory::{ACME_MANAGER, RUNTIME_BOOT_INFO},
+    log_info,
+    rpc_service::{
+        models::{
+            renew_acme_certificate_request::RenewAcmeCertificateRequest,
+            renew_acme_certificate_response::RenewAcmeCertificateResponse,
+        },
+        rpc_error::RPCError,
+    },
+};
+
+/// This JRPC service schedules a new certificate order for an ACME managed route. Order is placed on next manager tick
+pub fn renew_acme_certificate(params: Value) -> Result<Value, Error> {
+    let request = RenewAcmeCertificateRequest::new(params)?;
+    let source = request.get_source();
+
+    if !RUNTIME_BOOT_INFO.read().unwrap().config.acme.is_active {
+        return Err(RPCError::build(
+            &StatusCode::BAD_REQUEST,
+            "ACME is not active. Please enable 'acme' in engine config first",
+        ));
+    }
+
+    ACME_MANAGER
+        .request_renewal(&source)
+        .map_err(|error_message| RPCError::build(&StatusCode::NOT_FOUND, &error_message))?;
+
+    log_info!("ACME certificate renewal of '{}' is requested", source);
+
+    RenewAcmeCertificateResponse::build()
+}
@@ -0,0 +1,33 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    core::{log_service::update_logger_settings, shared_memory::LOGGER},
+    log_info,
+    rpc_service::{
+        models::{
+            get_logger_config_response::GetLoggerConfigResponse,
+            set_logger_config_request::SetLoggerConfigRequest,
+        },
+        rpc_error::RPCError,
+    },
+};
+
+/// This JRPC service changes log levels & log file rotation of the running engine and saves them into settings file
+pub fn set_logger_config(params: Value) -> Result<Value, Error> {
+    let request = SetLoggerConfigRequest::new(params)?;
+
+    let logger_settings = request.apply(&LOGGER.read().unwrap().settings);
+    let applied_settings = update_logger_settings(logger_settings).map_err(|error_message| {
+        RPCError::build(&StatusCode::INTERNAL_SERVER_ERROR, &error_message)
+    })?;
+
+    log_info!(
+        "Logger settings are updated. Levels: {:?}, log file size: {}, keeping last {} logs",
+        applied_settings.log_levels,
+        applied_settings.log_file_size,
+        applied_settings.keep_last_logs
+    );
+
+    GetLoggerConfigResponse::build(applied_settings)
+}
@@ -0,0 +1,21 @@
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    core::shared_memory::LOG_BUFFER,
+    rpc_service::models::{
+        get_logs_response::GetLogsResponse, subscribe_logs_request::SubscribeLogsRequest,
+    },
+};
+
+/// This JRPC service is responsible of streaming new engine log records to subscribers.
+/// JRPC server is request-response based, so subscribers poll with the cursor returned by their previous call.
+/// First call (without cursor) starts the subscription from the newest record
+pub fn subscribe_logs(params: Value) -> Result<Value, Error> {
+    let request = SubscribeLogsRequest::new(params)?;
+
+    GetLogsResponse::build(LOG_BUFFER.read_after(
+        request.get_cursor(),
+        request.get_filter(),
+        request.get_limit(),
+    ))
+}
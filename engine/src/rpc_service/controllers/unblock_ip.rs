@@ -0,0 +1,46 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::common::enums::ip_access_scope::IpAccessScope;
+use crate::core::ip_access_control::update_config_ip_access;
+use crate::core::shared_memory::ROUTER;
+use crate::rpc_service::models::unblock_ip_response::UnblockIpResponse;
+use crate::rpc_service::models::ip_access_entry_request::IpAccessEntryRequest;
+use crate::rpc_service::rpc_error::RPCError;
+
+/// This JRPC service removes an ip address or CIDR block from allow or deny list of given scope
+pub fn unblock_ip(params: Value) -> Result<Value, Error> {
+    let request = IpAccessEntryRequest::new(params)?;
+
+    let ip = request.get_ip();
+    let list_type = request.get_list_type();
+
+    let is_removed = match (request.get_scope(), request.get_route()) {
+        (IpAccessScope::Route, Some((route_type, source))) => {
+            let mut router = ROUTER.write().unwrap();
+            router
+                .remove_ip_access_entry(&route_type, &source, &list_type, &ip)
+                .ok_or_else(|| {
+                    RPCError::build(
+                        &StatusCode::NOT_FOUND,
+                        &format!("Route '{}' does not exist", source),
+                    )
+                })?
+        }
+        (scope, _) => update_config_ip_access(&scope, |ip_access| {
+            ip_access.remove_entry(&list_type, &ip)
+        })
+        .map_err(|error_message| {
+            RPCError::build(&StatusCode::INTERNAL_SERVER_ERROR, &error_message)
+        })?,
+    };
+
+    if !is_removed {
+        return Err(RPCError::build(
+            &StatusCode::NOT_FOUND,
+            &format!("'{}' is not in {} list", ip, list_type),
+        ));
+    }
+
+    UnblockIpResponse::build()
+}
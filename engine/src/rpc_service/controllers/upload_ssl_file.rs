@@ -0,0 +1,57 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    common::enums::{route_type::RouteType, ssl_file_type::SslFileType},
+    core::shared_memory::{ROUTER, RUNTIME_BOOT_INFO},
+    log_info,
+    rpc_service::{
+        models::{
+            upload_ssl_file_request::UploadSslFileRequest,
+            upload_ssl_file_response::UploadSslFileResponse,
+        },
+        rpc_error::RPCError,
+    },
+    utils::{
+        ssl_file_utility::{detect_ssl_file_type, save_ssl_file},
+        x509_utility::parse_pem_certificates,
+    },
+};
+
+/// This JRPC service stores a base64 encoded PEM certificate or private key into SSL upload directory.
+/// Uploaded files are referenced by routes as `@vanguard/<name>`
+pub fn upload_ssl_file(params: Value) -> Result<Value, Error> {
+    let request = UploadSslFileRequest::new(params)?;
+    let name = request.get_name();
+    let content = request.get_content();
+
+    // Certificates are parsed before saving, so broken uploads are reported instead of failing at route creation
+    if detect_ssl_file_type(content) == SslFileType::PemCertificate {
+        if let Err(error_message) = parse_pem_certificates(content) {
+            return Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                &format!("Uploaded certificate can not be read: {}", error_message),
+            ));
+        }
+    }
+
+    let ssl_file = save_ssl_file(&name, content, request.get_overwrite()).map_err(|error_message| {
+        let status_code = match error_message.ends_with("already exists") {
+            true => StatusCode::CONFLICT,
+            false => StatusCode::BAD_REQUEST,
+        };
+        RPCError::build(&status_code, &error_message)
+    })?;
+
+    log_info!("SSL file '{}' is uploaded as {}", name, ssl_file.reference);
+
+    // Routes using an overwritten file are reloaded with its new content
+    let config = RUNTIME_BOOT_INFO.read().unwrap().config.clone();
+    let router = ROUTER.read().unwrap();
+    if !router.find_ssl_file_users(&ssl_file.reference, &config).is_empty() {
+        router.publish(&RouteType::Https);
+    }
+    drop(router);
+
+    UploadSslFileResponse::build(ssl_file)
+}
@@ -1,3 +1,11 @@
+use crate::core::host_matcher::validate_host_pattern;
+use crate::core::load_balancer::validate_upstream_pool;
+use crate::core::forwarded_headers::validate_route_forwarded_header_settings;
+use crate::core::rate_limiter::validate_rate_limit_settings;
+use crate::core::header_rules::validate_header_rules;
+use crate::utils::tls_utility::validate_upstream_tls_settings;
+use crate::models::route::upstream_pool::UpstreamPool;
+use crate::models::route::header_rule::HeaderRule;
 use crate::{models::traffic_policy::scope_traffic_policy::ScopeTrafficPolicy, rpc_service::rpc_error::RPCError, utils::rpc_utility::RpcParameter};
 use hyper::StatusCode;
 use jsonrpc_core::{Error, Value};
@@ -6,6 +14,8 @@ pub struct AddHttpRouteRequest {
     source: String,
     target: String,
     traffic_policy: Option<ScopeTrafficPolicy>,
+    header_rules: Vec<HeaderRule>,
+    upstream_pool: Option<UpstreamPool>,
 }
 
 impl AddHttpRouteRequest {
@@ -13,6 +23,10 @@ impl AddHttpRouteRequest {
         let source = RpcParameter::extract_string("source", &params);
         let target = RpcParameter::extract_string("target", &params);
         let traffic_policy = RpcParameter::extract_traffic_policy("traffic_policy", &params);
+        let header_rules = RpcParameter::extract_header_rules("header_rules", &params)
+            .map_err(|error_message| RPCError::build(&StatusCode::BAD_REQUEST, &error_message))?;
+        let upstream_pool = RpcParameter::extract_upstream_pool("upstream_pool", &params)
+            .map_err(|error_message| RPCError::build(&StatusCode::BAD_REQUEST, &error_message))?;
 
         if source.is_none() {
             return Err(RPCError::build(
@@ -28,10 +42,38 @@ impl AddHttpRouteRequest {
             ));
         }
 
+        if let Err(error_message) = validate_host_pattern(source.as_ref().unwrap()) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
+        }
+
+        if let Some(ref upstream_pool) = upstream_pool {
+            if let Err(error_message) = validate_upstream_pool(upstream_pool) {
+                return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
+            }
+        }
+
+        if let Err(error) = validate_upstream_tls_settings(&traffic_policy) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error.message));
+        }
+
+        if let Err(error_message) = validate_rate_limit_settings(&traffic_policy) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
+        }
+
+        if let Err(error_message) = validate_route_forwarded_header_settings(&traffic_policy) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
+        }
+
+        if let Err(error_message) = validate_header_rules(&header_rules) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
+        }
+
         Ok(Self {
             source: source.unwrap(),
             target: target.unwrap(),
             traffic_policy,
+            upstream_pool,
+            header_rules,
         })
     }
 
@@ -47,4 +89,12 @@ impl AddHttpRouteRequest {
     pub fn get_traffic_policy(&self) -> Option<ScopeTrafficPolicy> {
         self.traffic_policy.clone()
     }
+
+    pub fn get_upstream_pool(&self) -> Option<UpstreamPool> {
+        self.upstream_pool.clone()
+    }
+
+    pub fn get_header_rules(&self) -> Vec<HeaderRule> {
+        self.header_rules.clone()
+    }
 }
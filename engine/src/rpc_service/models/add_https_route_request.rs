@@ -1,3 +1,15 @@
+use crate::core::host_matcher::validate_host_pattern;
+use crate::models::ssl_context::SslContext;
+use crate::rpc_service::models::ssl_context_request::{build_acme_ssl_context, build_ssl_context};
+use crate::core::load_balancer::validate_upstream_pool;
+use crate::core::forwarded_headers::validate_route_forwarded_header_settings;
+use crate::core::rate_limiter::validate_rate_limit_settings;
+use crate::core::header_rules::validate_header_rules;
+use crate::utils::tls_utility::{
+    validate_client_auth_settings, validate_route_tls_settings, validate_upstream_tls_settings,
+};
+use crate::models::route::upstream_pool::UpstreamPool;
+use crate::models::route::header_rule::HeaderRule;
 use crate::{
     models::traffic_policy::scope_traffic_policy::ScopeTrafficPolicy,
     rpc_service::rpc_error::RPCError, utils::rpc_utility::RpcParameter,
@@ -8,9 +20,10 @@ use jsonrpc_core::{Error, Value};
 pub struct AddHttpsRouteRequest {
     source: String,
     target: String,
-    ssl_cert_path: String,
-    ssl_private_key_path: String,
+    ssl_context: SslContext,
     traffic_policy: Option<ScopeTrafficPolicy>,
+    header_rules: Vec<HeaderRule>,
+    upstream_pool: Option<UpstreamPool>,
 }
 
 impl AddHttpsRouteRequest {
@@ -19,7 +32,16 @@ impl AddHttpsRouteRequest {
         let target = RpcParameter::extract_string("target", &params);
         let ssl_cert_path = RpcParameter::extract_string("ssl_cert_path", &params);
         let ssl_private_key_path = RpcParameter::extract_string("ssl_private_key_path", &params);
+        let is_acme_managed = RpcParameter::extract_bool("acme", &params).unwrap_or(false);
+        let client_auth = RpcParameter::extract_client_auth("client_auth", &params)
+            .map_err(|error_message| RPCError::build(&StatusCode::BAD_REQUEST, &error_message))?;
+        let tls_settings = RpcParameter::extract_tls_settings("tls", &params)
+            .map_err(|error_message| RPCError::build(&StatusCode::BAD_REQUEST, &error_message))?;
         let traffic_policy = RpcParameter::extract_traffic_policy("traffic_policy", &params);
+        let header_rules = RpcParameter::extract_header_rules("header_rules", &params)
+            .map_err(|error_message| RPCError::build(&StatusCode::BAD_REQUEST, &error_message))?;
+        let upstream_pool = RpcParameter::extract_upstream_pool("upstream_pool", &params)
+            .map_err(|error_message| RPCError::build(&StatusCode::BAD_REQUEST, &error_message))?;
 
         if source.is_none() {
             return Err(RPCError::build(
@@ -35,26 +57,54 @@ impl AddHttpsRouteRequest {
             ));
         }
 
-        if ssl_cert_path.is_none() {
-            return Err(RPCError::build(
-                &StatusCode::BAD_REQUEST,
-                "Please provide 'ssl_cert_path' parameter",
-            ));
+        if let Err(error_message) = validate_host_pattern(source.as_ref().unwrap()) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
         }
 
-        if ssl_private_key_path.is_none() {
-            return Err(RPCError::build(
-                &StatusCode::BAD_REQUEST,
-                "Please provide 'ssl_private_key_path' parameter",
-            ));
+        let mut ssl_context = match is_acme_managed {
+            true => build_acme_ssl_context(source.as_ref().unwrap())?,
+            false => build_ssl_context(ssl_cert_path, ssl_private_key_path)?,
+        };
+
+        if let Err(error) = validate_client_auth_settings(&client_auth) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error.message));
+        }
+        ssl_context.client_auth = client_auth;
+
+        if let Err(error) = validate_route_tls_settings(&tls_settings) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error.message));
+        }
+        ssl_context.tls = tls_settings;
+
+        if let Some(ref upstream_pool) = upstream_pool {
+            if let Err(error_message) = validate_upstream_pool(upstream_pool) {
+                return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
+            }
+        }
+
+        if let Err(error) = validate_upstream_tls_settings(&traffic_policy) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error.message));
+        }
+
+        if let Err(error_message) = validate_rate_limit_settings(&traffic_policy) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
+        }
+
+        if let Err(error_message) = validate_route_forwarded_header_settings(&traffic_policy) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
+        }
+
+        if let Err(error_message) = validate_header_rules(&header_rules) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
         }
 
         Ok(Self {
             source: source.unwrap(),
             target: target.unwrap(),
-            ssl_cert_path: ssl_cert_path.unwrap(),
-            ssl_private_key_path: ssl_private_key_path.unwrap(),
+            ssl_context,
             traffic_policy,
+            upstream_pool,
+            header_rules,
         })
     }
 
@@ -67,15 +117,19 @@ impl AddHttpsRouteRequest {
         self.target.clone()
     }
 
-    pub fn get_ssl_cert_path(&self) -> String {
-        self.ssl_cert_path.clone()
-    }
-
-    pub fn get_ssl_private_key_path(&self) -> String {
-        self.ssl_private_key_path.clone()
+    pub fn get_ssl_context(&self) -> SslContext {
+        self.ssl_context.clone()
     }
 
     pub fn get_traffic_policy(&self) -> Option<ScopeTrafficPolicy> {
         self.traffic_policy.clone()
     }
+
+    pub fn get_upstream_pool(&self) -> Option<UpstreamPool> {
+        self.upstream_pool.clone()
+    }
+
+    pub fn get_header_rules(&self) -> Vec<HeaderRule> {
+        self.header_rules.clone()
+    }
 }
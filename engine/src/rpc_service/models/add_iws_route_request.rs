@@ -1,3 +1,6 @@
+use crate::core::host_matcher::validate_host_pattern;
+use crate::core::header_rules::validate_header_rules;
+use crate::models::route::header_rule::HeaderRule;
 use crate::{models::traffic_policy::scope_traffic_policy::ScopeTrafficPolicy, rpc_service::rpc_error::RPCError, utils::rpc_utility::RpcParameter};
 use hyper::StatusCode;
 use jsonrpc_core::{Error, Value};
@@ -7,6 +10,7 @@ pub struct AddIwsRouteRequest {
     source: String,
     serving_path: String,
     traffic_policy: Option<ScopeTrafficPolicy>,
+    header_rules: Vec<HeaderRule>,
 }
 
 impl AddIwsRouteRequest {
@@ -14,6 +18,8 @@ impl AddIwsRouteRequest {
         let source = RpcParameter::extract_string("source", &params);
         let serving_path = RpcParameter::extract_string("serving_path", &params);
         let traffic_policy = RpcParameter::extract_traffic_policy("traffic_policy", &params);
+        let header_rules = RpcParameter::extract_header_rules("header_rules", &params)
+            .map_err(|error_message| RPCError::build(&StatusCode::BAD_REQUEST, &error_message))?;
 
         if source.is_none() {
             return Err(RPCError::build(
@@ -29,10 +35,19 @@ impl AddIwsRouteRequest {
             ));
         }
 
+        if let Err(error_message) = validate_host_pattern(source.as_ref().unwrap()) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
+        }
+
+        if let Err(error_message) = validate_header_rules(&header_rules) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
+        }
+
         Ok(Self {
             source: source.unwrap(),
             serving_path: serving_path.unwrap(),
             traffic_policy,
+            header_rules,
         })
     }
 
@@ -48,4 +63,8 @@ impl AddIwsRouteRequest {
     pub fn get_traffic_policy(&self) -> Option<ScopeTrafficPolicy> {
         self.traffic_policy.clone()
     }
+
+    pub fn get_header_rules(&self) -> Vec<HeaderRule> {
+        self.header_rules.clone()
+    }
 }
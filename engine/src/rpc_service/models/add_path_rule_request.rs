@@ -0,0 +1,110 @@
+use crate::core::path_matcher::validate_path_rule;
+use crate::{
+    common::enums::{path_match_type::PathMatchType, route_type::RouteType},
+    models::route::path_rule::PathRule,
+    rpc_service::rpc_error::RPCError,
+    utils::rpc_utility::RpcParameter,
+};
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+pub struct AddPathRuleRequest {
+    route_type: RouteType,
+    source: String,
+    path_rule: PathRule,
+}
+
+impl AddPathRuleRequest {
+    pub fn new(params: Value) -> Result<Self, Error> {
+        let route_type = RpcParameter::extract_string_enum::<RouteType>("route_type", &params);
+        let source = RpcParameter::extract_string("source", &params);
+        let path = RpcParameter::extract_string("path", &params);
+        let match_type = RpcParameter::extract_string("match_type", &params);
+        let target = RpcParameter::extract_string("target", &params);
+        let serving_path = RpcParameter::extract_string("serving_path", &params);
+        let strip_prefix = RpcParameter::extract_bool("strip_prefix", &params);
+        let path_policy = RpcParameter::extract_path_policy("path_policy", &params);
+
+        let route_type = match route_type {
+            Some(RouteType::All) | None => {
+                return Err(RPCError::build(
+                    &StatusCode::BAD_REQUEST,
+                    "Please provide 'route_type' parameter (http, https, iws, secure-iws)",
+                ));
+            }
+            Some(route_type) => route_type,
+        };
+
+        if source.is_none() {
+            return Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                "Please provide 'source' parameter",
+            ));
+        }
+
+        if path.is_none() {
+            return Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                "Please provide 'path' parameter",
+            ));
+        }
+
+        let match_type = match match_type {
+            Some(match_type) => match match_type.parse::<PathMatchType>() {
+                Ok(match_type) => match_type,
+                Err(error_message) => {
+                    return Err(RPCError::build(&StatusCode::BAD_REQUEST, error_message));
+                }
+            },
+            None => PathMatchType::default(),
+        };
+
+        let is_proxy_route = matches!(route_type, RouteType::Http | RouteType::Https);
+
+        if is_proxy_route && target.is_none() {
+            return Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                "Please provide 'target' parameter",
+            ));
+        }
+
+        if !is_proxy_route && serving_path.is_none() {
+            return Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                "Please provide 'serving_path' parameter",
+            ));
+        }
+
+        let path_rule = PathRule {
+            path: path.unwrap(),
+            match_type,
+            target: if is_proxy_route { target } else { None },
+            serving_path: if is_proxy_route { None } else { serving_path },
+            strip_prefix: strip_prefix.unwrap_or(false),
+            path_policy,
+        };
+
+        if let Err(error_message) = validate_path_rule(&path_rule) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
+        }
+
+        Ok(Self {
+            route_type,
+            source: source.unwrap(),
+            path_rule,
+        })
+    }
+
+    // getters
+    pub fn get_route_type(&self) -> RouteType {
+        self.route_type.clone()
+    }
+
+    pub fn get_source(&self) -> String {
+        self.source.clone()
+    }
+
+    pub fn get_path_rule(&self) -> PathRule {
+        self.path_rule.clone()
+    }
+}
@@ -0,0 +1,117 @@
+use crate::core::rewrite_matcher::validate_rewrite_rule;
+use crate::{
+    common::enums::{rewrite_action::RewriteAction, route_type::RouteType},
+    models::route::rewrite_rule::RewriteRule,
+    rpc_service::rpc_error::RPCError,
+    utils::rpc_utility::RpcParameter,
+};
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+pub struct AddRewriteRuleRequest {
+    route_type: RouteType,
+    source: String,
+    rewrite_rule: RewriteRule,
+}
+
+impl AddRewriteRuleRequest {
+    pub fn new(params: Value) -> Result<Self, Error> {
+        let route_type = RpcParameter::extract_string_enum::<RouteType>("route_type", &params);
+        let source = RpcParameter::extract_string("source", &params);
+        let pattern = RpcParameter::extract_string("pattern", &params);
+        let action = RpcParameter::extract_string("action", &params);
+        let replacement = RpcParameter::extract_string("replacement", &params);
+        let status_code = RpcParameter::extract_u64("status_code", &params);
+        let body = RpcParameter::extract_string("body", &params);
+        let conditions = RpcParameter::extract_rewrite_conditions("conditions", &params);
+
+        let route_type = match route_type {
+            Some(RouteType::All) | None => {
+                return Err(RPCError::build(
+                    &StatusCode::BAD_REQUEST,
+                    "Please provide 'route_type' parameter (http, https, iws, secure-iws)",
+                ));
+            }
+            Some(route_type) => route_type,
+        };
+
+        if source.is_none() {
+            return Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                "Please provide 'source' parameter",
+            ));
+        }
+
+        if pattern.is_none() {
+            return Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                "Please provide 'pattern' parameter",
+            ));
+        }
+
+        let action = match action {
+            Some(action) => match action.parse::<RewriteAction>() {
+                Ok(action) => action,
+                Err(error_message) => {
+                    return Err(RPCError::build(&StatusCode::BAD_REQUEST, error_message));
+                }
+            },
+            None => {
+                return Err(RPCError::build(
+                    &StatusCode::BAD_REQUEST,
+                    "Please provide 'action' parameter (rewrite, redirect, return)",
+                ));
+            }
+        };
+
+        let status_code = match status_code.map(u16::try_from) {
+            Some(Ok(status_code)) => Some(status_code),
+            Some(Err(_)) => {
+                return Err(RPCError::build(
+                    &StatusCode::BAD_REQUEST,
+                    "Invalid 'status_code' parameter",
+                ));
+            }
+            None => None,
+        };
+
+        let conditions = match conditions {
+            Ok(conditions) => conditions,
+            Err(error_message) => {
+                return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
+            }
+        };
+
+        let rewrite_rule = RewriteRule {
+            pattern: pattern.unwrap(),
+            action,
+            replacement,
+            status_code,
+            body,
+            conditions,
+        };
+
+        if let Err(error_message) = validate_rewrite_rule(&rewrite_rule) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
+        }
+
+        Ok(Self {
+            route_type,
+            source: source.unwrap(),
+            rewrite_rule,
+        })
+    }
+
+    // getters
+    pub fn get_route_type(&self) -> RouteType {
+        self.route_type.clone()
+    }
+
+    pub fn get_source(&self) -> String {
+        self.source.clone()
+    }
+
+    pub fn get_rewrite_rule(&self) -> RewriteRule {
+        self.rewrite_rule.clone()
+    }
+}
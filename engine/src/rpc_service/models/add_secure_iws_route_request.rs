@@ -1,3 +1,9 @@
+use crate::core::host_matcher::validate_host_pattern;
+use crate::core::header_rules::validate_header_rules;
+use crate::models::ssl_context::SslContext;
+use crate::models::route::header_rule::HeaderRule;
+use crate::utils::tls_utility::{validate_client_auth_settings, validate_route_tls_settings};
+use crate::rpc_service::models::ssl_context_request::{build_acme_ssl_context, build_ssl_context};
 use crate::{models::traffic_policy::scope_traffic_policy::ScopeTrafficPolicy, rpc_service::rpc_error::RPCError, utils::rpc_utility::RpcParameter};
 use hyper::StatusCode;
 use jsonrpc_core::{Error, Value};
@@ -5,9 +11,9 @@ use jsonrpc_core::{Error, Value};
 pub struct AddSecureIwsRouteRequest {
     source: String,
     serving_path: String,
-    ssl_cert_path: String,
-    ssl_private_key_path: String,
+    ssl_context: SslContext,
     traffic_policy: Option<ScopeTrafficPolicy>,
+    header_rules: Vec<HeaderRule>,
 }
 
 impl AddSecureIwsRouteRequest {
@@ -16,7 +22,14 @@ impl AddSecureIwsRouteRequest {
         let serving_path = RpcParameter::extract_string("serving_path", &params);
         let ssl_cert_path = RpcParameter::extract_string("ssl_cert_path", &params);
         let ssl_private_key_path = RpcParameter::extract_string("ssl_private_key_path", &params);
+        let is_acme_managed = RpcParameter::extract_bool("acme", &params).unwrap_or(false);
+        let client_auth = RpcParameter::extract_client_auth("client_auth", &params)
+            .map_err(|error_message| RPCError::build(&StatusCode::BAD_REQUEST, &error_message))?;
+        let tls_settings = RpcParameter::extract_tls_settings("tls", &params)
+            .map_err(|error_message| RPCError::build(&StatusCode::BAD_REQUEST, &error_message))?;
         let traffic_policy = RpcParameter::extract_traffic_policy("traffic_policy", &params);
+        let header_rules = RpcParameter::extract_header_rules("header_rules", &params)
+            .map_err(|error_message| RPCError::build(&StatusCode::BAD_REQUEST, &error_message))?;
 
         if source.is_none() {
             return Err(RPCError::build(
@@ -32,26 +45,35 @@ impl AddSecureIwsRouteRequest {
             ));
         }
 
-        if ssl_cert_path.is_none() {
-            return Err(RPCError::build(
-                &StatusCode::BAD_REQUEST,
-                "Please provide 'ssl_cert_path' parameter",
-            ));
+        if let Err(error_message) = validate_host_pattern(source.as_ref().unwrap()) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
         }
 
-        if ssl_private_key_path.is_none() {
-            return Err(RPCError::build(
-                &StatusCode::BAD_REQUEST,
-                "Please provide 'ssl_private_key_path' parameter",
-            ));
+        let mut ssl_context = match is_acme_managed {
+            true => build_acme_ssl_context(source.as_ref().unwrap())?,
+            false => build_ssl_context(ssl_cert_path, ssl_private_key_path)?,
+        };
+
+        if let Err(error) = validate_client_auth_settings(&client_auth) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error.message));
+        }
+        ssl_context.client_auth = client_auth;
+
+        if let Err(error) = validate_route_tls_settings(&tls_settings) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error.message));
+        }
+        ssl_context.tls = tls_settings;
+
+        if let Err(error_message) = validate_header_rules(&header_rules) {
+            return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
         }
 
         Ok(Self {
             source: source.unwrap(),
             serving_path: serving_path.unwrap(),
-            ssl_cert_path: ssl_cert_path.unwrap(),
-            ssl_private_key_path: ssl_private_key_path.unwrap(),
+            ssl_context,
             traffic_policy,
+            header_rules,
         })
     }
 
@@ -64,15 +86,15 @@ impl AddSecureIwsRouteRequest {
         self.serving_path.clone()
     }
 
-    pub fn get_ssl_cert_path(&self) -> String {
-        self.ssl_cert_path.clone()
-    }
-
-    pub fn get_ssl_private_key_path(&self) -> String {
-        self.ssl_private_key_path.clone()
+    pub fn get_ssl_context(&self) -> SslContext {
+        self.ssl_context.clone()
     }
 
     pub fn get_traffic_policy(&self) -> Option<ScopeTrafficPolicy> {
         self.traffic_policy.clone()
     }
+
+    pub fn get_header_rules(&self) -> Vec<HeaderRule> {
+        self.header_rules.clone()
+    }
 }
@@ -0,0 +1,63 @@
+use crate::{
+    common::enums::route_type::RouteType, rpc_service::rpc_error::RPCError,
+    utils::rpc_utility::RpcParameter,
+};
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+pub struct DeletePathRuleRequest {
+    route_type: RouteType,
+    source: String,
+    path: String,
+}
+
+impl DeletePathRuleRequest {
+    pub fn new(params: Value) -> Result<Self, Error> {
+        let route_type = RpcParameter::extract_string_enum::<RouteType>("route_type", &params);
+        let source = RpcParameter::extract_string("source", &params);
+        let path = RpcParameter::extract_string("path", &params);
+
+        let route_type = match route_type {
+            Some(RouteType::All) | None => {
+                return Err(RPCError::build(
+                    &StatusCode::BAD_REQUEST,
+                    "Please provide 'route_type' parameter (http, https, iws, secure-iws)",
+                ));
+            }
+            Some(route_type) => route_type,
+        };
+
+        if source.is_none() {
+            return Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                "Please provide 'source' parameter",
+            ));
+        }
+
+        if path.is_none() {
+            return Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                "Please provide 'path' parameter",
+            ));
+        }
+
+        Ok(Self {
+            route_type,
+            source: source.unwrap(),
+            path: path.unwrap(),
+        })
+    }
+
+    // getters
+    pub fn get_route_type(&self) -> RouteType {
+        self.route_type.clone()
+    }
+
+    pub fn get_source(&self) -> String {
+        self.source.clone()
+    }
+
+    pub fn get_path(&self) -> String {
+        self.path.clone()
+    }
+}
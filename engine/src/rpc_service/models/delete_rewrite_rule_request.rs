@@ -0,0 +1,63 @@
+use crate::{
+    common::enums::route_type::RouteType, rpc_service::rpc_error::RPCError,
+    utils::rpc_utility::RpcParameter,
+};
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+pub struct DeleteRewriteRuleRequest {
+    route_type: RouteType,
+    source: String,
+    pattern: String,
+}
+
+impl DeleteRewriteRuleRequest {
+    pub fn new(params: Value) -> Result<Self, Error> {
+        let route_type = RpcParameter::extract_string_enum::<RouteType>("route_type", &params);
+        let source = RpcParameter::extract_string("source", &params);
+        let pattern = RpcParameter::extract_string("pattern", &params);
+
+        let route_type = match route_type {
+            Some(RouteType::All) | None => {
+                return Err(RPCError::build(
+                    &StatusCode::BAD_REQUEST,
+                    "Please provide 'route_type' parameter (http, https, iws, secure-iws)",
+                ));
+            }
+            Some(route_type) => route_type,
+        };
+
+        if source.is_none() {
+            return Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                "Please provide 'source' parameter",
+            ));
+        }
+
+        if pattern.is_none() {
+            return Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                "Please provide 'pattern' parameter",
+            ));
+        }
+
+        Ok(Self {
+            route_type,
+            source: source.unwrap(),
+            pattern: pattern.unwrap(),
+        })
+    }
+
+    // getters
+    pub fn get_route_type(&self) -> RouteType {
+        self.route_type.clone()
+    }
+
+    pub fn get_source(&self) -> String {
+        self.source.clone()
+    }
+
+    pub fn get_pattern(&self) -> String {
+        self.pattern.clone()
+    }
+}
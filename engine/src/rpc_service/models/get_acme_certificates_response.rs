@@ -0,0 +1,33 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, ErrorCode, Value};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::acme_certificate_report::AcmeCertificateReport,
+    rpc_service::rpc_status_message::RpcStatusMessage,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct GetAcmeCertificatesResponse {
+    pub code: u16,
+    pub message: String,
+    pub acme_certificates: Vec<AcmeCertificateReport>,
+}
+
+impl GetAcmeCertificatesResponse {
+    pub fn build(acme_certificates: Vec<AcmeCertificateReport>) -> Result<Value, Error> {
+        let response = GetAcmeCertificatesResponse {
+            code: StatusCode::OK.as_u16(),
+            message: RpcStatusMessage::OK.to_string(),
+            acme_certificates,
+        };
+
+        let response_as_json = serde_json::to_value(response).map_err(|error_details| Error {
+            code: ErrorCode::InternalError,
+            message: error_details.to_string(),
+            data: None,
+        })?;
+
+        Ok(response_as_json)
+    }
+}
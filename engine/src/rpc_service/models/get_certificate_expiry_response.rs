@@ -0,0 +1,33 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, ErrorCode, Value};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::certificate_expiry_report::CertificateExpiryReport,
+    rpc_service::rpc_status_message::RpcStatusMessage,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct GetCertificateExpiryResponse {
+    pub code: u16,
+    pub message: String,
+    pub certificates: Vec<CertificateExpiryReport>,
+}
+
+impl GetCertificateExpiryResponse {
+    pub fn build(certificates: Vec<CertificateExpiryReport>) -> Result<Value, Error> {
+        let response = GetCertificateExpiryResponse {
+            code: StatusCode::OK.as_u16(),
+            message: RpcStatusMessage::OK.to_string(),
+            certificates,
+        };
+
+        let response_as_json = serde_json::to_value(response).map_err(|error_details| Error {
+            code: ErrorCode::InternalError,
+            message: error_details.to_string(),
+            data: None,
+        })?;
+
+        Ok(response_as_json)
+    }
+}
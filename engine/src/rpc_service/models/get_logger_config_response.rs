@@ -0,0 +1,34 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, ErrorCode, Value};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::settings::logger_settings::LoggerSettings,
+    rpc_service::rpc_status_message::RpcStatusMessage,
+};
+
+/// Response of `get_logger_config` & `set_logger_config`, carrying logger settings in effect
+#[derive(Serialize, Deserialize)]
+pub struct GetLoggerConfigResponse {
+    code: u16,
+    message: String,
+    logger: LoggerSettings,
+}
+
+impl GetLoggerConfigResponse {
+    pub fn build(logger: LoggerSettings) -> Result<Value, Error> {
+        let response = GetLoggerConfigResponse {
+            code: StatusCode::OK.as_u16(),
+            message: RpcStatusMessage::OK.to_string(),
+            logger,
+        };
+
+        let response_as_json = serde_json::to_value(response).map_err(|error_details| Error {
+            code: ErrorCode::InternalError,
+            message: error_details.to_string(),
+            data: None,
+        })?;
+
+        Ok(response_as_json)
+    }
+}
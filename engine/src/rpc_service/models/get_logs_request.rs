@@ -0,0 +1,113 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::constants::Constants;
+use crate::models::log_record::LogFilter;
+use crate::rpc_service::rpc_error::RPCError;
+use crate::utils::rpc_utility::RpcParameter;
+
+pub struct GetLogsRequest {
+    filter: LogFilter,
+    limit: usize,
+}
+
+impl GetLogsRequest {
+    pub fn new(params: Value) -> Result<Self, Error> {
+        let filter = extract_log_filter(&params)?;
+
+        if filter
+            .since
+            .zip(filter.until)
+            .is_some_and(|(since, until)| since > until)
+        {
+            return Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                "'since' parameter should not be later than 'until'",
+            ));
+        }
+
+        Ok(Self {
+            filter,
+            limit: extract_log_limit(&params)?,
+        })
+    }
+
+    // getters
+    pub fn get_filter(&self) -> &LogFilter {
+        &self.filter
+    }
+
+    pub fn get_limit(&self) -> usize {
+        self.limit
+    }
+}
+
+/// Extracts `levels`, `since`, `until` and `contains` parameters shared by log queries
+pub fn extract_log_filter(params: &Value) -> Result<LogFilter, Error> {
+    let levels = match params.get("levels") {
+        None | Some(Value::Null) => Vec::new(),
+        Some(_) => RpcParameter::extract_string_list("levels", params).ok_or_else(|| {
+            RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                "'levels' parameter should be a list of log levels",
+            )
+        })?,
+    };
+
+    let levels: Vec<String> = levels.iter().map(|level| level.trim().to_uppercase()).collect();
+    if let Some(invalid_level) = levels
+        .iter()
+        .find(|level| !Constants::LOG_LEVELS.contains(&level.as_str()))
+    {
+        return Err(RPCError::build(
+            &StatusCode::BAD_REQUEST,
+            &format!(
+                "'{}' is not a valid log level. Valid levels are: {}",
+                invalid_level,
+                Constants::LOG_LEVELS.join(", ")
+            ),
+        ));
+    }
+
+    let since = extract_optional_timestamp("since", params)?;
+    let until = extract_optional_timestamp("until", params)?;
+
+    let contains = RpcParameter::extract_string("contains", params)
+        .map(|text| text.to_lowercase())
+        .filter(|text| !text.is_empty());
+
+    Ok(LogFilter {
+        levels,
+        since,
+        until,
+        contains,
+    })
+}
+
+/// Extracts `limit` parameter. It is bounded by the number of records kept in memory
+pub fn extract_log_limit(params: &Value) -> Result<usize, Error> {
+    match params.get("limit") {
+        None | Some(Value::Null) => Ok(Constants::DEFAULT_LOG_QUERY_LIMIT),
+        Some(_) => match RpcParameter::extract_u64("limit", params) {
+            Some(limit) if limit > 0 => Ok((limit as usize).min(Constants::LOG_BUFFER_CAPACITY)),
+            _ => Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                "'limit' parameter should be a positive number",
+            )),
+        },
+    }
+}
+
+fn extract_optional_timestamp(parameter_name: &str, params: &Value) -> Result<Option<i64>, Error> {
+    match params.get(parameter_name) {
+        None | Some(Value::Null) => Ok(None),
+        Some(_) => RpcParameter::extract_i64(parameter_name, params)
+            .map(Some)
+            .ok_or_else(|| {
+                RPCError::build(
+                    &StatusCode::BAD_REQUEST,
+                    &format!("'{}' parameter should be a unix timestamp", parameter_name),
+                )
+            }),
+    }
+}
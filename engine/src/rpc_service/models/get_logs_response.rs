@@ -0,0 +1,39 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, ErrorCode, Value};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::log_buffer::LogQueryResult, models::log_record::LogRecord,
+    rpc_service::rpc_status_message::RpcStatusMessage,
+};
+
+/// Response of `get_logs` & `subscribe_logs`.
+/// `cursor` is sent back by subscribers to receive records written afterwards
+#[derive(Serialize, Deserialize)]
+pub struct GetLogsResponse {
+    pub code: u16,
+    pub message: String,
+    pub records: Vec<LogRecord>,
+    pub cursor: u64,
+    pub dropped_count: u64,
+}
+
+impl GetLogsResponse {
+    pub fn build(log_query_result: LogQueryResult) -> Result<Value, Error> {
+        let response = GetLogsResponse {
+            code: StatusCode::OK.as_u16(),
+            message: RpcStatusMessage::OK.to_string(),
+            records: log_query_result.records,
+            cursor: log_query_result.cursor,
+            dropped_count: log_query_result.dropped_count,
+        };
+
+        let response_as_json = serde_json::to_value(response).map_err(|error_details| Error {
+            code: ErrorCode::InternalError,
+            message: error_details.to_string(),
+            data: None,
+        })?;
+
+        Ok(response_as_json)
+    }
+}
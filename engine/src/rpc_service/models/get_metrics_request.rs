@@ -0,0 +1,34 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::constants::Constants;
+use crate::rpc_service::rpc_error::RPCError;
+use crate::utils::rpc_utility::RpcParameter;
+
+pub struct GetMetricsRequest {
+    top_limit: usize,
+}
+
+impl GetMetricsRequest {
+    pub fn new(params: Value) -> Result<Self, Error> {
+        let top_limit = match params.get("top_limit") {
+            None | Some(Value::Null) => Constants::DEFAULT_METRICS_TOP_LIMIT,
+            Some(_) => match RpcParameter::extract_u64("top_limit", &params) {
+                Some(top_limit) if top_limit > 0 => top_limit as usize,
+                _ => {
+                    return Err(RPCError::build(
+                        &StatusCode::BAD_REQUEST,
+                        "'top_limit' parameter should be a positive number",
+                    ));
+                }
+            },
+        };
+
+        Ok(Self { top_limit })
+    }
+
+    // getters
+    pub fn get_top_limit(&self) -> usize {
+        self.top_limit
+    }
+}
@@ -0,0 +1,33 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, ErrorCode, Value};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::metrics_snapshot::MetricsSnapshot, rpc_service::rpc_status_message::RpcStatusMessage,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct GetMetricsResponse {
+    pub code: u16,
+    pub message: String,
+    #[serde(flatten)]
+    pub metrics: MetricsSnapshot,
+}
+
+impl GetMetricsResponse {
+    pub fn build(metrics: MetricsSnapshot) -> Result<Value, Error> {
+        let response = GetMetricsResponse {
+            code: StatusCode::OK.as_u16(),
+            message: RpcStatusMessage::OK.to_string(),
+            metrics,
+        };
+
+        let response_as_json = serde_json::to_value(response).map_err(|error_details| Error {
+            code: ErrorCode::InternalError,
+            message: error_details.to_string(),
+            data: None,
+        })?;
+
+        Ok(response_as_json)
+    }
+}
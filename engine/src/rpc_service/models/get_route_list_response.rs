@@ -1,3 +1,4 @@
+use crate::models::degraded_route_report::DegradedRouteReport;
 use crate::models::route::http_route::HttpRoute;
 use crate::models::route::https_route::HttpsRoute;
 use crate::models::route::iws_route::IwsRoute;
@@ -17,6 +18,7 @@ pub struct GetRouteListResponse {
     pub https_routes: Option<HashMap<String, HttpsRoute>>,
     pub iws_routes: Option<HashMap<String, IwsRoute>>,
     pub secure_iws_routes: Option<HashMap<String, SecureIwsRoute>>,
+    pub degraded_routes: Option<Vec<DegradedRouteReport>>,
 }
 
 impl GetRouteListResponse {
@@ -28,6 +30,7 @@ impl GetRouteListResponse {
             https_routes: None,
             iws_routes: None,
             secure_iws_routes: None,
+            degraded_routes: None,
         };
 
         instance
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     core::router::Router,
-    models::{boot_result::BootResult, config::Config},
+    models::{boot_result::BootResult, config::Config, degraded_route_report::DegradedRouteReport},
 };
 
 #[derive(Serialize, Deserialize)]
@@ -24,10 +24,16 @@ pub struct GetStatusResponse {
     pub https_route_count: usize,
     pub iws_route_count: usize,
     pub secure_iws_route_count: usize,
+
+    pub degraded_routes: Vec<DegradedRouteReport>,
 }
 
 impl GetStatusResponse {
-    pub fn build(boot_info: BootResult, router: Router) -> Result<Value, Error> {
+    pub fn build(
+        boot_info: BootResult,
+        router: Router,
+        degraded_routes: Vec<DegradedRouteReport>,
+    ) -> Result<Value, Error> {
         let response = GetStatusResponse {
             code: StatusCode::OK.as_u16(),
             config: boot_info.config.clone(),
@@ -42,6 +48,8 @@ impl GetStatusResponse {
             https_route_count: router.get_https_routes().keys().count(),
             iws_route_count: router.get_iws_routes().keys().count(),
             secure_iws_route_count: router.get_secure_iws_routes().keys().count(),
+
+            degraded_routes,
         };
 
         let response_as_json = serde_json::to_value(response).map_err(|error_details| {
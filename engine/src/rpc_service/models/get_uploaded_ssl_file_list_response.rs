@@ -0,0 +1,30 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, ErrorCode, Value};
+use serde::{Deserialize, Serialize};
+
+use crate::{models::ssl_file::SslFile, rpc_service::rpc_status_message::RpcStatusMessage};
+
+#[derive(Serialize, Deserialize)]
+pub struct GetUploadedSslFileListResponse {
+    code: u16,
+    message: String,
+    ssl_file_list: Vec<SslFile>,
+}
+
+impl GetUploadedSslFileListResponse {
+    pub fn build(ssl_file_list: Vec<SslFile>) -> Result<Value, Error> {
+        let response = GetUploadedSslFileListResponse {
+            code: StatusCode::OK.as_u16(),
+            message: RpcStatusMessage::OK.to_string(),
+            ssl_file_list,
+        };
+
+        let response_as_json = serde_json::to_value(response).map_err(|error_details| Error {
+            code: ErrorCode::InternalError,
+            message: error_details.to_string(),
+            data: None,
+        })?;
+
+        Ok(response_as_json)
+    }
+}
@@ -0,0 +1,33 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, ErrorCode, Value};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::upstream_health_report::UpstreamHealthReport,
+    rpc_service::rpc_status_message::RpcStatusMessage,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct GetUpstreamHealthResponse {
+    pub code: u16,
+    pub message: String,
+    pub upstreams: Vec<UpstreamHealthReport>,
+}
+
+impl GetUpstreamHealthResponse {
+    pub fn build(upstreams: Vec<UpstreamHealthReport>) -> Result<Value, Error> {
+        let response = GetUpstreamHealthResponse {
+            code: StatusCode::OK.as_u16(),
+            message: RpcStatusMessage::OK.to_string(),
+            upstreams,
+        };
+
+        let response_as_json = serde_json::to_value(response).map_err(|error_details| Error {
+            code: ErrorCode::InternalError,
+            message: error_details.to_string(),
+            data: None,
+        })?;
+
+        Ok(response_as_json)
+    }
+}
@@ -0,0 +1,45 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, ErrorCode, Value};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::{certificate_info::CertificateInfo, ssl_file::SslFile},
+    rpc_service::rpc_status_message::RpcStatusMessage,
+};
+
+/// Details of an uploaded file. Certificate chains list every certificate, leaf first
+#[derive(Serialize, Deserialize)]
+pub struct InspectSslFileResponse {
+    code: u16,
+    message: String,
+    ssl_file: SslFile,
+    certificates: Vec<CertificateInfo>,
+    private_key_format: Option<String>,
+    used_by: Vec<String>,
+}
+
+impl InspectSslFileResponse {
+    pub fn build(
+        ssl_file: SslFile,
+        certificates: Vec<CertificateInfo>,
+        private_key_format: Option<String>,
+        used_by: Vec<String>,
+    ) -> Result<Value, Error> {
+        let response = InspectSslFileResponse {
+            code: StatusCode::OK.as_u16(),
+            message: RpcStatusMessage::OK.to_string(),
+            ssl_file,
+            certificates,
+            private_key_format,
+            used_by,
+        };
+
+        let response_as_json = serde_json::to_value(response).map_err(|error_details| Error {
+            code: ErrorCode::InternalError,
+            message: error_details.to_string(),
+            data: None,
+        })?;
+
+        Ok(response_as_json)
+    }
+}
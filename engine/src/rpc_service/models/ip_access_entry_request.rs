@@ -0,0 +1,107 @@
+use crate::core::ip_access_control::normalize_ip_entry;
+use crate::{
+    common::enums::{
+        ip_access_list_type::IpAccessListType, ip_access_scope::IpAccessScope,
+        route_type::RouteType,
+    },
+    rpc_service::rpc_error::RPCError,
+    utils::rpc_utility::RpcParameter,
+};
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+/// Request of `block_ip` & `unblock_ip` services
+pub struct IpAccessEntryRequest {
+    ip: String,
+    scope: IpAccessScope,
+    list_type: IpAccessListType,
+    route_type: Option<RouteType>,
+    source: Option<String>,
+}
+
+impl IpAccessEntryRequest {
+    pub fn new(params: Value) -> Result<Self, Error> {
+        let ip = RpcParameter::extract_string("ip", &params);
+        let scope = RpcParameter::extract_string("scope", &params);
+        let list_type = RpcParameter::extract_string("list", &params);
+        let route_type = RpcParameter::extract_string_enum::<RouteType>("route_type", &params);
+        let source = RpcParameter::extract_string("source", &params);
+
+        let ip = match ip {
+            Some(ip) => match normalize_ip_entry(&ip) {
+                Ok(ip) => ip,
+                Err(error_message) => {
+                    return Err(RPCError::build(&StatusCode::BAD_REQUEST, &error_message));
+                }
+            },
+            None => {
+                return Err(RPCError::build(
+                    &StatusCode::BAD_REQUEST,
+                    "Please provide 'ip' parameter",
+                ));
+            }
+        };
+
+        let scope = match scope {
+            Some(scope) => match scope.parse::<IpAccessScope>() {
+                Ok(scope) => scope,
+                Err(error_message) => {
+                    return Err(RPCError::build(&StatusCode::BAD_REQUEST, error_message));
+                }
+            },
+            None => IpAccessScope::default(),
+        };
+
+        let list_type = match list_type {
+            Some(list_type) => match list_type.parse::<IpAccessListType>() {
+                Ok(list_type) => list_type,
+                Err(error_message) => {
+                    return Err(RPCError::build(&StatusCode::BAD_REQUEST, error_message));
+                }
+            },
+            None => IpAccessListType::default(),
+        };
+
+        if scope == IpAccessScope::Route {
+            if matches!(route_type, Some(RouteType::All) | None) {
+                return Err(RPCError::build(
+                    &StatusCode::BAD_REQUEST,
+                    "Please provide 'route_type' parameter (http, https, iws, secure-iws)",
+                ));
+            }
+
+            if source.is_none() {
+                return Err(RPCError::build(
+                    &StatusCode::BAD_REQUEST,
+                    "Please provide 'source' parameter",
+                ));
+            }
+        }
+
+        Ok(Self {
+            ip,
+            scope,
+            list_type,
+            route_type,
+            source,
+        })
+    }
+
+    // getters
+    pub fn get_ip(&self) -> String {
+        self.ip.clone()
+    }
+
+    pub fn get_scope(&self) -> IpAccessScope {
+        self.scope.clone()
+    }
+
+    pub fn get_list_type(&self) -> IpAccessListType {
+        self.list_type.clone()
+    }
+
+    /// Route type & source of route scoped requests
+    pub fn get_route(&self) -> Option<(RouteType, String)> {
+        Some((self.route_type.clone()?, self.source.clone()?))
+    }
+}
@@ -0,0 +1,52 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, ErrorCode, Value};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::enums::route_type::RouteType,
+    models::settings::ip_access_settings::IpAccessSettings,
+    rpc_service::rpc_status_message::RpcStatusMessage,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct RouteIpAccess {
+    pub route_type: RouteType,
+    pub source: String,
+    pub ip_access: IpAccessSettings,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ListBlockedResponse {
+    pub code: u16,
+    pub message: String,
+    pub global: IpAccessSettings,
+    pub http_server: IpAccessSettings,
+    pub https_server: IpAccessSettings,
+    pub routes: Vec<RouteIpAccess>,
+}
+
+impl ListBlockedResponse {
+    pub fn build(
+        global: IpAccessSettings,
+        http_server: IpAccessSettings,
+        https_server: IpAccessSettings,
+        routes: Vec<RouteIpAccess>,
+    ) -> Result<Value, Error> {
+        let response = ListBlockedResponse {
+            code: StatusCode::OK.as_u16(),
+            message: RpcStatusMessage::OK.to_string(),
+            global,
+            http_server,
+            https_server,
+            routes,
+        };
+
+        let response_as_json = serde_json::to_value(response).map_err(|error_details| Error {
+            code: ErrorCode::InternalError,
+            message: error_details.to_string(),
+            data: None,
+        })?;
+
+        Ok(response_as_json)
+    }
+}
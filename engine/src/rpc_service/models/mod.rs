@@ -22,6 +22,18 @@ pub mod delete_iws_route_response;
 pub mod delete_secure_iws_route_request;
 pub mod delete_secure_iws_route_response;
 
+pub mod add_path_rule_request;
+pub mod add_path_rule_response;
+
+pub mod delete_path_rule_request;
+pub mod delete_path_rule_response;
+
+pub mod add_rewrite_rule_request;
+pub mod add_rewrite_rule_response;
+
+pub mod delete_rewrite_rule_request;
+pub mod delete_rewrite_rule_response;
+
 pub mod echo_request;
 pub mod echo_response;
 
@@ -30,4 +42,29 @@ pub mod get_route_list_response;
 
 pub mod get_build_version_response;
 pub mod get_status_response;
+pub mod get_upstream_health_response;
+
+pub mod ip_access_entry_request;
+pub mod block_ip_response;
+pub mod unblock_ip_response;
+pub mod list_blocked_response;
+
+pub mod get_metrics_request;
+pub mod get_metrics_response;
 
+pub mod get_logs_request;
+pub mod get_logs_response;
+pub mod subscribe_logs_request;
+pub mod get_logger_config_response;
+pub mod set_logger_config_request;
+pub mod ssl_file_request;
+pub mod upload_ssl_file_request;
+pub mod upload_ssl_file_response;
+pub mod get_uploaded_ssl_file_list_response;
+pub mod inspect_ssl_file_response;
+pub mod delete_ssl_file_response;
+pub mod ssl_context_request;
+pub mod get_acme_certificates_response;
+pub mod renew_acme_certificate_request;
+pub mod renew_acme_certificate_response;
+pub mod get_certificate_expiry_response;
@@ -0,0 +1,109 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::constants::Constants;
+use crate::models::settings::logger_settings::LoggerSettings;
+use crate::rpc_service::rpc_error::RPCError;
+use crate::utils::rpc_utility::RpcParameter;
+
+/// Changes of logger settings. Parameters which are not given keep their current values
+pub struct SetLoggerConfigRequest {
+    log_levels: Option<Vec<String>>,
+    log_file_size: Option<u64>,
+    keep_last_logs: Option<usize>,
+}
+
+impl SetLoggerConfigRequest {
+    pub fn new(params: Value) -> Result<Self, Error> {
+        let log_levels = match params.get("log_levels") {
+            None | Some(Value::Null) => None,
+            Some(_) => Some(extract_log_levels(&params)?),
+        };
+
+        let log_file_size = match params.get("log_file_size") {
+            None | Some(Value::Null) => None,
+            Some(_) => match RpcParameter::extract_u64("log_file_size", &params) {
+                Some(log_file_size) if log_file_size > 0 => Some(log_file_size),
+                _ => {
+                    return Err(RPCError::build(
+                        &StatusCode::BAD_REQUEST,
+                        "'log_file_size' parameter should be a positive number of bytes",
+                    ));
+                }
+            },
+        };
+
+        let keep_last_logs = match params.get("keep_last_logs") {
+            None | Some(Value::Null) => None,
+            Some(_) => match RpcParameter::extract_u64("keep_last_logs", &params) {
+                Some(keep_last_logs) if keep_last_logs > 0 => Some(keep_last_logs as usize),
+                _ => {
+                    return Err(RPCError::build(
+                        &StatusCode::BAD_REQUEST,
+                        "'keep_last_logs' parameter should be a positive number",
+                    ));
+                }
+            },
+        };
+
+        if log_levels.is_none() && log_file_size.is_none() && keep_last_logs.is_none() {
+            return Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                "At least one of 'log_levels', 'log_file_size' or 'keep_last_logs' parameters is required",
+            ));
+        }
+
+        Ok(Self {
+            log_levels,
+            log_file_size,
+            keep_last_logs,
+        })
+    }
+
+    /// Returns given settings with requested changes applied
+    pub fn apply(&self, logger_settings: &LoggerSettings) -> LoggerSettings {
+        let mut updated_settings = logger_settings.clone();
+
+        if let Some(log_levels) = &self.log_levels {
+            updated_settings.log_levels = log_levels.clone();
+        }
+        if let Some(log_file_size) = self.log_file_size {
+            updated_settings.log_file_size = log_file_size;
+        }
+        if let Some(keep_last_logs) = self.keep_last_logs {
+            updated_settings.keep_last_logs = keep_last_logs;
+        }
+
+        updated_settings
+    }
+}
+
+fn extract_log_levels(params: &Value) -> Result<Vec<String>, Error> {
+    let log_levels = RpcParameter::extract_string_list("log_levels", params).ok_or_else(|| {
+        RPCError::build(
+            &StatusCode::BAD_REQUEST,
+            "'log_levels' parameter should be a list of log levels",
+        )
+    })?;
+
+    let mut normalized_levels: Vec<String> = Vec::new();
+    for log_level in log_levels {
+        let log_level = log_level.trim().to_uppercase();
+        if !Constants::LOG_LEVELS.contains(&log_level.as_str()) {
+            return Err(RPCError::build(
+                &StatusCode::BAD_REQUEST,
+                &format!(
+                    "'{}' is not a valid log level. Valid levels are: {}",
+                    log_level,
+                    Constants::LOG_LEVELS.join(", ")
+                ),
+            ));
+        }
+
+        if !normalized_levels.contains(&log_level) {
+            normalized_levels.push(log_level);
+        }
+    }
+
+    Ok(normalized_levels)
+}
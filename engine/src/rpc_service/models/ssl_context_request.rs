@@ -0,0 +1,66 @@
+use hyper::StatusCode;
+use jsonrpc_core::Error;
+
+use crate::{
+    core::{host_matcher::HostPattern, shared_memory::RUNTIME_BOOT_INFO},
+    models::ssl_context::SslContext,
+    rpc_service::rpc_error::RPCError,
+    utils::ssl_file_utility::{get_acme_ssl_file_names, get_ssl_file_reference},
+};
+
+/// Builds SSL context of `add_https_route` & `add_secure_iws_route` services from given certificate & private key paths
+pub fn build_ssl_context(
+    ssl_cert_path: Option<String>,
+    ssl_private_key_path: Option<String>,
+) -> Result<SslContext, Error> {
+    let certificate_file_path = ssl_cert_path.ok_or_else(|| {
+        RPCError::build(
+            &StatusCode::BAD_REQUEST,
+            "Please provide 'ssl_cert_path' parameter",
+        )
+    })?;
+
+    let private_key_file_path = ssl_private_key_path.ok_or_else(|| {
+        RPCError::build(
+            &StatusCode::BAD_REQUEST,
+            "Please provide 'ssl_private_key_path' parameter",
+        )
+    })?;
+
+    Ok(SslContext {
+        certificate_file_path,
+        private_key_file_path,
+        acme: None,
+        client_auth: None,
+        tls: None,
+    })
+}
+
+/// Builds SSL context of a route whose certificate is issued by ACME client.
+/// Domain validation needs an exact host, so host patterns are rejected
+pub fn build_acme_ssl_context(source: &str) -> Result<SslContext, Error> {
+    let is_acme_active = RUNTIME_BOOT_INFO.read().unwrap().config.acme.is_active;
+    if !is_acme_active {
+        return Err(RPCError::build(
+            &StatusCode::BAD_REQUEST,
+            "ACME is not active. Please enable 'acme' in engine config first",
+        ));
+    }
+
+    if !HostPattern::is_exact(source) {
+        return Err(RPCError::build(
+            &StatusCode::BAD_REQUEST,
+            "ACME certificates can only be issued for exact hosts, not host patterns",
+        ));
+    }
+
+    let (certificate_file_name, private_key_file_name) = get_acme_ssl_file_names(source);
+
+    Ok(SslContext {
+        certificate_file_path: get_ssl_file_reference(&certificate_file_name),
+        private_key_file_path: get_ssl_file_reference(&private_key_file_name),
+        acme: Some(true),
+        client_auth: None,
+        tls: None,
+    })
+}
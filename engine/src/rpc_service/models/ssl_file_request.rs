@@ -0,0 +1,48 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    rpc_service::rpc_error::RPCError,
+    utils::{
+        rpc_utility::RpcParameter,
+        ssl_file_utility::{strip_ssl_file_reference, validate_ssl_file_name},
+    },
+};
+
+/// Request of `inspect_ssl_file` & `delete_ssl_file` services
+pub struct SslFileRequest {
+    name: String,
+}
+
+impl SslFileRequest {
+    pub fn new(params: Value) -> Result<Self, Error> {
+        let name = match RpcParameter::extract_string("name", &params) {
+            Some(name) => name,
+            None => {
+                return Err(RPCError::build(
+                    &StatusCode::BAD_REQUEST,
+                    "Please provide 'name' parameter",
+                ));
+            }
+        };
+
+        let name = extract_ssl_file_name(&name)?;
+
+        Ok(Self { name })
+    }
+
+    // getters
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// Accepts plain file names and `@vanguard/<name>` references
+pub fn extract_ssl_file_name(name: &str) -> Result<String, Error> {
+    let name = strip_ssl_file_reference(name);
+
+    validate_ssl_file_name(name)
+        .map_err(|error_message| RPCError::build(&StatusCode::BAD_REQUEST, &error_message))?;
+
+    Ok(name.to_string())
+}
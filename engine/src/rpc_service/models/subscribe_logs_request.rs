@@ -0,0 +1,46 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::models::log_record::LogFilter;
+use crate::rpc_service::models::get_logs_request::{extract_log_filter, extract_log_limit};
+use crate::rpc_service::rpc_error::RPCError;
+use crate::utils::rpc_utility::RpcParameter;
+
+pub struct SubscribeLogsRequest {
+    cursor: Option<u64>,
+    filter: LogFilter,
+    limit: usize,
+}
+
+impl SubscribeLogsRequest {
+    pub fn new(params: Value) -> Result<Self, Error> {
+        let cursor = match params.get("cursor") {
+            None | Some(Value::Null) => None,
+            Some(_) => Some(RpcParameter::extract_u64("cursor", &params).ok_or_else(|| {
+                RPCError::build(
+                    &StatusCode::BAD_REQUEST,
+                    "'cursor' parameter should be a cursor returned by previous call",
+                )
+            })?),
+        };
+
+        Ok(Self {
+            cursor,
+            filter: extract_log_filter(&params)?,
+            limit: extract_log_limit(&params)?,
+        })
+    }
+
+    // getters
+    pub fn get_cursor(&self) -> Option<u64> {
+        self.cursor
+    }
+
+    pub fn get_filter(&self) -> &LogFilter {
+        &self.filter
+    }
+
+    pub fn get_limit(&self) -> usize {
+        self.limit
+    }
+}
@@ -0,0 +1,68 @@
+use hyper::StatusCode;
+use jsonrpc_core::{Error, Value};
+
+use crate::{
+    rpc_service::{models::ssl_file_request::extract_ssl_file_name, rpc_error::RPCError},
+    utils::{base64_utility::decode_b64, rpc_utility::RpcParameter},
+};
+
+pub struct UploadSslFileRequest {
+    name: String,
+    content: String,
+    overwrite: bool,
+}
+
+impl UploadSslFileRequest {
+    pub fn new(params: Value) -> Result<Self, Error> {
+        let name = RpcParameter::extract_string("name", &params);
+        let content = RpcParameter::extract_string("content", &params);
+        let overwrite = RpcParameter::extract_bool("overwrite", &params).unwrap_or(false);
+
+        let name = match name {
+            Some(name) => extract_ssl_file_name(&name)?,
+            None => {
+                return Err(RPCError::build(
+                    &StatusCode::BAD_REQUEST,
+                    "Please provide 'name' parameter",
+                ));
+            }
+        };
+
+        let content = match content {
+            Some(content) => match decode_b64(content) {
+                Some(content) => content,
+                None => {
+                    return Err(RPCError::build(
+                        &StatusCode::BAD_REQUEST,
+                        "'content' parameter should be base64 encoded PEM text",
+                    ));
+                }
+            },
+            None => {
+                return Err(RPCError::build(
+                    &StatusCode::BAD_REQUEST,
+                    "Please provide 'content' parameter",
+                ));
+            }
+        };
+
+        Ok(Self {
+            name,
+            content,
+            overwrite,
+        })
+    }
+
+    // getters
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn get_overwrite(&self) -> bool {
+        self.overwrite
+    }
+}
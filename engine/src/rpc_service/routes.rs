@@ -5,11 +5,20 @@ use crate::rpc_service::{controllers::{get_build_version::get_build_version,}, r
 
 use super::controllers::{
     add_http_route::add_http_route, add_https_route::add_https_route, add_iws_route::add_iws_route,
-    add_secure_iws_route::add_secure_iws_route, delete_http_route::delete_http_route,
+    add_path_rule::add_path_rule, add_secure_iws_route::add_secure_iws_route,
+    delete_path_rule::delete_path_rule, add_rewrite_rule::add_rewrite_rule,
+    delete_rewrite_rule::delete_rewrite_rule, delete_http_route::delete_http_route,
     delete_https_route::delete_https_route, delete_iws_route::delete_iws_route,
     delete_secure_iws_route::delete_secure_iws_route, echo::echo, 
     get_route_list::get_route_list,
-    get_status::get_status
+    get_status::get_status, get_upstream_health::get_upstream_health,
+    block_ip::block_ip, unblock_ip::unblock_ip, list_blocked::list_blocked,
+    get_metrics::get_metrics, get_logs::get_logs, subscribe_logs::subscribe_logs,
+    get_logger_config::get_logger_config, set_logger_config::set_logger_config,
+    upload_ssl_file::upload_ssl_file, get_uploaded_ssl_file_list::get_uploaded_ssl_file_list,
+    inspect_ssl_file::inspect_ssl_file, delete_ssl_file::delete_ssl_file,
+    get_acme_certificates::get_acme_certificates, renew_acme_certificate::renew_acme_certificate,
+    get_certificate_expiry::get_certificate_expiry
 };
 
 pub static ROUTES: Lazy<Vec<(&'static str, RpcHandler)>> = Lazy::new(|| {
@@ -24,7 +33,27 @@ pub static ROUTES: Lazy<Vec<(&'static str, RpcHandler)>> = Lazy::new(|| {
         ("delete_https_route",Arc::new(delete_https_route) as RpcHandler),
         ("delete_iws_route", Arc::new(delete_iws_route) as RpcHandler),
         ("delete_secure_iws_route",Arc::new(delete_secure_iws_route) as RpcHandler),
+        ("add_path_rule", Arc::new(add_path_rule) as RpcHandler),
+        ("delete_path_rule", Arc::new(delete_path_rule) as RpcHandler),
+        ("add_rewrite_rule", Arc::new(add_rewrite_rule) as RpcHandler),
+        ("delete_rewrite_rule", Arc::new(delete_rewrite_rule) as RpcHandler),
         ("get_route_list", Arc::new(get_route_list) as RpcHandler),
-        ("get_status", Arc::new(get_status) as RpcHandler)
+        ("get_status", Arc::new(get_status) as RpcHandler),
+        ("get_upstream_health", Arc::new(get_upstream_health) as RpcHandler),
+        ("block_ip", Arc::new(block_ip) as RpcHandler),
+        ("unblock_ip", Arc::new(unblock_ip) as RpcHandler),
+        ("list_blocked", Arc::new(list_blocked) as RpcHandler),
+        ("get_metrics", Arc::new(get_metrics) as RpcHandler),
+        ("get_logs", Arc::new(get_logs) as RpcHandler),
+        ("subscribe_logs", Arc::new(subscribe_logs) as RpcHandler),
+        ("get_logger_config", Arc::new(get_logger_config) as RpcHandler),
+        ("set_logger_config", Arc::new(set_logger_config) as RpcHandler),
+        ("upload_ssl_file", Arc::new(upload_ssl_file) as RpcHandler),
+        ("get_uploaded_ssl_file_list", Arc::new(get_uploaded_ssl_file_list) as RpcHandler),
+        ("inspect_ssl_file", Arc::new(inspect_ssl_file) as RpcHandler),
+        ("delete_ssl_file", Arc::new(delete_ssl_file) as RpcHandler),
+        ("get_acme_certificates", Arc::new(get_acme_certificates) as RpcHandler),
+        ("renew_acme_certificate", Arc::new(renew_acme_certificate) as RpcHandler),
+        ("get_certificate_expiry", Arc::new(get_certificate_expiry) as RpcHandler)
     ]
 });
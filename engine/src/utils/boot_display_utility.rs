@@ -186,6 +186,27 @@ impl BootDisplayUtility {
         ]);
     }
 
+    fn add_metrics_server(&self, table: &mut Table) {
+        let is_active = self.boot_result.config.metrics_server.is_active;
+
+        let formatted_endpoint = if is_active {
+            format!(
+                "{}{}",
+                self.boot_result.config.metrics_server.get_endpoint(),
+                Constants::METRICS_PATH
+            )
+            .underline()
+        } else {
+            "".underline()
+        };
+
+        let flag = status_flag(is_active, "Active", "Passive");
+        table.add_row(row![
+            "Metrics Server",
+            format!("{} on {}", flag, &formatted_endpoint)
+        ]);
+    }
+
     fn add_http_server(&self, table: &mut Table) {
         let is_active = self.boot_result.config.http_server.is_active;
 
@@ -279,6 +300,7 @@ impl BootDisplayUtility {
         self.add_jrpc_authentication_token(&mut table);
         self.add_jrpc_encryption_key(&mut table);
         self.add_jrpc_server(&mut table);
+        self.add_metrics_server(&mut table);
         self.add_http_server(&mut table);
         self.add_https_server(&mut table);
         self.add_log_output_path(&mut table);
@@ -21,6 +21,23 @@ pub fn get_ssl_upload_path() -> PathBuf {
     ssl_path
 }
 
+/// Returns the path to the ACME directory which keeps ACME account key.
+///
+/// Creates the directory if it does not exist.
+///
+/// # Returns
+///
+/// * `PathBuf` pointing to the ACME directory.
+pub fn get_acme_path() -> PathBuf {
+    let acme_path = get_runtime_path().join(Constants::ACME_DIR_NAME);
+
+    if !acme_path.exists() {
+        create_path(&acme_path);
+    }
+
+    acme_path
+}
+
 /// Returns the runtime path for the application based on the operating system.
 ///
 /// Creates the directory if it does not exist.
@@ -1,7 +1,7 @@
 use mime_guess::{from_path, Mime};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use std::fs::{self, File, Metadata};
+use std::fs::{self, File, Metadata, OpenOptions};
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
@@ -62,6 +62,47 @@ pub fn write_file(file_path: PathBuf, content: &str) -> std::io::Result<()> {
     Ok(())
 }
 
+/// Writes the given string content to a file which is only accessible by the engine user on unix systems.
+/// Permissions are set while the file is opened, so content is never readable by other users.
+///
+/// # Arguments
+///
+/// * `file_path` - The destination file path.
+/// * `content` - The string content to write, e.g. a PEM encoded private key.
+/// * `create_new` - Fails if the file already exists, otherwise an existing file is overwritten.
+///
+/// # Returns
+///
+/// * `Ok(())` on success.
+/// * `Err` if the file cannot be opened, its permissions cannot be set or the write operation fails.
+pub fn write_private_file(file_path: &Path, content: &str, create_new: bool) -> std::io::Result<()> {
+    let mut open_options = OpenOptions::new();
+    open_options.write(true);
+
+    if create_new {
+        open_options.create_new(true);
+    } else {
+        open_options.create(true).truncate(true);
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        open_options.mode(0o600);
+    }
+
+    let mut file = open_options.open(file_path)?;
+
+    // Mode is only applied to new files, an overwritten file keeps its old permissions otherwise
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    }
+
+    file.write_all(content.as_bytes())
+}
+
 /// Deletes a file at the specified path.
 ///
 /// # Arguments
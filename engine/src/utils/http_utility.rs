@@ -1,4 +1,4 @@
-use hyper::{Body, Request};
+use hyper::{header, Body, Request, Uri, Version};
 
 use crate::common::errors::hyper_error::HyperError;
 
@@ -16,3 +16,62 @@ pub fn calculate_content_length(req: &Request<Body>) -> Result<u64, HyperError>
 
     Ok(content_length)
 }
+
+/// Replaces path of request URI with given path. Query string and authority of request are kept
+pub fn rewrite_request_path(req: Request<Body>, new_path: &str) -> Request<Body> {
+    let (mut parts, body) = req.into_parts();
+
+    let path_and_query = match parts.uri.query() {
+        Some(query) => format!("{}?{}", new_path, query),
+        None => new_path.to_string(),
+    };
+
+    let mut uri_parts = parts.uri.clone().into_parts();
+    uri_parts.path_and_query = path_and_query.parse().ok();
+
+    if let Ok(rewritten_uri) = Uri::from_parts(uri_parts) {
+        parts.uri = rewritten_uri;
+    }
+
+    Request::from_parts(parts, body)
+}
+
+/// Replaces path & query of a request with given `path?query`. Request is kept as is if the result is not a valid URI
+pub fn rewrite_request_target(req: Request<Body>, path_and_query: &str) -> Request<Body> {
+    let (mut parts, body) = req.into_parts();
+
+    let mut uri_parts = parts.uri.clone().into_parts();
+    uri_parts.path_and_query = path_and_query.parse().ok();
+
+    if let Ok(rewritten_uri) = Uri::from_parts(uri_parts) {
+        parts.uri = rewritten_uri;
+    }
+
+    Request::from_parts(parts, body)
+}
+
+/// Builds absolute URI of an upstream request. Targets without scheme are reached over plain HTTP,
+/// targets starting with `https://` are reached over TLS
+pub fn build_upstream_uri(target: &str, path_and_query: &str) -> String {
+    let target = target.trim_end_matches('/');
+
+    if target.starts_with("https://") || target.starts_with("http://") {
+        return format!("{}{}", target, path_and_query);
+    }
+
+    format!("http://{}{}", target, path_and_query)
+}
+
+/// Returns true if an HTTP/1.1 request asks to switch protocol, e.g. `Connection: Upgrade` & `Upgrade: websocket`
+pub fn is_upgrade_request(req: &Request<Body>) -> bool {
+    if req.version() != Version::HTTP_11 || !req.headers().contains_key(header::UPGRADE) {
+        return false;
+    }
+
+    req.headers()
+        .get_all(header::CONNECTION)
+        .iter()
+        .filter_map(|connection| connection.to_str().ok())
+        .flat_map(|connection| connection.split(','))
+        .any(|connection_option| connection_option.trim().eq_ignore_ascii_case("upgrade"))
+}
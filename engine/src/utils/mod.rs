@@ -11,3 +11,5 @@ pub mod text_utility;
 pub mod time_utility;
 pub mod tls_utility;
 pub mod http_utility;
+pub mod x509_utility;
+pub mod ssl_file_utility;
@@ -3,7 +3,14 @@ use std::str::FromStr;
 
 use jsonrpc_core::Value;
 
-use crate::models::traffic_policy::scope_traffic_policy::ScopeTrafficPolicy;
+use crate::models::route::header_rule::HeaderRule;
+use crate::models::route::rewrite_rule::RewriteCondition;
+use crate::models::route::upstream_pool::UpstreamPool;
+use crate::models::settings::client_auth_settings::ClientAuthSettings;
+use crate::models::settings::tls_settings::TlsSettings;
+use crate::models::traffic_policy::{
+    path_traffic_policy::PathTrafficPolicy, scope_traffic_policy::ScopeTrafficPolicy,
+};
 
 pub struct RpcParameter {}
 
@@ -38,4 +45,88 @@ impl RpcParameter {
             .get(parameter_name)
             .and_then(|v| serde_json::from_value::<ScopeTrafficPolicy>(v.clone()).ok())
     }
+
+    pub fn extract_bool(parameter_name: &str, params: &Value) -> Option<bool> {
+        params.get(parameter_name).and_then(|v| v.as_bool())
+    }
+
+    pub fn extract_u64(parameter_name: &str, params: &Value) -> Option<u64> {
+        params.get(parameter_name).and_then(|v| v.as_u64())
+    }
+
+    pub fn extract_i64(parameter_name: &str, params: &Value) -> Option<i64> {
+        params.get(parameter_name).and_then(|v| v.as_i64())
+    }
+
+    /// Extracts an array of strings. Returns None if parameter is not an array or has a non-string item
+    pub fn extract_string_list(parameter_name: &str, params: &Value) -> Option<Vec<String>> {
+        params.get(parameter_name)?.as_array()?.iter().map(|v| v.as_str().map(String::from)).collect()
+    }
+
+    /// Extracts a path_policy parameter from JSON-RPC params and deserializes it.
+    pub fn extract_path_policy(parameter_name: &str, params: &Value) -> Option<PathTrafficPolicy> {
+        params
+            .get(parameter_name)
+            .and_then(|v| serde_json::from_value::<PathTrafficPolicy>(v.clone()).ok())
+    }
+
+    /// Extracts an upstream_pool parameter from JSON-RPC params and deserializes it.
+    /// Returns an error message if the parameter exists but is malformed, since silently ignoring a pool would change routing.
+    pub fn extract_upstream_pool(
+        parameter_name: &str,
+        params: &Value,
+    ) -> Result<Option<UpstreamPool>, String> {
+        match params.get(parameter_name) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => serde_json::from_value::<UpstreamPool>(value.clone())
+                .map(Some)
+                .map_err(|error| format!("Invalid '{}' parameter: {}", parameter_name, error)),
+        }
+    }
+
+    /// Extracts header rules of a route from JSON-RPC params. Missing parameter means the route has no header rules
+    pub fn extract_header_rules(parameter_name: &str, params: &Value) -> Result<Vec<HeaderRule>, String> {
+        match params.get(parameter_name) {
+            None | Some(Value::Null) => Ok(Vec::new()),
+            Some(value) => serde_json::from_value::<Vec<HeaderRule>>(value.clone())
+                .map_err(|error| format!("Invalid '{}' parameter: {}", parameter_name, error)),
+        }
+    }
+
+    /// Extracts conditions of a rewrite rule from JSON-RPC params. Missing parameter means the rule has no conditions
+    pub fn extract_rewrite_conditions(parameter_name: &str, params: &Value) -> Result<Vec<RewriteCondition>, String> {
+        match params.get(parameter_name) {
+            None | Some(Value::Null) => Ok(Vec::new()),
+            Some(value) => serde_json::from_value::<Vec<RewriteCondition>>(value.clone())
+                .map_err(|error| format!("Invalid '{}' parameter: {}", parameter_name, error)),
+        }
+    }
+
+    /// Extracts a client_auth (mTLS) parameter from JSON-RPC params and deserializes it.
+    /// Returns an error message if the parameter exists but is malformed, since silently ignoring it would expose the route.
+    pub fn extract_client_auth(
+        parameter_name: &str,
+        params: &Value,
+    ) -> Result<Option<ClientAuthSettings>, String> {
+        match params.get(parameter_name) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => serde_json::from_value::<ClientAuthSettings>(value.clone())
+                .map(Some)
+                .map_err(|error| format!("Invalid '{}' parameter: {}", parameter_name, error)),
+        }
+    }
+
+    /// Extracts a route TLS settings parameter from JSON-RPC params and deserializes it.
+    /// Returns an error message if the parameter exists but is malformed, since silently ignoring it would weaken the handshake policy.
+    pub fn extract_tls_settings(
+        parameter_name: &str,
+        params: &Value,
+    ) -> Result<Option<TlsSettings>, String> {
+        match params.get(parameter_name) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => serde_json::from_value::<TlsSettings>(value.clone())
+                .map(Some)
+                .map_err(|error| format!("Invalid '{}' parameter: {}", parameter_name, error)),
+        }
+    }
 }
@@ -0,0 +1,191 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::common::enums::ssl_file_type::SslFileType;
+use crate::constants::Constants;
+use crate::models::ssl_file::SslFile;
+use crate::utils::directory_utility::get_ssl_upload_path;
+use crate::utils::file_utility::write_private_file;
+
+/// Resolves a certificate or private key path of a route.
+/// `@vanguard/<name>` references point to uploaded files in SSL directory, other values are used as file paths
+pub fn resolve_ssl_file_path(file_path: &str) -> Result<PathBuf, String> {
+    match file_path.strip_prefix(Constants::SSL_FILE_REFERENCE_PREFIX) {
+        Some(file_name) => {
+            validate_ssl_file_name(file_name)?;
+            Ok(get_ssl_upload_path().join(file_name))
+        }
+        None => Ok(PathBuf::from(file_path)),
+    }
+}
+
+/// Returns `@vanguard/<name>` reference of an uploaded file
+pub fn get_ssl_file_reference(file_name: &str) -> String {
+    format!("{}{}", Constants::SSL_FILE_REFERENCE_PREFIX, file_name)
+}
+
+/// Returns names of certificate & private key files which ACME client issues for given route source
+pub fn get_acme_ssl_file_names(source: &str) -> (String, String) {
+    let source = source.to_lowercase();
+    (format!("acme-{}.crt", source), format!("acme-{}.key", source))
+}
+
+/// Returns file name of a `@vanguard/<name>` reference, other values are returned as is
+pub fn strip_ssl_file_reference(file_name: &str) -> &str {
+    file_name
+        .strip_prefix(Constants::SSL_FILE_REFERENCE_PREFIX)
+        .unwrap_or(file_name)
+}
+
+/// Uploaded file names may only contain letters, digits, '.', '-' and '_', so they can not point outside of SSL directory
+pub fn validate_ssl_file_name(file_name: &str) -> Result<(), String> {
+    let is_valid = !file_name.is_empty()
+        && file_name.len() <= 255
+        && !file_name.starts_with('.')
+        && file_name
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || matches!(character, '.' | '-' | '_'));
+
+    match is_valid {
+        true => Ok(()),
+        false => Err(format!(
+            "'{}' is not a valid SSL file name. Only letters, digits, '.', '-' and '_' are allowed",
+            file_name
+        )),
+    }
+}
+
+/// Detects file type by PEM block labels. Files with both certificates and a key are reported as certificate
+pub fn detect_ssl_file_type(content: &str) -> SslFileType {
+    let labels = get_pem_labels(content);
+
+    if labels.iter().any(|label| label == "CERTIFICATE") {
+        return SslFileType::PemCertificate;
+    }
+
+    if labels.iter().any(|label| label.ends_with("PRIVATE KEY")) {
+        return SslFileType::PemPrivateKey;
+    }
+
+    SslFileType::Invalid
+}
+
+/// Describes encoding of the private key in given PEM content, e.g. `PKCS#8`
+pub fn get_private_key_format(content: &str) -> Option<String> {
+    get_pem_labels(content)
+        .iter()
+        .find(|label| label.ends_with("PRIVATE KEY"))
+        .map(|label| match label.as_str() {
+            "PRIVATE KEY" => "PKCS#8".to_string(),
+            "RSA PRIVATE KEY" => "RSA (PKCS#1)".to_string(),
+            "EC PRIVATE KEY" => "EC (SEC1)".to_string(),
+            "ENCRYPTED PRIVATE KEY" => "Encrypted PKCS#8".to_string(),
+            other => other.to_string(),
+        })
+}
+
+/// Returns labels of PEM blocks in given content, e.g. `CERTIFICATE` or `EC PRIVATE KEY`
+pub fn get_pem_labels(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(|line| {
+            line.trim()
+                .strip_prefix("-----BEGIN ")
+                .and_then(|line| line.strip_suffix("-----"))
+        })
+        .map(|label| label.to_string())
+        .collect()
+}
+
+/// Lists files in SSL upload directory, sorted by name
+pub fn list_ssl_files() -> Vec<SslFile> {
+    let entries = match fs::read_dir(get_ssl_upload_path()) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut ssl_files: Vec<SslFile> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| entry.file_name().to_str().map(|file_name| file_name.to_string()))
+        .filter(|file_name| validate_ssl_file_name(file_name).is_ok())
+        .filter_map(|file_name| get_ssl_file(&file_name).ok())
+        .collect();
+
+    ssl_files.sort_by(|first, second| first.name.cmp(&second.name));
+    ssl_files
+}
+
+/// Returns an uploaded file with its detected type
+pub fn get_ssl_file(file_name: &str) -> Result<SslFile, String> {
+    let content = read_ssl_file(file_name)?;
+
+    Ok(SslFile {
+        name: file_name.to_string(),
+        reference: get_ssl_file_reference(file_name),
+        file_type: detect_ssl_file_type(&content),
+        size: content.len() as u64,
+    })
+}
+
+/// Reads content of an uploaded file
+pub fn read_ssl_file(file_name: &str) -> Result<String, String> {
+    validate_ssl_file_name(file_name)?;
+
+    let file_path = get_ssl_upload_path().join(file_name);
+    if !file_path.is_file() {
+        return Err(format!("SSL file '{}' does not exist", file_name));
+    }
+
+    fs::read_to_string(&file_path)
+        .map_err(|error| format!("SSL file '{}' can not be read: {}", file_name, error))
+}
+
+/// Writes a PEM file into SSL upload directory. Private keys are only readable by engine user on unix systems
+pub fn save_ssl_file(file_name: &str, content: &str, overwrite: bool) -> Result<SslFile, String> {
+    validate_ssl_file_name(file_name)?;
+
+    if content.len() > Constants::MAX_SSL_FILE_SIZE {
+        return Err(format!(
+            "SSL file should not be larger than {} bytes",
+            Constants::MAX_SSL_FILE_SIZE
+        ));
+    }
+
+    let file_type = detect_ssl_file_type(content);
+    if file_type == SslFileType::Invalid {
+        return Err("Given content is not a PEM encoded certificate or private key".to_string());
+    }
+
+    let file_path = get_ssl_upload_path().join(file_name);
+    if file_path.exists() && !overwrite {
+        return Err(format!("SSL file '{}' already exists", file_name));
+    }
+
+    let write_result = match file_type {
+        SslFileType::PemPrivateKey => write_private_file(&file_path, content, !overwrite),
+        _ => fs::write(&file_path, content),
+    };
+
+    write_result.map_err(|error| format!("SSL file '{}' can not be written: {}", file_name, error))?;
+
+    Ok(SslFile {
+        name: file_name.to_string(),
+        reference: get_ssl_file_reference(file_name),
+        file_type,
+        size: content.len() as u64,
+    })
+}
+
+/// Removes an uploaded file
+pub fn delete_ssl_file(file_name: &str) -> Result<(), String> {
+    validate_ssl_file_name(file_name)?;
+
+    let file_path = get_ssl_upload_path().join(file_name);
+    if !file_path.is_file() {
+        return Err(format!("SSL file '{}' does not exist", file_name));
+    }
+
+    fs::remove_file(&file_path)
+        .map_err(|error| format!("SSL file '{}' can not be deleted: {}", file_name, error))
+}
@@ -1,106 +1,612 @@
 use jsonrpc_core::{Error, ErrorCode};
-use rustls::server::ResolvesServerCertUsingSni;
-use rustls::sign::{CertifiedKey, RsaSigningKey};
-use rustls::{Certificate, PrivateKey};
-use rustls_pemfile::{certs, pkcs8_private_keys};
+use rustls::client::{ServerCertVerified, ServerCertVerifier};
+use rustls::server::{
+    AllowAnyAnonymousOrAuthenticatedClient, AllowAnyAuthenticatedClient, ClientCertVerifier, NoClientAuth,
+    NoServerSessionStorage, ResolvesServerCertUsingSni, ServerSessionMemoryCache,
+};
+use ring::rand::SystemRandom;
+use ring::signature::{EcdsaKeyPair, ECDSA_P256_SHA256_ASN1_SIGNING};
+use rustls::sign::{any_ecdsa_type, any_supported_type, CertifiedKey};
+use rustls::{
+    version, Certificate, ClientConfig, OwnedTrustAnchor, PrivateKey, RootCertStore, ServerName, SupportedCipherSuite,
+    SupportedKxGroup, SupportedProtocolVersion, Ticketer, ALL_CIPHER_SUITES, ALL_KX_GROUPS,
+};
+use rustls_pemfile::certs;
 use tokio_rustls::rustls::{self, ServerConfig};
-use tokio_rustls::TlsAcceptor;
 
+use crate::common::enums::client_auth_mode::ClientAuthMode;
+use crate::common::enums::route_type::RouteType;
+use crate::constants::Constants;
+use crate::core::host_matcher::HostPattern;
+use crate::core::shared_memory::RUNTIME_BOOT_INFO;
+use crate::{log_debug, log_error};
+use crate::core::sni_resolver::{LiveSniResolver, SniResolver};
+use crate::models::degraded_route_report::DegradedRouteReport;
+use crate::models::https_server_config::HttpsServerConfig;
 use crate::models::route::https_route::HttpsRoute;
 use crate::models::route::secure_iws_route::SecureIwsRoute;
+use crate::models::settings::client_auth_settings::ClientAuthSettings;
+use crate::models::settings::tls_settings::TlsSettings;
+use crate::models::settings::upstream_tls_settings::UpstreamTlsSettings;
+use crate::models::ssl_context::SslContext;
+use crate::models::traffic_policy::scope_traffic_policy::ScopeTrafficPolicy;
+use crate::utils::ssl_file_utility::resolve_ssl_file_path;
+use crate::utils::x509_utility::{create_default_certificate, decode_pem_blocks};
 use std::collections::HashMap;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::BufReader;
-use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::SystemTime;
 
-/// Creates a TLS Acceptor configured with SNI (Server Name Indication) resolution for the given routes.
+/// Creates the default TLS server configuration which resolves certificates by SNI (Server Name Indication) from the live HTTPS route snapshot.
+/// Since certificates are looked up on each handshake, route changes apply to new connections without rebinding the listener.
+///
+/// # Arguments
+///
+/// * `tls_settings` - TLS handshake policy of HTTPS server.
+///
+/// # Returns
+///
+/// * `Ok(ServerConfig)` backed by `LiveSniResolver`, without client certificate authentication.
+/// * `Err` if TLS settings are invalid.
+pub fn create_ssl_context(tls_settings: &TlsSettings) -> Result<Arc<ServerConfig>, Error> {
+    create_server_config(NoClientAuth::new(), tls_settings)
+}
+
+/// Creates TLS server configuration of a route which defines client certificate authentication (mTLS) or its own TLS settings.
+///
+/// # Arguments
+///
+/// * `ssl_context` - SSL context of the route.
+/// * `server_tls_settings` - TLS handshake policy of HTTPS server, overridden by route settings.
+///
+/// # Returns
+///
+/// * `Ok(Some(ServerConfig))` for routes with their own handshake settings, `Ok(None)` for routes using server defaults.
+/// * `Err` if CA bundle can not be loaded or TLS settings are invalid.
+pub fn create_route_ssl_context(
+    ssl_context: &SslContext,
+    server_tls_settings: &TlsSettings,
+) -> Result<Option<Arc<ServerConfig>>, Error> {
+    if ssl_context.client_auth.is_none() && ssl_context.tls.is_none() {
+        return Ok(None);
+    }
+
+    let mut tls_settings = server_tls_settings.clone();
+    if let Some(ref route_tls_settings) = ssl_context.tls {
+        tls_settings.merge(route_tls_settings);
+    }
+
+    let client_cert_verifier = match ssl_context.client_auth {
+        Some(ref client_auth) => create_client_cert_verifier(client_auth)?,
+        None => NoClientAuth::new(),
+    };
+
+    create_server_config(client_cert_verifier, &tls_settings).map(Some)
+}
+
+/// Creates verifier which requires or requests client certificates by `mode`, issued by CA bundle of the route
+fn create_client_cert_verifier(client_auth: &ClientAuthSettings) -> Result<Arc<dyn ClientCertVerifier>, Error> {
+    let client_ca_store = load_ca_bundle(&client_auth.ca_bundle_path)?;
+
+    Ok(match client_auth.get_mode() {
+        ClientAuthMode::Required => AllowAnyAuthenticatedClient::new(client_ca_store),
+        ClientAuthMode::Optional => AllowAnyAnonymousOrAuthenticatedClient::new(client_ca_store),
+    })
+}
+
+fn create_server_config(
+    client_cert_verifier: Arc<dyn ClientCertVerifier>,
+    tls_settings: &TlsSettings,
+) -> Result<Arc<ServerConfig>, Error> {
+    let cipher_suites = get_cipher_suites(tls_settings)?;
+    let kx_groups = get_kx_groups(tls_settings)?;
+    let protocol_versions = get_protocol_versions(tls_settings)?;
+
+    let mut tls_config = ServerConfig::builder()
+        .with_cipher_suites(&cipher_suites)
+        .with_kx_groups(&kx_groups)
+        .with_protocol_versions(&protocol_versions)
+        .map_err(|error| {
+            invalid_tls_settings_error(format!(
+                "Cipher suites do not cover allowed TLS versions: {}",
+                error
+            ))
+        })?
+        .with_client_cert_verifier(client_cert_verifier)
+        .with_cert_resolver(Arc::new(LiveSniResolver));
+
+    let mut alpn_protocols = tls_settings.get_alpn_protocols();
+    if alpn_protocols.iter().any(|protocol| protocol.is_empty()) {
+        return Err(invalid_tls_settings_error("ALPN protocols can not be empty".to_string()));
+    }
+
+    // `acme-tls/1` is only selected by TLS-ALPN-01 validation servers, since browsers do not offer it.
+    // An empty list disables ALPN, so nothing is added there
+    if !alpn_protocols.is_empty() {
+        alpn_protocols.push(String::from_utf8_lossy(Constants::ACME_TLS_ALPN_PROTOCOL).to_string());
+    }
+    tls_config.alpn_protocols = alpn_protocols.into_iter().map(String::into_bytes).collect();
+
+    tls_config.session_storage = match tls_settings.get_session_resumption() {
+        true => ServerSessionMemoryCache::new(tls_settings.get_session_cache_size()),
+        false => Arc::new(NoServerSessionStorage {}),
+    };
+
+    if tls_settings.get_session_tickets() {
+        tls_config.ticketer = Ticketer::new().map_err(|error| {
+            invalid_tls_settings_error(format!("Session ticketer can not be created: {}", error))
+        })?;
+    }
+
+    Ok(Arc::new(tls_config))
+}
+
+/// Validates TLS handshake policy by building a server configuration with it
+pub fn validate_tls_settings(tls_settings: &TlsSettings) -> Result<(), Error> {
+    create_server_config(NoClientAuth::new(), tls_settings).map(|_| ())
+}
+
+/// Validates TLS settings of a route, if defined. Route settings are merged over settings of HTTPS server like on handshake
+pub fn validate_route_tls_settings(tls_settings: &Option<TlsSettings>) -> Result<(), Error> {
+    let Some(route_tls_settings) = tls_settings else {
+        return Ok(());
+    };
+
+    let mut merged_tls_settings = RUNTIME_BOOT_INFO.read().unwrap().config.https_server.get_tls_settings();
+    merged_tls_settings.merge(route_tls_settings);
+
+    validate_tls_settings(&merged_tls_settings)
+}
+
+fn get_protocol_versions(tls_settings: &TlsSettings) -> Result<Vec<&'static SupportedProtocolVersion>, Error> {
+    let parse_version = |version: String| match version.as_str() {
+        "1.2" => Ok(1.2),
+        "1.3" => Ok(1.3),
+        other => Err(invalid_tls_settings_error(format!(
+            "Unknown TLS version '{}'. Please type one of following: '1.2', '1.3'",
+            other
+        ))),
+    };
+
+    let min_version = parse_version(tls_settings.get_min_version())?;
+    let max_version = parse_version(tls_settings.get_max_version())?;
+
+    if min_version > max_version {
+        return Err(invalid_tls_settings_error(
+            "Minimum TLS version can not be higher than maximum TLS version".to_string(),
+        ));
+    }
+
+    Ok([(1.2, &version::TLS12), (1.3, &version::TLS13)]
+        .into_iter()
+        .filter(|(each_version, _)| (min_version..=max_version).contains(each_version))
+        .map(|(_, protocol_version)| protocol_version)
+        .collect())
+}
+
+fn get_cipher_suites(tls_settings: &TlsSettings) -> Result<Vec<SupportedCipherSuite>, Error> {
+    let Some(ref cipher_suite_names) = tls_settings.cipher_suites else {
+        return Ok(ALL_CIPHER_SUITES.to_vec());
+    };
+
+    if cipher_suite_names.is_empty() {
+        return Err(invalid_tls_settings_error("At least one cipher suite is required".to_string()));
+    }
+
+    cipher_suite_names
+        .iter()
+        .map(|cipher_suite_name| {
+            ALL_CIPHER_SUITES
+                .iter()
+                .find(|cipher_suite| format!("{:?}", cipher_suite.suite()).eq_ignore_ascii_case(cipher_suite_name))
+                .copied()
+                .ok_or_else(|| {
+                    let supported_names: Vec<String> = ALL_CIPHER_SUITES
+                        .iter()
+                        .map(|cipher_suite| format!("{:?}", cipher_suite.suite()))
+                        .collect();
+
+                    invalid_tls_settings_error(format!(
+                        "Unknown cipher suite '{}'. Supported cipher suites: {}",
+                        cipher_suite_name,
+                        supported_names.join(", ")
+                    ))
+                })
+        })
+        .collect()
+}
+
+fn get_kx_groups(tls_settings: &TlsSettings) -> Result<Vec<&'static SupportedKxGroup>, Error> {
+    let Some(ref kx_group_names) = tls_settings.kx_groups else {
+        return Ok(ALL_KX_GROUPS.to_vec());
+    };
+
+    if kx_group_names.is_empty() {
+        return Err(invalid_tls_settings_error("At least one key exchange group is required".to_string()));
+    }
+
+    kx_group_names
+        .iter()
+        .map(|kx_group_name| {
+            ALL_KX_GROUPS
+                .iter()
+                .find(|kx_group| format!("{:?}", kx_group.name).eq_ignore_ascii_case(kx_group_name))
+                .copied()
+                .ok_or_else(|| {
+                    invalid_tls_settings_error(format!(
+                        "Unknown key exchange group '{}'. Please type one of following: 'X25519', 'secp256r1', 'secp384r1'",
+                        kx_group_name
+                    ))
+                })
+        })
+        .collect()
+}
+
+fn invalid_tls_settings_error(message: String) -> Error {
+    Error {
+        code: ErrorCode::InvalidParams,
+        message: format!("Invalid TLS settings: {}", message),
+        data: None,
+    }
+}
+
+/// Validates client certificate authentication settings of a route, if defined
+pub fn validate_client_auth_settings(client_auth: &Option<ClientAuthSettings>) -> Result<(), Error> {
+    let Some(client_auth) = client_auth else {
+        return Ok(());
+    };
+
+    let has_empty_identity = client_auth
+        .allowed_identities
+        .as_ref()
+        .map(|allowed_identities| allowed_identities.iter().any(|identity| identity.trim().is_empty()))
+        .unwrap_or(false);
+
+    if has_empty_identity {
+        return Err(Error {
+            code: ErrorCode::InvalidParams,
+            message: "Allowed client identities can not be empty".to_string(),
+            data: None,
+        });
+    }
+
+    load_ca_bundle(&client_auth.ca_bundle_path).map(|_| ())
+}
+
+/// Creates an SNI resolver with the certificates of given routes.
+/// Route sources may be exact hosts or host patterns (see `HostPattern`).
+/// A route whose certificate can not be loaded is marked as degraded and served with fallback certificate of HTTPS server,
+/// or a self signed default certificate, so a single broken route never stops handshakes of other routes.
 ///
 /// # Arguments
 ///
 /// * `https_routes` - A map of HTTPS routes.
 /// * `secure_iws_routes` - A map of Secure IWS routes.
+/// * `https_server_config` - HTTPS server config, which keeps TLS handshake policy and fallback certificate.
 ///
 /// # Returns
 ///
-/// * A `TlsAcceptor` instance initialized with the certificates for the provided routes.
-pub fn create_ssl_context(
-    https_routes: HashMap<String, HttpsRoute>,
-    secure_iws_routes: HashMap<String, SecureIwsRoute>,
-) -> TlsAcceptor {
-    let mut sni_resolver = ResolvesServerCertUsingSni::new();
+/// * `SniResolver` initialized with the certificates of provided routes and their degraded states.
+pub fn create_sni_resolver(
+    https_routes: &HashMap<String, HttpsRoute>,
+    secure_iws_routes: &HashMap<String, SecureIwsRoute>,
+    https_server_config: &HttpsServerConfig,
+) -> SniResolver {
+    let mut sni_resolver = SniResolver::default();
+    let mut fallback_certified_key: Option<Option<Arc<CertifiedKey>>> = None;
+    let server_tls_settings = https_server_config.get_tls_settings();
 
-    /* Loop for creating sni resolving for all https routes */
-    for (source, https_route) in https_routes {
-        // Load certificate
-        let cert_file_path = &https_route.ssl_context.certificate_file_path;
-        let ssl_cert_list = match load_ssl_certs(cert_file_path) {
-            Ok(c) => c,
-            Err(err) => {
-                panic!(
-                    "An error occurred while loading SSL certificate for '{}': {}",
-                    source, err.message
-                );
+    let https_ssl_contexts = https_routes
+        .iter()
+        .map(|(source, https_route)| (source, RouteType::Https, &https_route.ssl_context));
+    let secure_iws_ssl_contexts = secure_iws_routes
+        .iter()
+        .map(|(source, secure_iws_route)| (source, RouteType::SecureIws, &secure_iws_route.ssl_context));
+
+    for (source, route_type, ssl_context) in https_ssl_contexts.chain(secure_iws_ssl_contexts) {
+        if is_acme_certificate_pending(source, ssl_context) {
+            continue;
+        }
+
+        // Without its own TLS settings (e.g. mTLS CA bundle), route is not served at all instead of being served less strictly
+        let server_config = match create_route_ssl_context(ssl_context, &server_tls_settings) {
+            Ok(server_config) => server_config,
+            Err(error) => {
+                let reason = format!("TLS settings can not be created: {}", error.message);
+                add_degraded_route(&mut sni_resolver, source, route_type, reason, false);
+                continue;
             }
         };
 
-        // Load private key
-        let private_key_file_path = &https_route.ssl_context.private_key_file_path;
-        let private_key = match load_ssl_private_key(private_key_file_path) {
-            Ok(k) => k,
-            Err(err) => {
-                panic!(
-                    "An error occurred while loading SSL private key for '{}' Https Route: {}",
-                    source, err.message
+        let certified_key = match load_certified_key(source, ssl_context) {
+            Ok(certified_key) => Arc::new(certified_key),
+            Err(error) => {
+                let fallback_certified_key = fallback_certified_key
+                    .get_or_insert_with(|| load_fallback_certified_key(https_server_config))
+                    .clone();
+
+                add_degraded_route(
+                    &mut sni_resolver,
+                    source,
+                    route_type.clone(),
+                    error.message,
+                    fallback_certified_key.is_some(),
                 );
+
+                match fallback_certified_key {
+                    Some(fallback_certified_key) => fallback_certified_key,
+                    None => continue,
+                }
             }
         };
 
-        let certified_key = create_certified_key(ssl_cert_list, private_key);
-        sni_resolver.add(source.as_str(), certified_key).unwrap();
+        let registration = sni_resolver.add(source, certified_key).and_then(|_| match server_config {
+            Some(server_config) => sni_resolver.add_server_config(source, server_config),
+            None => Ok(()),
+        });
+
+        if let Err(error_message) = registration {
+            add_degraded_route(&mut sni_resolver, source, route_type, error_message, false);
+        }
     }
 
-    /* Loop for creating sni resolving for all secure IWS routes */
-    for (source, secure_iws_route) in secure_iws_routes {
-        // Load certificate
-        let cert_file_path = &secure_iws_route.ssl_context.certificate_file_path;
-        let ssl_cert_list = match load_ssl_certs(cert_file_path) {
-            Ok(c) => c,
-            Err(err) => {
-                panic!(
-                    "An error occurred while loading SSL certificate for '{}' Secure IWS Route: {}",
-                    source, err.message
-                );
-            }
-        };
+    sni_resolver
+}
 
-        // Load private key
-        let private_key_file_path = &secure_iws_route.ssl_context.private_key_file_path;
-        let private_key = match load_ssl_private_key(private_key_file_path) {
-            Ok(k) => k,
-            Err(err) => {
-                panic!(
-                    "An error occurred while loading SSL private key for '{}': {}",
-                    source, err.message
-                );
-            }
+fn add_degraded_route(
+    sni_resolver: &mut SniResolver,
+    source: &str,
+    route_type: RouteType,
+    reason: String,
+    is_fallback_certificate_served: bool,
+) {
+    match is_fallback_certificate_served {
+        true => log_error!("Route '{}' is degraded and served with fallback certificate: {}", source, reason),
+        false => log_error!("Route '{}' is degraded and not served over TLS: {}", source, reason),
+    }
+
+    sni_resolver.add_degraded_route(DegradedRouteReport {
+        source: source.to_string(),
+        route_type,
+        reason,
+        is_fallback_certificate_served,
+    });
+}
+
+/// Loads fallback certificate of HTTPS server. If it is not defined or can not be loaded, a self signed default certificate is created
+fn load_fallback_certified_key(https_server_config: &HttpsServerConfig) -> Option<Arc<CertifiedKey>> {
+    if let Some(ref fallback_certificate) = https_server_config.fallback_certificate {
+        let fallback_ssl_context = SslContext {
+            certificate_file_path: fallback_certificate.certificate_file_path.clone(),
+            private_key_file_path: fallback_certificate.private_key_file_path.clone(),
+            ..Default::default()
         };
 
-        let certified_key = create_certified_key(ssl_cert_list, private_key);
-        sni_resolver.add(source.as_str(), certified_key).unwrap();
+        match load_certified_key("fallback certificate", &fallback_ssl_context) {
+            Ok(certified_key) => return Some(Arc::new(certified_key)),
+            Err(error) => log_error!("{}. Default certificate is used instead", error.message),
+        }
     }
 
-    let mut tls_config = ServerConfig::builder()
-        .with_safe_defaults()
-        .with_no_client_auth()
-        .with_cert_resolver(Arc::new(sni_resolver));
+    match create_default_certified_key() {
+        Ok(certified_key) => Some(Arc::new(certified_key)),
+        Err(error_message) => {
+            log_error!("Default certificate can not be created: {}", error_message);
+            None
+        }
+    }
+}
+
+fn create_default_certified_key() -> Result<CertifiedKey, String> {
+    let private_key = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &SystemRandom::new())
+        .map_err(|_| "Default certificate key can not be generated".to_string())?;
+    let signing_key = EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, private_key.as_ref())
+        .map_err(|error| format!("Default certificate key is rejected: {}", error))?;
 
-    tls_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    let certificate = create_default_certificate(Constants::DEFAULT_CERTIFICATE_COMMON_NAME, &signing_key)?;
 
-    TlsAcceptor::from(Arc::new(tls_config))
+    let tls_signing_key = any_ecdsa_type(&PrivateKey(private_key.as_ref().to_vec()))
+        .map_err(|_| "Default certificate key is not supported by TLS".to_string())?;
+
+    Ok(CertifiedKey::new(vec![Certificate(certificate)], tls_signing_key))
+}
+
+fn load_certified_key(source: &str, ssl_context: &SslContext) -> Result<CertifiedKey, Error> {
+    let ssl_cert_list = load_ssl_certs(&ssl_context.certificate_file_path).map_err(|err| Error {
+        code: ErrorCode::InternalError,
+        message: format!(
+            "An error occurred while loading SSL certificate for '{}': {}",
+            source, err.message
+        ),
+        data: None,
+    })?;
+
+    let private_key = load_ssl_private_key(&ssl_context.private_key_file_path).map_err(|err| Error {
+        code: ErrorCode::InternalError,
+        message: format!(
+            "An error occurred while loading SSL private key for '{}': {}",
+            source, err.message
+        ),
+        data: None,
+    })?;
+
+    create_certified_key(ssl_cert_list, private_key)
+}
+
+/// Routes of ACME are served once their first certificate is issued, until then they are left out of SNI resolver
+fn is_acme_certificate_pending(source: &str, ssl_context: &SslContext) -> bool {
+    if !ssl_context.is_acme_managed() {
+        return false;
+    }
+
+    let is_issued = resolve_ssl_file_path(&ssl_context.certificate_file_path)
+        .map(|certificate_path| certificate_path.is_file())
+        .unwrap_or(false);
+
+    if !is_issued {
+        log_debug!("ACME certificate of '{}' is not issued yet, route is not served over TLS", source);
+    }
+
+    !is_issued
+}
+
+/// Creates TLS client configuration used for connecting `https://` upstreams.
+///
+/// # Arguments
+///
+/// * `tls_settings` - Upstream TLS settings of the route.
+///
+/// # Returns
+///
+/// * `Ok(ClientConfig)` trusting web PKI roots, or only the CA bundle if defined. Client certificate is presented for mTLS if defined.
+/// * `Err` if CA bundle, client certificate or client private key can not be loaded.
+pub fn create_upstream_tls_config(tls_settings: &UpstreamTlsSettings) -> Result<ClientConfig, Error> {
+    let client_identity = match (
+        &tls_settings.client_certificate_path,
+        &tls_settings.client_private_key_path,
+    ) {
+        (Some(certificate_path), Some(private_key_path)) => Some((
+            load_ssl_certs(certificate_path)?,
+            load_ssl_private_key(private_key_path)?,
+        )),
+        (None, None) => None,
+        _ => {
+            return Err(Error {
+                code: ErrorCode::InvalidParams,
+                message: "Both client certificate and client private key are required for upstream mTLS".to_string(),
+                data: None,
+            })
+        }
+    };
+
+    let tls_config_builder = ClientConfig::builder().with_safe_defaults();
+
+    let tls_config = if tls_settings.get_insecure_skip_verify() {
+        let tls_config_builder =
+            tls_config_builder.with_custom_certificate_verifier(Arc::new(SkipServerVerification));
+
+        match client_identity {
+            Some((certificates, private_key)) => tls_config_builder
+                .with_single_cert(certificates, private_key)
+                .map_err(invalid_client_identity_error)?,
+            None => tls_config_builder.with_no_client_auth(),
+        }
+    } else {
+        let tls_config_builder =
+            tls_config_builder.with_root_certificates(load_root_certificates(tls_settings)?);
+
+        match client_identity {
+            Some((certificates, private_key)) => tls_config_builder
+                .with_single_cert(certificates, private_key)
+                .map_err(invalid_client_identity_error)?,
+            None => tls_config_builder.with_no_client_auth(),
+        }
+    };
+
+    Ok(tls_config)
+}
+
+/// Validates upstream TLS settings of given traffic policy (if any) by building its TLS client configuration
+pub fn validate_upstream_tls_settings(traffic_policy: &Option<ScopeTrafficPolicy>) -> Result<(), Error> {
+    let upstream_tls_settings = match traffic_policy
+        .as_ref()
+        .and_then(|traffic_policy| traffic_policy.upstream_settings.tls.as_ref())
+    {
+        Some(upstream_tls_settings) => upstream_tls_settings,
+        None => return Ok(()),
+    };
+
+    create_upstream_tls_config(upstream_tls_settings)?;
+    get_upstream_server_name(upstream_tls_settings)?;
+
+    Ok(())
+}
+
+/// Parses SNI override of upstream TLS settings (if any)
+pub fn get_upstream_server_name(tls_settings: &UpstreamTlsSettings) -> Result<Option<ServerName>, Error> {
+    let sni_override = match &tls_settings.sni_override {
+        Some(sni_override) => sni_override,
+        None => return Ok(None),
+    };
+
+    ServerName::try_from(sni_override.as_str())
+        .map(Some)
+        .map_err(|_| Error {
+            code: ErrorCode::InvalidParams,
+            message: format!("SNI override '{}' is not a valid server name", sni_override),
+            data: None,
+        })
+}
+
+fn load_root_certificates(tls_settings: &UpstreamTlsSettings) -> Result<RootCertStore, Error> {
+    let mut root_cert_store = RootCertStore::empty();
+
+    let ca_bundle_path = match &tls_settings.ca_bundle_path {
+        Some(ca_bundle_path) => ca_bundle_path,
+        None => {
+            root_cert_store.add_server_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.0.iter().map(
+                |trust_anchor| {
+                    OwnedTrustAnchor::from_subject_spki_name_constraints(
+                        trust_anchor.subject,
+                        trust_anchor.spki,
+                        trust_anchor.name_constraints,
+                    )
+                },
+            ));
+
+            return Ok(root_cert_store);
+        }
+    };
+
+    load_ca_bundle(ca_bundle_path)
+}
+
+/// Loads every certificate of a PEM CA bundle as trust anchor
+fn load_ca_bundle(ca_bundle_path: &String) -> Result<RootCertStore, Error> {
+    let mut root_cert_store = RootCertStore::empty();
+
+    for ca_certificate in load_ssl_certs(ca_bundle_path)? {
+        root_cert_store.add(&ca_certificate).map_err(|error| Error {
+            code: ErrorCode::InternalError,
+            message: format!(
+                "Invalid CA certificate in bundle '{}': {}",
+                ca_bundle_path, error
+            ),
+            data: None,
+        })?;
+    }
+
+    Ok(root_cert_store)
+}
+
+fn invalid_client_identity_error(error: rustls::Error) -> Error {
+    Error {
+        code: ErrorCode::InternalError,
+        message: format!("Invalid upstream client certificate: {}", error),
+        data: None,
+    }
+}
+
+/// Accepts any upstream certificate. Used when `insecure_skip_verify` is enabled
+struct SkipServerVerification;
+
+impl ServerCertVerifier for SkipServerVerification {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &Certificate,
+        _intermediates: &[Certificate],
+        _server_name: &ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: SystemTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
 }
 
 /// Validates that an SSL certificate and private key are valid and match each other for the given domain.
+/// Certificate name check is only applied on exact domains, since host patterns can not be checked against certificate names.
 ///
 /// # Arguments
 ///
@@ -120,8 +626,23 @@ pub fn validate_ssl_context(
     let ssl_cert_list = load_ssl_certs(ssl_cert_path)?;
     let private_key = load_ssl_private_key(ssl_private_key_path)?;
 
+    let certified_key = create_certified_key(ssl_cert_list, private_key)?;
+
+    if !HostPattern::is_exact(domain) {
+        let mut sni_resolver = SniResolver::default();
+
+        sni_resolver
+            .add(domain.as_str(), Arc::new(certified_key))
+            .map_err(|error_body| Error {
+                code: jsonrpc_core::ErrorCode::InternalError,
+                message: error_body,
+                data: None,
+            })?;
+
+        return Ok(());
+    }
+
     let mut sni_resolver = ResolvesServerCertUsingSni::new();
-    let certified_key = create_certified_key(ssl_cert_list, private_key);
 
     sni_resolver
         .add(domain.as_str(), certified_key)
@@ -135,9 +656,15 @@ pub fn validate_ssl_context(
 }
 
 /// Helper to create a CertifiedKey from certificates and a private key.
-fn create_certified_key(certs: Vec<Certificate>, key: PrivateKey) -> CertifiedKey {
-    let signing_key = RsaSigningKey::new(&key).unwrap();
-    CertifiedKey::new(certs, Arc::new(signing_key))
+/// Signing key type (RSA, ECDSA or Ed25519) is detected from the key itself.
+fn create_certified_key(certs: Vec<Certificate>, key: PrivateKey) -> Result<CertifiedKey, Error> {
+    let signing_key = any_supported_type(&key).map_err(|_| Error {
+        code: ErrorCode::InternalError,
+        message: "Private key is not a supported RSA, ECDSA or Ed25519 key".to_string(),
+        data: None,
+    })?;
+
+    Ok(CertifiedKey::new(certs, signing_key))
 }
 
 /// Loads SSL certificates from a file.
@@ -151,7 +678,11 @@ fn create_certified_key(certs: Vec<Certificate>, key: PrivateKey) -> CertifiedKe
 /// * `Ok(Vec<Certificate>)` on success.
 /// * `Err` on failure.
 fn load_ssl_certs(certificate_file_path: &String) -> Result<Vec<Certificate>, Error> {
-    let cert_file_path = PathBuf::from(certificate_file_path);
+    let cert_file_path = resolve_ssl_file_path(certificate_file_path).map_err(|error_message| Error {
+        code: ErrorCode::InvalidParams,
+        message: error_message,
+        data: None,
+    })?;
 
     if !cert_file_path.is_file() {
         return Err(Error {
@@ -184,6 +715,17 @@ fn load_ssl_certs(certificate_file_path: &String) -> Result<Vec<Certificate>, Er
         data: None,
     })?;
 
+    if certs.is_empty() {
+        return Err(Error {
+            code: ErrorCode::InternalError,
+            message: format!(
+                "No certificate found at path '{}'",
+                cert_file_path.to_string_lossy()
+            ),
+            data: None,
+        });
+    }
+
     Ok(certs.into_iter().map(Certificate).collect())
 }
 
@@ -198,7 +740,11 @@ fn load_ssl_certs(certificate_file_path: &String) -> Result<Vec<Certificate>, Er
 /// * `Ok(PrivateKey)` on success.
 /// * `Err` on failure.
 fn load_ssl_private_key(private_key_file_path: &String) -> Result<PrivateKey, Error> {
-    let private_key_path = PathBuf::from(private_key_file_path);
+    let private_key_path = resolve_ssl_file_path(private_key_file_path).map_err(|error_message| Error {
+        code: ErrorCode::InvalidParams,
+        message: error_message,
+        data: None,
+    })?;
 
     if !private_key_path.is_file() {
         return Err(Error {
@@ -211,27 +757,50 @@ fn load_ssl_private_key(private_key_file_path: &String) -> Result<PrivateKey, Er
         });
     }
 
-    let private_key_file = File::open(&private_key_path).map_err(|_| Error {
+    let private_key_content = fs::read_to_string(&private_key_path).map_err(|_| Error {
         code: jsonrpc_core::ErrorCode::InternalError,
         message: format!(
-            "File not found at path '{}'",
+            "File can not be read at path '{}'",
             &private_key_path.to_string_lossy()
         ),
         data: None,
     })?;
 
-    let mut reader = BufReader::new(private_key_file);
-
-    let private_keys = pkcs8_private_keys(&mut reader).map_err(|_| Error {
+    let pem_blocks = decode_pem_blocks(&private_key_content).map_err(|error_message| Error {
         code: jsonrpc_core::ErrorCode::InternalError,
         message: format!(
-            "Failed to load private key from path '{}'",
-            &private_key_path.to_string_lossy()
+            "Failed to load private key from path '{}': {}",
+            &private_key_path.to_string_lossy(),
+            error_message
         ),
         data: None,
     })?;
 
-    let primary_private_key_as_binary = private_keys[0].clone();
+    // PKCS#8, PKCS#1 (RSA) and SEC1 (EC) keys are all accepted, key algorithm is detected by `any_supported_type`
+    let private_key = pem_blocks.into_iter().find_map(|(label, der)| match label.as_str() {
+        "PRIVATE KEY" | "RSA PRIVATE KEY" | "EC PRIVATE KEY" => Some(Ok(der)),
+        "ENCRYPTED PRIVATE KEY" => Some(Err("Encrypted private keys are not supported")),
+        _ => None,
+    });
 
-    Ok(PrivateKey(primary_private_key_as_binary))
+    match private_key {
+        Some(Ok(private_key_as_binary)) => Ok(PrivateKey(private_key_as_binary)),
+        Some(Err(error_message)) => Err(Error {
+            code: jsonrpc_core::ErrorCode::InternalError,
+            message: format!(
+                "{} (path '{}')",
+                error_message,
+                &private_key_path.to_string_lossy()
+            ),
+            data: None,
+        }),
+        None => Err(Error {
+            code: jsonrpc_core::ErrorCode::InternalError,
+            message: format!(
+                "No private key found at path '{}'",
+                &private_key_path.to_string_lossy()
+            ),
+            data: None,
+        }),
+    }
 }
@@ -0,0 +1,893 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{Duration, NaiveDate, Utc};
+use ring::rand::SystemRandom;
+use ring::signature::{EcdsaKeyPair, KeyPair};
+use rustls_pemfile::certs;
+use std::io::BufReader;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::models::certificate_info::CertificateInfo;
+
+const TAG_BOOLEAN: u8 = 0x01;
+const TAG_INTEGER: u8 = 0x02;
+const TAG_BIT_STRING: u8 = 0x03;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_OID: u8 = 0x06;
+const TAG_UTF8_STRING: u8 = 0x0C;
+const TAG_PRINTABLE_STRING: u8 = 0x13;
+const TAG_T61_STRING: u8 = 0x14;
+const TAG_IA5_STRING: u8 = 0x16;
+const TAG_UTC_TIME: u8 = 0x17;
+const TAG_GENERALIZED_TIME: u8 = 0x18;
+const TAG_BMP_STRING: u8 = 0x1E;
+const TAG_SEQUENCE: u8 = 0x30;
+const TAG_SET: u8 = 0x31;
+const TAG_VERSION: u8 = 0xA0;
+const TAG_ATTRIBUTES: u8 = 0xA0;
+const TAG_EXTENSIONS: u8 = 0xA3;
+const TAG_SAN_EMAIL: u8 = 0x81;
+const TAG_SAN_DNS_NAME: u8 = 0x82;
+const TAG_SAN_URI: u8 = 0x86;
+const TAG_SAN_IP_ADDRESS: u8 = 0x87;
+
+const OID_SUBJECT_ALT_NAME: &[u8] = &[0x55, 0x1D, 0x11];
+const OID_COMMON_NAME: &[u8] = &[0x55, 0x04, 0x03];
+const OID_EC_PUBLIC_KEY: &[u8] = &[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x02, 0x01];
+const OID_PRIME256V1: &[u8] = &[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x03, 0x01, 0x07];
+const OID_ECDSA_WITH_SHA256: &[u8] = &[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x04, 0x03, 0x02];
+const OID_EXTENSION_REQUEST: &[u8] = &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x09, 0x0E];
+// RFC 8737: id-pe-acmeIdentifier
+const OID_ACME_IDENTIFIER: &[u8] = &[0x2B, 0x06, 0x01, 0x05, 0x05, 0x07, 0x01, 0x1F];
+
+/// Minimal reader of DER encoded elements, enough to read fields of X.509 certificates
+struct DerReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> DerReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position >= self.data.len()
+    }
+
+    fn peek_tag(&self) -> Option<u8> {
+        self.data.get(self.position).copied()
+    }
+
+    /// Reads next element, returning its tag and content
+    fn read(&mut self) -> Result<(u8, &'a [u8]), String> {
+        let tag = self.read_byte()?;
+        let first_length_byte = self.read_byte()?;
+
+        let length = if first_length_byte < 0x80 {
+            first_length_byte as usize
+        } else {
+            let length_byte_count = (first_length_byte & 0x7F) as usize;
+            if length_byte_count == 0 || length_byte_count > 4 {
+                return Err("Unsupported DER length encoding".to_string());
+            }
+
+            let mut length = 0usize;
+            for _ in 0..length_byte_count {
+                length = (length << 8) | self.read_byte()? as usize;
+            }
+            length
+        };
+
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| "DER element exceeds its container".to_string())?;
+
+        let content = &self.data[self.position..end];
+        self.position = end;
+        Ok((tag, content))
+    }
+
+    /// Reads next element and checks that it has the expected tag
+    fn read_expected(&mut self, expected_tag: u8) -> Result<&'a [u8], String> {
+        let (tag, content) = self.read()?;
+        if tag != expected_tag {
+            return Err(format!(
+                "Unexpected DER tag 0x{:02X}, expected 0x{:02X}",
+                tag, expected_tag
+            ));
+        }
+        Ok(content)
+    }
+
+    fn read_byte(&mut self) -> Result<u8, String> {
+        let byte = self
+            .data
+            .get(self.position)
+            .copied()
+            .ok_or_else(|| "Unexpected end of DER data".to_string())?;
+        self.position += 1;
+        Ok(byte)
+    }
+}
+
+/// Reads every certificate of a PEM encoded certificate chain
+pub fn parse_pem_certificates(content: &str) -> Result<Vec<CertificateInfo>, String> {
+    let mut reader = BufReader::new(content.as_bytes());
+    let certificates = certs(&mut reader).map_err(|_| "Certificate PEM blocks can not be read".to_string())?;
+
+    certificates
+        .iter()
+        .map(|certificate_der| parse_certificate(certificate_der))
+        .collect()
+}
+
+/// Reads subject, issuer, subject alternative names, serial number and validity of a DER encoded X.509 certificate
+pub fn parse_certificate(certificate_der: &[u8]) -> Result<CertificateInfo, String> {
+    let certificate = DerReader::new(certificate_der).read_expected(TAG_SEQUENCE)?;
+    let tbs_certificate = DerReader::new(certificate).read_expected(TAG_SEQUENCE)?;
+
+    let mut reader = DerReader::new(tbs_certificate);
+    if reader.peek_tag() == Some(TAG_VERSION) {
+        reader.read()?;
+    }
+
+    let serial_number = reader.read_expected(TAG_INTEGER)?;
+    reader.read_expected(TAG_SEQUENCE)?; // signature algorithm
+    let issuer = reader.read_expected(TAG_SEQUENCE)?;
+    let validity = reader.read_expected(TAG_SEQUENCE)?;
+    let subject = reader.read_expected(TAG_SEQUENCE)?;
+    reader.read_expected(TAG_SEQUENCE)?; // subject public key info
+
+    let mut subject_alt_names = Vec::new();
+    while !reader.is_empty() {
+        let (tag, content) = reader.read()?;
+        if tag == TAG_EXTENSIONS {
+            subject_alt_names = read_subject_alt_names(content)?;
+        }
+    }
+
+    let mut validity_reader = DerReader::new(validity);
+    let not_before = read_time(&mut validity_reader)?;
+    let not_after = read_time(&mut validity_reader)?;
+
+    let subject_attributes = read_name(subject)?;
+    let common_name = subject_attributes
+        .iter()
+        .find(|(attribute_name, _)| attribute_name == "CN")
+        .map(|(_, value)| value.clone());
+
+    Ok(CertificateInfo {
+        subject: format_name(&subject_attributes),
+        common_name,
+        issuer: format_name(&read_name(issuer)?),
+        subject_alt_names,
+        serial_number: hex::encode_upper(trim_leading_zeros(serial_number)),
+        not_before,
+        not_after,
+    })
+}
+
+fn read_subject_alt_names(extensions_wrapper: &[u8]) -> Result<Vec<String>, String> {
+    let extensions = DerReader::new(extensions_wrapper).read_expected(TAG_SEQUENCE)?;
+    let mut extensions_reader = DerReader::new(extensions);
+
+    while !extensions_reader.is_empty() {
+        let extension = extensions_reader.read_expected(TAG_SEQUENCE)?;
+        let mut extension_reader = DerReader::new(extension);
+
+        let oid = extension_reader.read_expected(TAG_OID)?;
+        if extension_reader.peek_tag() == Some(TAG_BOOLEAN) {
+            extension_reader.read()?; // critical flag
+        }
+        let value = extension_reader.read_expected(TAG_OCTET_STRING)?;
+
+        if oid != OID_SUBJECT_ALT_NAME {
+            continue;
+        }
+
+        let general_names = DerReader::new(value).read_expected(TAG_SEQUENCE)?;
+        let mut general_names_reader = DerReader::new(general_names);
+        let mut subject_alt_names = Vec::new();
+
+        while !general_names_reader.is_empty() {
+            let (tag, content) = general_names_reader.read()?;
+            match tag {
+                // Emails & URIs are mostly seen on client certificates (mTLS identities)
+                TAG_SAN_DNS_NAME | TAG_SAN_EMAIL | TAG_SAN_URI => {
+                    subject_alt_names.push(String::from_utf8_lossy(content).to_string())
+                }
+                TAG_SAN_IP_ADDRESS => {
+                    if let Some(ip) = read_ip_address(content) {
+                        subject_alt_names.push(ip.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        return Ok(subject_alt_names);
+    }
+
+    Ok(Vec::new())
+}
+
+/// Reads attributes of a distinguished name as (short name, value) pairs, in certificate order
+fn read_name(name: &[u8]) -> Result<Vec<(String, String)>, String> {
+    let mut attributes = Vec::new();
+    let mut name_reader = DerReader::new(name);
+
+    while !name_reader.is_empty() {
+        let relative_name = name_reader.read_expected(TAG_SET)?;
+        let mut relative_name_reader = DerReader::new(relative_name);
+
+        while !relative_name_reader.is_empty() {
+            let attribute = relative_name_reader.read_expected(TAG_SEQUENCE)?;
+            let mut attribute_reader = DerReader::new(attribute);
+
+            let oid = attribute_reader.read_expected(TAG_OID)?;
+            let (value_tag, value) = attribute_reader.read()?;
+
+            attributes.push((get_attribute_name(oid), read_string(value_tag, value)));
+        }
+    }
+
+    Ok(attributes)
+}
+
+fn format_name(attributes: &[(String, String)]) -> String {
+    attributes
+        .iter()
+        .map(|(attribute_name, value)| format!("{}={}", attribute_name, value))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn get_attribute_name(oid: &[u8]) -> String {
+    match oid {
+        [0x55, 0x04, 0x03] => "CN".to_string(),
+        [0x55, 0x04, 0x06] => "C".to_string(),
+        [0x55, 0x04, 0x07] => "L".to_string(),
+        [0x55, 0x04, 0x08] => "ST".to_string(),
+        [0x55, 0x04, 0x0A] => "O".to_string(),
+        [0x55, 0x04, 0x0B] => "OU".to_string(),
+        [0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x09, 0x01] => "emailAddress".to_string(),
+        _ => format_oid(oid),
+    }
+}
+
+/// Formats an object identifier in dotted notation (e.g. `2.5.4.3`)
+fn format_oid(oid: &[u8]) -> String {
+    let mut arcs: Vec<u64> = Vec::new();
+    let mut value: u64 = 0;
+
+    for byte in oid {
+        value = (value << 7) | (byte & 0x7F) as u64;
+        if byte & 0x80 != 0 {
+            continue;
+        }
+
+        if arcs.is_empty() {
+            let first_arc = (value / 40).min(2);
+            arcs.push(first_arc);
+            arcs.push(value - first_arc * 40);
+        } else {
+            arcs.push(value);
+        }
+        value = 0;
+    }
+
+    arcs.iter()
+        .map(|arc| arc.to_string())
+        .collect::<Vec<String>>()
+        .join(".")
+}
+
+fn read_string(tag: u8, value: &[u8]) -> String {
+    match tag {
+        TAG_BMP_STRING => {
+            let code_units: Vec<u16> = value
+                .chunks(2)
+                .map(|chunk| u16::from_be_bytes([chunk[0], *chunk.get(1).unwrap_or(&0)]))
+                .collect();
+            String::from_utf16_lossy(&code_units)
+        }
+        TAG_UTF8_STRING | TAG_PRINTABLE_STRING | TAG_IA5_STRING | TAG_T61_STRING => {
+            String::from_utf8_lossy(value).to_string()
+        }
+        _ => hex::encode_upper(value),
+    }
+}
+
+fn read_ip_address(content: &[u8]) -> Option<IpAddr> {
+    match content.len() {
+        4 => Some(IpAddr::V4(Ipv4Addr::new(
+            content[0], content[1], content[2], content[3],
+        ))),
+        16 => {
+            let mut octets = [0u8; 16];
+            octets.copy_from_slice(content);
+            Some(IpAddr::V6(Ipv6Addr::from(octets)))
+        }
+        _ => None,
+    }
+}
+
+/// Reads UTCTime or GeneralizedTime as unix timestamp
+fn read_time(reader: &mut DerReader) -> Result<i64, String> {
+    let (tag, content) = reader.read()?;
+    let text = std::str::from_utf8(content).map_err(|_| "Certificate time is not valid text".to_string())?;
+    let digits = text.trim_end_matches('Z');
+    if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(format!("Unsupported certificate time '{}'", text));
+    }
+
+    let (year, rest) = match tag {
+        TAG_UTC_TIME if digits.len() >= 10 => {
+            let year = parse_number(&digits[0..2])?;
+            // RFC 5280: two digit years from 50 belong to 20th century
+            let year = if year >= 50 { 1900 + year } else { 2000 + year };
+            (year, &digits[2..])
+        }
+        TAG_GENERALIZED_TIME if digits.len() >= 12 => (parse_number(&digits[0..4])?, &digits[4..]),
+        _ => return Err(format!("Unsupported certificate time '{}'", text)),
+    };
+
+    let month = parse_number(&rest[0..2])?;
+    let day = parse_number(&rest[2..4])?;
+    let hour = parse_number(&rest[4..6])?;
+    let minute = parse_number(&rest[6..8])?;
+    let second = match rest.get(8..10) {
+        Some(second) => parse_number(second)?,
+        None => 0,
+    };
+
+    NaiveDate::from_ymd_opt(year as i32, month, day)
+        .and_then(|date| date.and_hms_opt(hour, minute, second))
+        .map(|date_time| date_time.and_utc().timestamp())
+        .ok_or_else(|| format!("Invalid certificate time '{}'", text))
+}
+
+fn parse_number(digits: &str) -> Result<u32, String> {
+    digits
+        .parse()
+        .map_err(|_| format!("Invalid number '{}' in certificate time", digits))
+}
+
+fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let first_non_zero = bytes.iter().position(|byte| *byte != 0).unwrap_or(bytes.len().saturating_sub(1));
+    &bytes[first_non_zero..]
+}
+
+/// Encodes DER content with its tag and length
+fn encode_der(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![tag];
+    let length = content.len();
+
+    if length < 0x80 {
+        encoded.push(length as u8);
+    } else {
+        let length_bytes: Vec<u8> = length
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|byte| *byte == 0)
+            .collect();
+        encoded.push(0x80 | length_bytes.len() as u8);
+        encoded.extend(length_bytes);
+    }
+
+    encoded.extend_from_slice(content);
+    encoded
+}
+
+fn encode_der_list(tag: u8, elements: &[Vec<u8>]) -> Vec<u8> {
+    encode_der(tag, &elements.concat())
+}
+
+fn encode_integer(value: &[u8]) -> Vec<u8> {
+    let value = trim_leading_zeros(value);
+    match value.first() {
+        Some(first_byte) if first_byte & 0x80 != 0 => encode_der(TAG_INTEGER, &[&[0x00], value].concat()),
+        _ => encode_der(TAG_INTEGER, value),
+    }
+}
+
+fn encode_bit_string(value: &[u8]) -> Vec<u8> {
+    encode_der(TAG_BIT_STRING, &[&[0x00], value].concat())
+}
+
+fn encode_common_name(common_name: &str) -> Vec<u8> {
+    let attribute = encode_der_list(
+        TAG_SEQUENCE,
+        &[
+            encode_der(TAG_OID, OID_COMMON_NAME),
+            encode_der(TAG_UTF8_STRING, common_name.as_bytes()),
+        ],
+    );
+
+    encode_der_list(TAG_SEQUENCE, &[encode_der(TAG_SET, &attribute)])
+}
+
+fn encode_public_key_info(private_key: &EcdsaKeyPair) -> Vec<u8> {
+    let algorithm = encode_der_list(
+        TAG_SEQUENCE,
+        &[
+            encode_der(TAG_OID, OID_EC_PUBLIC_KEY),
+            encode_der(TAG_OID, OID_PRIME256V1),
+        ],
+    );
+
+    encode_der_list(
+        TAG_SEQUENCE,
+        &[algorithm, encode_bit_string(private_key.public_key().as_ref())],
+    )
+}
+
+fn encode_extension(oid: &[u8], is_critical: bool, value: &[u8]) -> Vec<u8> {
+    let mut extension = vec![encode_der(TAG_OID, oid)];
+    if is_critical {
+        extension.push(encode_der(TAG_BOOLEAN, &[0xFF]));
+    }
+    extension.push(encode_der(TAG_OCTET_STRING, value));
+
+    encode_der_list(TAG_SEQUENCE, &extension)
+}
+
+fn encode_subject_alt_names(domains: &[String]) -> Vec<u8> {
+    let general_names: Vec<Vec<u8>> = domains
+        .iter()
+        .map(|domain| encode_der(TAG_SAN_DNS_NAME, domain.as_bytes()))
+        .collect();
+
+    encode_extension(
+        OID_SUBJECT_ALT_NAME,
+        false,
+        &encode_der_list(TAG_SEQUENCE, &general_names),
+    )
+}
+
+fn encode_utc_time(timestamp: chrono::DateTime<Utc>) -> Vec<u8> {
+    encode_der(
+        TAG_UTC_TIME,
+        timestamp.format("%y%m%d%H%M%SZ").to_string().as_bytes(),
+    )
+}
+
+/// Signs given DER content and wraps it with ECDSA with SHA-256 algorithm identifier and signature
+fn sign_der(content: Vec<u8>, private_key: &EcdsaKeyPair) -> Result<Vec<u8>, String> {
+    let signature = private_key
+        .sign(&SystemRandom::new(), &content)
+        .map_err(|_| "DER content can not be signed".to_string())?;
+
+    Ok(encode_der_list(
+        TAG_SEQUENCE,
+        &[
+            content,
+            encode_der_list(TAG_SEQUENCE, &[encode_der(TAG_OID, OID_ECDSA_WITH_SHA256)]),
+            encode_bit_string(signature.as_ref()),
+        ],
+    ))
+}
+
+/// Creates a DER encoded PKCS#10 certificate signing request for given domains.
+/// `private_key` should be a P-256 key pair of `ECDSA_P256_SHA256_ASN1_SIGNING` algorithm
+pub fn create_certificate_signing_request(
+    domains: &[String],
+    private_key: &EcdsaKeyPair,
+) -> Result<Vec<u8>, String> {
+    let common_name = domains
+        .first()
+        .ok_or_else(|| "At least one domain is required for certificate signing request".to_string())?;
+
+    let extension_request = encode_der_list(
+        TAG_SEQUENCE,
+        &[
+            encode_der(TAG_OID, OID_EXTENSION_REQUEST),
+            encode_der(
+                TAG_SET,
+                &encode_der(TAG_SEQUENCE, &encode_subject_alt_names(domains)),
+            ),
+        ],
+    );
+
+    let certification_request_info = encode_der_list(
+        TAG_SEQUENCE,
+        &[
+            encode_integer(&[0x00]),
+            encode_common_name(common_name),
+            encode_public_key_info(private_key),
+            encode_der(TAG_ATTRIBUTES, &extension_request),
+        ],
+    );
+
+    sign_der(certification_request_info, private_key)
+}
+
+/// Creates a short living self signed certificate for answering TLS-ALPN-01 challenges (RFC 8737).
+/// `acme_identifier` is SHA-256 digest of the key authorization of the challenge
+pub fn create_tls_alpn_certificate(
+    domain: &str,
+    acme_identifier: &[u8],
+    private_key: &EcdsaKeyPair,
+) -> Result<Vec<u8>, String> {
+    let extensions = [
+        encode_subject_alt_names(&[domain.to_string()]),
+        encode_extension(
+            OID_ACME_IDENTIFIER,
+            true,
+            &encode_der(TAG_OCTET_STRING, acme_identifier),
+        ),
+    ];
+
+    create_self_signed_certificate(domain, Duration::days(7), &extensions, private_key)
+}
+
+/// Creates a self signed certificate without subject alternative names, served by routes whose own certificate can not be loaded.
+/// Clients are expected to reject it, but other routes keep their handshakes working
+pub fn create_default_certificate(common_name: &str, private_key: &EcdsaKeyPair) -> Result<Vec<u8>, String> {
+    create_self_signed_certificate(common_name, Duration::days(365), &[], private_key)
+}
+
+fn create_self_signed_certificate(
+    common_name: &str,
+    validity: Duration,
+    extensions: &[Vec<u8>],
+    private_key: &EcdsaKeyPair,
+) -> Result<Vec<u8>, String> {
+    let now = Utc::now();
+    let serial_number: [u8; 8] = rand::random();
+
+    let mut tbs_certificate_fields = vec![
+        encode_der(TAG_VERSION, &encode_integer(&[0x02])),
+        encode_integer(&serial_number),
+        encode_der_list(TAG_SEQUENCE, &[encode_der(TAG_OID, OID_ECDSA_WITH_SHA256)]),
+        encode_common_name(common_name),
+        encode_der_list(
+            TAG_SEQUENCE,
+            &[
+                encode_utc_time(now - Duration::days(1)),
+                encode_utc_time(now + validity),
+            ],
+        ),
+        encode_common_name(common_name),
+        encode_public_key_info(private_key),
+    ];
+
+    if !extensions.is_empty() {
+        tbs_certificate_fields.push(encode_der(
+            TAG_EXTENSIONS,
+            &encode_der_list(TAG_SEQUENCE, extensions),
+        ));
+    }
+
+    sign_der(encode_der_list(TAG_SEQUENCE, &tbs_certificate_fields), private_key)
+}
+
+/// Encodes DER content as PEM block with given label, e.g. `CERTIFICATE`
+pub fn encode_pem(label: &str, der: &[u8]) -> String {
+    let encoded = STANDARD.encode(der);
+    let lines: Vec<&str> = encoded
+        .as_bytes()
+        .chunks(64)
+        .map(|line| std::str::from_utf8(line).unwrap_or_default())
+        .collect();
+
+    format!(
+        "-----BEGIN {label}-----\n{}\n-----END {label}-----\n",
+        lines.join("\n"),
+        label = label
+    )
+}
+
+/// Decodes every PEM block of given content as its label & DER content, e.g. `("EC PRIVATE KEY", der)`
+pub fn decode_pem_blocks(content: &str) -> Result<Vec<(String, Vec<u8>)>, String> {
+    let mut blocks = Vec::new();
+    let mut current_block: Option<(String, String)> = None;
+
+    for line in content.lines().map(str::trim) {
+        if let Some(label) = line.strip_prefix("-----BEGIN ").and_then(|line| line.strip_suffix("-----")) {
+            current_block = Some((label.to_string(), String::new()));
+            continue;
+        }
+
+        if let Some(label) = line.strip_prefix("-----END ").and_then(|line| line.strip_suffix("-----")) {
+            let (begin_label, encoded) = current_block
+                .take()
+                .ok_or_else(|| format!("PEM block '{}' ends before it begins", label))?;
+
+            if begin_label != label {
+                return Err(format!("PEM block '{}' is not closed", begin_label));
+            }
+
+            let der = STANDARD
+                .decode(encoded)
+                .map_err(|_| format!("PEM block '{}' is not valid base64", label))?;
+
+            blocks.push((begin_label, der));
+            continue;
+        }
+
+        // Headers of legacy encrypted keys (e.g. `Proc-Type`) are kept out of base64 content
+        if let Some((_, encoded)) = current_block.as_mut() {
+            if !line.contains(':') {
+                encoded.push_str(line);
+            }
+        }
+    }
+
+    if let Some((label, _)) = current_block {
+        return Err(format!("PEM block '{}' is not closed", label));
+    }
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::signature::{UnparsedPublicKey, ECDSA_P256_SHA256_ASN1, ECDSA_P256_SHA256_ASN1_SIGNING};
+
+    /// RSA 2048 certificate with UTCTime validity, DNS, wildcard DNS, IPv4 & IPv6 subject alternative names
+    const RSA_CERTIFICATE: &str = "\
+-----BEGIN CERTIFICATE-----
+MIIDoDCCAoigAwIBAgIEEjSrzTANBgkqhkiG9w0BAQsFADBBMQswCQYDVQQGEwJU
+UjEWMBQGA1UECgwNVmFuZ3VhcmQgVGVzdDEaMBgGA1UEAwwRcnNhLnZhbmd1YXJk
+LnRlc3QwHhcNMjUwMTAxMDAwMDAwWhcNMzUwMTAxMDAwMDAwWjBBMQswCQYDVQQG
+EwJUUjEWMBQGA1UECgwNVmFuZ3VhcmQgVGVzdDEaMBgGA1UEAwwRcnNhLnZhbmd1
+YXJkLnRlc3QwggEiMA0GCSqGSIb3DQEBAQUAA4IBDwAwggEKAoIBAQCriOsWo+6/
+gYAxFtaGrfKrXSq4IdKgdQzRVaqqGwLrOGmTZ/NsD7XbYUrNEMYl9OyzPeL2lQXB
+lnDvzVs8RHQ5BIU34Yko+dMb2mqw0Be18Z0tF+65YsaX4+JtQ7fQpq3o7K+RcY0o
+6hwaTy9LO6eBpVVHYXEJkgutOZZTPErm0KE7RFnffxX/BK9vfevZPMlRIQ5Tztj1
+vMQbYZDC+DBoy/SO2tPBcpKKG61pqC3vxOHwxhxAEEnmP6oe/Ar0SaSSxR58oqJp
+JNoQVzUZduuvL9uiKCzM3CKojgciyZwJQKL11drGAjKxQmzmRLiPglxQV3LfS830
+FftyXtAFxl4DAgMBAAGjgZ8wgZwwHQYDVR0OBBYEFBofiwywLtpP6n+MTP4IKifB
+hjLrMB8GA1UdIwQYMBaAFBofiwywLtpP6n+MTP4IKifBhjLrMA8GA1UdEwEB/wQF
+MAMBAf8wSQYDVR0RBEIwQIIRcnNhLnZhbmd1YXJkLnRlc3SCEyoucnNhLnZhbmd1
+YXJkLnRlc3SHBH8AAAGHEAAAAAAAAAAAAAAAAAAAAAEwDQYJKoZIhvcNAQELBQAD
+ggEBAJp7eOxSmhAUSlnsIercaEKa/IXHNnwbSIsQLQDWP5K40r4uf3aaEEPtdnBv
+JPe+Ojwwpk2FO0D7hdgYKYva+tTs8ivYhOSlhBwiEXiLSlpf7dL0SbJ6AxLgR9HS
+j5NMUtqQZfYFhsRMnbpXiwXWM2D/Fs7jxWZTBv1m+LiV5bBmEh7PyHXVKWtVH28o
+w2ZE353WKH5+GFYSVPjuATLzEPMZuMIFkgKT2f1S+fOwsFLExF8F6YURKn+fYwra
+xrZX/gIEtAdntj/Nfex3yGR3r2XQ1ZCMkJZiQWFuEAbY4DsM80jspIEE5Hdggfk4
+CRs1MrhVAys5+byIxT5QeNvNKBA=
+-----END CERTIFICATE-----
+";
+
+    /// ECDSA P-256 certificate valid from 1999 (UTCTime) to 2060 (GeneralizedTime), serial number 0x80FF
+    const ECDSA_CERTIFICATE: &str = "\
+-----BEGIN CERTIFICATE-----
+MIIBnzCCAUWgAwIBAgIDAID/MAoGCCqGSM49BAMCMBsxGTAXBgNVBAMMEGVjLnZh
+bmd1YXJkLnRlc3QwIBcNOTkxMjMxMjM1OTU5WhgPMjA2MDAxMDEwMDAwMDBaMBsx
+GTAXBgNVBAMMEGVjLnZhbmd1YXJkLnRlc3QwWTATBgcqhkjOPQIBBggqhkjOPQMB
+BwNCAAQ077U9JjVV0FHJ74Ifn1zoxy6OND9CJlk+/sp2b6yuuW2koDyNqAIOBw/Z
+Ex9ta9wpcmUDnTkkSEL/BM8fHmZ6o3YwdDAdBgNVHQ4EFgQUd68eUhXBwOPSYpfr
+617Jxpn2A6QwHwYDVR0jBBgwFoAUd68eUhXBwOPSYpfr617Jxpn2A6QwDwYDVR0T
+AQH/BAUwAwEB/zAhBgNVHREEGjAYghBlYy52YW5ndWFyZC50ZXN0hwQKAAABMAoG
+CCqGSM49BAMCA0gAMEUCIGHpSTc8v/MYrGFjJ7lRSZR6lZmu1FCW4i9O2UJDzc+u
+AiEAgHcDtcZGVWzhdW7TP4YTOwfN0w4fLH8HkHI/BKXMYSk=
+-----END CERTIFICATE-----
+";
+
+    fn generate_key_pair() -> EcdsaKeyPair {
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &SystemRandom::new()).unwrap();
+        EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref()).unwrap()
+    }
+
+    /// Signed DER structure (certificate or CSR) split into signed content, its fields and signature
+    struct SignedDer {
+        signed_content: Vec<u8>,
+        fields: Vec<(u8, Vec<u8>)>,
+        signature: Vec<u8>,
+    }
+
+    fn read_signed_der(signed_der: &[u8]) -> SignedDer {
+        let mut reader = DerReader::new(DerReader::new(signed_der).read_expected(TAG_SEQUENCE).unwrap());
+        let signed_content = reader.read_expected(TAG_SEQUENCE).unwrap();
+        let signature_algorithm = reader.read_expected(TAG_SEQUENCE).unwrap();
+        let signature = reader.read_expected(TAG_BIT_STRING).unwrap();
+
+        assert_eq!(signature_algorithm, encode_der(TAG_OID, OID_ECDSA_WITH_SHA256));
+        assert_eq!(signature[0], 0x00);
+
+        let mut fields = Vec::new();
+        let mut fields_reader = DerReader::new(signed_content);
+        while !fields_reader.is_empty() {
+            let (tag, content) = fields_reader.read().unwrap();
+            fields.push((tag, content.to_vec()));
+        }
+
+        SignedDer {
+            signed_content: encode_der(TAG_SEQUENCE, signed_content),
+            fields,
+            signature: signature[1..].to_vec(),
+        }
+    }
+
+    fn verify_signature(signed_der: &[u8], private_key: &EcdsaKeyPair) {
+        let signed_der = read_signed_der(signed_der);
+
+        UnparsedPublicKey::new(&ECDSA_P256_SHA256_ASN1, private_key.public_key().as_ref())
+            .verify(&signed_der.signed_content, &signed_der.signature)
+            .expect("signature should be valid");
+    }
+
+    /// Returns (critical flag, value) of the extension with given oid in a list of extensions
+    fn find_extension(extensions: &[u8], expected_oid: &[u8]) -> Option<(bool, Vec<u8>)> {
+        let mut extensions_reader = DerReader::new(DerReader::new(extensions).read_expected(TAG_SEQUENCE).unwrap());
+
+        while !extensions_reader.is_empty() {
+            let mut extension_reader = DerReader::new(extensions_reader.read_expected(TAG_SEQUENCE).unwrap());
+            let oid = extension_reader.read_expected(TAG_OID).unwrap();
+            let is_critical = extension_reader.peek_tag() == Some(TAG_BOOLEAN)
+                && extension_reader.read().unwrap().1 == [0xFF];
+            let value = extension_reader.read_expected(TAG_OCTET_STRING).unwrap();
+
+            if oid == expected_oid {
+                return Some((is_critical, value.to_vec()));
+            }
+        }
+
+        None
+    }
+
+    fn read_test_time(tag: u8, text: &str) -> Result<i64, String> {
+        read_time(&mut DerReader::new(&encode_der(tag, text.as_bytes())))
+    }
+
+    #[test]
+    fn parses_rsa_certificate() {
+        let certificate = &parse_pem_certificates(RSA_CERTIFICATE).unwrap()[0];
+
+        assert_eq!(certificate.subject, "C=TR, O=Vanguard Test, CN=rsa.vanguard.test");
+        assert_eq!(certificate.issuer, certificate.subject);
+        assert_eq!(certificate.common_name.as_deref(), Some("rsa.vanguard.test"));
+        assert_eq!(certificate.serial_number, "1234ABCD");
+        assert_eq!(certificate.not_before, 1735689600); // 2025-01-01T00:00:00Z
+        assert_eq!(certificate.not_after, 2051222400); // 2035-01-01T00:00:00Z
+    }
+
+    #[test]
+    fn parses_ecdsa_certificate() {
+        let certificate = &parse_pem_certificates(ECDSA_CERTIFICATE).unwrap()[0];
+
+        assert_eq!(certificate.subject, "CN=ec.vanguard.test");
+        assert_eq!(certificate.common_name.as_deref(), Some("ec.vanguard.test"));
+        assert_eq!(certificate.serial_number, "80FF");
+        assert_eq!(certificate.not_before, 946684799); // 1999-12-31T23:59:59Z as UTCTime
+        assert_eq!(certificate.not_after, 2840140800); // 2060-01-01T00:00:00Z as GeneralizedTime
+    }
+
+    #[test]
+    fn parses_dns_and_ip_subject_alt_names() {
+        let rsa_certificate = &parse_pem_certificates(RSA_CERTIFICATE).unwrap()[0];
+        let ecdsa_certificate = &parse_pem_certificates(ECDSA_CERTIFICATE).unwrap()[0];
+
+        assert_eq!(
+            rsa_certificate.subject_alt_names,
+            vec!["rsa.vanguard.test", "*.rsa.vanguard.test", "127.0.0.1", "::1"]
+        );
+        assert_eq!(ecdsa_certificate.subject_alt_names, vec!["ec.vanguard.test", "10.0.0.1"]);
+    }
+
+    #[test]
+    fn parses_certificate_chain_in_order() {
+        let chain = format!("{}{}", ECDSA_CERTIFICATE, RSA_CERTIFICATE);
+        let certificates = parse_pem_certificates(&chain).unwrap();
+
+        let common_names: Vec<_> = certificates
+            .iter()
+            .map(|certificate| certificate.common_name.as_deref().unwrap())
+            .collect();
+        assert_eq!(common_names, vec!["ec.vanguard.test", "rsa.vanguard.test"]);
+    }
+
+    #[test]
+    fn rejects_truncated_certificate() {
+        let (_, certificate_der) = decode_pem_blocks(RSA_CERTIFICATE).unwrap().remove(0);
+
+        assert!(parse_certificate(&certificate_der[..certificate_der.len() / 2]).is_err());
+        assert!(parse_certificate(&[]).is_err());
+    }
+
+    #[test]
+    fn reads_utc_and_generalized_time() {
+        // RFC 5280: UTCTime years 00-49 are 20xx and 50-99 are 19xx
+        assert_eq!(read_test_time(TAG_UTC_TIME, "491231235959Z"), Ok(2524607999));
+        assert_eq!(read_test_time(TAG_UTC_TIME, "500101000000Z"), Ok(-631152000));
+        assert_eq!(read_test_time(TAG_GENERALIZED_TIME, "20491231235959Z"), Ok(2524607999));
+        assert_eq!(read_test_time(TAG_GENERALIZED_TIME, "19500101000000Z"), Ok(-631152000));
+
+        assert!(read_test_time(TAG_UTC_TIME, "491331235959Z").is_err());
+        assert!(read_test_time(TAG_UTC_TIME, "4912312359+0100").is_err());
+        assert!(read_test_time(TAG_GENERALIZED_TIME, "491231235959Z").is_err());
+        assert!(read_test_time(TAG_UTF8_STRING, "491231235959Z").is_err());
+    }
+
+    #[test]
+    fn pem_encoding_round_trips() {
+        let blocks = decode_pem_blocks(&format!("{}{}", RSA_CERTIFICATE, ECDSA_CERTIFICATE)).unwrap();
+        assert_eq!(blocks.len(), 2);
+
+        assert_eq!(encode_pem(&blocks[0].0, &blocks[0].1), RSA_CERTIFICATE);
+        assert_eq!(encode_pem(&blocks[1].0, &blocks[1].1), ECDSA_CERTIFICATE);
+    }
+
+    #[test]
+    fn rejects_unclosed_pem_blocks() {
+        assert!(decode_pem_blocks("-----BEGIN CERTIFICATE-----\nAAAA\n").is_err());
+        assert!(decode_pem_blocks("-----BEGIN CERTIFICATE-----\nAAAA\n-----END PRIVATE KEY-----\n").is_err());
+        assert!(decode_pem_blocks("-----BEGIN CERTIFICATE-----\n!!!!\n-----END CERTIFICATE-----\n").is_err());
+    }
+
+    #[test]
+    fn default_certificate_round_trips() {
+        let private_key = generate_key_pair();
+        let certificate_der = create_default_certificate("fallback.vanguard.test", &private_key).unwrap();
+        let certificate = parse_certificate(&certificate_der).unwrap();
+
+        assert_eq!(certificate.subject, "CN=fallback.vanguard.test");
+        assert_eq!(certificate.issuer, certificate.subject);
+        assert!(certificate.subject_alt_names.is_empty());
+        assert_eq!(certificate.not_after - certificate.not_before, Duration::days(366).num_seconds());
+        assert!(certificate.not_before < Utc::now().timestamp());
+
+        verify_signature(&certificate_der, &private_key);
+
+        let pem = encode_pem("CERTIFICATE", &certificate_der);
+        assert_eq!(parse_pem_certificates(&pem).unwrap()[0].serial_number, certificate.serial_number);
+    }
+
+    #[test]
+    fn tls_alpn_certificate_round_trips() {
+        let private_key = generate_key_pair();
+        let acme_identifier = [0xABu8; 32];
+        let certificate_der =
+            create_tls_alpn_certificate("acme.vanguard.test", &acme_identifier, &private_key).unwrap();
+        let certificate = parse_certificate(&certificate_der).unwrap();
+
+        assert_eq!(certificate.common_name.as_deref(), Some("acme.vanguard.test"));
+        assert_eq!(certificate.subject_alt_names, vec!["acme.vanguard.test"]);
+        assert_eq!(certificate.not_after - certificate.not_before, Duration::days(8).num_seconds());
+
+        verify_signature(&certificate_der, &private_key);
+
+        // RFC 8737: acmeIdentifier extension is critical and holds an octet string of the digest
+        let fields = read_signed_der(&certificate_der).fields;
+        let (_, extensions) = fields.iter().find(|(tag, _)| *tag == TAG_EXTENSIONS).unwrap();
+        let (is_critical, value) = find_extension(extensions, OID_ACME_IDENTIFIER).unwrap();
+
+        assert!(is_critical);
+        assert_eq!(value, encode_der(TAG_OCTET_STRING, &acme_identifier));
+    }
+
+    #[test]
+    fn certificate_signing_request_round_trips() {
+        let private_key = generate_key_pair();
+        let domains = vec!["vanguard.test".to_string(), "www.vanguard.test".to_string()];
+        let csr_der = create_certificate_signing_request(&domains, &private_key).unwrap();
+
+        verify_signature(&csr_der, &private_key);
+
+        let fields = read_signed_der(&csr_der).fields;
+        let tags: Vec<u8> = fields.iter().map(|(tag, _)| *tag).collect();
+        assert_eq!(tags, vec![TAG_INTEGER, TAG_SEQUENCE, TAG_SEQUENCE, TAG_ATTRIBUTES]);
+
+        assert_eq!(fields[0].1, vec![0x00]);
+        assert_eq!(format_name(&read_name(&fields[1].1).unwrap()), "CN=vanguard.test");
+        assert_eq!(encode_der(TAG_SEQUENCE, &fields[2].1), encode_public_key_info(&private_key));
+
+        let extension_request = DerReader::new(&fields[3].1).read_expected(TAG_SEQUENCE).unwrap();
+        let mut extension_request_reader = DerReader::new(extension_request);
+        assert_eq!(extension_request_reader.read_expected(TAG_OID).unwrap(), OID_EXTENSION_REQUEST);
+
+        // Extensions are requested as a single SEQUENCE, the same content as `[3] EXPLICIT` extensions of a certificate
+        let extensions = extension_request_reader.read_expected(TAG_SET).unwrap();
+        assert_eq!(read_subject_alt_names(extensions).unwrap(), domains);
+    }
+
+    #[test]
+    fn certificate_signing_request_needs_a_domain() {
+        assert!(create_certificate_signing_request(&[], &generate_key_pair()).is_err());
+    }
+}